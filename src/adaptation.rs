@@ -0,0 +1,361 @@
+use super::*;
+
+/// A standard white point, as defined by the CIE for the 2° observer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Illuminant {
+    /// Incandescent light, about 2856 K.
+    A,
+    /// Direct sunlight at noon, about 4874 K. Obsolete.
+    B,
+    /// Average daylight, about 6774 K. Obsolete, replaced by [`Illuminant::D65`].
+    C,
+    /// Horizon light, about 5003 K. This is the white point of ICC profiles and print workflows.
+    D50,
+    /// Mid-morning or mid-afternoon daylight, about 5503 K.
+    D55,
+    /// Noon daylight, about 6504 K. This is the white point of sRGB, and of [`Color`].
+    D65,
+    /// North sky daylight, about 7504 K.
+    D75,
+    /// The equal energy illuminant, which has the same power at every wavelength.
+    E,
+    /// Cool white fluorescent light, about 4230 K.
+    F2,
+    /// Broadband daylight fluorescent light, about 6500 K.
+    F7,
+    /// Narrow tri-band fluorescent light, about 4000 K.
+    F11,
+}
+
+impl Illuminant {
+    /// Gets the CIE XYZ values of the white point, with Y = 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Illuminant};
+    ///
+    /// let (x, y, z) = Illuminant::D65.white_point();
+    /// let (x2, y2, z2) = constants::primary::WHITE.to_xyz();
+    ///
+    /// assert!((x - x2).abs() < 0.001 && (y - y2).abs() < 0.001 && (z - z2).abs() < 0.001);
+    /// ```
+    pub fn white_point(self) -> (f32, f32, f32) {
+        match self {
+            Self::A => (1.098_5, 1.0, 0.355_85),
+            Self::B => (0.990_72, 1.0, 0.852_23),
+            Self::C => (0.980_74, 1.0, 1.182_32),
+            Self::D50 => (0.964_22, 1.0, 0.825_21),
+            Self::D55 => (0.956_82, 1.0, 0.921_49),
+            Self::D65 => (0.950_47, 1.0, 1.088_83),
+            Self::D75 => (0.949_72, 1.0, 1.226_38),
+            Self::E => (1.0, 1.0, 1.0),
+            Self::F2 => (0.991_86, 1.0, 0.673_93),
+            Self::F7 => (0.950_41, 1.0, 1.087_47),
+            Self::F11 => (1.009_62, 1.0, 0.643_5),
+        }
+    }
+}
+
+/// The method used by [`adapt_with()`](Color#method.adapt_with) to estimate how colors look under
+/// a different white point. Each one converts to a different model of the cone responses of the
+/// eye, and scales them by the ratio of the white points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChromaticAdaptation {
+    /// The Bradford transform, which is used by ICC profiles. The default, and a good choice for
+    /// most uses.
+    #[default]
+    Bradford,
+    /// The von Kries transform with the Hunt-Pointer-Estevez cone responses, which is the
+    /// simplest but least accurate.
+    VonKries,
+    /// The transform of the CAM16 color appearance model, which is the most recent.
+    Cat16,
+}
+
+impl ChromaticAdaptation {
+    /// Gets the matrix from CIE XYZ to the cone responses, and its inverse.
+    fn matrices(self) -> (Matrix, Matrix) {
+        match self {
+            Self::Bradford => (
+                [
+                    [0.8951, 0.2664, -0.1614],
+                    [-0.7502, 1.7135, 0.0367],
+                    [0.0389, -0.0685, 1.0296],
+                ],
+                [
+                    [0.986_993, -0.147_054_3, 0.159_962_7],
+                    [0.432_305_3, 0.518_360_3, 0.049_291_2],
+                    [-0.008_528_7, 0.040_042_8, 0.968_486_7],
+                ],
+            ),
+            Self::VonKries => (
+                [
+                    [0.400_24, 0.707_6, -0.080_81],
+                    [-0.226_3, 1.165_32, 0.045_7],
+                    [0.0, 0.0, 0.918_22],
+                ],
+                [
+                    [1.859_936_4, -1.129_381_6, 0.219_897_4],
+                    [0.361_191_4, 0.638_812_5, -0.000_006_4],
+                    [0.0, 0.0, 1.089_063_6],
+                ],
+            ),
+            Self::Cat16 => (
+                [
+                    [0.401_288, 0.650_173, -0.051_461],
+                    [-0.250_268, 1.204_414, 0.045_854],
+                    [-0.002_079, 0.048_952, 0.953_127],
+                ],
+                [
+                    [1.862_067_9, -1.011_254_6, 0.149_186_8],
+                    [0.387_526_5, 0.621_447_4, -0.008_973_98],
+                    [-0.015_841_5, -0.034_122_94, 1.049_964_4],
+                ],
+            ),
+        }
+    }
+
+    /// Adapts CIE XYZ values seen under the white point `from` to how they would look under the
+    /// white point `to`, by scaling the cone responses.
+    pub(crate) fn adapt(
+        self,
+        xyz: (f32, f32, f32),
+        from: (f32, f32, f32),
+        to: (f32, f32, f32),
+    ) -> (f32, f32, f32) {
+        let (forward, inverse) = self.matrices();
+        let (l, m, s) = transform(&forward, xyz);
+        let from = transform(&forward, from);
+        let to = transform(&forward, to);
+        transform(
+            &inverse,
+            (l * to.0 / from.0, m * to.1 / from.1, s * to.2 / from.2),
+        )
+    }
+}
+
+/// Gets the CIE XYZ values (Y = 1.0) of the white point of a black body at `kelvin`, using the
+/// cubic spline approximation of the Planckian locus by Kim et al.
+fn planckian_white(kelvin: f32) -> (f32, f32, f32) {
+    if !(1667.0..=25000.0).contains(&kelvin) {
+        panic!("Unsupported temperature: {kelvin}");
+    }
+    let t = kelvin;
+    let x = if t <= 4000.0 {
+        -0.266_123_9e9 / t.powi(3) - 0.234_358_9e6 / t.powi(2) + 0.877_695_6e3 / t + 0.179_910
+    } else {
+        -3.025_846_9e9 / t.powi(3) + 2.107_038e6 / t.powi(2) + 0.222_634_7e3 / t + 0.240_390
+    };
+    let y = if t <= 2222.0 {
+        -1.106_381_4 * x.powi(3) - 1.348_110_2 * x.powi(2) + 2.185_558_3 * x - 0.202_196_83
+    } else if t <= 4000.0 {
+        -0.954_947_6 * x.powi(3) - 1.374_185_9 * x.powi(2) + 2.091_37 * x - 0.167_488_67
+    } else {
+        3.081_758 * x.powi(3) - 5.873_387 * x.powi(2) + 3.751_13 * x - 0.370_014_83
+    };
+    (x / y, 1.0, (1.0 - x - y) / y)
+}
+
+impl Color {
+    /// Corrects the white balance of a color which was captured under light with the color
+    /// temperature `from_kelvin`, so it looks as if it was captured under `to_kelvin` instead.
+    /// For example, `white_balance(3000.0, 6500.0)` neutralizes the orange cast of warm indoor
+    /// lighting. This uses the Bradford chromatic adaptation transform.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `from_kelvin` or `to_kelvin` is < 1667.0 or > 25000.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let paper = Color::new(255, 200, 140);
+    /// let corrected = paper.white_balance(3000.0, 6500.0);
+    ///
+    /// assert!(corrected.get_hsl_saturation() < paper.get_hsl_saturation());
+    /// assert_eq!(paper.white_balance(5000.0, 5000.0), paper);
+    /// ```
+    pub fn white_balance(&self, from_kelvin: f32, to_kelvin: f32) -> Self {
+        let (x, y, z) = ChromaticAdaptation::Bradford.adapt(
+            self.to_xyz(),
+            planckian_white(from_kelvin),
+            planckian_white(to_kelvin),
+        );
+        Self::from_xyz(x, y, z)
+    }
+
+    /// Estimates how the color, seen under the white point `from`, looks under the white point
+    /// `to`, using the Bradford transform. Same as [`adapt_with()`](Color#method.adapt_with) with
+    /// [`ChromaticAdaptation::Bradford`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Illuminant};
+    ///
+    /// let white = constants::primary::WHITE.adapt(Illuminant::D65, Illuminant::A);
+    ///
+    /// assert!(white.get_red() > white.get_blue());
+    /// ```
+    pub fn adapt(&self, from: Illuminant, to: Illuminant) -> Self {
+        self.adapt_with(from, to, ChromaticAdaptation::Bradford)
+    }
+
+    /// Estimates how the color, seen under the white point `from`, looks under the white point
+    /// `to`, using the chromatic adaptation transform `method`. Colors that fall outside of the
+    /// RGB gamut are clamped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{ChromaticAdaptation, Color, Illuminant};
+    ///
+    /// let color = Color::new(100, 150, 200);
+    /// let back = color
+    ///     .adapt_with(Illuminant::D65, Illuminant::D50, ChromaticAdaptation::Cat16)
+    ///     .adapt_with(Illuminant::D50, Illuminant::D65, ChromaticAdaptation::Cat16);
+    ///
+    /// assert!(back.approx_eq(&color, 1));
+    /// ```
+    pub fn adapt_with(
+        &self,
+        from: Illuminant,
+        to: Illuminant,
+        method: ChromaticAdaptation,
+    ) -> Self {
+        let (x, y, z) = method.adapt(self.to_xyz(), from.white_point(), to.white_point());
+        Self::from_xyz(x, y, z)
+    }
+
+    /// Converts a color to CIE XYZ relative to the white point `illuminant` instead of D65, using
+    /// the Bradford transform. With [`Illuminant::D50`], this gives the XYZ values expected by
+    /// ICC profiles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Illuminant};
+    ///
+    /// let (x, y, z) = constants::primary::WHITE.to_xyz_under(Illuminant::D50);
+    /// let (x2, y2, z2) = Illuminant::D50.white_point();
+    ///
+    /// assert!((x - x2).abs() < 0.001 && (y - y2).abs() < 0.001 && (z - z2).abs() < 0.001);
+    /// ```
+    pub fn to_xyz_under(&self, illuminant: Illuminant) -> (f32, f32, f32) {
+        ChromaticAdaptation::Bradford.adapt(
+            self.to_xyz(),
+            Illuminant::D65.white_point(),
+            illuminant.white_point(),
+        )
+    }
+
+    /// Converts CIE XYZ relative to the white point `illuminant` to a color, using the Bradford
+    /// transform. This is the inverse of [`to_xyz_under()`](Color#method.to_xyz_under).
+    ///
+    /// # Note
+    ///
+    /// Colors that fall outside of the RGB gamut are clamped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, Illuminant};
+    ///
+    /// let color = Color::new(100, 150, 200);
+    /// let (x, y, z) = color.to_xyz_under(Illuminant::D50);
+    ///
+    /// assert!(Color::from_xyz_under(x, y, z, Illuminant::D50).approx_eq(&color, 1));
+    /// ```
+    pub fn from_xyz_under(x: f32, y: f32, z: f32, illuminant: Illuminant) -> Self {
+        let (x, y, z) = ChromaticAdaptation::Bradford.adapt(
+            (x, y, z),
+            illuminant.white_point(),
+            Illuminant::D65.white_point(),
+        );
+        Self::from_xyz(x, y, z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn planckian_white() {
+        let (x, y, z) = super::planckian_white(6504.0);
+        let (x, y) = (x / (x + y + z), y / (x + y + z));
+        assert!((x - 0.3135).abs() < 0.001 && (y - 0.3236).abs() < 0.001);
+        let (x, y, z) = super::planckian_white(2856.0);
+        let (x, y) = (x / (x + y + z), y / (x + y + z));
+        assert!((x - 0.4476).abs() < 0.001 && (y - 0.4074).abs() < 0.001);
+    }
+
+    #[test]
+    fn white_balance() {
+        let color = Color::new(100, 150, 200);
+        assert!(color.white_balance(6500.0, 6500.0).approx_eq(&color, 1));
+        let warm = color.white_balance(6500.0, 3000.0);
+        assert!(warm.get_red() > color.get_red() && warm.get_blue() < color.get_blue());
+        assert!(warm.white_balance(3000.0, 6500.0).approx_eq(&color, 1));
+        let (x, y, z) = super::planckian_white(3000.0);
+        let warm_white = Color::from_xyz(x * 0.5, y * 0.5, z * 0.5);
+        let neutral = warm_white.white_balance(3000.0, 6504.0);
+        assert!(neutral.get_hsl_saturation() < 0.05, "{neutral:?}");
+    }
+
+    #[test]
+    fn matrices() {
+        for method in [
+            ChromaticAdaptation::Bradford,
+            ChromaticAdaptation::VonKries,
+            ChromaticAdaptation::Cat16,
+        ] {
+            let (forward, inverse) = method.matrices();
+            for (i, expected) in [(1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0)]
+                .into_iter()
+                .enumerate()
+            {
+                let column = (forward[0][i], forward[1][i], forward[2][i]);
+                let (a, b, c) = transform(&inverse, column);
+                assert!(
+                    (a - expected.0).abs() < 0.0001
+                        && (b - expected.1).abs() < 0.0001
+                        && (c - expected.2).abs() < 0.0001,
+                    "{method:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn adapt() {
+        let color = Color::new(100, 150, 200);
+        assert!(color
+            .adapt(Illuminant::D65, Illuminant::D65)
+            .approx_eq(&color, 1));
+        for method in [
+            ChromaticAdaptation::Bradford,
+            ChromaticAdaptation::VonKries,
+            ChromaticAdaptation::Cat16,
+        ] {
+            let warm = color.adapt_with(Illuminant::D65, Illuminant::A, method);
+            assert!(warm.get_red() > color.get_red(), "{method:?}");
+            let back = warm.adapt_with(Illuminant::A, Illuminant::D65, method);
+            assert!(back.approx_eq(&color, 2), "{method:?}: {back:?}");
+        }
+        let (x, y, z) = Illuminant::D50.white_point();
+        assert_eq!(
+            Color::from_xyz_under(x, y, z, Illuminant::D50),
+            constants::primary::WHITE
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported temperature: 1000")]
+    fn white_balance_range() {
+        constants::primary::WHITE.white_balance(1000.0, 6500.0);
+    }
+}
@@ -0,0 +1,187 @@
+use super::*;
+use std::collections::{BTreeSet, HashMap};
+use std::hash::Hash;
+
+/// The lightness and chroma in OKLCH of generated colors, which are readable on both light and
+/// dark backgrounds.
+const GENERATED_LIGHTNESS: f32 = 0.7;
+const GENERATED_CHROMA: f32 = 0.14;
+
+/// Hands out colors to IDs, such as the series of a chart or the services of a trace, so that the
+/// same ID always gets the same color while it is assigned. Colors are taken from a [`Palette`]
+/// or generated by spacing hues with the golden ratio. When an ID is released, its color is given
+/// to the next new ID, so colors stay as distinct as possible. Requires the `std` feature.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{ColorAssigner, constants, Palette};
+///
+/// let palette = Palette::from(vec![constants::primary::RED, constants::primary::BLUE]);
+/// let mut assigner = ColorAssigner::from_palette(palette);
+///
+/// assert_eq!(assigner.get("api"), constants::primary::RED);
+/// assert_eq!(assigner.get("db"), constants::primary::BLUE);
+/// assert_eq!(assigner.get("api"), constants::primary::RED);
+///
+/// assigner.release(&"api");
+/// assert_eq!(assigner.get("cache"), constants::primary::RED);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColorAssigner<K> {
+    palette: Option<Palette>,
+    slots: HashMap<K, usize>,
+    free: BTreeSet<usize>,
+    next: usize,
+}
+
+impl<K: Hash + Eq> ColorAssigner<K> {
+    /// Creates an assigner which generates colors with hues spaced by the golden ratio, so any
+    /// number of IDs can get a color, and consecutive colors are far apart.
+    pub fn new() -> Self {
+        Self {
+            palette: None,
+            slots: HashMap::new(),
+            free: BTreeSet::new(),
+            next: 0,
+        }
+    }
+
+    /// Creates an assigner which hands out the colors of `palette` in order. When there are more
+    /// IDs than colors, the colors are reused from the start.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `palette` is empty.
+    pub fn from_palette(palette: Palette) -> Self {
+        if palette.is_empty() {
+            panic!("Unsupported palette: a color assigner needs at least 1 color");
+        }
+        Self {
+            palette: Some(palette),
+            ..Self::new()
+        }
+    }
+
+    /// Gets the color of `id`, assigning the next free color when it doesn't have one.
+    pub fn get(&mut self, id: K) -> Color {
+        let slot = match self.slots.get(&id) {
+            Some(&slot) => slot,
+            None => {
+                let slot = self.free.pop_first().unwrap_or_else(|| {
+                    self.next += 1;
+                    self.next - 1
+                });
+                self.slots.insert(id, slot);
+                slot
+            }
+        };
+        self.color(slot)
+    }
+
+    /// Gets the color of `id` without assigning one. Returns `None` when `id` doesn't have a
+    /// color.
+    pub fn peek(&self, id: &K) -> Option<Color> {
+        self.slots.get(id).map(|&slot| self.color(slot))
+    }
+
+    /// Releases the color of `id`, so it can be given to a new ID. Returns the color, or `None`
+    /// when `id` didn't have one.
+    pub fn release(&mut self, id: &K) -> Option<Color> {
+        let slot = self.slots.remove(id)?;
+        self.free.insert(slot);
+        Some(self.color(slot))
+    }
+
+    /// Gets the number of IDs which have a color.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Checks whether no ID has a color.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Releases the colors of every ID, so the next ID gets the first color again.
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.free.clear();
+        self.next = 0;
+    }
+
+    fn color(&self, slot: usize) -> Color {
+        match &self.palette {
+            Some(palette) => palette[slot % palette.len()],
+            None => {
+                let hue = (slot as f32 * 0.618_034).fract();
+                Oklch::new(GENERATED_LIGHTNESS, GENERATED_CHROMA, hue)
+                    .gamut_map(GamutMapping::ChromaReduction)
+            }
+        }
+    }
+}
+
+impl<K: Hash + Eq> Default for ColorAssigner<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated() {
+        let mut assigner = ColorAssigner::new();
+        let colors: Vec<_> = (0..10).map(|id| assigner.get(id)).collect();
+        assert_eq!(assigner.len(), 10);
+        for (i, a) in colors.iter().enumerate() {
+            for b in &colors[i + 1..] {
+                assert!(a.delta_e(b) > 5.0, "{a:?} {b:?}");
+            }
+        }
+        assert!(colors[0].delta_e(&colors[1]) > 20.0);
+        assert_eq!(
+            (0..10).map(|id| assigner.get(id)).collect::<Vec<_>>(),
+            colors
+        );
+        assert_eq!(assigner.peek(&3), Some(colors[3]));
+        assert_eq!(assigner.peek(&10), None);
+    }
+
+    #[test]
+    fn release() {
+        let mut assigner = ColorAssigner::new();
+        let colors: Vec<_> = (0..4).map(|id| assigner.get(id)).collect();
+        assert_eq!(assigner.release(&2), Some(colors[2]));
+        assert_eq!(assigner.release(&0), Some(colors[0]));
+        assert_eq!(assigner.release(&0), None);
+        assert_eq!(assigner.get(10), colors[0]);
+        assert_eq!(assigner.get(11), colors[2]);
+        assert_ne!(assigner.get(12), colors[3]);
+        assigner.clear();
+        assert!(assigner.is_empty());
+        assert_eq!(assigner.get(20), colors[0]);
+    }
+
+    #[test]
+    fn palette() {
+        let palette = Palette::categorical(3);
+        let mut assigner = ColorAssigner::from_palette(palette.clone());
+        for id in ["a", "b", "c", "d"] {
+            assigner.get(id);
+        }
+        assert_eq!(assigner.peek(&"a"), Some(palette[0]));
+        assert_eq!(assigner.peek(&"d"), Some(palette[0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported palette")]
+    fn empty_palette() {
+        ColorAssigner::<u32>::from_palette(Palette::new());
+    }
+}
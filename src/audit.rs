@@ -0,0 +1,552 @@
+use super::*;
+use alloc::{string::String, vec::Vec};
+use core::fmt::{Formatter, Write};
+
+/// The WCAG AA contrast ratio required for normal text.
+const MIN_CONTRAST_RATIO: f32 = 4.5;
+
+/// Colors with a Delta E below this are hard to tell apart at a glance.
+const MIN_DISTINGUISHABLE_DELTA_E: f32 = 10.0;
+
+/// The result of auditing a palette, as returned by
+/// [`audit_contrast()`](Palette#method.audit_contrast) and
+/// [`audit_distinguishability()`](Palette#method.audit_distinguishability). Its [`Display`]
+/// implementation lists every failure, so it can be used directly in assertion messages.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{constants, Palette};
+///
+/// let palette = Palette::from(vec![constants::primary::BLACK, constants::primary::BLUE]);
+/// let report = palette.audit_contrast(&constants::primary::WHITE);
+///
+/// assert!(report.passed(), "{report}");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditReport<T> {
+    threshold: f32,
+    failures: Vec<T>,
+}
+
+impl<T> AuditReport<T> {
+    /// Checks whether the palette passed the audit, meaning that there are no failures.
+    #[inline]
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Gets the failures, in the order of the colors in the palette.
+    #[inline]
+    pub fn failures(&self) -> &[T] {
+        &self.failures
+    }
+
+    /// Gets the minimum contrast ratio or Delta E which the colors had to reach.
+    #[inline]
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+}
+
+impl<T: Display> Display for AuditReport<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.passed() {
+            return write!(f, "no failures below {}", self.threshold);
+        }
+        write!(
+            f,
+            "{} failures below {}:",
+            self.failures.len(),
+            self.threshold
+        )?;
+        for failure in &self.failures {
+            write!(f, "\n{failure}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A color without enough contrast against the background, as reported by
+/// [`audit_contrast()`](Palette#method.audit_contrast).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastFailure {
+    /// The index of the color in the palette.
+    pub index: usize,
+    /// The color itself.
+    pub color: Color,
+    /// The contrast ratio between the color and the background.
+    pub ratio: f32,
+}
+
+impl Display for ContrastFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "color {} ({}) has a contrast ratio of {:.2}",
+            self.index,
+            self.color.to_css_string(),
+            self.ratio
+        )
+    }
+}
+
+/// Two colors which are hard to tell apart, as reported by
+/// [`audit_distinguishability()`](Palette#method.audit_distinguishability).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DistinguishabilityFailure {
+    /// The indices of the colors in the palette, with the lower one first.
+    pub indices: (usize, usize),
+    /// The colors themselves, without the deficiency simulated.
+    pub colors: (Color, Color),
+    /// The Delta E between the colors as they are seen with the deficiency.
+    pub delta_e: f32,
+}
+
+impl Display for DistinguishabilityFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "colors {} ({}) and {} ({}) have a Delta E of {:.2}",
+            self.indices.0,
+            self.colors.0.to_css_string(),
+            self.indices.1,
+            self.colors.1.to_css_string(),
+            self.delta_e
+        )
+    }
+}
+
+/// The WCAG level that a contrast ratio between text and its background reaches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WcagLevel {
+    /// Below 3.0, which is not enough for any text.
+    Fail,
+    /// At least 3.0, which is enough for large text under AA.
+    AaLarge,
+    /// At least 4.5, which is enough for normal text under AA (and large text under AAA).
+    Aa,
+    /// At least 7.0, which is enough for normal text under AAA.
+    Aaa,
+}
+
+impl WcagLevel {
+    /// Gets the level that `ratio` reaches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::WcagLevel;
+    ///
+    /// assert_eq!(WcagLevel::from_ratio(4.6), WcagLevel::Aa);
+    /// assert_eq!(WcagLevel::from_ratio(2.0), WcagLevel::Fail);
+    /// ```
+    pub fn from_ratio(ratio: f32) -> Self {
+        if ratio >= 7.0 {
+            Self::Aaa
+        } else if ratio >= MIN_CONTRAST_RATIO {
+            Self::Aa
+        } else if ratio >= 3.0 {
+            Self::AaLarge
+        } else {
+            Self::Fail
+        }
+    }
+
+    /// Gets the name of the level, as used by WCAG, such as "AA Large".
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Fail => "Fail",
+            Self::AaLarge => "AA Large",
+            Self::Aa => "AA",
+            Self::Aaa => "AAA",
+        }
+    }
+}
+
+impl Display for WcagLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The contrast ratio between every foreground color of one palette and every background color
+/// of another, as returned by [`contrast_grid()`](Palette#method.contrast_grid). Colors are
+/// labeled by their names in the palettes, or by their `#rrggbb` hex strings when they don't
+/// have one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastGrid {
+    foregrounds: Vec<(String, Color)>,
+    backgrounds: Vec<(String, Color)>,
+    ratios: Vec<f32>,
+}
+
+impl ContrastGrid {
+    /// Gets the number of foreground colors, which are the rows of the grid.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.foregrounds.len()
+    }
+
+    /// Gets the number of background colors, which are the columns of the grid.
+    #[inline]
+    pub fn columns(&self) -> usize {
+        self.backgrounds.len()
+    }
+
+    /// Gets the contrast ratio between the foreground color at index `row` and the background
+    /// color at index `column`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `row` or `column` is out of bounds.
+    pub fn ratio(&self, row: usize, column: usize) -> f32 {
+        if row >= self.rows() || column >= self.columns() {
+            panic!("Unsupported contrast grid cell: ({row}, {column})");
+        }
+        self.ratios[row * self.columns() + column]
+    }
+
+    /// Gets the WCAG level of the contrast ratio between the foreground color at index `row` and
+    /// the background color at index `column`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `row` or `column` is out of bounds.
+    pub fn level(&self, row: usize, column: usize) -> WcagLevel {
+        WcagLevel::from_ratio(self.ratio(row, column))
+    }
+
+    /// Gets every pair of foreground and background labels, with their contrast ratio and WCAG
+    /// level, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (&str, &str, f32, WcagLevel)> {
+        self.foregrounds
+            .iter()
+            .enumerate()
+            .flat_map(move |(row, foreground)| {
+                self.backgrounds
+                    .iter()
+                    .enumerate()
+                    .map(move |(column, background)| {
+                        let ratio = self.ratio(row, column);
+                        (
+                            foreground.0.as_str(),
+                            background.0.as_str(),
+                            ratio,
+                            WcagLevel::from_ratio(ratio),
+                        )
+                    })
+            })
+    }
+
+    /// Writes the grid as CSV, with a header and then one line per pair of colors, in the columns
+    /// `foreground`, `background`, `ratio` (with two decimals), and `level`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Palette};
+    ///
+    /// let text = Palette::from(vec![constants::primary::BLACK]);
+    /// let backgrounds = Palette::from(vec![constants::primary::WHITE]);
+    ///
+    /// assert_eq!(
+    ///     text.contrast_grid(&backgrounds).to_csv(),
+    ///     "foreground,background,ratio,level\n#000000,#ffffff,21.00,AAA\n"
+    /// );
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let field = |value: &str| {
+            if value.contains([',', '"', '\n', '\r']) {
+                alloc::format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.into()
+            }
+        };
+        let mut csv = String::from("foreground,background,ratio,level\n");
+        for (foreground, background, ratio, level) in self.cells() {
+            let (foreground, background) = (field(foreground), field(background));
+            writeln!(csv, "{foreground},{background},{ratio:.2},{level}").unwrap();
+        }
+        csv
+    }
+
+    /// Writes the grid as a JSON array with one object per pair of colors, with the keys
+    /// `foreground`, `background`, `ratio` (with two decimals), and `level`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Palette};
+    ///
+    /// let mut text = Palette::new();
+    /// text.push_named("body", constants::primary::BLACK);
+    /// let backgrounds = Palette::from(vec![constants::primary::WHITE]);
+    ///
+    /// assert_eq!(
+    ///     text.contrast_grid(&backgrounds).to_json(),
+    ///     r##"[{"foreground":"body","background":"#ffffff","ratio":21.00,"level":"AAA"}]"##
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("[");
+        for (i, (foreground, background, ratio, level)) in self.cells().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"foreground\":");
+            write_json_string(&mut json, foreground);
+            json.push_str(",\"background\":");
+            write_json_string(&mut json, background);
+            write!(json, ",\"ratio\":{ratio:.2},\"level\":\"{level}\"}}").unwrap();
+        }
+        json.push(']');
+        json
+    }
+}
+
+/// Writes `value` as a JSON string, escaping the characters which JSON requires.
+fn write_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// Gets the colors of `palette` with their labels in a [`ContrastGrid`].
+fn labeled(palette: &Palette) -> Vec<(String, Color)> {
+    palette
+        .iter_named()
+        .map(|(name, color)| match name {
+            Some(name) => (name.into(), *color),
+            None => (color.to_css_string(), *color),
+        })
+        .collect()
+}
+
+impl Palette {
+    /// Checks that every color has a contrast ratio of at least 4.5 against `background`, which
+    /// is what WCAG AA requires for normal text. See
+    /// [`contrast_ratio()`](Color#method.contrast_ratio).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants, Palette};
+    ///
+    /// let palette = Palette::from(vec![Color::new(20, 20, 120), Color::new(255, 220, 0)]);
+    /// let report = palette.audit_contrast(&constants::primary::WHITE);
+    ///
+    /// assert!(!report.passed());
+    /// assert_eq!(report.failures()[0].index, 1);
+    /// ```
+    pub fn audit_contrast(&self, background: &Color) -> AuditReport<ContrastFailure> {
+        self.audit_contrast_with_ratio(background, MIN_CONTRAST_RATIO)
+    }
+
+    /// Same as [`audit_contrast()`](Palette#method.audit_contrast), but with a custom minimum
+    /// contrast ratio, such as 3.0 for large text or 7.0 for WCAG AAA.
+    pub fn audit_contrast_with_ratio(
+        &self,
+        background: &Color,
+        min_ratio: f32,
+    ) -> AuditReport<ContrastFailure> {
+        let failures = self
+            .iter()
+            .enumerate()
+            .map(|(index, &color)| ContrastFailure {
+                index,
+                color,
+                ratio: color.contrast_ratio(background),
+            })
+            .filter(|failure| failure.ratio < min_ratio)
+            .collect();
+        AuditReport {
+            threshold: min_ratio,
+            failures,
+        }
+    }
+
+    /// Gets the contrast ratios between every color of this palette as the foreground and every
+    /// color of `backgrounds`, along with the WCAG levels they reach. The grid can be written as
+    /// CSV or JSON, like the contrast grids published by design systems.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants, Palette, WcagLevel};
+    ///
+    /// let text = Palette::from(vec![constants::primary::BLACK, Color::new(130, 130, 130)]);
+    /// let backgrounds = Palette::from(vec![constants::primary::WHITE, Color::new(40, 40, 40)]);
+    /// let grid = text.contrast_grid(&backgrounds);
+    ///
+    /// assert_eq!((grid.rows(), grid.columns()), (2, 2));
+    /// assert_eq!(grid.level(0, 0), WcagLevel::Aaa);
+    /// assert_eq!(grid.level(1, 0), WcagLevel::AaLarge);
+    /// assert_eq!(grid.level(0, 1), WcagLevel::Fail);
+    /// ```
+    pub fn contrast_grid(&self, backgrounds: &Palette) -> ContrastGrid {
+        let ratios = self
+            .iter()
+            .flat_map(|foreground| {
+                backgrounds
+                    .iter()
+                    .map(move |background| foreground.contrast_ratio(background))
+            })
+            .collect();
+        ContrastGrid {
+            foregrounds: labeled(self),
+            backgrounds: labeled(backgrounds),
+            ratios,
+        }
+    }
+
+    /// Checks that every pair of colors has a Delta E of at least 10.0 when they are seen with
+    /// the color vision deficiency `cvd`, so they can still be told apart at a glance. See
+    /// [`simulate_cvd()`](Color#method.simulate_cvd).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, CvdType, Palette};
+    ///
+    /// let palette = Palette::from(vec![Color::new(200, 80, 60), Color::new(110, 150, 40)]);
+    /// let report = palette.audit_distinguishability(CvdType::Deuteranopia);
+    ///
+    /// assert!(!report.passed());
+    /// assert_eq!(report.failures()[0].indices, (0, 1));
+    /// ```
+    pub fn audit_distinguishability(&self, cvd: CvdType) -> AuditReport<DistinguishabilityFailure> {
+        self.audit_distinguishability_with_delta_e(cvd, MIN_DISTINGUISHABLE_DELTA_E)
+    }
+
+    /// Same as [`audit_distinguishability()`](Palette#method.audit_distinguishability), but with
+    /// a custom minimum Delta E.
+    pub fn audit_distinguishability_with_delta_e(
+        &self,
+        cvd: CvdType,
+        min_delta_e: f32,
+    ) -> AuditReport<DistinguishabilityFailure> {
+        let seen: Vec<_> = self
+            .iter()
+            .map(|color| color.simulate_cvd(cvd).to_lab())
+            .collect();
+        let mut failures = Vec::new();
+        for (i, &first) in self.iter().enumerate() {
+            for (j, &second) in self.iter().enumerate().skip(i + 1) {
+                let delta_e = ciede2000(seen[i], seen[j]);
+                if delta_e < min_delta_e {
+                    failures.push(DistinguishabilityFailure {
+                        indices: (i, j),
+                        colors: (first, second),
+                        delta_e,
+                    });
+                }
+            }
+        }
+        AuditReport {
+            threshold: min_delta_e,
+            failures,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn audit_contrast() {
+        let palette = Palette::from(vec![
+            constants::primary::BLACK,
+            Color::new(119, 119, 119),
+            Color::new(118, 118, 118),
+            constants::primary::WHITE,
+        ]);
+        let report = palette.audit_contrast(&constants::primary::WHITE);
+        assert_eq!(report.threshold(), 4.5);
+        let indices: Vec<_> = report.failures().iter().map(|f| f.index).collect();
+        assert_eq!(indices, [1, 3]);
+        assert_eq!(report.failures()[1].ratio, 1.0);
+        assert_eq!(
+            report.to_string(),
+            "2 failures below 4.5:\n\
+             color 1 (#777777) has a contrast ratio of 4.48\n\
+             color 3 (#ffffff) has a contrast ratio of 1.00"
+        );
+        let large = palette.audit_contrast_with_ratio(&constants::primary::WHITE, 3.0);
+        assert_eq!(large.failures().len(), 1);
+        assert!(Palette::new()
+            .audit_contrast(&constants::primary::WHITE)
+            .passed());
+    }
+
+    #[test]
+    fn contrast_grid() {
+        let mut text = Palette::new();
+        text.push_named("body, \"primary\"", constants::primary::BLACK);
+        text.push(Color::new(118, 118, 118));
+        let backgrounds = Palette::from(vec![constants::primary::WHITE, constants::OCTARINE]);
+        let grid = text.contrast_grid(&backgrounds);
+        for row in 0..grid.rows() {
+            for column in 0..grid.columns() {
+                assert_eq!(
+                    grid.ratio(row, column),
+                    text[row].contrast_ratio(&backgrounds[column])
+                );
+            }
+        }
+        assert_eq!(grid.level(1, 0), WcagLevel::Aa);
+        assert_eq!(grid.cells().count(), 4);
+        let csv = grid.to_csv();
+        assert_eq!(csv.lines().count(), 5);
+        assert!(csv
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("\"body, \"\"primary\"\"\",#ffffff,21.00,AAA"));
+        let json = grid.to_json();
+        assert!(json.starts_with(r##"[{"foreground":"body, \"primary\"","background":"#ffffff""##));
+        assert_eq!(json.matches('{').count(), 4);
+        let empty = Palette::new().contrast_grid(&backgrounds);
+        assert_eq!((empty.rows(), empty.to_json()), (0, "[]".to_string()));
+        assert_eq!(WcagLevel::AaLarge.to_string(), "AA Large");
+        assert!(WcagLevel::Aaa > WcagLevel::Aa);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported contrast grid cell")]
+    fn contrast_grid_out_of_bounds() {
+        let palette = Palette::from(vec![constants::primary::BLACK]);
+        palette.contrast_grid(&palette).ratio(0, 1);
+    }
+
+    #[test]
+    fn audit_distinguishability() {
+        let palette = Palette::from(vec![
+            Color::new(200, 80, 60),
+            Color::new(110, 150, 40),
+            Color::new(30, 60, 200),
+        ]);
+        let report = palette.audit_distinguishability(CvdType::Deuteranopia);
+        assert_eq!(report.failures().len(), 1);
+        let failure = report.failures()[0];
+        assert_eq!(failure.indices, (0, 1));
+        assert_eq!(failure.colors, (palette[0], palette[1]));
+        assert!(failure.delta_e < palette[0].delta_e(&palette[1]));
+        assert!(palette
+            .audit_distinguishability(CvdType::Tritanopia)
+            .passed());
+        let strict = palette.audit_distinguishability_with_delta_e(CvdType::Tritanopia, 100.0);
+        assert_eq!(strict.failures().len(), 3);
+        assert_eq!(report.to_string().lines().count(), 2);
+    }
+}
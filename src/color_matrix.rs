@@ -0,0 +1,404 @@
+use super::*;
+use core::ops::{Mul, MulAssign};
+
+/// A 4x5 matrix which transforms RGBA colors, the same as `feColorMatrix` in SVG. Each row gives
+/// the weights of R, G, B, and A for one output channel, followed by an offset, where channels
+/// go from 0.0 to 1.0. The presets match the definitions of the SVG and CSS filter effects.
+///
+/// Matrices are combined by multiplying them, where `a * b` applies `b` first, so a whole filter
+/// chain can be stored as a single matrix.
+///
+/// # Note
+///
+/// The matrices are applied to the sRGB channels directly, which is what browsers do for CSS
+/// filters, instead of converting to linear RGB first.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{Color, ColorMatrix};
+///
+/// let filter = ColorMatrix::sepia(1.0) * ColorMatrix::saturate(0.5);
+/// let color = filter.apply(&Color::new(100, 150, 200));
+///
+/// assert!(color.get_red() > color.get_blue());
+/// assert_eq!(ColorMatrix::IDENTITY.apply(&color), color);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorMatrix([[f32; 5]; 4]);
+
+impl ColorMatrix {
+    /// The matrix which doesn't change colors.
+    pub const IDENTITY: Self = Self([
+        [1.0, 0.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0, 0.0],
+    ]);
+
+    /// Creates a matrix from its rows, which are in the same order as the `values` of
+    /// `feColorMatrix`.
+    #[inline]
+    pub const fn new(rows: [[f32; 5]; 4]) -> Self {
+        Self(rows)
+    }
+
+    /// Gets the rows of the matrix.
+    #[inline]
+    pub const fn rows(&self) -> &[[f32; 5]; 4] {
+        &self.0
+    }
+
+    /// Changes the saturation, the same as `type="saturate"` in SVG and `saturate()` in CSS.
+    /// 0.0 is grayscale, 1.0 is unchanged, and values above 1.0 oversaturate.
+    pub fn saturate(amount: f32) -> Self {
+        let s = amount;
+        Self([
+            [
+                0.213 + 0.787 * s,
+                0.715 - 0.715 * s,
+                0.072 - 0.072 * s,
+                0.0,
+                0.0,
+            ],
+            [
+                0.213 - 0.213 * s,
+                0.715 + 0.285 * s,
+                0.072 - 0.072 * s,
+                0.0,
+                0.0,
+            ],
+            [
+                0.213 - 0.213 * s,
+                0.715 - 0.715 * s,
+                0.072 + 0.928 * s,
+                0.0,
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0, 0.0],
+        ])
+    }
+
+    /// Rotates the hue by `amount`, where 1.0 is a full rotation, the same as
+    /// `type="hueRotate"` in SVG and `hue-rotate()` in CSS. The hue is approximated using the
+    /// luminance weights, so the result differs from [`rotate_hue()`](Color#method.rotate_hue).
+    pub fn hue_rotate(amount: f32) -> Self {
+        let (sin, cos) = (amount * core::f32::consts::TAU).sin_cos();
+        Self([
+            [
+                0.213 + cos * 0.787 - sin * 0.213,
+                0.715 - cos * 0.715 - sin * 0.715,
+                0.072 - cos * 0.072 + sin * 0.928,
+                0.0,
+                0.0,
+            ],
+            [
+                0.213 - cos * 0.213 + sin * 0.143,
+                0.715 + cos * 0.285 + sin * 0.140,
+                0.072 - cos * 0.072 - sin * 0.283,
+                0.0,
+                0.0,
+            ],
+            [
+                0.213 - cos * 0.213 - sin * 0.787,
+                0.715 - cos * 0.715 + sin * 0.715,
+                0.072 + cos * 0.928 + sin * 0.072,
+                0.0,
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0, 0.0],
+        ])
+    }
+
+    /// Tints colors with brown like an old photograph, the same as `sepia()` in CSS. 0.0 is
+    /// unchanged and 1.0 is fully sepia. Like in CSS, `amount` is clamped to that range.
+    pub fn sepia(amount: f32) -> Self {
+        let t = 1.0 - amount.clamp(0.0, 1.0);
+        Self([
+            [
+                0.393 + 0.607 * t,
+                0.769 - 0.769 * t,
+                0.189 - 0.189 * t,
+                0.0,
+                0.0,
+            ],
+            [
+                0.349 - 0.349 * t,
+                0.686 + 0.314 * t,
+                0.168 - 0.168 * t,
+                0.0,
+                0.0,
+            ],
+            [
+                0.272 - 0.272 * t,
+                0.534 - 0.534 * t,
+                0.131 + 0.869 * t,
+                0.0,
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0, 0.0],
+        ])
+    }
+
+    /// Replaces the alpha with the luminance and the color with black, the same as
+    /// `type="luminanceToAlpha"` in SVG, which is useful for creating masks. Use
+    /// [`apply_rgba()`](ColorMatrix::apply_rgba) to get the alpha.
+    pub const fn luminance_to_alpha() -> Self {
+        Self([
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.2125, 0.7154, 0.0721, 0.0, 0.0],
+        ])
+    }
+
+    /// Applies the matrix to RGBA channels between 0.0 and 1.0. The results are clamped to the
+    /// same range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::ColorMatrix;
+    ///
+    /// let [_, _, _, alpha] = ColorMatrix::luminance_to_alpha().apply_rgba([1.0, 1.0, 1.0, 1.0]);
+    ///
+    /// assert!((alpha - 1.0).abs() < 0.001);
+    /// ```
+    pub fn apply_rgba(&self, rgba: [f32; 4]) -> [f32; 4] {
+        self.0.map(|row| {
+            let sum: f32 = row.iter().zip(rgba).map(|(weight, v)| weight * v).sum();
+            (sum + row[4]).clamp(0.0, 1.0)
+        })
+    }
+
+    /// Applies the matrix to an opaque color. The resulting alpha is ignored.
+    pub fn apply(&self, color: &Color) -> Color {
+        let (r, g, b) = color.to_rgb_float();
+        let [r, g, b, _] = self.apply_rgba([r, g, b, 1.0]);
+        Color::from_rgb_float(r, g, b)
+    }
+}
+
+impl Default for ColorMatrix {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for ColorMatrix {
+    type Output = Self;
+
+    /// Combines two matrices, so that the result applies `rhs` and then `self`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.0, rhs.0);
+        Self(core::array::from_fn(|i| {
+            core::array::from_fn(|j| {
+                // The missing fifth row of `rhs` is [0, 0, 0, 0, 1], which carries the offsets.
+                let sum: f32 = (0..4).map(|k| a[i][k] * b[k][j]).sum();
+                if j == 4 {
+                    sum + a[i][4]
+                } else {
+                    sum
+                }
+            })
+        }))
+    }
+}
+
+impl MulAssign for ColorMatrix {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Classic photo filters, which are applied with [`filter()`](Color#method.filter) or combined
+/// with other matrices through [`matrix()`](Filter::matrix).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Filter {
+    /// Brown tones like an old photograph, the same as [`ColorMatrix::sepia()`] at full strength.
+    Sepia,
+    /// Shifts colors towards blue, like a cloudy day.
+    Cool,
+    /// Shifts colors towards orange, like a sunset.
+    Warm,
+    /// Lifts the shadows and lowers the contrast and saturation, like a faded print.
+    Fade,
+    /// Swaps light and dark while keeping the hue, so dark text on a light background becomes
+    /// light text on a dark background. Like the other matrices, this works on the sRGB channels,
+    /// so it inverts the luma (the weighted sum of the gamma-encoded channels) rather than the
+    /// [`relative_luminance()`](Color#method.relative_luminance), which is in linear light.
+    InvertLuminance,
+}
+
+impl Filter {
+    /// Gets the matrix which applies the filter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ColorMatrix, Filter};
+    ///
+    /// let matrix = Filter::Warm.matrix() * ColorMatrix::saturate(1.2);
+    /// let color = Color::new(100, 150, 200);
+    ///
+    /// assert!(matrix.apply(&color).get_red() > color.get_red());
+    /// ```
+    pub fn matrix(self) -> ColorMatrix {
+        match self {
+            Self::Sepia => ColorMatrix::sepia(1.0),
+            Self::Cool => ColorMatrix::new([
+                [0.9, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 1.1, 0.0, 0.05],
+                [0.0, 0.0, 0.0, 1.0, 0.0],
+            ]),
+            Self::Warm => ColorMatrix::new([
+                [1.1, 0.0, 0.0, 0.0, 0.05],
+                [0.0, 1.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.9, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0, 0.0],
+            ]),
+            Self::Fade => {
+                let contrast = ColorMatrix::new([
+                    [0.8, 0.0, 0.0, 0.0, 0.1],
+                    [0.0, 0.8, 0.0, 0.0, 0.1],
+                    [0.0, 0.0, 0.8, 0.0, 0.1],
+                    [0.0, 0.0, 0.0, 1.0, 0.0],
+                ]);
+                contrast * ColorMatrix::saturate(0.8)
+            }
+            Self::InvertLuminance => {
+                // Adds 1 - 2Y' to every channel, which turns the luma Y' of the sRGB channels
+                // into 1 - Y'.
+                let [r, g, b, ..] = ColorMatrix::luminance_to_alpha().0[3];
+                let row = |i: usize| {
+                    let mut row = [-2.0 * r, -2.0 * g, -2.0 * b, 0.0, 1.0];
+                    row[i] += 1.0;
+                    row
+                };
+                ColorMatrix::new([row(0), row(1), row(2), [0.0, 0.0, 0.0, 1.0, 0.0]])
+            }
+        }
+    }
+}
+
+impl Color {
+    /// Tints the color with brown like an old photograph, the same as `sepia()` in CSS. 0.0 is
+    /// unchanged and 1.0 is fully sepia. Like in CSS, `strength` is clamped to that range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// assert_eq!(constants::primary::WHITE.sepia(1.0), Color::new(255, 255, 239));
+    /// assert_eq!(constants::primary::WHITE.sepia(0.0), constants::primary::WHITE);
+    /// ```
+    pub fn sepia(&self, strength: f32) -> Self {
+        ColorMatrix::sepia(strength).apply(self)
+    }
+
+    /// Applies the photo filter `filter`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants, Filter};
+    ///
+    /// let cool = Color::new(128, 128, 128).filter(Filter::Cool);
+    /// let inverted = constants::primary::BLACK.filter(Filter::InvertLuminance);
+    ///
+    /// assert!(cool.get_blue() > cool.get_red());
+    /// assert_eq!(inverted, constants::primary::WHITE);
+    /// ```
+    pub fn filter(&self, filter: Filter) -> Self {
+        filter.matrix().apply(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets() {
+        let color = Color::new(100, 150, 200);
+        assert_eq!(ColorMatrix::saturate(1.0).apply(&color), color);
+        assert_eq!(ColorMatrix::hue_rotate(0.0).apply(&color), color);
+        assert_eq!(ColorMatrix::sepia(0.0).apply(&color), color);
+        let gray = ColorMatrix::saturate(0.0).apply(&color);
+        assert!(gray.get_red().abs_diff(gray.get_blue()) <= 1);
+        let rotated = ColorMatrix::hue_rotate(0.5).apply(&constants::primary::RED);
+        assert!(rotated.get_red() < rotated.get_green() && rotated.get_red() < rotated.get_blue());
+        assert_eq!(
+            ColorMatrix::sepia(1.0).apply(&constants::primary::WHITE),
+            Color::new(255, 255, 239)
+        );
+        assert_eq!(
+            ColorMatrix::luminance_to_alpha().apply_rgba([0.0, 1.0, 0.0, 0.5]),
+            [0.0, 0.0, 0.0, 0.7154]
+        );
+    }
+
+    #[test]
+    fn sepia_amount() {
+        assert_eq!(ColorMatrix::sepia(2.0), ColorMatrix::sepia(1.0));
+        assert_eq!(ColorMatrix::sepia(-1.0), ColorMatrix::IDENTITY);
+    }
+
+    #[test]
+    fn filters() {
+        let color = Color::new(100, 150, 200);
+        assert_eq!(color.sepia(1.0), color.filter(Filter::Sepia));
+        assert_eq!(color.sepia(0.0), color);
+        let warm = color.filter(Filter::Warm);
+        assert!(warm.get_red() > color.get_red() && warm.get_blue() < color.get_blue());
+        let cool = color.filter(Filter::Cool);
+        assert!(cool.get_red() < color.get_red() && cool.get_blue() > color.get_blue());
+        let faded = constants::primary::BLACK.filter(Filter::Fade);
+        assert!(faded.get_red() > 0);
+        assert!(
+            constants::primary::RED
+                .filter(Filter::Fade)
+                .get_hsl_saturation()
+                < constants::primary::RED.get_hsl_saturation()
+        );
+        let inverted = Color::new(220, 200, 180).filter(Filter::InvertLuminance);
+        assert!(inverted.relative_luminance() < 0.2);
+        assert!(
+            hue_distance(
+                inverted.get_hsl_hue(),
+                Color::new(220, 200, 180).get_hsl_hue()
+            ) < 0.01
+        );
+        assert_eq!(
+            constants::primary::WHITE.filter(Filter::InvertLuminance),
+            constants::primary::BLACK
+        );
+    }
+
+    #[test]
+    fn mul() {
+        let color = Color::new(100, 150, 200);
+        let (a, b) = (ColorMatrix::sepia(0.7), ColorMatrix::hue_rotate(0.2));
+        let offset = ColorMatrix::new([
+            [1.0, 0.0, 0.0, 0.0, 0.1],
+            [0.0, 1.0, 0.0, 0.0, -0.1],
+            [0.0, 0.0, 0.5, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0, 0.0],
+        ]);
+        assert!((a * b)
+            .apply(&color)
+            .approx_eq(&a.apply(&b.apply(&color)), 1));
+        assert!((offset * a)
+            .apply(&color)
+            .approx_eq(&offset.apply(&a.apply(&color)), 1));
+        assert!((a * offset)
+            .apply(&color)
+            .approx_eq(&a.apply(&offset.apply(&color)), 1));
+        assert_eq!(ColorMatrix::IDENTITY * a, a);
+        let mut chain = ColorMatrix::default();
+        chain *= a;
+        assert_eq!(chain, a);
+    }
+}
@@ -0,0 +1,422 @@
+//! Perceptually uniform colormaps for mapping scalar data to colors.
+//!
+//! # Example
+//!
+//! ```
+//! use octarine::{colormap, Color};
+//!
+//! assert_eq!(colormap::VIRIDIS.sample(0.0), Color::from_hex(0x440154));
+//! assert_eq!(colormap::VIRIDIS.sample(1.0), Color::from_hex(0xFDE725));
+//! ```
+
+use super::*;
+
+/// Matplotlib's default colormap.
+pub const VIRIDIS: Colormap = Colormap(Kind::Table(&VIRIDIS_DATA));
+/// Matplotlib's colormap from black through purple and pink to pale yellow.
+pub const MAGMA: Colormap = Colormap(Kind::Table(&MAGMA_DATA));
+/// Matplotlib's colormap from black through purple and orange to bright yellow, like fire.
+pub const INFERNO: Colormap = Colormap(Kind::Table(&INFERNO_DATA));
+/// Matplotlib's colormap from dark blue through magenta and orange to yellow, without black.
+pub const PLASMA: Colormap = Colormap(Kind::Table(&PLASMA_DATA));
+/// A colormap optimized for viewers with color vision deficiency. This uses a polynomial
+/// approximation of the original colormap.
+pub const CIVIDIS: Colormap = Colormap(Kind::Cividis);
+/// Google's improved rainbow colormap. This uses a polynomial approximation of the original
+/// colormap.
+pub const TURBO: Colormap = Colormap(Kind::Turbo);
+/// Dave Green's cubehelix colormap with its default parameters. Refer to
+/// [`Colormap::cubehelix()`] for more information.
+pub const CUBEHELIX: Colormap = Colormap(Kind::Cubehelix {
+    start: 0.5,
+    rotations: -1.5,
+    hue: 1.0,
+    gamma: 1.0,
+});
+
+/// A colormap which maps values between 0.0 and 1.0 to colors. Refer to the constants in the
+/// [`colormap`](self) module for the built-in colormaps, or use the constructors of this type to
+/// create your own.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Colormap(Kind);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Table(&'static [u32; 256]),
+    Cividis,
+    Turbo,
+    Diverging {
+        low: (f32, f32, f32),
+        mid: (f32, f32, f32),
+        high: (f32, f32, f32),
+    },
+    Cubehelix {
+        start: f32,
+        rotations: f32,
+        hue: f32,
+        gamma: f32,
+    },
+}
+
+impl Colormap {
+    /// Creates a diverging colormap, which goes from `low` (at 0.0) through `mid` (at 0.5) to
+    /// `high` (at 1.0), e.g. for heatmaps centered around zero. Both halves are interpolated in
+    /// OKLab, and the perceived lightness of `low` and `high` is averaged so that the colormap is
+    /// perceptually symmetric around `mid`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{colormap::Colormap, constants};
+    ///
+    /// let colormap = Colormap::diverging(
+    ///     &constants::primary::BLUE,
+    ///     &constants::primary::WHITE,
+    ///     &constants::primary::RED,
+    /// );
+    ///
+    /// assert_eq!(colormap.sample(0.5), constants::primary::WHITE);
+    /// ```
+    pub fn diverging(low: &Color, mid: &Color, high: &Color) -> Self {
+        let mut low = low.to_oklab();
+        let mut high = high.to_oklab();
+        let lightness = (low.0 + high.0) / 2.0;
+        low.0 = lightness;
+        high.0 = lightness;
+        Self(Kind::Diverging {
+            low,
+            mid: mid.to_oklab(),
+            high,
+        })
+    }
+
+    /// Creates a [cubehelix](https://people.phy.cam.ac.uk/dag9/CUBEHELIX/) colormap, which goes
+    /// from black to white while its perceived brightness increases monotonically, and its hue
+    /// rotates around the color wheel.
+    ///
+    /// - `start` is the starting hue (0.0 - 3.0, where 1.0 is red, 2.0 is green and 3.0 is blue).
+    /// - `rotations` is the number of rotations around the color wheel from start to end, where
+    ///   negative values rotate from red to blue.
+    /// - `hue` is the saturation of the colors, where 0.0 gives a grayscale colormap.
+    /// - `gamma` emphasizes low (< 1.0) or high (> 1.0) intensity values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{colormap::{self, Colormap}, constants};
+    ///
+    /// let colormap = Colormap::cubehelix(0.5, -1.5, 1.0, 1.0);
+    ///
+    /// assert_eq!(colormap, colormap::CUBEHELIX);
+    /// assert_eq!(colormap.sample(0.0), constants::primary::BLACK);
+    /// assert_eq!(colormap.sample(1.0), constants::primary::WHITE);
+    /// ```
+    pub fn cubehelix(start: f32, rotations: f32, hue: f32, gamma: f32) -> Self {
+        Self(Kind::Cubehelix {
+            start,
+            rotations,
+            hue,
+            gamma,
+        })
+    }
+
+    /// Gets the color at `t`, where `t` is between 0.0 (start of the colormap) and 1.0 (end of
+    /// the colormap). Values outside of that range are clamped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{colormap, Color};
+    ///
+    /// let color = colormap::MAGMA.sample(0.5);
+    ///
+    /// assert_eq!(color, Color::from_hex(0xB6377A));
+    /// ```
+    pub fn sample(&self, t: f32) -> Color {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        match self.0 {
+            Kind::Table(data) => {
+                let position = t * (data.len() - 1) as f32;
+                let index = (position as usize).min(data.len() - 2);
+                let a = Color::from_hex(data[index]);
+                let b = Color::from_hex(data[index + 1]);
+                lerp_rgb(&a, &b, position - index as f32)
+            }
+            Kind::Cividis => {
+                let r = -4.54
+                    - t * (35.34 - t * (2381.73 - t * (6402.7 - t * (7024.72 - t * 2710.57))));
+                let g =
+                    32.49 + t * (170.73 + t * (52.82 - t * (131.46 - t * (176.58 - t * 67.37))));
+                let b = 81.24
+                    + t * (442.36 - t * (2482.43 - t * (6167.24 - t * (6614.94 - t * 2475.67))));
+                color_from_polynomial(r, g, b)
+            }
+            Kind::Turbo => {
+                let r = 34.61
+                    + t * (1172.33
+                        - t * (10793.56 - t * (33300.12 - t * (38394.49 - t * 14825.05))));
+                let g = 23.31
+                    + t * (557.33 + t * (1225.33 - t * (3574.96 - t * (1073.77 + t * 707.56))));
+                let b = 27.2
+                    + t * (3211.1 - t * (15327.97 - t * (27814.0 - t * (22569.18 - t * 6838.66))));
+                color_from_polynomial(r, g, b)
+            }
+            Kind::Diverging { low, mid, high } => {
+                let (l, a, b) = if t < 0.5 {
+                    lerp_oklab(low, mid, t * 2.0)
+                } else {
+                    lerp_oklab(mid, high, t * 2.0 - 1.0)
+                };
+                Color::from_oklab(l, a, b)
+            }
+            Kind::Cubehelix {
+                start,
+                rotations,
+                hue,
+                gamma,
+            } => {
+                let phi = core::f32::consts::TAU * (start / 3.0 + rotations * t);
+                let (sin, cos) = phi.sin_cos();
+                let t = t.powf(gamma);
+                let amplitude = hue * t * (1.0 - t) / 2.0;
+                let r = t + amplitude * (-0.14861 * cos + 1.78277 * sin);
+                let g = t + amplitude * (-0.29227 * cos - 0.90649 * sin);
+                let b = t + amplitude * (1.97294 * cos);
+                Color::from_rgb_float(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0))
+            }
+        }
+    }
+
+    /// Gets `n` evenly spaced colors from the colormap, including both ends. When `n` is 1, the
+    /// middle of the colormap is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{colormap, Color};
+    ///
+    /// let palette = colormap::VIRIDIS.discrete(3);
+    ///
+    /// assert_eq!(palette[0], Color::from_hex(0x440154));
+    /// assert_eq!(palette[1], colormap::VIRIDIS.sample(0.5));
+    /// assert_eq!(palette[2], Color::from_hex(0xFDE725));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn discrete(&self, n: usize) -> Palette {
+        if n == 1 {
+            return Palette::from(vec![self.sample(0.5)]);
+        }
+        (0..n)
+            .map(|i| self.sample(i as f32 / (n - 1) as f32))
+            .collect()
+    }
+}
+
+fn lerp_oklab(a: (f32, f32, f32), b: (f32, f32, f32), t: f32) -> (f32, f32, f32) {
+    (
+        a.0 + (b.0 - a.0) * t,
+        a.1 + (b.1 - a.1) * t,
+        a.2 + (b.2 - a.2) * t,
+    )
+}
+
+fn color_from_polynomial(r: f32, g: f32, b: f32) -> Color {
+    let channel = |v: f32| (v.clamp(0.0, 255.0) + 0.5) as u8;
+    Color(channel(r), channel(g), channel(b))
+}
+
+static VIRIDIS_DATA: [u32; 256] = [
+    0x440154, 0x440256, 0x450457, 0x450559, 0x46075A, 0x46085C, 0x460A5D, 0x460B5E, 0x470D60,
+    0x470E61, 0x471063, 0x471164, 0x471365, 0x481467, 0x481668, 0x481769, 0x48186A, 0x481A6C,
+    0x481B6D, 0x481C6E, 0x481D6F, 0x481F70, 0x482071, 0x482173, 0x482374, 0x482475, 0x482576,
+    0x482677, 0x482878, 0x482979, 0x472A7A, 0x472C7A, 0x472D7B, 0x472E7C, 0x472F7D, 0x46307E,
+    0x46327E, 0x46337F, 0x463480, 0x453581, 0x453781, 0x453882, 0x443983, 0x443A83, 0x443B84,
+    0x433D84, 0x433E85, 0x423F85, 0x424086, 0x424186, 0x414287, 0x414487, 0x404588, 0x404688,
+    0x3F4788, 0x3F4889, 0x3E4989, 0x3E4A89, 0x3E4C8A, 0x3D4D8A, 0x3D4E8A, 0x3C4F8A, 0x3C508B,
+    0x3B518B, 0x3B528B, 0x3A538B, 0x3A548C, 0x39558C, 0x39568C, 0x38588C, 0x38598C, 0x375A8C,
+    0x375B8D, 0x365C8D, 0x365D8D, 0x355E8D, 0x355F8D, 0x34608D, 0x34618D, 0x33628D, 0x33638D,
+    0x32648E, 0x32658E, 0x31668E, 0x31678E, 0x31688E, 0x30698E, 0x306A8E, 0x2F6B8E, 0x2F6C8E,
+    0x2E6D8E, 0x2E6E8E, 0x2E6F8E, 0x2D708E, 0x2D718E, 0x2C718E, 0x2C728E, 0x2C738E, 0x2B748E,
+    0x2B758E, 0x2A768E, 0x2A778E, 0x2A788E, 0x29798E, 0x297A8E, 0x297B8E, 0x287C8E, 0x287D8E,
+    0x277E8E, 0x277F8E, 0x27808E, 0x26818E, 0x26828E, 0x26828E, 0x25838E, 0x25848E, 0x25858E,
+    0x24868E, 0x24878E, 0x23888E, 0x23898E, 0x238A8D, 0x228B8D, 0x228C8D, 0x228D8D, 0x218E8D,
+    0x218F8D, 0x21908D, 0x21918C, 0x20928C, 0x20928C, 0x20938C, 0x1F948C, 0x1F958B, 0x1F968B,
+    0x1F978B, 0x1F988B, 0x1F998A, 0x1F9A8A, 0x1E9B8A, 0x1E9C89, 0x1E9D89, 0x1F9E89, 0x1F9F88,
+    0x1FA088, 0x1FA188, 0x1FA187, 0x1FA287, 0x20A386, 0x20A486, 0x21A585, 0x21A685, 0x22A785,
+    0x22A884, 0x23A983, 0x24AA83, 0x25AB82, 0x25AC82, 0x26AD81, 0x27AD81, 0x28AE80, 0x29AF7F,
+    0x2AB07F, 0x2CB17E, 0x2DB27D, 0x2EB37C, 0x2FB47C, 0x31B57B, 0x32B67A, 0x34B679, 0x35B779,
+    0x37B878, 0x38B977, 0x3ABA76, 0x3BBB75, 0x3DBC74, 0x3FBC73, 0x40BD72, 0x42BE71, 0x44BF70,
+    0x46C06F, 0x48C16E, 0x4AC16D, 0x4CC26C, 0x4EC36B, 0x50C46A, 0x52C569, 0x54C568, 0x56C667,
+    0x58C765, 0x5AC864, 0x5CC863, 0x5EC962, 0x60CA60, 0x63CB5F, 0x65CB5E, 0x67CC5C, 0x69CD5B,
+    0x6CCD5A, 0x6ECE58, 0x70CF57, 0x73D056, 0x75D054, 0x77D153, 0x7AD151, 0x7CD250, 0x7FD34E,
+    0x81D34D, 0x84D44B, 0x86D549, 0x89D548, 0x8BD646, 0x8ED645, 0x90D743, 0x93D741, 0x95D840,
+    0x98D83E, 0x9BD93C, 0x9DD93B, 0xA0DA39, 0xA2DA37, 0xA5DB36, 0xA8DB34, 0xAADC32, 0xADDC30,
+    0xB0DD2F, 0xB2DD2D, 0xB5DE2B, 0xB8DE29, 0xBADE28, 0xBDDF26, 0xC0DF25, 0xC2DF23, 0xC5E021,
+    0xC8E020, 0xCAE11F, 0xCDE11D, 0xD0E11C, 0xD2E21B, 0xD5E21A, 0xD8E219, 0xDAE319, 0xDDE318,
+    0xDFE318, 0xE2E418, 0xE5E419, 0xE7E419, 0xEAE51A, 0xECE51B, 0xEFE51C, 0xF1E51D, 0xF4E61E,
+    0xF6E620, 0xF8E621, 0xFBE723, 0xFDE725,
+];
+
+static INFERNO_DATA: [u32; 256] = [
+    0x000004, 0x010005, 0x010106, 0x010108, 0x02010A, 0x02020C, 0x02020E, 0x030210, 0x040312,
+    0x040314, 0x050417, 0x060419, 0x07051B, 0x08051D, 0x09061F, 0x0A0722, 0x0B0724, 0x0C0826,
+    0x0D0829, 0x0E092B, 0x10092D, 0x110A30, 0x120A32, 0x140B34, 0x150B37, 0x160B39, 0x180C3C,
+    0x190C3E, 0x1B0C41, 0x1C0C43, 0x1E0C45, 0x1F0C48, 0x210C4A, 0x230C4C, 0x240C4F, 0x260C51,
+    0x280B53, 0x290B55, 0x2B0B57, 0x2D0B59, 0x2F0A5B, 0x310A5C, 0x320A5E, 0x340A5F, 0x360961,
+    0x380962, 0x390963, 0x3B0964, 0x3D0965, 0x3E0966, 0x400A67, 0x420A68, 0x440A68, 0x450A69,
+    0x470B6A, 0x490B6A, 0x4A0C6B, 0x4C0C6B, 0x4D0D6C, 0x4F0D6C, 0x510E6C, 0x520E6D, 0x540F6D,
+    0x550F6D, 0x57106E, 0x59106E, 0x5A116E, 0x5C126E, 0x5D126E, 0x5F136E, 0x61136E, 0x62146E,
+    0x64156E, 0x65156E, 0x67166E, 0x69166E, 0x6A176E, 0x6C186E, 0x6D186E, 0x6F196E, 0x71196E,
+    0x721A6E, 0x741A6E, 0x751B6E, 0x771C6D, 0x781C6D, 0x7A1D6D, 0x7C1D6D, 0x7D1E6D, 0x7F1E6C,
+    0x801F6C, 0x82206C, 0x84206B, 0x85216B, 0x87216B, 0x88226A, 0x8A226A, 0x8C2369, 0x8D2369,
+    0x8F2469, 0x902568, 0x922568, 0x932667, 0x952667, 0x972766, 0x982766, 0x9A2865, 0x9B2964,
+    0x9D2964, 0x9F2A63, 0xA02A63, 0xA22B62, 0xA32C61, 0xA52C60, 0xA62D60, 0xA82E5F, 0xA92E5E,
+    0xAB2F5E, 0xAD305D, 0xAE305C, 0xB0315B, 0xB1325A, 0xB3325A, 0xB43359, 0xB63458, 0xB73557,
+    0xB93556, 0xBA3655, 0xBC3754, 0xBD3853, 0xBF3952, 0xC03A51, 0xC13A50, 0xC33B4F, 0xC43C4E,
+    0xC63D4D, 0xC73E4C, 0xC83F4B, 0xCA404A, 0xCB4149, 0xCC4248, 0xCE4347, 0xCF4446, 0xD04545,
+    0xD24644, 0xD34743, 0xD44842, 0xD54A41, 0xD74B3F, 0xD84C3E, 0xD94D3D, 0xDA4E3C, 0xDB503B,
+    0xDD513A, 0xDE5238, 0xDF5337, 0xE05536, 0xE15635, 0xE25734, 0xE35933, 0xE45A31, 0xE55C30,
+    0xE65D2F, 0xE75E2E, 0xE8602D, 0xE9612B, 0xEA632A, 0xEB6429, 0xEB6628, 0xEC6726, 0xED6925,
+    0xEE6A24, 0xEF6C23, 0xEF6E21, 0xF06F20, 0xF1711F, 0xF1731D, 0xF2741C, 0xF3761B, 0xF37819,
+    0xF47918, 0xF57B17, 0xF57D15, 0xF67E14, 0xF68013, 0xF78212, 0xF78410, 0xF8850F, 0xF8870E,
+    0xF8890C, 0xF98B0B, 0xF98C0A, 0xF98E09, 0xFA9008, 0xFA9207, 0xFA9407, 0xFB9606, 0xFB9706,
+    0xFB9906, 0xFB9B06, 0xFB9D07, 0xFC9F07, 0xFCA108, 0xFCA309, 0xFCA50A, 0xFCA60C, 0xFCA80D,
+    0xFCAA0F, 0xFCAC11, 0xFCAE12, 0xFCB014, 0xFCB216, 0xFCB418, 0xFBB61A, 0xFBB81D, 0xFBBA1F,
+    0xFBBC21, 0xFBBE23, 0xFAC026, 0xFAC228, 0xFAC42A, 0xFAC62D, 0xF9C72F, 0xF9C932, 0xF9CB35,
+    0xF8CD37, 0xF8CF3A, 0xF7D13D, 0xF7D340, 0xF6D543, 0xF6D746, 0xF5D949, 0xF5DB4C, 0xF4DD4F,
+    0xF4DF53, 0xF4E156, 0xF3E35A, 0xF3E55D, 0xF2E661, 0xF2E865, 0xF2EA69, 0xF1EC6D, 0xF1ED71,
+    0xF1EF75, 0xF1F179, 0xF2F27D, 0xF2F482, 0xF3F586, 0xF3F68A, 0xF4F88E, 0xF5F992, 0xF6FA96,
+    0xF8FB9A, 0xF9FC9D, 0xFAFDA1, 0xFCFFA4,
+];
+
+static MAGMA_DATA: [u32; 256] = [
+    0x000004, 0x010005, 0x010106, 0x010108, 0x020109, 0x02020B, 0x02020D, 0x03030F, 0x030312,
+    0x040414, 0x050416, 0x060518, 0x06051A, 0x07061C, 0x08071E, 0x090720, 0x0A0822, 0x0B0924,
+    0x0C0926, 0x0D0A29, 0x0E0B2B, 0x100B2D, 0x110C2F, 0x120D31, 0x130D34, 0x140E36, 0x150E38,
+    0x160F3B, 0x180F3D, 0x19103F, 0x1A1042, 0x1C1044, 0x1D1147, 0x1E1149, 0x20114B, 0x21114E,
+    0x221150, 0x241253, 0x251255, 0x271258, 0x29115A, 0x2A115C, 0x2C115F, 0x2D1161, 0x2F1163,
+    0x311165, 0x331067, 0x341069, 0x36106B, 0x38106C, 0x390F6E, 0x3B0F70, 0x3D0F71, 0x3F0F72,
+    0x400F74, 0x420F75, 0x440F76, 0x451077, 0x471078, 0x491078, 0x4A1079, 0x4C117A, 0x4E117B,
+    0x4F127B, 0x51127C, 0x52137C, 0x54137D, 0x56147D, 0x57157E, 0x59157E, 0x5A167E, 0x5C167F,
+    0x5D177F, 0x5F187F, 0x601880, 0x621980, 0x641A80, 0x651A80, 0x671B80, 0x681C81, 0x6A1C81,
+    0x6B1D81, 0x6D1D81, 0x6E1E81, 0x701F81, 0x721F81, 0x732081, 0x752181, 0x762181, 0x782281,
+    0x792282, 0x7B2382, 0x7C2382, 0x7E2482, 0x802582, 0x812581, 0x832681, 0x842681, 0x862781,
+    0x882781, 0x892881, 0x8B2981, 0x8C2981, 0x8E2A81, 0x902A81, 0x912B81, 0x932B80, 0x942C80,
+    0x962C80, 0x982D80, 0x992D80, 0x9B2E7F, 0x9C2E7F, 0x9E2F7F, 0xA02F7F, 0xA1307E, 0xA3307E,
+    0xA5317E, 0xA6317D, 0xA8327D, 0xAA337D, 0xAB337C, 0xAD347C, 0xAE347B, 0xB0357B, 0xB2357B,
+    0xB3367A, 0xB5367A, 0xB73779, 0xB83779, 0xBA3878, 0xBC3978, 0xBD3977, 0xBF3A77, 0xC03A76,
+    0xC23B75, 0xC43C75, 0xC53C74, 0xC73D73, 0xC83E73, 0xCA3E72, 0xCC3F71, 0xCD4071, 0xCF4070,
+    0xD0416F, 0xD2426F, 0xD3436E, 0xD5446D, 0xD6456C, 0xD8456C, 0xD9466B, 0xDB476A, 0xDC4869,
+    0xDE4968, 0xDF4A68, 0xE04C67, 0xE24D66, 0xE34E65, 0xE44F64, 0xE55064, 0xE75263, 0xE85362,
+    0xE95462, 0xEA5661, 0xEB5760, 0xEC5860, 0xED5A5F, 0xEE5B5E, 0xEF5D5E, 0xF05F5E, 0xF1605D,
+    0xF2625D, 0xF2645C, 0xF3655C, 0xF4675C, 0xF4695C, 0xF56B5C, 0xF66C5C, 0xF66E5C, 0xF7705C,
+    0xF7725C, 0xF8745C, 0xF8765C, 0xF9785D, 0xF9795D, 0xF97B5D, 0xFA7D5E, 0xFA7F5E, 0xFA815F,
+    0xFB835F, 0xFB8560, 0xFB8761, 0xFC8961, 0xFC8A62, 0xFC8C63, 0xFC8E64, 0xFC9065, 0xFD9266,
+    0xFD9467, 0xFD9668, 0xFD9869, 0xFD9A6A, 0xFD9B6B, 0xFE9D6C, 0xFE9F6D, 0xFEA16E, 0xFEA36F,
+    0xFEA571, 0xFEA772, 0xFEA973, 0xFEAA74, 0xFEAC76, 0xFEAE77, 0xFEB078, 0xFEB27A, 0xFEB47B,
+    0xFEB67C, 0xFEB77E, 0xFEB97F, 0xFEBB81, 0xFEBD82, 0xFEBF84, 0xFEC185, 0xFEC287, 0xFEC488,
+    0xFEC68A, 0xFEC88C, 0xFECA8D, 0xFECC8F, 0xFECD90, 0xFECF92, 0xFED194, 0xFED395, 0xFED597,
+    0xFED799, 0xFED89A, 0xFDDA9C, 0xFDDC9E, 0xFDDEA0, 0xFDE0A1, 0xFDE2A3, 0xFDE3A5, 0xFDE5A7,
+    0xFDE7A9, 0xFDE9AA, 0xFDEBAC, 0xFCECAE, 0xFCEEB0, 0xFCF0B2, 0xFCF2B4, 0xFCF4B6, 0xFCF6B8,
+    0xFCF7B9, 0xFCF9BB, 0xFCFBBD, 0xFCFDBF,
+];
+
+static PLASMA_DATA: [u32; 256] = [
+    0x0D0887, 0x100788, 0x130789, 0x16078A, 0x19068C, 0x1B068D, 0x1D068E, 0x20068F, 0x220690,
+    0x240691, 0x260591, 0x280592, 0x2A0593, 0x2C0594, 0x2E0595, 0x2F0596, 0x310597, 0x330597,
+    0x350498, 0x370499, 0x38049A, 0x3A049A, 0x3C049B, 0x3E049C, 0x3F049C, 0x41049D, 0x43039E,
+    0x44039E, 0x46039F, 0x48039F, 0x4903A0, 0x4B03A1, 0x4C02A1, 0x4E02A2, 0x5002A2, 0x5102A3,
+    0x5302A3, 0x5502A4, 0x5601A4, 0x5801A4, 0x5901A5, 0x5B01A5, 0x5C01A6, 0x5E01A6, 0x6001A6,
+    0x6100A7, 0x6300A7, 0x6400A7, 0x6600A7, 0x6700A8, 0x6900A8, 0x6A00A8, 0x6C00A8, 0x6E00A8,
+    0x6F00A8, 0x7100A8, 0x7201A8, 0x7401A8, 0x7501A8, 0x7701A8, 0x7801A8, 0x7A02A8, 0x7B02A8,
+    0x7D03A8, 0x7E03A8, 0x8004A8, 0x8104A7, 0x8305A7, 0x8405A7, 0x8606A6, 0x8707A6, 0x8808A6,
+    0x8A09A5, 0x8B0AA5, 0x8D0BA5, 0x8E0CA4, 0x8F0DA4, 0x910EA3, 0x920FA3, 0x9410A2, 0x9511A1,
+    0x9613A1, 0x9814A0, 0x99159F, 0x9A169F, 0x9C179E, 0x9D189D, 0x9E199D, 0xA01A9C, 0xA11B9B,
+    0xA21D9A, 0xA31E9A, 0xA51F99, 0xA62098, 0xA72197, 0xA82296, 0xAA2395, 0xAB2494, 0xAC2694,
+    0xAD2793, 0xAE2892, 0xB02991, 0xB12A90, 0xB22B8F, 0xB32C8E, 0xB42E8D, 0xB52F8C, 0xB6308B,
+    0xB7318A, 0xB83289, 0xBA3388, 0xBB3488, 0xBC3587, 0xBD3786, 0xBE3885, 0xBF3984, 0xC03A83,
+    0xC13B82, 0xC23C81, 0xC33D80, 0xC43E7F, 0xC5407E, 0xC6417D, 0xC7427C, 0xC8437B, 0xC9447A,
+    0xCA457A, 0xCB4679, 0xCC4778, 0xCC4977, 0xCD4A76, 0xCE4B75, 0xCF4C74, 0xD04D73, 0xD14E72,
+    0xD24F71, 0xD35171, 0xD45270, 0xD5536F, 0xD5546E, 0xD6556D, 0xD7566C, 0xD8576B, 0xD9586A,
+    0xDA5A6A, 0xDA5B69, 0xDB5C68, 0xDC5D67, 0xDD5E66, 0xDE5F65, 0xDE6164, 0xDF6263, 0xE06363,
+    0xE16462, 0xE26561, 0xE26660, 0xE3685F, 0xE4695E, 0xE56A5D, 0xE56B5D, 0xE66C5C, 0xE76E5B,
+    0xE76F5A, 0xE87059, 0xE97158, 0xE97257, 0xEA7457, 0xEB7556, 0xEB7655, 0xEC7754, 0xED7953,
+    0xED7A52, 0xEE7B51, 0xEF7C51, 0xEF7E50, 0xF07F4F, 0xF0804E, 0xF1814D, 0xF1834C, 0xF2844B,
+    0xF3854B, 0xF3874A, 0xF48849, 0xF48948, 0xF58B47, 0xF58C46, 0xF68D45, 0xF68F44, 0xF79044,
+    0xF79143, 0xF79342, 0xF89441, 0xF89540, 0xF9973F, 0xF9983E, 0xF99A3E, 0xFA9B3D, 0xFA9C3C,
+    0xFA9E3B, 0xFB9F3A, 0xFBA139, 0xFBA238, 0xFCA338, 0xFCA537, 0xFCA636, 0xFCA835, 0xFCA934,
+    0xFDAB33, 0xFDAC33, 0xFDAE32, 0xFDAF31, 0xFDB130, 0xFDB22F, 0xFDB42F, 0xFDB52E, 0xFEB72D,
+    0xFEB82C, 0xFEBA2C, 0xFEBB2B, 0xFEBD2A, 0xFEBE2A, 0xFEC029, 0xFDC229, 0xFDC328, 0xFDC527,
+    0xFDC627, 0xFDC827, 0xFDCA26, 0xFDCB26, 0xFCCD25, 0xFCCE25, 0xFCD025, 0xFCD225, 0xFBD324,
+    0xFBD524, 0xFBD724, 0xFAD824, 0xFADA24, 0xF9DC24, 0xF9DD25, 0xF8DF25, 0xF8E125, 0xF7E225,
+    0xF7E425, 0xF6E626, 0xF6E826, 0xF5E926, 0xF5EB27, 0xF4ED27, 0xF3EE27, 0xF3F027, 0xF2F227,
+    0xF1F426, 0xF1F525, 0xF0F724, 0xF0F921,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample() {
+        assert_eq!(VIRIDIS.sample(0.0), Color::from_hex(0x440154));
+        assert_eq!(VIRIDIS.sample(1.0), Color::from_hex(0xFDE725));
+        assert_eq!(VIRIDIS.sample(-1.0), VIRIDIS.sample(0.0));
+        assert_eq!(VIRIDIS.sample(2.0), VIRIDIS.sample(1.0));
+        assert_eq!(INFERNO.sample(0.0), Color::from_hex(0x000004));
+        assert_eq!(MAGMA.sample(1.0), Color::from_hex(0xFCFDBF));
+        assert_eq!(PLASMA.sample(0.0), Color::from_hex(0x0D0887));
+        assert_eq!(VIRIDIS.sample(0.5 / 255.0), Color::from_hex(0x440255));
+        let turbo = TURBO.sample(0.0);
+        assert!(turbo.delta_e(&Color::from_hex(0x23171B)) < 2.0);
+        let cividis = CIVIDIS.sample(1.0);
+        assert!(cividis.delta_e(&Color::from_hex(0xFEE838)) < 2.0);
+    }
+
+    #[test]
+    fn diverging() {
+        let low = Color::from_hex(0x2166AC);
+        let mid = Color::from_hex(0xF7F7F7);
+        let high = Color::from_hex(0xB2182B);
+        let colormap = Colormap::diverging(&low, &mid, &high);
+        assert_eq!(colormap.sample(0.5), mid);
+        assert_eq!(colormap.sample(-1.0), colormap.sample(0.0));
+        for i in 0..=10 {
+            let t = i as f32 / 20.0;
+            let a = colormap.sample(t).to_oklab().0;
+            let b = colormap.sample(1.0 - t).to_oklab().0;
+            assert!((a - b).abs() < 0.01);
+        }
+        let palette = colormap.discrete(5);
+        let lightness: Vec<f32> = palette.iter().map(|c| c.to_oklab().0).collect();
+        assert!(lightness[0] < lightness[1] && lightness[1] < lightness[2]);
+        assert!(lightness[2] > lightness[3] && lightness[3] > lightness[4]);
+    }
+
+    #[test]
+    fn cubehelix() {
+        assert_eq!(CUBEHELIX.sample(0.5), Color::new(160, 121, 73));
+        let mut previous = -1.0;
+        for i in 0..=20 {
+            let luma = CUBEHELIX.sample(i as f32 / 20.0).to_xyz().1;
+            assert!(luma > previous);
+            previous = luma;
+        }
+        let gray = Colormap::cubehelix(0.5, -1.5, 0.0, 1.0).sample(0.5);
+        assert_eq!(gray, Color::new(128, 128, 128));
+        let gamma = Colormap::cubehelix(0.5, -1.5, 0.0, 2.0).sample(0.5);
+        assert_eq!(gamma, Color::new(64, 64, 64));
+    }
+
+    #[test]
+    fn discrete() {
+        for colormap in [VIRIDIS, MAGMA, INFERNO, PLASMA, CIVIDIS, TURBO, CUBEHELIX] {
+            let palette = colormap.discrete(10);
+            assert_eq!(palette.len(), 10);
+            assert_eq!(palette[0], colormap.sample(0.0));
+            assert_eq!(palette[9], colormap.sample(1.0));
+        }
+        assert_eq!(VIRIDIS.discrete(1).into_colors(), vec![VIRIDIS.sample(0.5)]);
+        assert!(VIRIDIS.discrete(0).is_empty());
+    }
+}
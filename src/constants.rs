@@ -1,5 +1,13 @@
 use super::*;
-use phf::phf_map;
+
+pub mod brewer;
+pub mod css;
+pub mod material;
+pub mod tailwind;
+pub mod themes;
+pub mod x11;
+#[cfg(feature = "xkcd")]
+pub mod xkcd;
 
 pub mod primary {
     use super::*;
@@ -26,155 +34,313 @@ pub mod rainbow {
 /// <https://discworld.fandom.com/wiki/Octarine>
 pub const OCTARINE: Color = Color(204, 221, 0);
 
-pub(crate) static RGB_TO_COLOR_NAMES: phf::Map<&'static str, Color> = phf_map! {
-    "black" => Color(0, 0, 0),
-    "navy" => Color(0, 0, 128),
-    "navyblue" => Color(0, 0, 128),
-    "darkblue" => Color(0, 0, 139),
-    "mediumblue" => Color(0, 0, 205),
-    "blue" => Color(0, 0, 255),
-    "darkgreen" => Color(0, 100, 0),
-    "green" => Color(0, 128, 0),
-    "darkcyan" => Color(0, 139, 139),
-    "deepskyblue" => Color(0, 191, 255),
-    "darkturquoise" => Color(0, 206, 209),
-    "mediumspringgreen" => Color(0, 250, 154),
-    "lime" => Color(0, 255, 0),
-    "springgreen" => Color(0, 255, 127),
-    "cyan" => Color(0, 255, 255),
-    "aqua" => Color(0, 255, 255),
-    "midnightblue" => Color(25, 25, 112),
-    "dodgerblue" => Color(30, 144, 255),
-    "lightseagreen" => Color(32, 178, 170),
-    "forestgreen" => Color(34, 139, 34),
-    "seagreen" => Color(46, 139, 87),
-    "darkslategray" => Color(47, 79, 79),
-    "darkslategrey" => Color(47, 79, 79),
-    "limegreen" => Color(50, 205, 50),
-    "mediumseagreen" => Color(60, 179, 113),
-    "turquoise" => Color(64, 224, 208),
-    "royalblue" => Color(65, 105, 225),
-    "steelblue" => Color(70, 130, 180),
-    "darkslateblue" => Color(72, 61, 139),
-    "mediumturquoise" => Color(72, 209, 204),
-    "indigo" => Color(75, 0, 130),
-    "darkolivegreen" => Color(85, 107, 47),
-    "cadetblue" => Color(95, 158, 160),
-    "cornflowerblue" => Color(100, 149, 237),
-    "mediumaquamarine" => Color(102, 205, 170),
-    "dimgray" => Color(105, 105, 105),
-    "dimgrey" => Color(105, 105, 105),
-    "slateblue" => Color(106, 90, 205),
-    "olivedrab" => Color(107, 142, 35),
-    "slategray" => Color(112, 128, 144),
-    "slategrey" => Color(112, 128, 144),
-    "lightslategray" => Color(119, 136, 153),
-    "lightslategrey" => Color(119, 136, 153),
-    "mediumslateblue" => Color(123, 104, 238),
-    "lawngreen" => Color(124, 252, 0),
-    "chartreuse" => Color(127, 255, 0),
-    "aquamarine" => Color(127, 255, 212),
-    "maroon" => Color(128, 0, 0),
-    "purple" => Color(128, 0, 128),
-    "olive" => Color(128, 128, 0),
-    "gray" => Color(128, 128, 128),
-    "grey" => Color(128, 128, 128),
-    "lightslateblue" => Color(132, 112, 255),
-    "skyblue" => Color(135, 206, 235),
-    "lightskyblue" => Color(135, 206, 250),
-    "blueviolet" => Color(138, 43, 226),
-    "darkred" => Color(139, 0, 0),
-    "darkmagenta" => Color(139, 0, 139),
-    "saddlebrown" => Color(139, 69, 19),
-    "darkseagreen" => Color(143, 188, 143),
-    "lightgreen" => Color(144, 238, 144),
-    "mediumpurple" => Color(147, 112, 219),
-    "darkviolet" => Color(148, 0, 211),
-    "palegreen" => Color(152, 251, 152),
-    "darkorchid" => Color(153, 50, 204),
-    "yellowgreen" => Color(154, 205, 50),
-    "sienna" => Color(160, 82, 45),
-    "brown" => Color(165, 42, 42),
-    "darkgray" => Color(169, 169, 169),
-    "darkgrey" => Color(169, 169, 169),
-    "lightblue" => Color(173, 216, 230),
-    "greenyellow" => Color(173, 255, 47),
-    "paleturquoise" => Color(175, 238, 238),
-    "lightsteelblue" => Color(176, 196, 222),
-    "powderblue" => Color(176, 224, 230),
-    "firebrick" => Color(178, 34, 34),
-    "darkgoldenrod" => Color(184, 134, 11),
-    "mediumorchid" => Color(186, 85, 211),
-    "rosybrown" => Color(188, 143, 143),
-    "darkkhaki" => Color(189, 183, 107),
-    "silver" => Color(192, 192, 192),
-    "mediumvioletred" => Color(199, 21, 133),
-    "indianred" => Color(205, 92, 92),
-    "peru" => Color(205, 133, 63),
-    "violetred" => Color(208, 32, 144),
-    "chocolate" => Color(210, 105, 30),
-    "tan" => Color(210, 180, 140),
-    "lightgray" => Color(211, 211, 211),
-    "lightgrey" => Color(211, 211, 211),
-    "thistle" => Color(216, 191, 216),
-    "orchid" => Color(218, 112, 214),
-    "goldenrod" => Color(218, 165, 32),
-    "palevioletred" => Color(219, 112, 147),
-    "crimson" => Color(220, 20, 60),
-    "gainsboro" => Color(220, 220, 220),
-    "plum" => Color(221, 160, 221),
-    "burlywood" => Color(222, 184, 135),
-    "lightcyan" => Color(224, 255, 255),
-    "lavender" => Color(230, 230, 250),
-    "darksalmon" => Color(233, 150, 122),
-    "violet" => Color(238, 130, 238),
-    "lightgoldenrod" => Color(238, 221, 130),
-    "palegoldenrod" => Color(238, 232, 170),
-    "lightcoral" => Color(240, 128, 128),
-    "khaki" => Color(240, 230, 140),
-    "aliceblue" => Color(240, 248, 255),
-    "honeydew" => Color(240, 255, 240),
-    "azure" => Color(240, 255, 255),
-    "sandybrown" => Color(244, 164, 96),
-    "wheat" => Color(245, 222, 179),
-    "beige" => Color(245, 245, 220),
-    "whitesmoke" => Color(245, 245, 245),
-    "mintcream" => Color(245, 255, 250),
-    "ghostwhite" => Color(248, 248, 255),
-    "salmon" => Color(250, 128, 114),
-    "antiquewhite" => Color(250, 235, 215),
-    "linen" => Color(250, 240, 230),
-    "lightgoldenrodyellow" => Color(250, 250, 210),
-    "oldlace" => Color(253, 245, 230),
-    "red" => Color(255, 0, 0),
-    "magenta" => Color(255, 0, 255),
-    "fuchsia" => Color(255, 0, 255),
-    "deeppink" => Color(255, 20, 147),
-    "orangered" => Color(255, 69, 0),
-    "tomato" => Color(255, 99, 71),
-    "hotpink" => Color(255, 105, 180),
-    "coral" => Color(255, 127, 80),
-    "darkorange" => Color(255, 140, 0),
-    "lightsalmon" => Color(255, 160, 122),
-    "orange" => Color(255, 165, 0),
-    "lightpink" => Color(255, 182, 193),
-    "pink" => Color(255, 192, 203),
-    "gold" => Color(255, 215, 0),
-    "peachpuff" => Color(255, 218, 185),
-    "navajowhite" => Color(255, 222, 173),
-    "moccasin" => Color(255, 228, 181),
-    "bisque" => Color(255, 228, 196),
-    "mistyrose" => Color(255, 228, 225),
-    "blanchedalmond" => Color(255, 235, 205),
-    "papayawhip" => Color(255, 239, 213),
-    "lavenderblush" => Color(255, 240, 245),
-    "seashell" => Color(255, 245, 238),
-    "cornsilk" => Color(255, 248, 220),
-    "lemonchiffon" => Color(255, 250, 205),
-    "floralwhite" => Color(255, 250, 240),
-    "snow" => Color(255, 250, 250),
-    "yellow" => Color(255, 255, 0),
-    "lightyellow" => Color(255, 255, 224),
-    "ivory" => Color(255, 255, 240),
-    "white" => Color(255, 255, 255),
-};
+/// The W3C web colors ordered by their RGB values, where the first name of a color is the one
+/// returned by [`get_web_color()`](Color#method.get_web_color).
+pub(crate) static WEB_COLORS: &[(&str, Color)] = &[
+    ("black", Color(0, 0, 0)),
+    ("navyblue", Color(0, 0, 128)),
+    ("navy", Color(0, 0, 128)),
+    ("darkblue", Color(0, 0, 139)),
+    ("mediumblue", Color(0, 0, 205)),
+    ("blue", Color(0, 0, 255)),
+    ("darkgreen", Color(0, 100, 0)),
+    ("green", Color(0, 128, 0)),
+    ("teal", Color(0, 128, 128)),
+    ("darkcyan", Color(0, 139, 139)),
+    ("deepskyblue", Color(0, 191, 255)),
+    ("darkturquoise", Color(0, 206, 209)),
+    ("mediumspringgreen", Color(0, 250, 154)),
+    ("lime", Color(0, 255, 0)),
+    ("springgreen", Color(0, 255, 127)),
+    ("cyan", Color(0, 255, 255)),
+    ("aqua", Color(0, 255, 255)),
+    ("midnightblue", Color(25, 25, 112)),
+    ("dodgerblue", Color(30, 144, 255)),
+    ("lightseagreen", Color(32, 178, 170)),
+    ("forestgreen", Color(34, 139, 34)),
+    ("seagreen", Color(46, 139, 87)),
+    ("darkslategray", Color(47, 79, 79)),
+    ("darkslategrey", Color(47, 79, 79)),
+    ("limegreen", Color(50, 205, 50)),
+    ("mediumseagreen", Color(60, 179, 113)),
+    ("turquoise", Color(64, 224, 208)),
+    ("royalblue", Color(65, 105, 225)),
+    ("steelblue", Color(70, 130, 180)),
+    ("darkslateblue", Color(72, 61, 139)),
+    ("mediumturquoise", Color(72, 209, 204)),
+    ("indigo", Color(75, 0, 130)),
+    ("darkolivegreen", Color(85, 107, 47)),
+    ("cadetblue", Color(95, 158, 160)),
+    ("cornflowerblue", Color(100, 149, 237)),
+    ("rebeccapurple", Color(102, 51, 153)),
+    ("mediumaquamarine", Color(102, 205, 170)),
+    ("dimgray", Color(105, 105, 105)),
+    ("dimgrey", Color(105, 105, 105)),
+    ("slateblue", Color(106, 90, 205)),
+    ("olivedrab", Color(107, 142, 35)),
+    ("slategrey", Color(112, 128, 144)),
+    ("slategray", Color(112, 128, 144)),
+    ("lightslategray", Color(119, 136, 153)),
+    ("lightslategrey", Color(119, 136, 153)),
+    ("mediumslateblue", Color(123, 104, 238)),
+    ("lawngreen", Color(124, 252, 0)),
+    ("chartreuse", Color(127, 255, 0)),
+    ("aquamarine", Color(127, 255, 212)),
+    ("maroon", Color(128, 0, 0)),
+    ("purple", Color(128, 0, 128)),
+    ("olive", Color(128, 128, 0)),
+    ("gray", Color(128, 128, 128)),
+    ("grey", Color(128, 128, 128)),
+    ("lightslateblue", Color(132, 112, 255)),
+    ("skyblue", Color(135, 206, 235)),
+    ("lightskyblue", Color(135, 206, 250)),
+    ("blueviolet", Color(138, 43, 226)),
+    ("darkred", Color(139, 0, 0)),
+    ("darkmagenta", Color(139, 0, 139)),
+    ("saddlebrown", Color(139, 69, 19)),
+    ("darkseagreen", Color(143, 188, 143)),
+    ("lightgreen", Color(144, 238, 144)),
+    ("mediumpurple", Color(147, 112, 219)),
+    ("darkviolet", Color(148, 0, 211)),
+    ("palegreen", Color(152, 251, 152)),
+    ("darkorchid", Color(153, 50, 204)),
+    ("yellowgreen", Color(154, 205, 50)),
+    ("sienna", Color(160, 82, 45)),
+    ("brown", Color(165, 42, 42)),
+    ("darkgray", Color(169, 169, 169)),
+    ("darkgrey", Color(169, 169, 169)),
+    ("lightblue", Color(173, 216, 230)),
+    ("greenyellow", Color(173, 255, 47)),
+    ("paleturquoise", Color(175, 238, 238)),
+    ("lightsteelblue", Color(176, 196, 222)),
+    ("powderblue", Color(176, 224, 230)),
+    ("firebrick", Color(178, 34, 34)),
+    ("darkgoldenrod", Color(184, 134, 11)),
+    ("mediumorchid", Color(186, 85, 211)),
+    ("rosybrown", Color(188, 143, 143)),
+    ("darkkhaki", Color(189, 183, 107)),
+    ("silver", Color(192, 192, 192)),
+    ("mediumvioletred", Color(199, 21, 133)),
+    ("indianred", Color(205, 92, 92)),
+    ("peru", Color(205, 133, 63)),
+    ("violetred", Color(208, 32, 144)),
+    ("chocolate", Color(210, 105, 30)),
+    ("tan", Color(210, 180, 140)),
+    ("lightgrey", Color(211, 211, 211)),
+    ("lightgray", Color(211, 211, 211)),
+    ("thistle", Color(216, 191, 216)),
+    ("orchid", Color(218, 112, 214)),
+    ("goldenrod", Color(218, 165, 32)),
+    ("palevioletred", Color(219, 112, 147)),
+    ("crimson", Color(220, 20, 60)),
+    ("gainsboro", Color(220, 220, 220)),
+    ("plum", Color(221, 160, 221)),
+    ("burlywood", Color(222, 184, 135)),
+    ("lightcyan", Color(224, 255, 255)),
+    ("lavender", Color(230, 230, 250)),
+    ("darksalmon", Color(233, 150, 122)),
+    ("violet", Color(238, 130, 238)),
+    ("lightgoldenrod", Color(238, 221, 130)),
+    ("palegoldenrod", Color(238, 232, 170)),
+    ("lightcoral", Color(240, 128, 128)),
+    ("khaki", Color(240, 230, 140)),
+    ("aliceblue", Color(240, 248, 255)),
+    ("honeydew", Color(240, 255, 240)),
+    ("azure", Color(240, 255, 255)),
+    ("sandybrown", Color(244, 164, 96)),
+    ("wheat", Color(245, 222, 179)),
+    ("beige", Color(245, 245, 220)),
+    ("whitesmoke", Color(245, 245, 245)),
+    ("mintcream", Color(245, 255, 250)),
+    ("ghostwhite", Color(248, 248, 255)),
+    ("salmon", Color(250, 128, 114)),
+    ("antiquewhite", Color(250, 235, 215)),
+    ("linen", Color(250, 240, 230)),
+    ("lightgoldenrodyellow", Color(250, 250, 210)),
+    ("oldlace", Color(253, 245, 230)),
+    ("red", Color(255, 0, 0)),
+    ("fuchsia", Color(255, 0, 255)),
+    ("magenta", Color(255, 0, 255)),
+    ("deeppink", Color(255, 20, 147)),
+    ("orangered", Color(255, 69, 0)),
+    ("tomato", Color(255, 99, 71)),
+    ("hotpink", Color(255, 105, 180)),
+    ("coral", Color(255, 127, 80)),
+    ("darkorange", Color(255, 140, 0)),
+    ("lightsalmon", Color(255, 160, 122)),
+    ("orange", Color(255, 165, 0)),
+    ("lightpink", Color(255, 182, 193)),
+    ("pink", Color(255, 192, 203)),
+    ("gold", Color(255, 215, 0)),
+    ("peachpuff", Color(255, 218, 185)),
+    ("navajowhite", Color(255, 222, 173)),
+    ("moccasin", Color(255, 228, 181)),
+    ("bisque", Color(255, 228, 196)),
+    ("mistyrose", Color(255, 228, 225)),
+    ("blanchedalmond", Color(255, 235, 205)),
+    ("papayawhip", Color(255, 239, 213)),
+    ("lavenderblush", Color(255, 240, 245)),
+    ("seashell", Color(255, 245, 238)),
+    ("cornsilk", Color(255, 248, 220)),
+    ("lemonchiffon", Color(255, 250, 205)),
+    ("floralwhite", Color(255, 250, 240)),
+    ("snow", Color(255, 250, 250)),
+    ("yellow", Color(255, 255, 0)),
+    ("lightyellow", Color(255, 255, 224)),
+    ("ivory", Color(255, 255, 240)),
+    ("white", Color(255, 255, 255)),
+];
+
+/// The number of slots in [`WEB_COLORS_BY_RGB`] and [`WEB_COLORS_BY_NAME`], a power of two over
+/// three times the number of web colors, so that lookups rarely need to probe more than one slot.
+const WEB_COLOR_SLOTS: usize = 512;
+
+/// An open addressing hash table from RGB values to the canonical name in [`WEB_COLORS`], built
+/// at compile time. Each slot holds the index of the color plus one, or zero when it is empty.
+static WEB_COLORS_BY_RGB: [u8; WEB_COLOR_SLOTS] = web_colors_by_rgb();
+
+/// An open addressing hash table from names to the colors in [`WEB_COLORS`], the same as
+/// [`WEB_COLORS_BY_RGB`]. Names are hashed in ASCII lowercase, so lookups are case-insensitive
+/// without allocating a lowercase copy of the name.
+static WEB_COLORS_BY_NAME: [u8; WEB_COLOR_SLOTS] = web_colors_by_name();
+
+const fn rgb_key(color: Color) -> u32 {
+    (color.0 as u32) << 16 | (color.1 as u32) << 8 | color.2 as u32
+}
+
+/// Fibonacci hashing, which spreads out the nearby RGB values of similar colors.
+const fn rgb_slot(rgb: u32) -> usize {
+    (rgb.wrapping_mul(0x9E37_79B9) >> (32 - WEB_COLOR_SLOTS.trailing_zeros())) as usize
+}
+
+const fn web_colors_by_rgb() -> [u8; WEB_COLOR_SLOTS] {
+    assert!(WEB_COLORS.len() < u8::MAX as usize);
+    let mut table = [0; WEB_COLOR_SLOTS];
+    let mut index = 0;
+    while index < WEB_COLORS.len() {
+        let rgb = rgb_key(WEB_COLORS[index].1);
+        let mut slot = rgb_slot(rgb);
+        loop {
+            if table[slot] == 0 {
+                table[slot] = index as u8 + 1;
+                break;
+            }
+            // Later names of the same color are aliases, so the first one is kept.
+            if rgb_key(WEB_COLORS[table[slot] as usize - 1].1) == rgb {
+                break;
+            }
+            slot = (slot + 1) % WEB_COLOR_SLOTS;
+        }
+        index += 1;
+    }
+    table
+}
+
+/// FNV-1a of the name in ASCII lowercase.
+const fn name_slot(name: &[u8]) -> usize {
+    let mut hash: u32 = 0x811C_9DC5;
+    let mut i = 0;
+    while i < name.len() {
+        hash ^= name[i].to_ascii_lowercase() as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+    hash as usize % WEB_COLOR_SLOTS
+}
+
+const fn web_colors_by_name() -> [u8; WEB_COLOR_SLOTS] {
+    let mut table = [0; WEB_COLOR_SLOTS];
+    let mut index = 0;
+    while index < WEB_COLORS.len() {
+        let mut slot = name_slot(WEB_COLORS[index].0.as_bytes());
+        while table[slot] != 0 {
+            slot = (slot + 1) % WEB_COLOR_SLOTS;
+        }
+        table[slot] = index as u8 + 1;
+        index += 1;
+    }
+    table
+}
+
+/// Finds a web color by its name, ignoring ASCII case, in constant time.
+pub(crate) fn web_color(name: &str) -> Option<Color> {
+    let mut slot = name_slot(name.as_bytes());
+    loop {
+        let (web_color, color) =
+            WEB_COLORS.get(WEB_COLORS_BY_NAME[slot].checked_sub(1)? as usize)?;
+        if web_color.eq_ignore_ascii_case(name) {
+            return Some(*color);
+        }
+        slot = (slot + 1) % WEB_COLOR_SLOTS;
+    }
+}
+
+/// Finds the canonical name of a web color in constant time.
+pub(crate) fn web_color_name(color: Color) -> Option<&'static str> {
+    let rgb = rgb_key(color);
+    let mut slot = rgb_slot(rgb);
+    loop {
+        let (name, web_color) = WEB_COLORS.get(WEB_COLORS_BY_RGB[slot].checked_sub(1)? as usize)?;
+        if *web_color == color {
+            return Some(name);
+        }
+        slot = (slot + 1) % WEB_COLOR_SLOTS;
+    }
+}
+
+/// Gets every web color known to [`from_web_color()`](Color#method.from_web_color), with its
+/// name, ordered by their RGB values. Colors with more than one name, such as `"gray"` and
+/// `"grey"`, are returned once for each name.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{constants, Color};
+///
+/// let names: Vec<_> = constants::web_colors().map(|(name, _)| name).collect();
+///
+/// assert_eq!(names.first(), Some(&"black"));
+/// assert!(names.contains(&"rebeccapurple"));
+/// ```
+pub fn web_colors() -> impl Iterator<Item = (&'static str, Color)> {
+    WEB_COLORS.iter().copied()
+}
+
+/// Gets the web colors which belong to `family`, the same as [`web_colors()`].
+///
+/// # Example
+///
+/// ```
+/// use octarine::{constants, HueFamily};
+///
+/// let blues: Vec<_> = constants::web_colors_by_hue_family(HueFamily::Blue)
+///     .map(|(name, _)| name)
+///     .collect();
+///
+/// assert!(blues.contains(&"navy") && blues.contains(&"skyblue"));
+/// assert!(!blues.contains(&"red"));
+/// ```
+pub fn web_colors_by_hue_family(family: HueFamily) -> impl Iterator<Item = (&'static str, Color)> {
+    web_colors().filter(move |(_, color)| family.contains(color))
+}
+
+/// Gets the web colors whose names start with `prefix` (case-insensitive), the same as
+/// [`web_colors()`].
+///
+/// # Example
+///
+/// ```
+/// use octarine::constants;
+///
+/// let mut names: Vec<_> = constants::web_colors_with_prefix("Dark")
+///     .map(|(name, _)| name)
+///     .collect();
+/// names.sort();
+///
+/// assert_eq!(&names[..3], ["darkblue", "darkcyan", "darkgoldenrod"]);
+/// ```
+pub fn web_colors_with_prefix(prefix: &str) -> impl Iterator<Item = (&'static str, Color)> + '_ {
+    web_colors().filter(move |(name, _)| {
+        name.len() >= prefix.len() && name[..prefix.len()].eq_ignore_ascii_case(prefix)
+    })
+}
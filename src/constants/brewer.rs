@@ -0,0 +1,862 @@
+//! The [ColorBrewer](https://colorbrewer2.org) color schemes by Cynthia Brewer, Mark Harrower,
+//! and The Pennsylvania State University, licensed under the Apache License, Version 2.0.
+//!
+//! # Example
+//!
+//! ```
+//! use octarine::{constants::brewer, Color};
+//!
+//! let palette = brewer::palette("RdBu", 3).unwrap();
+//!
+//! assert_eq!(palette[0], Color::from_hex(0xEF8A62));
+//! assert_eq!(brewer::RD_BU.palette(3), Some(palette));
+//! ```
+
+use super::*;
+use core::ops::RangeInclusive;
+
+/// A ColorBrewer color scheme, which comes in several sizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scheme {
+    name: &'static str,
+    kind: SchemeKind,
+    colors: &'static [&'static [u32]],
+}
+
+/// The kinds of data a ColorBrewer [`Scheme`] is designed for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemeKind {
+    /// Ordered data that progresses from low to high.
+    Sequential,
+    /// Ordered data with a critical midpoint, e.g. zero.
+    Diverging,
+    /// Categorical data without any order.
+    Qualitative,
+}
+
+impl Scheme {
+    /// Gets the ColorBrewer name of the scheme (e.g. `"RdBu"`).
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Gets the kind of data the scheme is designed for.
+    #[inline]
+    pub fn kind(&self) -> SchemeKind {
+        self.kind
+    }
+
+    /// Gets the range of sizes (number of colors) the scheme is available in.
+    pub fn sizes(&self) -> RangeInclusive<usize> {
+        match self.kind {
+            SchemeKind::Qualitative => 3..=self.colors[0].len(),
+            _ => 3..=self.colors.len() + 2,
+        }
+    }
+
+    /// Gets the scheme with `n` colors. Returns `None` when the scheme is not available in that
+    /// size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants::brewer;
+    ///
+    /// assert_eq!(brewer::BLUES.palette(9).unwrap().len(), 9);
+    /// assert_eq!(brewer::BLUES.palette(10), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn palette(&self, n: usize) -> Option<Palette> {
+        if !self.sizes().contains(&n) {
+            return None;
+        }
+        let colors = match self.kind {
+            SchemeKind::Qualitative => &self.colors[0][..n],
+            _ => self.colors[n - 3],
+        };
+        Some(colors.iter().map(|&hex| Color::from_hex(hex)).collect())
+    }
+}
+
+/// Every ColorBrewer scheme.
+pub const SCHEMES: [Scheme; 35] = [
+    BLUES, GREENS, GREYS, ORANGES, PURPLES, REDS, BU_GN, BU_PU, GN_BU, OR_RD, PU_BU_GN, PU_BU,
+    PU_RD, RD_PU, YL_GN_BU, YL_GN, YL_OR_BR, YL_OR_RD, BR_BG, PR_GN, PI_YG, PU_OR, RD_BU, RD_GY,
+    RD_YL_BU, RD_YL_GN, SPECTRAL, ACCENT, DARK2, PAIRED, PASTEL1, PASTEL2, SET1, SET2, SET3,
+];
+
+/// Gets a scheme by its ColorBrewer name (case-insensitive). Returns `None` when the scheme
+/// cannot be found.
+///
+/// # Example
+///
+/// ```
+/// use octarine::constants::brewer;
+///
+/// assert_eq!(brewer::scheme("spectral"), Some(brewer::SPECTRAL));
+/// ```
+pub fn scheme(name: &str) -> Option<Scheme> {
+    SCHEMES
+        .into_iter()
+        .find(|scheme| scheme.name.eq_ignore_ascii_case(name))
+}
+
+/// Gets the scheme named `name` (case-insensitive) with `n` colors. Returns `None` when the
+/// scheme cannot be found or is not available in that size.
+///
+/// # Example
+///
+/// ```
+/// use octarine::constants::brewer;
+///
+/// assert_eq!(brewer::palette("RdBu", 9).unwrap().len(), 9);
+/// assert_eq!(brewer::palette("RdBu", 12), None);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn palette(name: &str, n: usize) -> Option<Palette> {
+    scheme(name)?.palette(n)
+}
+
+pub const BLUES: Scheme = Scheme {
+    name: "Blues",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xDEEBF7, 0x9ECAE1, 0x3182BD],
+        &[0xEFF3FF, 0xBDD7E7, 0x6BAED6, 0x2171B5],
+        &[0xEFF3FF, 0xBDD7E7, 0x6BAED6, 0x3182BD, 0x08519C],
+        &[0xEFF3FF, 0xC6DBEF, 0x9ECAE1, 0x6BAED6, 0x3182BD, 0x08519C],
+        &[
+            0xEFF3FF, 0xC6DBEF, 0x9ECAE1, 0x6BAED6, 0x4292C6, 0x2171B5, 0x084594,
+        ],
+        &[
+            0xF7FBFF, 0xDEEBF7, 0xC6DBEF, 0x9ECAE1, 0x6BAED6, 0x4292C6, 0x2171B5, 0x084594,
+        ],
+        &[
+            0xF7FBFF, 0xDEEBF7, 0xC6DBEF, 0x9ECAE1, 0x6BAED6, 0x4292C6, 0x2171B5, 0x08519C,
+            0x08306B,
+        ],
+    ],
+};
+
+pub const GREENS: Scheme = Scheme {
+    name: "Greens",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xE5F5E0, 0xA1D99B, 0x31A354],
+        &[0xEDF8E9, 0xBAE4B3, 0x74C476, 0x238B45],
+        &[0xEDF8E9, 0xBAE4B3, 0x74C476, 0x31A354, 0x006D2C],
+        &[0xEDF8E9, 0xC7E9C0, 0xA1D99B, 0x74C476, 0x31A354, 0x006D2C],
+        &[
+            0xEDF8E9, 0xC7E9C0, 0xA1D99B, 0x74C476, 0x41AB5D, 0x238B45, 0x005A32,
+        ],
+        &[
+            0xF7FCF5, 0xE5F5E0, 0xC7E9C0, 0xA1D99B, 0x74C476, 0x41AB5D, 0x238B45, 0x005A32,
+        ],
+        &[
+            0xF7FCF5, 0xE5F5E0, 0xC7E9C0, 0xA1D99B, 0x74C476, 0x41AB5D, 0x238B45, 0x006D2C,
+            0x00441B,
+        ],
+    ],
+};
+
+pub const GREYS: Scheme = Scheme {
+    name: "Greys",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xF0F0F0, 0xBDBDBD, 0x636363],
+        &[0xF7F7F7, 0xCCCCCC, 0x969696, 0x525252],
+        &[0xF7F7F7, 0xCCCCCC, 0x969696, 0x636363, 0x252525],
+        &[0xF7F7F7, 0xD9D9D9, 0xBDBDBD, 0x969696, 0x636363, 0x252525],
+        &[
+            0xF7F7F7, 0xD9D9D9, 0xBDBDBD, 0x969696, 0x737373, 0x525252, 0x252525,
+        ],
+        &[
+            0xFFFFFF, 0xF0F0F0, 0xD9D9D9, 0xBDBDBD, 0x969696, 0x737373, 0x525252, 0x252525,
+        ],
+        &[
+            0xFFFFFF, 0xF0F0F0, 0xD9D9D9, 0xBDBDBD, 0x969696, 0x737373, 0x525252, 0x252525,
+            0x000000,
+        ],
+    ],
+};
+
+pub const ORANGES: Scheme = Scheme {
+    name: "Oranges",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xFEE6CE, 0xFDAE6B, 0xE6550D],
+        &[0xFEEDDE, 0xFDBE85, 0xFD8D3C, 0xD94701],
+        &[0xFEEDDE, 0xFDBE85, 0xFD8D3C, 0xE6550D, 0xA63603],
+        &[0xFEEDDE, 0xFDD0A2, 0xFDAE6B, 0xFD8D3C, 0xE6550D, 0xA63603],
+        &[
+            0xFEEDDE, 0xFDD0A2, 0xFDAE6B, 0xFD8D3C, 0xF16913, 0xD94801, 0x8C2D04,
+        ],
+        &[
+            0xFFF5EB, 0xFEE6CE, 0xFDD0A2, 0xFDAE6B, 0xFD8D3C, 0xF16913, 0xD94801, 0x8C2D04,
+        ],
+        &[
+            0xFFF5EB, 0xFEE6CE, 0xFDD0A2, 0xFDAE6B, 0xFD8D3C, 0xF16913, 0xD94801, 0xA63603,
+            0x7F2704,
+        ],
+    ],
+};
+
+pub const PURPLES: Scheme = Scheme {
+    name: "Purples",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xEFEDF5, 0xBCBDDC, 0x756BB1],
+        &[0xF2F0F7, 0xCBC9E2, 0x9E9AC8, 0x6A51A3],
+        &[0xF2F0F7, 0xCBC9E2, 0x9E9AC8, 0x756BB1, 0x54278F],
+        &[0xF2F0F7, 0xDADAEB, 0xBCBDDC, 0x9E9AC8, 0x756BB1, 0x54278F],
+        &[
+            0xF2F0F7, 0xDADAEB, 0xBCBDDC, 0x9E9AC8, 0x807DBA, 0x6A51A3, 0x4A1486,
+        ],
+        &[
+            0xFCFBFD, 0xEFEDF5, 0xDADAEB, 0xBCBDDC, 0x9E9AC8, 0x807DBA, 0x6A51A3, 0x4A1486,
+        ],
+        &[
+            0xFCFBFD, 0xEFEDF5, 0xDADAEB, 0xBCBDDC, 0x9E9AC8, 0x807DBA, 0x6A51A3, 0x54278F,
+            0x3F007D,
+        ],
+    ],
+};
+
+pub const REDS: Scheme = Scheme {
+    name: "Reds",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xFEE0D2, 0xFC9272, 0xDE2D26],
+        &[0xFEE5D9, 0xFCAE91, 0xFB6A4A, 0xCB181D],
+        &[0xFEE5D9, 0xFCAE91, 0xFB6A4A, 0xDE2D26, 0xA50F15],
+        &[0xFEE5D9, 0xFCBBA1, 0xFC9272, 0xFB6A4A, 0xDE2D26, 0xA50F15],
+        &[
+            0xFEE5D9, 0xFCBBA1, 0xFC9272, 0xFB6A4A, 0xEF3B2C, 0xCB181D, 0x99000D,
+        ],
+        &[
+            0xFFF5F0, 0xFEE0D2, 0xFCBBA1, 0xFC9272, 0xFB6A4A, 0xEF3B2C, 0xCB181D, 0x99000D,
+        ],
+        &[
+            0xFFF5F0, 0xFEE0D2, 0xFCBBA1, 0xFC9272, 0xFB6A4A, 0xEF3B2C, 0xCB181D, 0xA50F15,
+            0x67000D,
+        ],
+    ],
+};
+
+pub const BU_GN: Scheme = Scheme {
+    name: "BuGn",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xE5F5F9, 0x99D8C9, 0x2CA25F],
+        &[0xEDF8FB, 0xB2E2E2, 0x66C2A4, 0x238B45],
+        &[0xEDF8FB, 0xB2E2E2, 0x66C2A4, 0x2CA25F, 0x006D2C],
+        &[0xEDF8FB, 0xCCECE6, 0x99D8C9, 0x66C2A4, 0x2CA25F, 0x006D2C],
+        &[
+            0xEDF8FB, 0xCCECE6, 0x99D8C9, 0x66C2A4, 0x41AE76, 0x238B45, 0x005824,
+        ],
+        &[
+            0xF7FCFD, 0xE5F5F9, 0xCCECE6, 0x99D8C9, 0x66C2A4, 0x41AE76, 0x238B45, 0x005824,
+        ],
+        &[
+            0xF7FCFD, 0xE5F5F9, 0xCCECE6, 0x99D8C9, 0x66C2A4, 0x41AE76, 0x238B45, 0x006D2C,
+            0x00441B,
+        ],
+    ],
+};
+
+pub const BU_PU: Scheme = Scheme {
+    name: "BuPu",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xE0ECF4, 0x9EBCDA, 0x8856A7],
+        &[0xEDF8FB, 0xB3CDE3, 0x8C96C6, 0x88419D],
+        &[0xEDF8FB, 0xB3CDE3, 0x8C96C6, 0x8856A7, 0x810F7C],
+        &[0xEDF8FB, 0xBFD3E6, 0x9EBCDA, 0x8C96C6, 0x8856A7, 0x810F7C],
+        &[
+            0xEDF8FB, 0xBFD3E6, 0x9EBCDA, 0x8C96C6, 0x8C6BB1, 0x88419D, 0x6E016B,
+        ],
+        &[
+            0xF7FCFD, 0xE0ECF4, 0xBFD3E6, 0x9EBCDA, 0x8C96C6, 0x8C6BB1, 0x88419D, 0x6E016B,
+        ],
+        &[
+            0xF7FCFD, 0xE0ECF4, 0xBFD3E6, 0x9EBCDA, 0x8C96C6, 0x8C6BB1, 0x88419D, 0x810F7C,
+            0x4D004B,
+        ],
+    ],
+};
+
+pub const GN_BU: Scheme = Scheme {
+    name: "GnBu",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xE0F3DB, 0xA8DDB5, 0x43A2CA],
+        &[0xF0F9E8, 0xBAE4BC, 0x7BCCC4, 0x2B8CBE],
+        &[0xF0F9E8, 0xBAE4BC, 0x7BCCC4, 0x43A2CA, 0x0868AC],
+        &[0xF0F9E8, 0xCCEBC5, 0xA8DDB5, 0x7BCCC4, 0x43A2CA, 0x0868AC],
+        &[
+            0xF0F9E8, 0xCCEBC5, 0xA8DDB5, 0x7BCCC4, 0x4EB3D3, 0x2B8CBE, 0x08589E,
+        ],
+        &[
+            0xF7FCF0, 0xE0F3DB, 0xCCEBC5, 0xA8DDB5, 0x7BCCC4, 0x4EB3D3, 0x2B8CBE, 0x08589E,
+        ],
+        &[
+            0xF7FCF0, 0xE0F3DB, 0xCCEBC5, 0xA8DDB5, 0x7BCCC4, 0x4EB3D3, 0x2B8CBE, 0x0868AC,
+            0x084081,
+        ],
+    ],
+};
+
+pub const OR_RD: Scheme = Scheme {
+    name: "OrRd",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xFEE8C8, 0xFDBB84, 0xE34A33],
+        &[0xFEF0D9, 0xFDCC8A, 0xFC8D59, 0xD7301F],
+        &[0xFEF0D9, 0xFDCC8A, 0xFC8D59, 0xE34A33, 0xB30000],
+        &[0xFEF0D9, 0xFDD49E, 0xFDBB84, 0xFC8D59, 0xE34A33, 0xB30000],
+        &[
+            0xFEF0D9, 0xFDD49E, 0xFDBB84, 0xFC8D59, 0xEF6548, 0xD7301F, 0x990000,
+        ],
+        &[
+            0xFFF7EC, 0xFEE8C8, 0xFDD49E, 0xFDBB84, 0xFC8D59, 0xEF6548, 0xD7301F, 0x990000,
+        ],
+        &[
+            0xFFF7EC, 0xFEE8C8, 0xFDD49E, 0xFDBB84, 0xFC8D59, 0xEF6548, 0xD7301F, 0xB30000,
+            0x7F0000,
+        ],
+    ],
+};
+
+pub const PU_BU_GN: Scheme = Scheme {
+    name: "PuBuGn",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xECE2F0, 0xA6BDDB, 0x1C9099],
+        &[0xF6EFF7, 0xBDC9E1, 0x67A9CF, 0x02818A],
+        &[0xF6EFF7, 0xBDC9E1, 0x67A9CF, 0x1C9099, 0x016C59],
+        &[0xF6EFF7, 0xD0D1E6, 0xA6BDDB, 0x67A9CF, 0x1C9099, 0x016C59],
+        &[
+            0xF6EFF7, 0xD0D1E6, 0xA6BDDB, 0x67A9CF, 0x3690C0, 0x02818A, 0x016450,
+        ],
+        &[
+            0xFFF7FB, 0xECE2F0, 0xD0D1E6, 0xA6BDDB, 0x67A9CF, 0x3690C0, 0x02818A, 0x016450,
+        ],
+        &[
+            0xFFF7FB, 0xECE2F0, 0xD0D1E6, 0xA6BDDB, 0x67A9CF, 0x3690C0, 0x02818A, 0x016C59,
+            0x014636,
+        ],
+    ],
+};
+
+pub const PU_BU: Scheme = Scheme {
+    name: "PuBu",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xECE7F2, 0xA6BDDB, 0x2B8CBE],
+        &[0xF1EEF6, 0xBDC9E1, 0x74A9CF, 0x0570B0],
+        &[0xF1EEF6, 0xBDC9E1, 0x74A9CF, 0x2B8CBE, 0x045A8D],
+        &[0xF1EEF6, 0xD0D1E6, 0xA6BDDB, 0x74A9CF, 0x2B8CBE, 0x045A8D],
+        &[
+            0xF1EEF6, 0xD0D1E6, 0xA6BDDB, 0x74A9CF, 0x3690C0, 0x0570B0, 0x034E7B,
+        ],
+        &[
+            0xFFF7FB, 0xECE7F2, 0xD0D1E6, 0xA6BDDB, 0x74A9CF, 0x3690C0, 0x0570B0, 0x034E7B,
+        ],
+        &[
+            0xFFF7FB, 0xECE7F2, 0xD0D1E6, 0xA6BDDB, 0x74A9CF, 0x3690C0, 0x0570B0, 0x045A8D,
+            0x023858,
+        ],
+    ],
+};
+
+pub const PU_RD: Scheme = Scheme {
+    name: "PuRd",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xE7E1EF, 0xC994C7, 0xDD1C77],
+        &[0xF1EEF6, 0xD7B5D8, 0xDF65B0, 0xCE1256],
+        &[0xF1EEF6, 0xD7B5D8, 0xDF65B0, 0xDD1C77, 0x980043],
+        &[0xF1EEF6, 0xD4B9DA, 0xC994C7, 0xDF65B0, 0xDD1C77, 0x980043],
+        &[
+            0xF1EEF6, 0xD4B9DA, 0xC994C7, 0xDF65B0, 0xE7298A, 0xCE1256, 0x91003F,
+        ],
+        &[
+            0xF7F4F9, 0xE7E1EF, 0xD4B9DA, 0xC994C7, 0xDF65B0, 0xE7298A, 0xCE1256, 0x91003F,
+        ],
+        &[
+            0xF7F4F9, 0xE7E1EF, 0xD4B9DA, 0xC994C7, 0xDF65B0, 0xE7298A, 0xCE1256, 0x980043,
+            0x67001F,
+        ],
+    ],
+};
+
+pub const RD_PU: Scheme = Scheme {
+    name: "RdPu",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xFDE0DD, 0xFA9FB5, 0xC51B8A],
+        &[0xFEEBE2, 0xFBB4B9, 0xF768A1, 0xAE017E],
+        &[0xFEEBE2, 0xFBB4B9, 0xF768A1, 0xC51B8A, 0x7A0177],
+        &[0xFEEBE2, 0xFCC5C0, 0xFA9FB5, 0xF768A1, 0xC51B8A, 0x7A0177],
+        &[
+            0xFEEBE2, 0xFCC5C0, 0xFA9FB5, 0xF768A1, 0xDD3497, 0xAE017E, 0x7A0177,
+        ],
+        &[
+            0xFFF7F3, 0xFDE0DD, 0xFCC5C0, 0xFA9FB5, 0xF768A1, 0xDD3497, 0xAE017E, 0x7A0177,
+        ],
+        &[
+            0xFFF7F3, 0xFDE0DD, 0xFCC5C0, 0xFA9FB5, 0xF768A1, 0xDD3497, 0xAE017E, 0x7A0177,
+            0x49006A,
+        ],
+    ],
+};
+
+pub const YL_GN_BU: Scheme = Scheme {
+    name: "YlGnBu",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xEDF8B1, 0x7FCDBB, 0x2C7FB8],
+        &[0xFFFFCC, 0xA1DAB4, 0x41B6C4, 0x225EA8],
+        &[0xFFFFCC, 0xA1DAB4, 0x41B6C4, 0x2C7FB8, 0x253494],
+        &[0xFFFFCC, 0xC7E9B4, 0x7FCDBB, 0x41B6C4, 0x2C7FB8, 0x253494],
+        &[
+            0xFFFFCC, 0xC7E9B4, 0x7FCDBB, 0x41B6C4, 0x1D91C0, 0x225EA8, 0x0C2C84,
+        ],
+        &[
+            0xFFFFD9, 0xEDF8B1, 0xC7E9B4, 0x7FCDBB, 0x41B6C4, 0x1D91C0, 0x225EA8, 0x0C2C84,
+        ],
+        &[
+            0xFFFFD9, 0xEDF8B1, 0xC7E9B4, 0x7FCDBB, 0x41B6C4, 0x1D91C0, 0x225EA8, 0x253494,
+            0x081D58,
+        ],
+    ],
+};
+
+pub const YL_GN: Scheme = Scheme {
+    name: "YlGn",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xF7FCB9, 0xADDD8E, 0x31A354],
+        &[0xFFFFCC, 0xC2E699, 0x78C679, 0x238443],
+        &[0xFFFFCC, 0xC2E699, 0x78C679, 0x31A354, 0x006837],
+        &[0xFFFFCC, 0xD9F0A3, 0xADDD8E, 0x78C679, 0x31A354, 0x006837],
+        &[
+            0xFFFFCC, 0xD9F0A3, 0xADDD8E, 0x78C679, 0x41AB5D, 0x238443, 0x005A32,
+        ],
+        &[
+            0xFFFFE5, 0xF7FCB9, 0xD9F0A3, 0xADDD8E, 0x78C679, 0x41AB5D, 0x238443, 0x005A32,
+        ],
+        &[
+            0xFFFFE5, 0xF7FCB9, 0xD9F0A3, 0xADDD8E, 0x78C679, 0x41AB5D, 0x238443, 0x006837,
+            0x004529,
+        ],
+    ],
+};
+
+pub const YL_OR_BR: Scheme = Scheme {
+    name: "YlOrBr",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xFFF7BC, 0xFEC44F, 0xD95F0E],
+        &[0xFFFFD4, 0xFED98E, 0xFE9929, 0xCC4C02],
+        &[0xFFFFD4, 0xFED98E, 0xFE9929, 0xD95F0E, 0x993404],
+        &[0xFFFFD4, 0xFEE391, 0xFEC44F, 0xFE9929, 0xD95F0E, 0x993404],
+        &[
+            0xFFFFD4, 0xFEE391, 0xFEC44F, 0xFE9929, 0xEC7014, 0xCC4C02, 0x8C2D04,
+        ],
+        &[
+            0xFFFFE5, 0xFFF7BC, 0xFEE391, 0xFEC44F, 0xFE9929, 0xEC7014, 0xCC4C02, 0x8C2D04,
+        ],
+        &[
+            0xFFFFE5, 0xFFF7BC, 0xFEE391, 0xFEC44F, 0xFE9929, 0xEC7014, 0xCC4C02, 0x993404,
+            0x662506,
+        ],
+    ],
+};
+
+pub const YL_OR_RD: Scheme = Scheme {
+    name: "YlOrRd",
+    kind: SchemeKind::Sequential,
+    colors: &[
+        &[0xFFEDA0, 0xFEB24C, 0xF03B20],
+        &[0xFFFFB2, 0xFECC5C, 0xFD8D3C, 0xE31A1C],
+        &[0xFFFFB2, 0xFECC5C, 0xFD8D3C, 0xF03B20, 0xBD0026],
+        &[0xFFFFB2, 0xFED976, 0xFEB24C, 0xFD8D3C, 0xF03B20, 0xBD0026],
+        &[
+            0xFFFFB2, 0xFED976, 0xFEB24C, 0xFD8D3C, 0xFC4E2A, 0xE31A1C, 0xB10026,
+        ],
+        &[
+            0xFFFFCC, 0xFFEDA0, 0xFED976, 0xFEB24C, 0xFD8D3C, 0xFC4E2A, 0xE31A1C, 0xB10026,
+        ],
+        &[
+            0xFFFFCC, 0xFFEDA0, 0xFED976, 0xFEB24C, 0xFD8D3C, 0xFC4E2A, 0xE31A1C, 0xBD0026,
+            0x800026,
+        ],
+    ],
+};
+
+pub const BR_BG: Scheme = Scheme {
+    name: "BrBG",
+    kind: SchemeKind::Diverging,
+    colors: &[
+        &[0xD8B365, 0xF5F5F5, 0x5AB4AC],
+        &[0xA6611A, 0xDFC27D, 0x80CDC1, 0x018571],
+        &[0xA6611A, 0xDFC27D, 0xF5F5F5, 0x80CDC1, 0x018571],
+        &[0x8C510A, 0xD8B365, 0xF6E8C3, 0xC7EAE5, 0x5AB4AC, 0x01665E],
+        &[
+            0x8C510A, 0xD8B365, 0xF6E8C3, 0xF5F5F5, 0xC7EAE5, 0x5AB4AC, 0x01665E,
+        ],
+        &[
+            0x8C510A, 0xBF812D, 0xDFC27D, 0xF6E8C3, 0xC7EAE5, 0x80CDC1, 0x35978F, 0x01665E,
+        ],
+        &[
+            0x8C510A, 0xBF812D, 0xDFC27D, 0xF6E8C3, 0xF5F5F5, 0xC7EAE5, 0x80CDC1, 0x35978F,
+            0x01665E,
+        ],
+        &[
+            0x543005, 0x8C510A, 0xBF812D, 0xDFC27D, 0xF6E8C3, 0xC7EAE5, 0x80CDC1, 0x35978F,
+            0x01665E, 0x003C30,
+        ],
+        &[
+            0x543005, 0x8C510A, 0xBF812D, 0xDFC27D, 0xF6E8C3, 0xF5F5F5, 0xC7EAE5, 0x80CDC1,
+            0x35978F, 0x01665E, 0x003C30,
+        ],
+    ],
+};
+
+pub const PR_GN: Scheme = Scheme {
+    name: "PRGn",
+    kind: SchemeKind::Diverging,
+    colors: &[
+        &[0xAF8DC3, 0xF7F7F7, 0x7FBF7B],
+        &[0x7B3294, 0xC2A5CF, 0xA6DBA0, 0x008837],
+        &[0x7B3294, 0xC2A5CF, 0xF7F7F7, 0xA6DBA0, 0x008837],
+        &[0x762A83, 0xAF8DC3, 0xE7D4E8, 0xD9F0D3, 0x7FBF7B, 0x1B7837],
+        &[
+            0x762A83, 0xAF8DC3, 0xE7D4E8, 0xF7F7F7, 0xD9F0D3, 0x7FBF7B, 0x1B7837,
+        ],
+        &[
+            0x762A83, 0x9970AB, 0xC2A5CF, 0xE7D4E8, 0xD9F0D3, 0xA6DBA0, 0x5AAE61, 0x1B7837,
+        ],
+        &[
+            0x762A83, 0x9970AB, 0xC2A5CF, 0xE7D4E8, 0xF7F7F7, 0xD9F0D3, 0xA6DBA0, 0x5AAE61,
+            0x1B7837,
+        ],
+        &[
+            0x40004B, 0x762A83, 0x9970AB, 0xC2A5CF, 0xE7D4E8, 0xD9F0D3, 0xA6DBA0, 0x5AAE61,
+            0x1B7837, 0x00441B,
+        ],
+        &[
+            0x40004B, 0x762A83, 0x9970AB, 0xC2A5CF, 0xE7D4E8, 0xF7F7F7, 0xD9F0D3, 0xA6DBA0,
+            0x5AAE61, 0x1B7837, 0x00441B,
+        ],
+    ],
+};
+
+pub const PI_YG: Scheme = Scheme {
+    name: "PiYG",
+    kind: SchemeKind::Diverging,
+    colors: &[
+        &[0xE9A3C9, 0xF7F7F7, 0xA1D76A],
+        &[0xD01C8B, 0xF1B6DA, 0xB8E186, 0x4DAC26],
+        &[0xD01C8B, 0xF1B6DA, 0xF7F7F7, 0xB8E186, 0x4DAC26],
+        &[0xC51B7D, 0xE9A3C9, 0xFDE0EF, 0xE6F5D0, 0xA1D76A, 0x4D9221],
+        &[
+            0xC51B7D, 0xE9A3C9, 0xFDE0EF, 0xF7F7F7, 0xE6F5D0, 0xA1D76A, 0x4D9221,
+        ],
+        &[
+            0xC51B7D, 0xDE77AE, 0xF1B6DA, 0xFDE0EF, 0xE6F5D0, 0xB8E186, 0x7FBC41, 0x4D9221,
+        ],
+        &[
+            0xC51B7D, 0xDE77AE, 0xF1B6DA, 0xFDE0EF, 0xF7F7F7, 0xE6F5D0, 0xB8E186, 0x7FBC41,
+            0x4D9221,
+        ],
+        &[
+            0x8E0152, 0xC51B7D, 0xDE77AE, 0xF1B6DA, 0xFDE0EF, 0xE6F5D0, 0xB8E186, 0x7FBC41,
+            0x4D9221, 0x276419,
+        ],
+        &[
+            0x8E0152, 0xC51B7D, 0xDE77AE, 0xF1B6DA, 0xFDE0EF, 0xF7F7F7, 0xE6F5D0, 0xB8E186,
+            0x7FBC41, 0x4D9221, 0x276419,
+        ],
+    ],
+};
+
+pub const PU_OR: Scheme = Scheme {
+    name: "PuOr",
+    kind: SchemeKind::Diverging,
+    colors: &[
+        &[0x998EC3, 0xF7F7F7, 0xF1A340],
+        &[0x5E3C99, 0xB2ABD2, 0xFDB863, 0xE66101],
+        &[0x5E3C99, 0xB2ABD2, 0xF7F7F7, 0xFDB863, 0xE66101],
+        &[0x542788, 0x998EC3, 0xD8DAEB, 0xFEE0B6, 0xF1A340, 0xB35806],
+        &[
+            0x542788, 0x998EC3, 0xD8DAEB, 0xF7F7F7, 0xFEE0B6, 0xF1A340, 0xB35806,
+        ],
+        &[
+            0x542788, 0x8073AC, 0xB2ABD2, 0xD8DAEB, 0xFEE0B6, 0xFDB863, 0xE08214, 0xB35806,
+        ],
+        &[
+            0x542788, 0x8073AC, 0xB2ABD2, 0xD8DAEB, 0xF7F7F7, 0xFEE0B6, 0xFDB863, 0xE08214,
+            0xB35806,
+        ],
+        &[
+            0x2D004B, 0x542788, 0x8073AC, 0xB2ABD2, 0xD8DAEB, 0xFEE0B6, 0xFDB863, 0xE08214,
+            0xB35806, 0x7F3B08,
+        ],
+        &[
+            0x2D004B, 0x542788, 0x8073AC, 0xB2ABD2, 0xD8DAEB, 0xF7F7F7, 0xFEE0B6, 0xFDB863,
+            0xE08214, 0xB35806, 0x7F3B08,
+        ],
+    ],
+};
+
+pub const RD_BU: Scheme = Scheme {
+    name: "RdBu",
+    kind: SchemeKind::Diverging,
+    colors: &[
+        &[0xEF8A62, 0xF7F7F7, 0x67A9CF],
+        &[0xCA0020, 0xF4A582, 0x92C5DE, 0x0571B0],
+        &[0xCA0020, 0xF4A582, 0xF7F7F7, 0x92C5DE, 0x0571B0],
+        &[0xB2182B, 0xEF8A62, 0xFDDBC7, 0xD1E5F0, 0x67A9CF, 0x2166AC],
+        &[
+            0xB2182B, 0xEF8A62, 0xFDDBC7, 0xF7F7F7, 0xD1E5F0, 0x67A9CF, 0x2166AC,
+        ],
+        &[
+            0xB2182B, 0xD6604D, 0xF4A582, 0xFDDBC7, 0xD1E5F0, 0x92C5DE, 0x4393C3, 0x2166AC,
+        ],
+        &[
+            0xB2182B, 0xD6604D, 0xF4A582, 0xFDDBC7, 0xF7F7F7, 0xD1E5F0, 0x92C5DE, 0x4393C3,
+            0x2166AC,
+        ],
+        &[
+            0x67001F, 0xB2182B, 0xD6604D, 0xF4A582, 0xFDDBC7, 0xD1E5F0, 0x92C5DE, 0x4393C3,
+            0x2166AC, 0x053061,
+        ],
+        &[
+            0x67001F, 0xB2182B, 0xD6604D, 0xF4A582, 0xFDDBC7, 0xF7F7F7, 0xD1E5F0, 0x92C5DE,
+            0x4393C3, 0x2166AC, 0x053061,
+        ],
+    ],
+};
+
+pub const RD_GY: Scheme = Scheme {
+    name: "RdGy",
+    kind: SchemeKind::Diverging,
+    colors: &[
+        &[0xEF8A62, 0xFFFFFF, 0x999999],
+        &[0xCA0020, 0xF4A582, 0xBABABA, 0x404040],
+        &[0xCA0020, 0xF4A582, 0xFFFFFF, 0xBABABA, 0x404040],
+        &[0xB2182B, 0xEF8A62, 0xFDDBC7, 0xE0E0E0, 0x999999, 0x4D4D4D],
+        &[
+            0xB2182B, 0xEF8A62, 0xFDDBC7, 0xFFFFFF, 0xE0E0E0, 0x999999, 0x4D4D4D,
+        ],
+        &[
+            0xB2182B, 0xD6604D, 0xF4A582, 0xFDDBC7, 0xE0E0E0, 0xBABABA, 0x878787, 0x4D4D4D,
+        ],
+        &[
+            0xB2182B, 0xD6604D, 0xF4A582, 0xFDDBC7, 0xFFFFFF, 0xE0E0E0, 0xBABABA, 0x878787,
+            0x4D4D4D,
+        ],
+        &[
+            0x67001F, 0xB2182B, 0xD6604D, 0xF4A582, 0xFDDBC7, 0xE0E0E0, 0xBABABA, 0x878787,
+            0x4D4D4D, 0x1A1A1A,
+        ],
+        &[
+            0x67001F, 0xB2182B, 0xD6604D, 0xF4A582, 0xFDDBC7, 0xFFFFFF, 0xE0E0E0, 0xBABABA,
+            0x878787, 0x4D4D4D, 0x1A1A1A,
+        ],
+    ],
+};
+
+pub const RD_YL_BU: Scheme = Scheme {
+    name: "RdYlBu",
+    kind: SchemeKind::Diverging,
+    colors: &[
+        &[0xFC8D59, 0xFFFFBF, 0x91BFDB],
+        &[0xD7191C, 0xFDAE61, 0xABD9E9, 0x2C7BB6],
+        &[0xD7191C, 0xFDAE61, 0xFFFFBF, 0xABD9E9, 0x2C7BB6],
+        &[0xD73027, 0xFC8D59, 0xFEE090, 0xE0F3F8, 0x91BFDB, 0x4575B4],
+        &[
+            0xD73027, 0xFC8D59, 0xFEE090, 0xFFFFBF, 0xE0F3F8, 0x91BFDB, 0x4575B4,
+        ],
+        &[
+            0xD73027, 0xF46D43, 0xFDAE61, 0xFEE090, 0xE0F3F8, 0xABD9E9, 0x74ADD1, 0x4575B4,
+        ],
+        &[
+            0xD73027, 0xF46D43, 0xFDAE61, 0xFEE090, 0xFFFFBF, 0xE0F3F8, 0xABD9E9, 0x74ADD1,
+            0x4575B4,
+        ],
+        &[
+            0xA50026, 0xD73027, 0xF46D43, 0xFDAE61, 0xFEE090, 0xE0F3F8, 0xABD9E9, 0x74ADD1,
+            0x4575B4, 0x313695,
+        ],
+        &[
+            0xA50026, 0xD73027, 0xF46D43, 0xFDAE61, 0xFEE090, 0xFFFFBF, 0xE0F3F8, 0xABD9E9,
+            0x74ADD1, 0x4575B4, 0x313695,
+        ],
+    ],
+};
+
+pub const RD_YL_GN: Scheme = Scheme {
+    name: "RdYlGn",
+    kind: SchemeKind::Diverging,
+    colors: &[
+        &[0xFC8D59, 0xFFFFBF, 0x91CF60],
+        &[0xD7191C, 0xFDAE61, 0xA6D96A, 0x1A9641],
+        &[0xD7191C, 0xFDAE61, 0xFFFFBF, 0xA6D96A, 0x1A9641],
+        &[0xD73027, 0xFC8D59, 0xFEE08B, 0xD9EF8B, 0x91CF60, 0x1A9850],
+        &[
+            0xD73027, 0xFC8D59, 0xFEE08B, 0xFFFFBF, 0xD9EF8B, 0x91CF60, 0x1A9850,
+        ],
+        &[
+            0xD73027, 0xF46D43, 0xFDAE61, 0xFEE08B, 0xD9EF8B, 0xA6D96A, 0x66BD63, 0x1A9850,
+        ],
+        &[
+            0xD73027, 0xF46D43, 0xFDAE61, 0xFEE08B, 0xFFFFBF, 0xD9EF8B, 0xA6D96A, 0x66BD63,
+            0x1A9850,
+        ],
+        &[
+            0xA50026, 0xD73027, 0xF46D43, 0xFDAE61, 0xFEE08B, 0xD9EF8B, 0xA6D96A, 0x66BD63,
+            0x1A9850, 0x006837,
+        ],
+        &[
+            0xA50026, 0xD73027, 0xF46D43, 0xFDAE61, 0xFEE08B, 0xFFFFBF, 0xD9EF8B, 0xA6D96A,
+            0x66BD63, 0x1A9850, 0x006837,
+        ],
+    ],
+};
+
+pub const SPECTRAL: Scheme = Scheme {
+    name: "Spectral",
+    kind: SchemeKind::Diverging,
+    colors: &[
+        &[0xFC8D59, 0xFFFFBF, 0x99D594],
+        &[0xD7191C, 0xFDAE61, 0xABDDA4, 0x2B83BA],
+        &[0xD7191C, 0xFDAE61, 0xFFFFBF, 0xABDDA4, 0x2B83BA],
+        &[0xD53E4F, 0xFC8D59, 0xFEE08B, 0xE6F598, 0x99D594, 0x3288BD],
+        &[
+            0xD53E4F, 0xFC8D59, 0xFEE08B, 0xFFFFBF, 0xE6F598, 0x99D594, 0x3288BD,
+        ],
+        &[
+            0xD53E4F, 0xF46D43, 0xFDAE61, 0xFEE08B, 0xE6F598, 0xABDDA4, 0x66C2A5, 0x3288BD,
+        ],
+        &[
+            0xD53E4F, 0xF46D43, 0xFDAE61, 0xFEE08B, 0xFFFFBF, 0xE6F598, 0xABDDA4, 0x66C2A5,
+            0x3288BD,
+        ],
+        &[
+            0x9E0142, 0xD53E4F, 0xF46D43, 0xFDAE61, 0xFEE08B, 0xE6F598, 0xABDDA4, 0x66C2A5,
+            0x3288BD, 0x5E4FA2,
+        ],
+        &[
+            0x9E0142, 0xD53E4F, 0xF46D43, 0xFDAE61, 0xFEE08B, 0xFFFFBF, 0xE6F598, 0xABDDA4,
+            0x66C2A5, 0x3288BD, 0x5E4FA2,
+        ],
+    ],
+};
+
+pub const ACCENT: Scheme = Scheme {
+    name: "Accent",
+    kind: SchemeKind::Qualitative,
+    colors: &[&[
+        0x7FC97F, 0xBEAED4, 0xFDC086, 0xFFFF99, 0x386CB0, 0xF0027F, 0xBF5B17, 0x666666,
+    ]],
+};
+
+pub const DARK2: Scheme = Scheme {
+    name: "Dark2",
+    kind: SchemeKind::Qualitative,
+    colors: &[&[
+        0x1B9E77, 0xD95F02, 0x7570B3, 0xE7298A, 0x66A61E, 0xE6AB02, 0xA6761D, 0x666666,
+    ]],
+};
+
+pub const PAIRED: Scheme = Scheme {
+    name: "Paired",
+    kind: SchemeKind::Qualitative,
+    colors: &[&[
+        0xA6CEE3, 0x1F78B4, 0xB2DF8A, 0x33A02C, 0xFB9A99, 0xE31A1C, 0xFDBF6F, 0xFF7F00, 0xCAB2D6,
+        0x6A3D9A, 0xFFFF99, 0xB15928,
+    ]],
+};
+
+pub const PASTEL1: Scheme = Scheme {
+    name: "Pastel1",
+    kind: SchemeKind::Qualitative,
+    colors: &[&[
+        0xFBB4AE, 0xB3CDE3, 0xCCEBC5, 0xDECBE4, 0xFED9A6, 0xFFFFCC, 0xE5D8BD, 0xFDDAEC, 0xF2F2F2,
+    ]],
+};
+
+pub const PASTEL2: Scheme = Scheme {
+    name: "Pastel2",
+    kind: SchemeKind::Qualitative,
+    colors: &[&[
+        0xB3E2CD, 0xFDCDAC, 0xCBD5E8, 0xF4CAE4, 0xE6F5C9, 0xFFF2AE, 0xF1E2CC, 0xCCCCCC,
+    ]],
+};
+
+pub const SET1: Scheme = Scheme {
+    name: "Set1",
+    kind: SchemeKind::Qualitative,
+    colors: &[&[
+        0xE41A1C, 0x377EB8, 0x4DAF4A, 0x984EA3, 0xFF7F00, 0xFFFF33, 0xA65628, 0xF781BF, 0x999999,
+    ]],
+};
+
+pub const SET2: Scheme = Scheme {
+    name: "Set2",
+    kind: SchemeKind::Qualitative,
+    colors: &[&[
+        0x66C2A5, 0xFC8D62, 0x8DA0CB, 0xE78AC3, 0xA6D854, 0xFFD92F, 0xE5C494, 0xB3B3B3,
+    ]],
+};
+
+pub const SET3: Scheme = Scheme {
+    name: "Set3",
+    kind: SchemeKind::Qualitative,
+    colors: &[&[
+        0x8DD3C7, 0xFFFFB3, 0xBEBADA, 0xFB8072, 0x80B1D3, 0xFDB462, 0xB3DE69, 0xFCCDE5, 0xD9D9D9,
+        0xBC80BD, 0xCCEBC5, 0xFFED6F,
+    ]],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes() {
+        assert_eq!(BLUES.sizes(), 3..=9);
+        assert_eq!(RD_BU.sizes(), 3..=11);
+        assert_eq!(PAIRED.sizes(), 3..=12);
+        for scheme in SCHEMES {
+            for n in scheme.sizes() {
+                assert_eq!(scheme.palette(n).unwrap().len(), n);
+            }
+            assert_eq!(scheme.palette(2), None);
+            assert_eq!(scheme.palette(*scheme.sizes().end() + 1), None);
+        }
+    }
+
+    #[test]
+    fn lookup() {
+        assert_eq!(scheme("RdBu"), Some(RD_BU));
+        assert_eq!(scheme("rdbu"), Some(RD_BU));
+        assert_eq!(scheme("Viridis"), None);
+        let set1 = palette("Set1", 3).unwrap().into_colors();
+        assert_eq!(
+            set1,
+            vec![
+                Color::from_hex(0xE41A1C),
+                Color::from_hex(0x377EB8),
+                Color::from_hex(0x4DAF4A)
+            ]
+        );
+        assert_eq!(SET1.kind(), SchemeKind::Qualitative);
+        assert_eq!(RD_BU.kind(), SchemeKind::Diverging);
+        assert_eq!(YL_GN_BU.name(), "YlGnBu");
+    }
+}
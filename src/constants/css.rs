@@ -0,0 +1,163 @@
+//! The 148 named colors of [CSS Color Module Level 4](https://www.w3.org/TR/css-color-4/#named-colors),
+//! in alphabetical order. These are the same colors as
+//! [`from_web_color()`](crate::Color#method.from_web_color), but known at compile time.
+//!
+//! # Example
+//!
+//! ```
+//! use octarine::{constants::css, Color};
+//!
+//! assert_eq!(Color::from_web_color("rebeccapurple"), Some(css::REBECCAPURPLE));
+//! assert_eq!(css::GRAY, css::GREY);
+//! ```
+
+use super::*;
+
+pub const ALICEBLUE: Color = Color(240, 248, 255);
+pub const ANTIQUEWHITE: Color = Color(250, 235, 215);
+pub const AQUA: Color = Color(0, 255, 255);
+pub const AQUAMARINE: Color = Color(127, 255, 212);
+pub const AZURE: Color = Color(240, 255, 255);
+pub const BEIGE: Color = Color(245, 245, 220);
+pub const BISQUE: Color = Color(255, 228, 196);
+pub const BLACK: Color = Color(0, 0, 0);
+pub const BLANCHEDALMOND: Color = Color(255, 235, 205);
+pub const BLUE: Color = Color(0, 0, 255);
+pub const BLUEVIOLET: Color = Color(138, 43, 226);
+pub const BROWN: Color = Color(165, 42, 42);
+pub const BURLYWOOD: Color = Color(222, 184, 135);
+pub const CADETBLUE: Color = Color(95, 158, 160);
+pub const CHARTREUSE: Color = Color(127, 255, 0);
+pub const CHOCOLATE: Color = Color(210, 105, 30);
+pub const CORAL: Color = Color(255, 127, 80);
+pub const CORNFLOWERBLUE: Color = Color(100, 149, 237);
+pub const CORNSILK: Color = Color(255, 248, 220);
+pub const CRIMSON: Color = Color(220, 20, 60);
+pub const CYAN: Color = Color(0, 255, 255);
+pub const DARKBLUE: Color = Color(0, 0, 139);
+pub const DARKCYAN: Color = Color(0, 139, 139);
+pub const DARKGOLDENROD: Color = Color(184, 134, 11);
+pub const DARKGRAY: Color = Color(169, 169, 169);
+pub const DARKGREEN: Color = Color(0, 100, 0);
+pub const DARKGREY: Color = Color(169, 169, 169);
+pub const DARKKHAKI: Color = Color(189, 183, 107);
+pub const DARKMAGENTA: Color = Color(139, 0, 139);
+pub const DARKOLIVEGREEN: Color = Color(85, 107, 47);
+pub const DARKORANGE: Color = Color(255, 140, 0);
+pub const DARKORCHID: Color = Color(153, 50, 204);
+pub const DARKRED: Color = Color(139, 0, 0);
+pub const DARKSALMON: Color = Color(233, 150, 122);
+pub const DARKSEAGREEN: Color = Color(143, 188, 143);
+pub const DARKSLATEBLUE: Color = Color(72, 61, 139);
+pub const DARKSLATEGRAY: Color = Color(47, 79, 79);
+pub const DARKSLATEGREY: Color = Color(47, 79, 79);
+pub const DARKTURQUOISE: Color = Color(0, 206, 209);
+pub const DARKVIOLET: Color = Color(148, 0, 211);
+pub const DEEPPINK: Color = Color(255, 20, 147);
+pub const DEEPSKYBLUE: Color = Color(0, 191, 255);
+pub const DIMGRAY: Color = Color(105, 105, 105);
+pub const DIMGREY: Color = Color(105, 105, 105);
+pub const DODGERBLUE: Color = Color(30, 144, 255);
+pub const FIREBRICK: Color = Color(178, 34, 34);
+pub const FLORALWHITE: Color = Color(255, 250, 240);
+pub const FORESTGREEN: Color = Color(34, 139, 34);
+pub const FUCHSIA: Color = Color(255, 0, 255);
+pub const GAINSBORO: Color = Color(220, 220, 220);
+pub const GHOSTWHITE: Color = Color(248, 248, 255);
+pub const GOLD: Color = Color(255, 215, 0);
+pub const GOLDENROD: Color = Color(218, 165, 32);
+pub const GRAY: Color = Color(128, 128, 128);
+pub const GREEN: Color = Color(0, 128, 0);
+pub const GREENYELLOW: Color = Color(173, 255, 47);
+pub const GREY: Color = Color(128, 128, 128);
+pub const HONEYDEW: Color = Color(240, 255, 240);
+pub const HOTPINK: Color = Color(255, 105, 180);
+pub const INDIANRED: Color = Color(205, 92, 92);
+pub const INDIGO: Color = Color(75, 0, 130);
+pub const IVORY: Color = Color(255, 255, 240);
+pub const KHAKI: Color = Color(240, 230, 140);
+pub const LAVENDER: Color = Color(230, 230, 250);
+pub const LAVENDERBLUSH: Color = Color(255, 240, 245);
+pub const LAWNGREEN: Color = Color(124, 252, 0);
+pub const LEMONCHIFFON: Color = Color(255, 250, 205);
+pub const LIGHTBLUE: Color = Color(173, 216, 230);
+pub const LIGHTCORAL: Color = Color(240, 128, 128);
+pub const LIGHTCYAN: Color = Color(224, 255, 255);
+pub const LIGHTGOLDENRODYELLOW: Color = Color(250, 250, 210);
+pub const LIGHTGRAY: Color = Color(211, 211, 211);
+pub const LIGHTGREEN: Color = Color(144, 238, 144);
+pub const LIGHTGREY: Color = Color(211, 211, 211);
+pub const LIGHTPINK: Color = Color(255, 182, 193);
+pub const LIGHTSALMON: Color = Color(255, 160, 122);
+pub const LIGHTSEAGREEN: Color = Color(32, 178, 170);
+pub const LIGHTSKYBLUE: Color = Color(135, 206, 250);
+pub const LIGHTSLATEGRAY: Color = Color(119, 136, 153);
+pub const LIGHTSLATEGREY: Color = Color(119, 136, 153);
+pub const LIGHTSTEELBLUE: Color = Color(176, 196, 222);
+pub const LIGHTYELLOW: Color = Color(255, 255, 224);
+pub const LIME: Color = Color(0, 255, 0);
+pub const LIMEGREEN: Color = Color(50, 205, 50);
+pub const LINEN: Color = Color(250, 240, 230);
+pub const MAGENTA: Color = Color(255, 0, 255);
+pub const MAROON: Color = Color(128, 0, 0);
+pub const MEDIUMAQUAMARINE: Color = Color(102, 205, 170);
+pub const MEDIUMBLUE: Color = Color(0, 0, 205);
+pub const MEDIUMORCHID: Color = Color(186, 85, 211);
+pub const MEDIUMPURPLE: Color = Color(147, 112, 219);
+pub const MEDIUMSEAGREEN: Color = Color(60, 179, 113);
+pub const MEDIUMSLATEBLUE: Color = Color(123, 104, 238);
+pub const MEDIUMSPRINGGREEN: Color = Color(0, 250, 154);
+pub const MEDIUMTURQUOISE: Color = Color(72, 209, 204);
+pub const MEDIUMVIOLETRED: Color = Color(199, 21, 133);
+pub const MIDNIGHTBLUE: Color = Color(25, 25, 112);
+pub const MINTCREAM: Color = Color(245, 255, 250);
+pub const MISTYROSE: Color = Color(255, 228, 225);
+pub const MOCCASIN: Color = Color(255, 228, 181);
+pub const NAVAJOWHITE: Color = Color(255, 222, 173);
+pub const NAVY: Color = Color(0, 0, 128);
+pub const OLDLACE: Color = Color(253, 245, 230);
+pub const OLIVE: Color = Color(128, 128, 0);
+pub const OLIVEDRAB: Color = Color(107, 142, 35);
+pub const ORANGE: Color = Color(255, 165, 0);
+pub const ORANGERED: Color = Color(255, 69, 0);
+pub const ORCHID: Color = Color(218, 112, 214);
+pub const PALEGOLDENROD: Color = Color(238, 232, 170);
+pub const PALEGREEN: Color = Color(152, 251, 152);
+pub const PALETURQUOISE: Color = Color(175, 238, 238);
+pub const PALEVIOLETRED: Color = Color(219, 112, 147);
+pub const PAPAYAWHIP: Color = Color(255, 239, 213);
+pub const PEACHPUFF: Color = Color(255, 218, 185);
+pub const PERU: Color = Color(205, 133, 63);
+pub const PINK: Color = Color(255, 192, 203);
+pub const PLUM: Color = Color(221, 160, 221);
+pub const POWDERBLUE: Color = Color(176, 224, 230);
+pub const PURPLE: Color = Color(128, 0, 128);
+pub const REBECCAPURPLE: Color = Color(102, 51, 153);
+pub const RED: Color = Color(255, 0, 0);
+pub const ROSYBROWN: Color = Color(188, 143, 143);
+pub const ROYALBLUE: Color = Color(65, 105, 225);
+pub const SADDLEBROWN: Color = Color(139, 69, 19);
+pub const SALMON: Color = Color(250, 128, 114);
+pub const SANDYBROWN: Color = Color(244, 164, 96);
+pub const SEAGREEN: Color = Color(46, 139, 87);
+pub const SEASHELL: Color = Color(255, 245, 238);
+pub const SIENNA: Color = Color(160, 82, 45);
+pub const SILVER: Color = Color(192, 192, 192);
+pub const SKYBLUE: Color = Color(135, 206, 235);
+pub const SLATEBLUE: Color = Color(106, 90, 205);
+pub const SLATEGRAY: Color = Color(112, 128, 144);
+pub const SLATEGREY: Color = Color(112, 128, 144);
+pub const SNOW: Color = Color(255, 250, 250);
+pub const SPRINGGREEN: Color = Color(0, 255, 127);
+pub const STEELBLUE: Color = Color(70, 130, 180);
+pub const TAN: Color = Color(210, 180, 140);
+pub const TEAL: Color = Color(0, 128, 128);
+pub const THISTLE: Color = Color(216, 191, 216);
+pub const TOMATO: Color = Color(255, 99, 71);
+pub const TURQUOISE: Color = Color(64, 224, 208);
+pub const VIOLET: Color = Color(238, 130, 238);
+pub const WHEAT: Color = Color(245, 222, 179);
+pub const WHITE: Color = Color(255, 255, 255);
+pub const WHITESMOKE: Color = Color(245, 245, 245);
+pub const YELLOW: Color = Color(255, 255, 0);
+pub const YELLOWGREEN: Color = Color(154, 205, 50);
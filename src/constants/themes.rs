@@ -0,0 +1,391 @@
+//! Popular color schemes of terminals and editors, with the colors named by their roles in the
+//! scheme.
+//!
+//! # Example
+//!
+//! ```
+//! use octarine::{constants::themes, Color};
+//!
+//! assert_eq!(themes::NORD.nord8, Color::from_hex(0x88C0D0));
+//! assert_eq!(themes::CATPPUCCIN_MOCHA.base, Color::from_hex(0x1E1E2E));
+//! ```
+
+use super::*;
+
+/// [Solarized](https://ethanschoonover.com/solarized/) by Ethan Schoonover. The same colors are
+/// used for the light and dark modes, with `base03` to `base3` going from the darkest background
+/// to the lightest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Solarized {
+    /// The background of the dark mode.
+    pub base03: Color,
+    /// The highlighted background of the dark mode.
+    pub base02: Color,
+    /// Comments in the dark mode, and emphasized content in the light mode.
+    pub base01: Color,
+    /// The body text of the light mode.
+    pub base00: Color,
+    /// The body text of the dark mode.
+    pub base0: Color,
+    /// Comments in the light mode, and emphasized content in the dark mode.
+    pub base1: Color,
+    /// The highlighted background of the light mode.
+    pub base2: Color,
+    /// The background of the light mode.
+    pub base3: Color,
+    pub yellow: Color,
+    pub orange: Color,
+    pub red: Color,
+    pub magenta: Color,
+    pub violet: Color,
+    pub blue: Color,
+    pub cyan: Color,
+    pub green: Color,
+}
+
+pub const SOLARIZED: Solarized = Solarized {
+    base03: Color::from_hex(0x002B36),
+    base02: Color::from_hex(0x073642),
+    base01: Color::from_hex(0x586E75),
+    base00: Color::from_hex(0x657B83),
+    base0: Color::from_hex(0x839496),
+    base1: Color::from_hex(0x93A1A1),
+    base2: Color::from_hex(0xEEE8D5),
+    base3: Color::from_hex(0xFDF6E3),
+    yellow: Color::from_hex(0xB58900),
+    orange: Color::from_hex(0xCB4B16),
+    red: Color::from_hex(0xDC322F),
+    magenta: Color::from_hex(0xD33682),
+    violet: Color::from_hex(0x6C71C4),
+    blue: Color::from_hex(0x268BD2),
+    cyan: Color::from_hex(0x2AA198),
+    green: Color::from_hex(0x859900),
+};
+
+/// [Nord](https://www.nordtheme.com) by Arctic Ice Studio, with the colors named as in the
+/// scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Nord {
+    /// The darkest color of Polar Night, which is the background.
+    pub nord0: Color,
+    pub nord1: Color,
+    pub nord2: Color,
+    pub nord3: Color,
+    /// The darkest color of Snow Storm, which is the body text.
+    pub nord4: Color,
+    pub nord5: Color,
+    pub nord6: Color,
+    /// The first color of Frost, which is used for classes and types.
+    pub nord7: Color,
+    pub nord8: Color,
+    pub nord9: Color,
+    pub nord10: Color,
+    /// The first color of Aurora, which is red and used for errors.
+    pub nord11: Color,
+    pub nord12: Color,
+    pub nord13: Color,
+    pub nord14: Color,
+    pub nord15: Color,
+}
+
+pub const NORD: Nord = Nord {
+    nord0: Color::from_hex(0x2E3440),
+    nord1: Color::from_hex(0x3B4252),
+    nord2: Color::from_hex(0x434C5E),
+    nord3: Color::from_hex(0x4C566A),
+    nord4: Color::from_hex(0xD8DEE9),
+    nord5: Color::from_hex(0xE5E9F0),
+    nord6: Color::from_hex(0xECEFF4),
+    nord7: Color::from_hex(0x8FBCBB),
+    nord8: Color::from_hex(0x88C0D0),
+    nord9: Color::from_hex(0x81A1C1),
+    nord10: Color::from_hex(0x5E81AC),
+    nord11: Color::from_hex(0xBF616A),
+    nord12: Color::from_hex(0xD08770),
+    nord13: Color::from_hex(0xEBCB8B),
+    nord14: Color::from_hex(0xA3BE8C),
+    nord15: Color::from_hex(0xB48EAD),
+};
+
+/// [Dracula](https://draculatheme.com) by Zeno Rocha.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dracula {
+    pub background: Color,
+    /// The background of the current line and of selections.
+    pub current_line: Color,
+    pub foreground: Color,
+    pub comment: Color,
+    pub cyan: Color,
+    pub green: Color,
+    pub orange: Color,
+    pub pink: Color,
+    pub purple: Color,
+    pub red: Color,
+    pub yellow: Color,
+}
+
+pub const DRACULA: Dracula = Dracula {
+    background: Color::from_hex(0x282A36),
+    current_line: Color::from_hex(0x44475A),
+    foreground: Color::from_hex(0xF8F8F2),
+    comment: Color::from_hex(0x6272A4),
+    cyan: Color::from_hex(0x8BE9FD),
+    green: Color::from_hex(0x50FA7B),
+    orange: Color::from_hex(0xFFB86C),
+    pink: Color::from_hex(0xFF79C6),
+    purple: Color::from_hex(0xBD93F9),
+    red: Color::from_hex(0xFF5555),
+    yellow: Color::from_hex(0xF1FA8C),
+};
+
+/// [Gruvbox](https://github.com/morhetz/gruvbox) by Pavel Pertsev. The backgrounds go from
+/// `bg0_h` (hard contrast) to `bg4`, and the foregrounds from `fg4` to `fg0`, which has the most
+/// contrast with the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gruvbox {
+    pub bg0_h: Color,
+    pub bg0: Color,
+    pub bg0_s: Color,
+    pub bg1: Color,
+    pub bg2: Color,
+    pub bg3: Color,
+    pub bg4: Color,
+    pub gray: Color,
+    pub fg4: Color,
+    pub fg3: Color,
+    pub fg2: Color,
+    pub fg1: Color,
+    pub fg0: Color,
+    pub red: Color,
+    pub green: Color,
+    pub yellow: Color,
+    pub blue: Color,
+    pub purple: Color,
+    pub aqua: Color,
+    pub orange: Color,
+}
+
+/// The dark mode of Gruvbox, which uses the bright accent colors.
+pub const GRUVBOX_DARK: Gruvbox = Gruvbox {
+    bg0_h: Color::from_hex(0x1D2021),
+    bg0: Color::from_hex(0x282828),
+    bg0_s: Color::from_hex(0x32302F),
+    bg1: Color::from_hex(0x3C3836),
+    bg2: Color::from_hex(0x504945),
+    bg3: Color::from_hex(0x665C54),
+    bg4: Color::from_hex(0x7C6F64),
+    gray: Color::from_hex(0x928374),
+    fg4: Color::from_hex(0xA89984),
+    fg3: Color::from_hex(0xBDAE93),
+    fg2: Color::from_hex(0xD5C4A1),
+    fg1: Color::from_hex(0xEBDBB2),
+    fg0: Color::from_hex(0xFBF1C7),
+    red: Color::from_hex(0xFB4934),
+    green: Color::from_hex(0xB8BB26),
+    yellow: Color::from_hex(0xFABD2F),
+    blue: Color::from_hex(0x83A598),
+    purple: Color::from_hex(0xD3869B),
+    aqua: Color::from_hex(0x8EC07C),
+    orange: Color::from_hex(0xFE8019),
+};
+
+/// The light mode of Gruvbox, which uses the faded accent colors.
+pub const GRUVBOX_LIGHT: Gruvbox = Gruvbox {
+    bg0_h: Color::from_hex(0xF9F5D7),
+    bg0: Color::from_hex(0xFBF1C7),
+    bg0_s: Color::from_hex(0xF2E5BC),
+    bg1: Color::from_hex(0xEBDBB2),
+    bg2: Color::from_hex(0xD5C4A1),
+    bg3: Color::from_hex(0xBDAE93),
+    bg4: Color::from_hex(0xA89984),
+    gray: Color::from_hex(0x928374),
+    fg4: Color::from_hex(0x7C6F64),
+    fg3: Color::from_hex(0x665C54),
+    fg2: Color::from_hex(0x504945),
+    fg1: Color::from_hex(0x3C3836),
+    fg0: Color::from_hex(0x282828),
+    red: Color::from_hex(0x9D0006),
+    green: Color::from_hex(0x79740E),
+    yellow: Color::from_hex(0xB57614),
+    blue: Color::from_hex(0x076678),
+    purple: Color::from_hex(0x8F3F71),
+    aqua: Color::from_hex(0x427B58),
+    orange: Color::from_hex(0xAF3A03),
+};
+
+/// [Catppuccin](https://catppuccin.com), which comes in the flavors Latte (light), Frappé,
+/// Macchiato, and Mocha (the darkest). `base`, `mantle`, and `crust` are backgrounds, the
+/// `surface` and `overlay` colors go from the background to the text, and the rest are accents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Catppuccin {
+    pub rosewater: Color,
+    pub flamingo: Color,
+    pub pink: Color,
+    pub mauve: Color,
+    pub red: Color,
+    pub maroon: Color,
+    pub peach: Color,
+    pub yellow: Color,
+    pub green: Color,
+    pub teal: Color,
+    pub sky: Color,
+    pub sapphire: Color,
+    pub blue: Color,
+    pub lavender: Color,
+    pub text: Color,
+    pub subtext1: Color,
+    pub subtext0: Color,
+    pub overlay2: Color,
+    pub overlay1: Color,
+    pub overlay0: Color,
+    pub surface2: Color,
+    pub surface1: Color,
+    pub surface0: Color,
+    pub base: Color,
+    pub mantle: Color,
+    pub crust: Color,
+}
+
+pub const CATPPUCCIN_LATTE: Catppuccin = Catppuccin {
+    rosewater: Color::from_hex(0xDC8A78),
+    flamingo: Color::from_hex(0xDD7878),
+    pink: Color::from_hex(0xEA76CB),
+    mauve: Color::from_hex(0x8839EF),
+    red: Color::from_hex(0xD20F39),
+    maroon: Color::from_hex(0xE64553),
+    peach: Color::from_hex(0xFE640B),
+    yellow: Color::from_hex(0xDF8E1D),
+    green: Color::from_hex(0x40A02B),
+    teal: Color::from_hex(0x179299),
+    sky: Color::from_hex(0x04A5E5),
+    sapphire: Color::from_hex(0x209FB5),
+    blue: Color::from_hex(0x1E66F5),
+    lavender: Color::from_hex(0x7287FD),
+    text: Color::from_hex(0x4C4F69),
+    subtext1: Color::from_hex(0x5C5F77),
+    subtext0: Color::from_hex(0x6C6F85),
+    overlay2: Color::from_hex(0x7C7F93),
+    overlay1: Color::from_hex(0x8C8FA1),
+    overlay0: Color::from_hex(0x9CA0B0),
+    surface2: Color::from_hex(0xACB0BE),
+    surface1: Color::from_hex(0xBCC0CC),
+    surface0: Color::from_hex(0xCCD0DA),
+    base: Color::from_hex(0xEFF1F5),
+    mantle: Color::from_hex(0xE6E9EF),
+    crust: Color::from_hex(0xDCE0E8),
+};
+
+pub const CATPPUCCIN_FRAPPE: Catppuccin = Catppuccin {
+    rosewater: Color::from_hex(0xF2D5CF),
+    flamingo: Color::from_hex(0xEEBEBE),
+    pink: Color::from_hex(0xF4B8E4),
+    mauve: Color::from_hex(0xCA9EE6),
+    red: Color::from_hex(0xE78284),
+    maroon: Color::from_hex(0xEA999C),
+    peach: Color::from_hex(0xEF9F76),
+    yellow: Color::from_hex(0xE5C890),
+    green: Color::from_hex(0xA6D189),
+    teal: Color::from_hex(0x81C8BE),
+    sky: Color::from_hex(0x99D1DB),
+    sapphire: Color::from_hex(0x85C1DC),
+    blue: Color::from_hex(0x8CAAEE),
+    lavender: Color::from_hex(0xBABBF1),
+    text: Color::from_hex(0xC6D0F5),
+    subtext1: Color::from_hex(0xB5BFE2),
+    subtext0: Color::from_hex(0xA5ADCE),
+    overlay2: Color::from_hex(0x949CBB),
+    overlay1: Color::from_hex(0x838BA7),
+    overlay0: Color::from_hex(0x737994),
+    surface2: Color::from_hex(0x626880),
+    surface1: Color::from_hex(0x51576D),
+    surface0: Color::from_hex(0x414559),
+    base: Color::from_hex(0x303446),
+    mantle: Color::from_hex(0x292C3C),
+    crust: Color::from_hex(0x232634),
+};
+
+pub const CATPPUCCIN_MACCHIATO: Catppuccin = Catppuccin {
+    rosewater: Color::from_hex(0xF4DBD6),
+    flamingo: Color::from_hex(0xF0C6C6),
+    pink: Color::from_hex(0xF5BDE6),
+    mauve: Color::from_hex(0xC6A0F6),
+    red: Color::from_hex(0xED8796),
+    maroon: Color::from_hex(0xEE99A0),
+    peach: Color::from_hex(0xF5A97F),
+    yellow: Color::from_hex(0xEED49F),
+    green: Color::from_hex(0xA6DA95),
+    teal: Color::from_hex(0x8BD5CA),
+    sky: Color::from_hex(0x91D7E3),
+    sapphire: Color::from_hex(0x7DC4E4),
+    blue: Color::from_hex(0x8AADF4),
+    lavender: Color::from_hex(0xB7BDF8),
+    text: Color::from_hex(0xCAD3F5),
+    subtext1: Color::from_hex(0xB8C0E0),
+    subtext0: Color::from_hex(0xA5ADCB),
+    overlay2: Color::from_hex(0x939AB7),
+    overlay1: Color::from_hex(0x8087A2),
+    overlay0: Color::from_hex(0x6E738D),
+    surface2: Color::from_hex(0x5B6078),
+    surface1: Color::from_hex(0x494D64),
+    surface0: Color::from_hex(0x363A4F),
+    base: Color::from_hex(0x24273A),
+    mantle: Color::from_hex(0x1E2030),
+    crust: Color::from_hex(0x181926),
+};
+
+pub const CATPPUCCIN_MOCHA: Catppuccin = Catppuccin {
+    rosewater: Color::from_hex(0xF5E0DC),
+    flamingo: Color::from_hex(0xF2CDCD),
+    pink: Color::from_hex(0xF5C2E7),
+    mauve: Color::from_hex(0xCBA6F7),
+    red: Color::from_hex(0xF38BA8),
+    maroon: Color::from_hex(0xEBA0AC),
+    peach: Color::from_hex(0xFAB387),
+    yellow: Color::from_hex(0xF9E2AF),
+    green: Color::from_hex(0xA6E3A1),
+    teal: Color::from_hex(0x94E2D5),
+    sky: Color::from_hex(0x89DCEB),
+    sapphire: Color::from_hex(0x74C7EC),
+    blue: Color::from_hex(0x89B4FA),
+    lavender: Color::from_hex(0xB4BEFE),
+    text: Color::from_hex(0xCDD6F4),
+    subtext1: Color::from_hex(0xBAC2DE),
+    subtext0: Color::from_hex(0xA6ADC8),
+    overlay2: Color::from_hex(0x9399B2),
+    overlay1: Color::from_hex(0x7F849C),
+    overlay0: Color::from_hex(0x6C7086),
+    surface2: Color::from_hex(0x585B70),
+    surface1: Color::from_hex(0x45475A),
+    surface0: Color::from_hex(0x313244),
+    base: Color::from_hex(0x1E1E2E),
+    mantle: Color::from_hex(0x181825),
+    crust: Color::from_hex(0x11111B),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contrast() {
+        let pairs = [
+            (SOLARIZED.base0, SOLARIZED.base03),
+            (SOLARIZED.base01, SOLARIZED.base3),
+            (NORD.nord4, NORD.nord0),
+            (DRACULA.foreground, DRACULA.background),
+            (GRUVBOX_DARK.fg1, GRUVBOX_DARK.bg0),
+            (GRUVBOX_LIGHT.fg1, GRUVBOX_LIGHT.bg0),
+            (CATPPUCCIN_LATTE.text, CATPPUCCIN_LATTE.base),
+            (CATPPUCCIN_FRAPPE.text, CATPPUCCIN_FRAPPE.base),
+            (CATPPUCCIN_MACCHIATO.text, CATPPUCCIN_MACCHIATO.base),
+            (CATPPUCCIN_MOCHA.text, CATPPUCCIN_MOCHA.base),
+        ];
+        for (text, background) in pairs {
+            assert!(text.contrast_ratio(&background) >= 4.5, "{text:?}");
+        }
+        let flavors = [CATPPUCCIN_FRAPPE, CATPPUCCIN_MACCHIATO, CATPPUCCIN_MOCHA];
+        for pair in flavors.windows(2) {
+            assert!(pair[0].crust.relative_luminance() > pair[1].crust.relative_luminance());
+        }
+    }
+}
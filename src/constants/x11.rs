@@ -0,0 +1,18 @@
+//! The X11 named colors which are not in CSS, in alphabetical order. They are also recognized by
+//! [`from_web_color()`](crate::Color#method.from_web_color). The other X11 colors share their names
+//! with the colors in [`css`], which have the CSS values.
+//!
+//! # Example
+//!
+//! ```
+//! use octarine::{constants::x11, Color};
+//!
+//! assert_eq!(Color::from_web_color("violetred"), Some(x11::VIOLETRED));
+//! ```
+
+use super::*;
+
+pub const LIGHTGOLDENROD: Color = Color(238, 221, 130);
+pub const LIGHTSLATEBLUE: Color = Color(132, 112, 255);
+pub const NAVYBLUE: Color = Color(0, 0, 128);
+pub const VIOLETRED: Color = Color(208, 32, 144);
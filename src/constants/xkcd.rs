@@ -0,0 +1,1039 @@
+//! The 949 colors named by the [XKCD color survey](https://blog.xkcd.com/2010/05/03/color-survey-results/),
+//! which are the names people most often use for colors, such as "puke green", licensed under
+//! CC0. Requires the `xkcd` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use octarine::{constants::xkcd, Color};
+//!
+//! assert_eq!(xkcd::color("Puke Green"), Some(Color::from_hex(0x9AAE07)));
+//! assert_eq!(xkcd::nearest(&Color::new(152, 172, 9)).0, "puke green");
+//! ```
+
+use super::*;
+
+/// The XKCD colors, sorted by name. Names are in lowercase, with words separated by spaces.
+pub static COLORS: &[(&str, Color)] = &[
+    ("acid green", Color(143, 254, 9)),
+    ("adobe", Color(189, 108, 72)),
+    ("algae", Color(84, 172, 104)),
+    ("algae green", Color(33, 195, 111)),
+    ("almost black", Color(7, 13, 13)),
+    ("amber", Color(254, 179, 8)),
+    ("amethyst", Color(155, 95, 192)),
+    ("apple", Color(110, 203, 60)),
+    ("apple green", Color(118, 205, 38)),
+    ("apricot", Color(255, 177, 109)),
+    ("aqua", Color(19, 234, 201)),
+    ("aqua blue", Color(2, 216, 233)),
+    ("aqua green", Color(18, 225, 147)),
+    ("aqua marine", Color(46, 232, 187)),
+    ("aquamarine", Color(4, 216, 178)),
+    ("army green", Color(75, 93, 22)),
+    ("asparagus", Color(119, 171, 86)),
+    ("aubergine", Color(61, 7, 52)),
+    ("auburn", Color(154, 48, 1)),
+    ("avocado", Color(144, 177, 52)),
+    ("avocado green", Color(135, 169, 34)),
+    ("azul", Color(29, 93, 236)),
+    ("azure", Color(6, 154, 243)),
+    ("baby blue", Color(162, 207, 254)),
+    ("baby green", Color(140, 255, 158)),
+    ("baby pink", Color(255, 183, 206)),
+    ("baby poo", Color(171, 144, 4)),
+    ("baby poop", Color(147, 124, 0)),
+    ("baby poop green", Color(143, 152, 5)),
+    ("baby puke green", Color(182, 196, 6)),
+    ("baby purple", Color(202, 155, 247)),
+    ("baby shit brown", Color(173, 144, 13)),
+    ("baby shit green", Color(136, 151, 23)),
+    ("banana", Color(255, 255, 126)),
+    ("banana yellow", Color(250, 254, 75)),
+    ("barbie pink", Color(254, 70, 165)),
+    ("barf green", Color(148, 172, 2)),
+    ("barney", Color(172, 29, 184)),
+    ("barney purple", Color(160, 4, 152)),
+    ("battleship grey", Color(107, 124, 133)),
+    ("beige", Color(230, 218, 166)),
+    ("berry", Color(153, 15, 75)),
+    ("bile", Color(181, 195, 6)),
+    ("black", Color(0, 0, 0)),
+    ("bland", Color(175, 168, 139)),
+    ("blood", Color(119, 0, 1)),
+    ("blood orange", Color(254, 75, 3)),
+    ("blood red", Color(152, 0, 2)),
+    ("blue", Color(3, 67, 223)),
+    ("blue blue", Color(34, 66, 199)),
+    ("blue green", Color(19, 126, 109)),
+    ("blue grey", Color(96, 124, 142)),
+    ("blue purple", Color(87, 41, 206)),
+    ("blue violet", Color(93, 6, 233)),
+    ("blue with a hint of purple", Color(83, 60, 198)),
+    ("blue/green", Color(15, 155, 142)),
+    ("blue/grey", Color(117, 141, 163)),
+    ("blue/purple", Color(90, 6, 239)),
+    ("blueberry", Color(70, 65, 150)),
+    ("bluegreen", Color(1, 122, 121)),
+    ("bluegrey", Color(133, 163, 178)),
+    ("bluey green", Color(43, 177, 121)),
+    ("bluey grey", Color(137, 160, 176)),
+    ("bluey purple", Color(98, 65, 199)),
+    ("bluish", Color(41, 118, 187)),
+    ("bluish green", Color(16, 166, 116)),
+    ("bluish grey", Color(116, 139, 151)),
+    ("bluish purple", Color(112, 59, 231)),
+    ("blurple", Color(85, 57, 204)),
+    ("blush", Color(242, 158, 142)),
+    ("blush pink", Color(254, 130, 140)),
+    ("booger", Color(155, 181, 60)),
+    ("booger green", Color(150, 180, 3)),
+    ("bordeaux", Color(123, 0, 44)),
+    ("boring green", Color(99, 179, 101)),
+    ("bottle green", Color(4, 74, 5)),
+    ("brick", Color(160, 54, 35)),
+    ("brick orange", Color(193, 74, 9)),
+    ("brick red", Color(143, 20, 2)),
+    ("bright aqua", Color(11, 249, 234)),
+    ("bright blue", Color(1, 101, 252)),
+    ("bright cyan", Color(65, 253, 254)),
+    ("bright green", Color(1, 255, 7)),
+    ("bright lavender", Color(199, 96, 255)),
+    ("bright light blue", Color(38, 247, 253)),
+    ("bright light green", Color(45, 254, 84)),
+    ("bright lilac", Color(201, 94, 251)),
+    ("bright lime", Color(135, 253, 5)),
+    ("bright lime green", Color(101, 254, 8)),
+    ("bright magenta", Color(255, 8, 232)),
+    ("bright olive", Color(156, 187, 4)),
+    ("bright orange", Color(255, 91, 0)),
+    ("bright pink", Color(254, 1, 177)),
+    ("bright purple", Color(190, 3, 253)),
+    ("bright red", Color(255, 0, 13)),
+    ("bright sea green", Color(5, 255, 166)),
+    ("bright sky blue", Color(2, 204, 254)),
+    ("bright teal", Color(1, 249, 198)),
+    ("bright turquoise", Color(15, 254, 249)),
+    ("bright violet", Color(173, 10, 253)),
+    ("bright yellow", Color(255, 253, 1)),
+    ("bright yellow green", Color(157, 255, 0)),
+    ("british racing green", Color(5, 72, 13)),
+    ("bronze", Color(168, 121, 0)),
+    ("brown", Color(101, 55, 0)),
+    ("brown green", Color(112, 108, 17)),
+    ("brown grey", Color(141, 132, 104)),
+    ("brown orange", Color(185, 105, 2)),
+    ("brown red", Color(146, 43, 5)),
+    ("brown yellow", Color(178, 151, 5)),
+    ("brownish", Color(156, 109, 87)),
+    ("brownish green", Color(106, 110, 9)),
+    ("brownish grey", Color(134, 119, 95)),
+    ("brownish orange", Color(203, 119, 35)),
+    ("brownish pink", Color(194, 126, 121)),
+    ("brownish purple", Color(118, 66, 78)),
+    ("brownish red", Color(158, 54, 35)),
+    ("brownish yellow", Color(201, 176, 3)),
+    ("browny green", Color(111, 108, 10)),
+    ("browny orange", Color(202, 107, 2)),
+    ("bruise", Color(126, 64, 113)),
+    ("bubble gum pink", Color(255, 105, 175)),
+    ("bubblegum", Color(255, 108, 181)),
+    ("bubblegum pink", Color(254, 131, 204)),
+    ("buff", Color(254, 246, 158)),
+    ("burgundy", Color(97, 0, 35)),
+    ("burnt orange", Color(192, 78, 1)),
+    ("burnt red", Color(159, 35, 5)),
+    ("burnt siena", Color(183, 82, 3)),
+    ("burnt sienna", Color(176, 78, 15)),
+    ("burnt umber", Color(160, 69, 14)),
+    ("burnt yellow", Color(213, 171, 9)),
+    ("burple", Color(104, 50, 227)),
+    ("butter", Color(255, 255, 129)),
+    ("butter yellow", Color(255, 253, 116)),
+    ("butterscotch", Color(253, 177, 71)),
+    ("cadet blue", Color(78, 116, 150)),
+    ("camel", Color(198, 159, 89)),
+    ("camo", Color(127, 143, 78)),
+    ("camo green", Color(82, 101, 37)),
+    ("camouflage green", Color(75, 97, 19)),
+    ("canary", Color(253, 255, 99)),
+    ("canary yellow", Color(255, 254, 64)),
+    ("candy pink", Color(255, 99, 233)),
+    ("caramel", Color(175, 111, 9)),
+    ("carmine", Color(157, 2, 22)),
+    ("carnation", Color(253, 121, 143)),
+    ("carnation pink", Color(255, 127, 167)),
+    ("carolina blue", Color(138, 184, 254)),
+    ("celadon", Color(190, 253, 183)),
+    ("celery", Color(193, 253, 149)),
+    ("cement", Color(165, 163, 145)),
+    ("cerise", Color(222, 12, 98)),
+    ("cerulean", Color(4, 133, 209)),
+    ("cerulean blue", Color(5, 110, 238)),
+    ("charcoal", Color(52, 56, 55)),
+    ("charcoal grey", Color(60, 65, 66)),
+    ("chartreuse", Color(193, 248, 10)),
+    ("cherry", Color(207, 2, 52)),
+    ("cherry red", Color(247, 2, 42)),
+    ("chestnut", Color(116, 40, 2)),
+    ("chocolate", Color(61, 28, 2)),
+    ("chocolate brown", Color(65, 25, 0)),
+    ("cinnamon", Color(172, 79, 6)),
+    ("claret", Color(104, 0, 24)),
+    ("clay", Color(182, 106, 80)),
+    ("clay brown", Color(178, 113, 61)),
+    ("clear blue", Color(36, 122, 253)),
+    ("cloudy blue", Color(172, 194, 217)),
+    ("cobalt", Color(30, 72, 143)),
+    ("cobalt blue", Color(3, 10, 167)),
+    ("cocoa", Color(135, 95, 66)),
+    ("coffee", Color(166, 129, 76)),
+    ("cool blue", Color(73, 132, 184)),
+    ("cool green", Color(51, 184, 100)),
+    ("cool grey", Color(149, 163, 166)),
+    ("copper", Color(182, 99, 37)),
+    ("coral", Color(252, 90, 80)),
+    ("coral pink", Color(255, 97, 99)),
+    ("cornflower", Color(106, 121, 247)),
+    ("cornflower blue", Color(81, 112, 215)),
+    ("cranberry", Color(158, 0, 58)),
+    ("cream", Color(255, 255, 194)),
+    ("creme", Color(255, 255, 182)),
+    ("crimson", Color(140, 0, 15)),
+    ("custard", Color(255, 253, 120)),
+    ("cyan", Color(0, 255, 255)),
+    ("dandelion", Color(254, 223, 8)),
+    ("dark", Color(27, 36, 49)),
+    ("dark aqua", Color(5, 105, 107)),
+    ("dark aquamarine", Color(1, 115, 113)),
+    ("dark beige", Color(172, 147, 98)),
+    ("dark blue", Color(0, 3, 91)),
+    ("dark blue green", Color(0, 82, 73)),
+    ("dark blue grey", Color(31, 59, 77)),
+    ("dark brown", Color(52, 28, 2)),
+    ("dark coral", Color(207, 82, 78)),
+    ("dark cream", Color(255, 243, 154)),
+    ("dark cyan", Color(10, 136, 138)),
+    ("dark forest green", Color(0, 45, 4)),
+    ("dark fuchsia", Color(157, 7, 89)),
+    ("dark gold", Color(181, 148, 16)),
+    ("dark grass green", Color(56, 128, 4)),
+    ("dark green", Color(3, 53, 0)),
+    ("dark green blue", Color(31, 99, 87)),
+    ("dark grey", Color(54, 55, 55)),
+    ("dark grey blue", Color(41, 70, 91)),
+    ("dark hot pink", Color(217, 1, 102)),
+    ("dark indigo", Color(31, 9, 84)),
+    ("dark khaki", Color(155, 143, 85)),
+    ("dark lavender", Color(133, 103, 152)),
+    ("dark lilac", Color(156, 109, 165)),
+    ("dark lime", Color(132, 183, 1)),
+    ("dark lime green", Color(126, 189, 1)),
+    ("dark magenta", Color(150, 0, 86)),
+    ("dark maroon", Color(60, 0, 8)),
+    ("dark mauve", Color(135, 76, 98)),
+    ("dark mint", Color(72, 192, 114)),
+    ("dark mint green", Color(32, 192, 115)),
+    ("dark mustard", Color(168, 137, 5)),
+    ("dark navy", Color(0, 4, 53)),
+    ("dark navy blue", Color(0, 2, 46)),
+    ("dark olive", Color(55, 62, 2)),
+    ("dark olive green", Color(60, 77, 3)),
+    ("dark orange", Color(198, 81, 2)),
+    ("dark pastel green", Color(86, 174, 87)),
+    ("dark peach", Color(222, 126, 93)),
+    ("dark periwinkle", Color(102, 95, 209)),
+    ("dark pink", Color(203, 65, 107)),
+    ("dark plum", Color(63, 1, 44)),
+    ("dark purple", Color(53, 6, 62)),
+    ("dark red", Color(132, 0, 0)),
+    ("dark rose", Color(181, 72, 93)),
+    ("dark royal blue", Color(2, 6, 111)),
+    ("dark sage", Color(89, 133, 86)),
+    ("dark salmon", Color(200, 90, 83)),
+    ("dark sand", Color(168, 143, 89)),
+    ("dark sea green", Color(17, 135, 93)),
+    ("dark seafoam", Color(31, 181, 122)),
+    ("dark seafoam green", Color(62, 175, 118)),
+    ("dark sky blue", Color(68, 142, 228)),
+    ("dark slate blue", Color(33, 71, 97)),
+    ("dark tan", Color(175, 136, 74)),
+    ("dark taupe", Color(127, 104, 78)),
+    ("dark teal", Color(1, 77, 78)),
+    ("dark turquoise", Color(4, 92, 90)),
+    ("dark violet", Color(52, 1, 63)),
+    ("dark yellow", Color(213, 182, 10)),
+    ("dark yellow green", Color(114, 143, 2)),
+    ("darkblue", Color(3, 7, 100)),
+    ("darkgreen", Color(5, 73, 7)),
+    ("darkish blue", Color(1, 65, 130)),
+    ("darkish green", Color(40, 124, 55)),
+    ("darkish pink", Color(218, 70, 125)),
+    ("darkish purple", Color(117, 25, 115)),
+    ("darkish red", Color(169, 3, 8)),
+    ("deep aqua", Color(8, 120, 127)),
+    ("deep blue", Color(4, 2, 115)),
+    ("deep brown", Color(65, 2, 0)),
+    ("deep green", Color(2, 89, 15)),
+    ("deep lavender", Color(141, 94, 183)),
+    ("deep lilac", Color(150, 110, 189)),
+    ("deep magenta", Color(160, 2, 92)),
+    ("deep orange", Color(220, 77, 1)),
+    ("deep pink", Color(203, 1, 98)),
+    ("deep purple", Color(54, 1, 63)),
+    ("deep red", Color(154, 2, 0)),
+    ("deep rose", Color(199, 71, 103)),
+    ("deep sea blue", Color(1, 84, 130)),
+    ("deep sky blue", Color(13, 117, 248)),
+    ("deep teal", Color(0, 85, 90)),
+    ("deep turquoise", Color(1, 115, 116)),
+    ("deep violet", Color(73, 6, 72)),
+    ("denim", Color(59, 99, 140)),
+    ("denim blue", Color(59, 91, 146)),
+    ("desert", Color(204, 173, 96)),
+    ("diarrhea", Color(159, 131, 3)),
+    ("dirt", Color(138, 110, 69)),
+    ("dirt brown", Color(131, 101, 57)),
+    ("dirty blue", Color(63, 130, 157)),
+    ("dirty green", Color(102, 126, 44)),
+    ("dirty orange", Color(200, 118, 6)),
+    ("dirty pink", Color(202, 123, 128)),
+    ("dirty purple", Color(115, 74, 101)),
+    ("dirty yellow", Color(205, 197, 10)),
+    ("dodger blue", Color(62, 130, 252)),
+    ("drab", Color(130, 131, 68)),
+    ("drab green", Color(116, 149, 81)),
+    ("dried blood", Color(75, 1, 1)),
+    ("duck egg blue", Color(195, 251, 244)),
+    ("dull blue", Color(73, 117, 156)),
+    ("dull brown", Color(135, 110, 75)),
+    ("dull green", Color(116, 166, 98)),
+    ("dull orange", Color(216, 134, 59)),
+    ("dull pink", Color(213, 134, 157)),
+    ("dull purple", Color(132, 89, 126)),
+    ("dull red", Color(187, 63, 63)),
+    ("dull teal", Color(95, 158, 143)),
+    ("dull yellow", Color(238, 220, 91)),
+    ("dusk", Color(78, 84, 129)),
+    ("dusk blue", Color(38, 83, 141)),
+    ("dusky blue", Color(71, 95, 148)),
+    ("dusky pink", Color(204, 122, 139)),
+    ("dusky purple", Color(137, 91, 123)),
+    ("dusky rose", Color(186, 104, 115)),
+    ("dust", Color(178, 153, 110)),
+    ("dusty blue", Color(90, 134, 173)),
+    ("dusty green", Color(118, 169, 115)),
+    ("dusty lavender", Color(172, 134, 168)),
+    ("dusty orange", Color(240, 131, 58)),
+    ("dusty pink", Color(213, 138, 148)),
+    ("dusty purple", Color(130, 95, 135)),
+    ("dusty red", Color(185, 72, 78)),
+    ("dusty rose", Color(192, 115, 122)),
+    ("dusty teal", Color(76, 144, 133)),
+    ("earth", Color(162, 101, 62)),
+    ("easter green", Color(140, 253, 126)),
+    ("easter purple", Color(192, 113, 254)),
+    ("ecru", Color(254, 255, 202)),
+    ("egg shell", Color(255, 252, 196)),
+    ("eggplant", Color(56, 8, 53)),
+    ("eggplant purple", Color(67, 5, 65)),
+    ("eggshell", Color(255, 255, 212)),
+    ("eggshell blue", Color(196, 255, 247)),
+    ("electric blue", Color(6, 82, 255)),
+    ("electric green", Color(33, 252, 13)),
+    ("electric lime", Color(168, 255, 4)),
+    ("electric pink", Color(255, 4, 144)),
+    ("electric purple", Color(170, 35, 255)),
+    ("emerald", Color(1, 160, 73)),
+    ("emerald green", Color(2, 143, 30)),
+    ("evergreen", Color(5, 71, 42)),
+    ("faded blue", Color(101, 140, 187)),
+    ("faded green", Color(123, 178, 116)),
+    ("faded orange", Color(240, 148, 77)),
+    ("faded pink", Color(222, 157, 172)),
+    ("faded purple", Color(145, 110, 153)),
+    ("faded red", Color(211, 73, 78)),
+    ("faded yellow", Color(254, 255, 127)),
+    ("fawn", Color(207, 175, 123)),
+    ("fern", Color(99, 169, 80)),
+    ("fern green", Color(84, 141, 68)),
+    ("fire engine red", Color(254, 0, 2)),
+    ("flat blue", Color(60, 115, 168)),
+    ("flat green", Color(105, 157, 76)),
+    ("fluorescent green", Color(8, 255, 8)),
+    ("fluro green", Color(10, 255, 2)),
+    ("foam green", Color(144, 253, 169)),
+    ("forest", Color(11, 85, 9)),
+    ("forest green", Color(6, 71, 12)),
+    ("forrest green", Color(21, 68, 6)),
+    ("french blue", Color(67, 107, 173)),
+    ("fresh green", Color(105, 216, 79)),
+    ("frog green", Color(88, 188, 8)),
+    ("fuchsia", Color(237, 13, 217)),
+    ("gold", Color(219, 180, 12)),
+    ("golden", Color(245, 191, 3)),
+    ("golden brown", Color(178, 122, 1)),
+    ("golden rod", Color(249, 188, 8)),
+    ("golden yellow", Color(254, 198, 21)),
+    ("goldenrod", Color(250, 194, 5)),
+    ("grape", Color(108, 52, 97)),
+    ("grape purple", Color(93, 20, 81)),
+    ("grapefruit", Color(253, 89, 86)),
+    ("grass", Color(92, 172, 45)),
+    ("grass green", Color(63, 155, 11)),
+    ("grassy green", Color(65, 156, 3)),
+    ("green", Color(21, 176, 26)),
+    ("green apple", Color(94, 220, 31)),
+    ("green blue", Color(6, 180, 139)),
+    ("green brown", Color(84, 78, 3)),
+    ("green grey", Color(119, 146, 111)),
+    ("green teal", Color(12, 181, 119)),
+    ("green yellow", Color(201, 255, 39)),
+    ("green/blue", Color(1, 192, 141)),
+    ("green/yellow", Color(181, 206, 8)),
+    ("greenblue", Color(35, 196, 139)),
+    ("greenish", Color(64, 163, 104)),
+    ("greenish beige", Color(201, 209, 121)),
+    ("greenish blue", Color(11, 139, 135)),
+    ("greenish brown", Color(105, 97, 18)),
+    ("greenish cyan", Color(42, 254, 183)),
+    ("greenish grey", Color(150, 174, 141)),
+    ("greenish tan", Color(188, 203, 122)),
+    ("greenish teal", Color(50, 191, 132)),
+    ("greenish turquoise", Color(0, 251, 176)),
+    ("greenish yellow", Color(205, 253, 2)),
+    ("greeny blue", Color(66, 179, 149)),
+    ("greeny brown", Color(105, 96, 6)),
+    ("greeny grey", Color(126, 160, 122)),
+    ("greeny yellow", Color(198, 248, 8)),
+    ("grey", Color(146, 149, 145)),
+    ("grey blue", Color(107, 139, 164)),
+    ("grey brown", Color(127, 112, 83)),
+    ("grey green", Color(120, 155, 115)),
+    ("grey pink", Color(195, 144, 155)),
+    ("grey purple", Color(130, 109, 140)),
+    ("grey teal", Color(94, 155, 138)),
+    ("grey/blue", Color(100, 125, 142)),
+    ("grey/green", Color(134, 161, 125)),
+    ("greyblue", Color(119, 161, 181)),
+    ("greyish", Color(168, 164, 149)),
+    ("greyish blue", Color(94, 129, 157)),
+    ("greyish brown", Color(122, 106, 79)),
+    ("greyish green", Color(130, 166, 125)),
+    ("greyish pink", Color(200, 141, 148)),
+    ("greyish purple", Color(136, 113, 145)),
+    ("greyish teal", Color(113, 159, 145)),
+    ("gross green", Color(160, 191, 22)),
+    ("gunmetal", Color(83, 98, 103)),
+    ("hazel", Color(142, 118, 24)),
+    ("heather", Color(164, 132, 172)),
+    ("heliotrope", Color(217, 79, 245)),
+    ("highlighter green", Color(27, 252, 6)),
+    ("hospital green", Color(155, 229, 170)),
+    ("hot green", Color(37, 255, 41)),
+    ("hot magenta", Color(245, 4, 201)),
+    ("hot pink", Color(255, 2, 141)),
+    ("hot purple", Color(203, 0, 245)),
+    ("hunter green", Color(11, 64, 8)),
+    ("ice", Color(214, 255, 250)),
+    ("ice blue", Color(215, 255, 254)),
+    ("icky green", Color(143, 174, 34)),
+    ("indian red", Color(133, 14, 4)),
+    ("indigo", Color(56, 2, 130)),
+    ("indigo blue", Color(58, 24, 177)),
+    ("iris", Color(98, 88, 196)),
+    ("irish green", Color(1, 149, 41)),
+    ("ivory", Color(255, 255, 203)),
+    ("jade", Color(31, 167, 116)),
+    ("jade green", Color(43, 175, 106)),
+    ("jungle green", Color(4, 130, 67)),
+    ("kelley green", Color(0, 147, 55)),
+    ("kelly green", Color(2, 171, 46)),
+    ("kermit green", Color(92, 178, 0)),
+    ("key lime", Color(174, 255, 110)),
+    ("khaki", Color(170, 166, 98)),
+    ("khaki green", Color(114, 134, 57)),
+    ("kiwi", Color(156, 239, 67)),
+    ("kiwi green", Color(142, 229, 63)),
+    ("lavender", Color(199, 159, 239)),
+    ("lavender blue", Color(139, 136, 248)),
+    ("lavender pink", Color(221, 133, 215)),
+    ("lawn green", Color(77, 164, 9)),
+    ("leaf", Color(113, 170, 52)),
+    ("leaf green", Color(92, 169, 4)),
+    ("leafy green", Color(81, 183, 59)),
+    ("leather", Color(172, 116, 52)),
+    ("lemon", Color(253, 255, 82)),
+    ("lemon green", Color(173, 248, 2)),
+    ("lemon lime", Color(191, 254, 40)),
+    ("lemon yellow", Color(253, 255, 56)),
+    ("lichen", Color(143, 182, 123)),
+    ("light aqua", Color(140, 255, 219)),
+    ("light aquamarine", Color(123, 253, 199)),
+    ("light beige", Color(255, 254, 182)),
+    ("light blue", Color(149, 208, 252)),
+    ("light blue green", Color(126, 251, 179)),
+    ("light blue grey", Color(183, 201, 226)),
+    ("light bluish green", Color(118, 253, 168)),
+    ("light bright green", Color(83, 254, 92)),
+    ("light brown", Color(173, 129, 80)),
+    ("light burgundy", Color(168, 65, 91)),
+    ("light cyan", Color(172, 255, 252)),
+    ("light eggplant", Color(137, 69, 133)),
+    ("light forest green", Color(79, 145, 83)),
+    ("light gold", Color(253, 220, 92)),
+    ("light grass green", Color(154, 247, 100)),
+    ("light green", Color(150, 249, 123)),
+    ("light green blue", Color(86, 252, 162)),
+    ("light greenish blue", Color(99, 247, 180)),
+    ("light grey", Color(216, 220, 214)),
+    ("light grey blue", Color(157, 188, 212)),
+    ("light grey green", Color(183, 225, 161)),
+    ("light indigo", Color(109, 90, 207)),
+    ("light khaki", Color(230, 242, 162)),
+    ("light lavendar", Color(239, 192, 254)),
+    ("light lavender", Color(223, 197, 254)),
+    ("light light blue", Color(202, 255, 251)),
+    ("light light green", Color(200, 255, 176)),
+    ("light lilac", Color(237, 200, 255)),
+    ("light lime", Color(174, 253, 108)),
+    ("light lime green", Color(185, 255, 102)),
+    ("light magenta", Color(250, 95, 247)),
+    ("light maroon", Color(162, 72, 87)),
+    ("light mauve", Color(194, 146, 161)),
+    ("light mint", Color(182, 255, 187)),
+    ("light mint green", Color(166, 251, 178)),
+    ("light moss green", Color(166, 200, 117)),
+    ("light mustard", Color(247, 213, 96)),
+    ("light navy", Color(21, 80, 132)),
+    ("light navy blue", Color(46, 90, 136)),
+    ("light neon green", Color(78, 253, 84)),
+    ("light olive", Color(172, 191, 105)),
+    ("light olive green", Color(164, 190, 92)),
+    ("light orange", Color(253, 170, 72)),
+    ("light pastel green", Color(178, 251, 165)),
+    ("light pea green", Color(196, 254, 130)),
+    ("light peach", Color(255, 216, 177)),
+    ("light periwinkle", Color(193, 198, 252)),
+    ("light pink", Color(255, 209, 223)),
+    ("light plum", Color(157, 87, 131)),
+    ("light purple", Color(191, 119, 246)),
+    ("light red", Color(255, 71, 76)),
+    ("light rose", Color(255, 197, 203)),
+    ("light royal blue", Color(58, 46, 254)),
+    ("light sage", Color(188, 236, 172)),
+    ("light salmon", Color(254, 169, 147)),
+    ("light sea green", Color(152, 246, 176)),
+    ("light seafoam", Color(160, 254, 191)),
+    ("light seafoam green", Color(167, 255, 181)),
+    ("light sky blue", Color(198, 252, 255)),
+    ("light tan", Color(251, 238, 172)),
+    ("light teal", Color(144, 228, 193)),
+    ("light turquoise", Color(126, 244, 204)),
+    ("light urple", Color(179, 111, 246)),
+    ("light violet", Color(214, 180, 252)),
+    ("light yellow", Color(255, 254, 122)),
+    ("light yellow green", Color(204, 253, 127)),
+    ("light yellowish green", Color(194, 255, 137)),
+    ("lightblue", Color(123, 200, 246)),
+    ("lighter green", Color(117, 253, 99)),
+    ("lighter purple", Color(165, 90, 244)),
+    ("lightgreen", Color(118, 255, 123)),
+    ("lightish blue", Color(61, 122, 253)),
+    ("lightish green", Color(97, 225, 96)),
+    ("lightish purple", Color(165, 82, 230)),
+    ("lightish red", Color(254, 47, 74)),
+    ("lilac", Color(206, 162, 253)),
+    ("liliac", Color(196, 142, 253)),
+    ("lime", Color(170, 255, 50)),
+    ("lime green", Color(137, 254, 5)),
+    ("lime yellow", Color(208, 254, 29)),
+    ("lipstick", Color(213, 23, 78)),
+    ("lipstick red", Color(192, 2, 47)),
+    ("macaroni and cheese", Color(239, 180, 53)),
+    ("magenta", Color(194, 0, 120)),
+    ("mahogany", Color(74, 1, 0)),
+    ("maize", Color(244, 208, 84)),
+    ("mango", Color(255, 166, 43)),
+    ("manilla", Color(255, 250, 134)),
+    ("marigold", Color(252, 192, 6)),
+    ("marine", Color(4, 46, 96)),
+    ("marine blue", Color(1, 56, 106)),
+    ("maroon", Color(101, 0, 33)),
+    ("mauve", Color(174, 113, 129)),
+    ("medium blue", Color(44, 111, 187)),
+    ("medium brown", Color(127, 81, 18)),
+    ("medium green", Color(57, 173, 72)),
+    ("medium grey", Color(125, 127, 124)),
+    ("medium pink", Color(243, 97, 150)),
+    ("medium purple", Color(158, 67, 162)),
+    ("melon", Color(255, 120, 85)),
+    ("merlot", Color(115, 0, 57)),
+    ("metallic blue", Color(79, 115, 142)),
+    ("mid blue", Color(39, 106, 179)),
+    ("mid green", Color(80, 167, 71)),
+    ("midnight", Color(3, 1, 45)),
+    ("midnight blue", Color(2, 0, 53)),
+    ("midnight purple", Color(40, 1, 55)),
+    ("military green", Color(102, 124, 62)),
+    ("milk chocolate", Color(127, 78, 30)),
+    ("mint", Color(159, 254, 176)),
+    ("mint green", Color(143, 255, 159)),
+    ("minty green", Color(11, 247, 125)),
+    ("mocha", Color(157, 118, 81)),
+    ("moss", Color(118, 153, 88)),
+    ("moss green", Color(101, 139, 56)),
+    ("mossy green", Color(99, 139, 39)),
+    ("mud", Color(115, 92, 18)),
+    ("mud brown", Color(96, 70, 15)),
+    ("mud green", Color(96, 102, 2)),
+    ("muddy brown", Color(136, 104, 6)),
+    ("muddy green", Color(101, 116, 50)),
+    ("muddy yellow", Color(191, 172, 5)),
+    ("mulberry", Color(146, 10, 78)),
+    ("murky green", Color(108, 122, 14)),
+    ("mushroom", Color(186, 158, 136)),
+    ("mustard", Color(206, 179, 1)),
+    ("mustard brown", Color(172, 126, 4)),
+    ("mustard green", Color(168, 181, 4)),
+    ("mustard yellow", Color(210, 189, 10)),
+    ("muted blue", Color(59, 113, 159)),
+    ("muted green", Color(95, 160, 82)),
+    ("muted pink", Color(209, 118, 143)),
+    ("muted purple", Color(128, 91, 135)),
+    ("nasty green", Color(112, 178, 60)),
+    ("navy", Color(1, 21, 62)),
+    ("navy blue", Color(0, 17, 70)),
+    ("navy green", Color(53, 83, 10)),
+    ("neon blue", Color(4, 217, 255)),
+    ("neon green", Color(12, 255, 12)),
+    ("neon pink", Color(254, 1, 154)),
+    ("neon purple", Color(188, 19, 254)),
+    ("neon red", Color(255, 7, 58)),
+    ("neon yellow", Color(207, 255, 4)),
+    ("nice blue", Color(16, 122, 176)),
+    ("night blue", Color(4, 3, 72)),
+    ("ocean", Color(1, 123, 146)),
+    ("ocean blue", Color(3, 113, 156)),
+    ("ocean green", Color(61, 153, 115)),
+    ("ocher", Color(191, 155, 12)),
+    ("ochre", Color(191, 144, 5)),
+    ("ocre", Color(198, 156, 4)),
+    ("off blue", Color(86, 132, 174)),
+    ("off green", Color(107, 163, 83)),
+    ("off white", Color(255, 255, 228)),
+    ("off yellow", Color(241, 243, 63)),
+    ("old pink", Color(199, 121, 134)),
+    ("old rose", Color(200, 127, 137)),
+    ("olive", Color(110, 117, 14)),
+    ("olive brown", Color(100, 84, 3)),
+    ("olive drab", Color(111, 118, 50)),
+    ("olive green", Color(103, 122, 4)),
+    ("olive yellow", Color(194, 183, 9)),
+    ("orange", Color(249, 115, 6)),
+    ("orange brown", Color(190, 100, 0)),
+    ("orange pink", Color(255, 111, 82)),
+    ("orange red", Color(253, 65, 30)),
+    ("orange yellow", Color(255, 173, 1)),
+    ("orangeish", Color(253, 141, 73)),
+    ("orangered", Color(254, 66, 15)),
+    ("orangey brown", Color(177, 96, 2)),
+    ("orangey red", Color(250, 66, 36)),
+    ("orangey yellow", Color(253, 185, 21)),
+    ("orangish", Color(252, 130, 74)),
+    ("orangish brown", Color(178, 95, 3)),
+    ("orangish red", Color(244, 54, 5)),
+    ("orchid", Color(200, 117, 196)),
+    ("pale", Color(255, 249, 208)),
+    ("pale aqua", Color(184, 255, 235)),
+    ("pale blue", Color(208, 254, 254)),
+    ("pale brown", Color(177, 145, 110)),
+    ("pale cyan", Color(183, 255, 250)),
+    ("pale gold", Color(253, 222, 108)),
+    ("pale green", Color(199, 253, 181)),
+    ("pale grey", Color(253, 253, 254)),
+    ("pale lavender", Color(238, 207, 254)),
+    ("pale light green", Color(177, 252, 153)),
+    ("pale lilac", Color(228, 203, 255)),
+    ("pale lime", Color(190, 253, 115)),
+    ("pale lime green", Color(177, 255, 101)),
+    ("pale magenta", Color(215, 103, 173)),
+    ("pale mauve", Color(254, 208, 252)),
+    ("pale olive", Color(185, 204, 129)),
+    ("pale olive green", Color(177, 210, 123)),
+    ("pale orange", Color(255, 167, 86)),
+    ("pale peach", Color(255, 229, 173)),
+    ("pale pink", Color(255, 207, 220)),
+    ("pale purple", Color(183, 144, 212)),
+    ("pale red", Color(217, 84, 77)),
+    ("pale rose", Color(253, 193, 197)),
+    ("pale salmon", Color(255, 177, 154)),
+    ("pale sky blue", Color(189, 246, 254)),
+    ("pale teal", Color(130, 203, 178)),
+    ("pale turquoise", Color(165, 251, 213)),
+    ("pale violet", Color(206, 174, 250)),
+    ("pale yellow", Color(255, 255, 132)),
+    ("parchment", Color(254, 252, 175)),
+    ("pastel blue", Color(162, 191, 254)),
+    ("pastel green", Color(176, 255, 157)),
+    ("pastel orange", Color(255, 150, 79)),
+    ("pastel pink", Color(255, 186, 205)),
+    ("pastel purple", Color(202, 160, 255)),
+    ("pastel red", Color(219, 88, 86)),
+    ("pastel yellow", Color(255, 254, 113)),
+    ("pea", Color(164, 191, 32)),
+    ("pea green", Color(142, 171, 18)),
+    ("pea soup", Color(146, 153, 1)),
+    ("pea soup green", Color(148, 166, 23)),
+    ("peach", Color(255, 176, 124)),
+    ("peachy pink", Color(255, 154, 138)),
+    ("peacock blue", Color(1, 103, 149)),
+    ("pear", Color(203, 248, 95)),
+    ("periwinkle", Color(142, 130, 254)),
+    ("periwinkle blue", Color(143, 153, 251)),
+    ("perrywinkle", Color(143, 140, 231)),
+    ("petrol", Color(0, 95, 106)),
+    ("pig pink", Color(231, 142, 169)),
+    ("pine", Color(43, 93, 52)),
+    ("pine green", Color(10, 72, 30)),
+    ("pink", Color(255, 129, 192)),
+    ("pink purple", Color(219, 75, 218)),
+    ("pink red", Color(245, 5, 79)),
+    ("pink/purple", Color(239, 29, 231)),
+    ("pinkish", Color(212, 106, 126)),
+    ("pinkish brown", Color(177, 114, 97)),
+    ("pinkish grey", Color(200, 172, 169)),
+    ("pinkish orange", Color(255, 114, 76)),
+    ("pinkish purple", Color(214, 72, 215)),
+    ("pinkish red", Color(241, 12, 69)),
+    ("pinkish tan", Color(217, 155, 130)),
+    ("pinky", Color(252, 134, 170)),
+    ("pinky purple", Color(201, 76, 190)),
+    ("pinky red", Color(252, 38, 71)),
+    ("piss yellow", Color(221, 214, 24)),
+    ("pistachio", Color(192, 250, 139)),
+    ("plum", Color(88, 15, 65)),
+    ("plum purple", Color(78, 5, 80)),
+    ("poison green", Color(64, 253, 20)),
+    ("poo", Color(143, 115, 3)),
+    ("poo brown", Color(136, 95, 1)),
+    ("poop", Color(127, 94, 0)),
+    ("poop brown", Color(122, 89, 1)),
+    ("poop green", Color(111, 124, 0)),
+    ("powder blue", Color(177, 209, 252)),
+    ("powder pink", Color(255, 178, 208)),
+    ("primary blue", Color(8, 4, 249)),
+    ("prussian blue", Color(0, 69, 119)),
+    ("puce", Color(165, 126, 82)),
+    ("puke", Color(165, 165, 2)),
+    ("puke brown", Color(148, 119, 6)),
+    ("puke green", Color(154, 174, 7)),
+    ("puke yellow", Color(194, 190, 14)),
+    ("pumpkin", Color(225, 119, 1)),
+    ("pumpkin orange", Color(251, 125, 7)),
+    ("pure blue", Color(2, 3, 226)),
+    ("purple", Color(126, 30, 156)),
+    ("purple blue", Color(99, 45, 233)),
+    ("purple brown", Color(103, 58, 63)),
+    ("purple grey", Color(134, 111, 133)),
+    ("purple pink", Color(224, 63, 216)),
+    ("purple red", Color(153, 1, 71)),
+    ("purple/blue", Color(93, 33, 208)),
+    ("purple/pink", Color(215, 37, 222)),
+    ("purpleish", Color(152, 86, 141)),
+    ("purpleish blue", Color(97, 64, 239)),
+    ("purpleish pink", Color(223, 78, 200)),
+    ("purpley", Color(135, 86, 228)),
+    ("purpley blue", Color(95, 52, 231)),
+    ("purpley grey", Color(148, 126, 148)),
+    ("purpley pink", Color(200, 60, 185)),
+    ("purplish", Color(148, 86, 140)),
+    ("purplish blue", Color(96, 30, 249)),
+    ("purplish brown", Color(107, 66, 71)),
+    ("purplish grey", Color(122, 104, 127)),
+    ("purplish pink", Color(206, 93, 174)),
+    ("purplish red", Color(176, 5, 75)),
+    ("purply", Color(152, 63, 178)),
+    ("purply blue", Color(102, 26, 238)),
+    ("purply pink", Color(240, 117, 230)),
+    ("putty", Color(190, 174, 138)),
+    ("racing green", Color(1, 70, 0)),
+    ("radioactive green", Color(44, 250, 31)),
+    ("raspberry", Color(176, 1, 73)),
+    ("raw sienna", Color(154, 98, 0)),
+    ("raw umber", Color(167, 94, 9)),
+    ("really light blue", Color(212, 255, 255)),
+    ("red", Color(229, 0, 0)),
+    ("red brown", Color(139, 46, 22)),
+    ("red orange", Color(253, 60, 6)),
+    ("red pink", Color(250, 42, 85)),
+    ("red purple", Color(130, 7, 71)),
+    ("red violet", Color(158, 1, 104)),
+    ("red wine", Color(140, 0, 52)),
+    ("reddish", Color(196, 66, 64)),
+    ("reddish brown", Color(127, 43, 10)),
+    ("reddish grey", Color(153, 117, 112)),
+    ("reddish orange", Color(248, 72, 28)),
+    ("reddish pink", Color(254, 44, 84)),
+    ("reddish purple", Color(145, 9, 81)),
+    ("reddy brown", Color(110, 16, 5)),
+    ("rich blue", Color(2, 27, 249)),
+    ("rich purple", Color(114, 0, 88)),
+    ("robin egg blue", Color(138, 241, 254)),
+    ("robin's egg", Color(109, 237, 253)),
+    ("robin's egg blue", Color(152, 239, 249)),
+    ("rosa", Color(254, 134, 164)),
+    ("rose", Color(207, 98, 117)),
+    ("rose pink", Color(247, 135, 154)),
+    ("rose red", Color(190, 1, 60)),
+    ("rosy pink", Color(246, 104, 142)),
+    ("rouge", Color(171, 18, 57)),
+    ("royal", Color(12, 23, 147)),
+    ("royal blue", Color(5, 4, 170)),
+    ("royal purple", Color(75, 0, 110)),
+    ("ruby", Color(202, 1, 71)),
+    ("russet", Color(161, 57, 5)),
+    ("rust", Color(168, 60, 9)),
+    ("rust brown", Color(139, 49, 3)),
+    ("rust orange", Color(196, 85, 8)),
+    ("rust red", Color(170, 39, 4)),
+    ("rusty orange", Color(205, 89, 9)),
+    ("rusty red", Color(175, 47, 13)),
+    ("saffron", Color(254, 178, 9)),
+    ("sage", Color(135, 174, 115)),
+    ("sage green", Color(136, 179, 120)),
+    ("salmon", Color(255, 121, 108)),
+    ("salmon pink", Color(254, 123, 124)),
+    ("sand", Color(226, 202, 118)),
+    ("sand brown", Color(203, 165, 96)),
+    ("sand yellow", Color(252, 225, 102)),
+    ("sandstone", Color(201, 174, 116)),
+    ("sandy", Color(241, 218, 122)),
+    ("sandy brown", Color(196, 166, 97)),
+    ("sandy yellow", Color(253, 238, 115)),
+    ("sap green", Color(92, 139, 21)),
+    ("sapphire", Color(33, 56, 171)),
+    ("scarlet", Color(190, 1, 25)),
+    ("sea", Color(60, 153, 146)),
+    ("sea blue", Color(4, 116, 149)),
+    ("sea green", Color(83, 252, 161)),
+    ("seafoam", Color(128, 249, 173)),
+    ("seafoam blue", Color(120, 209, 182)),
+    ("seafoam green", Color(122, 249, 171)),
+    ("seaweed", Color(24, 209, 123)),
+    ("seaweed green", Color(53, 173, 107)),
+    ("sepia", Color(152, 94, 43)),
+    ("shamrock", Color(1, 180, 76)),
+    ("shamrock green", Color(2, 193, 77)),
+    ("shit", Color(127, 95, 0)),
+    ("shit brown", Color(123, 88, 4)),
+    ("shit green", Color(117, 128, 0)),
+    ("shocking pink", Color(254, 2, 162)),
+    ("sick green", Color(157, 185, 44)),
+    ("sickly green", Color(148, 178, 28)),
+    ("sickly yellow", Color(208, 228, 41)),
+    ("sienna", Color(169, 86, 30)),
+    ("silver", Color(197, 201, 199)),
+    ("sky", Color(130, 202, 252)),
+    ("sky blue", Color(117, 187, 253)),
+    ("slate", Color(81, 101, 114)),
+    ("slate blue", Color(91, 124, 153)),
+    ("slate green", Color(101, 141, 109)),
+    ("slate grey", Color(89, 101, 109)),
+    ("slime green", Color(153, 204, 4)),
+    ("snot", Color(172, 187, 13)),
+    ("snot green", Color(157, 193, 0)),
+    ("soft blue", Color(100, 136, 234)),
+    ("soft green", Color(111, 194, 118)),
+    ("soft pink", Color(253, 176, 192)),
+    ("soft purple", Color(166, 111, 181)),
+    ("spearmint", Color(30, 248, 118)),
+    ("spring green", Color(169, 249, 113)),
+    ("spruce", Color(10, 95, 56)),
+    ("squash", Color(242, 171, 21)),
+    ("steel", Color(115, 133, 149)),
+    ("steel blue", Color(90, 125, 154)),
+    ("steel grey", Color(111, 130, 138)),
+    ("stone", Color(173, 165, 135)),
+    ("stormy blue", Color(80, 123, 156)),
+    ("straw", Color(252, 246, 121)),
+    ("strawberry", Color(251, 41, 67)),
+    ("strong blue", Color(12, 6, 247)),
+    ("strong pink", Color(255, 7, 137)),
+    ("sun yellow", Color(255, 223, 34)),
+    ("sunflower", Color(255, 197, 18)),
+    ("sunflower yellow", Color(255, 218, 3)),
+    ("sunny yellow", Color(255, 249, 23)),
+    ("sunshine yellow", Color(255, 253, 55)),
+    ("swamp", Color(105, 131, 57)),
+    ("swamp green", Color(116, 133, 0)),
+    ("tan", Color(209, 178, 111)),
+    ("tan brown", Color(171, 126, 76)),
+    ("tan green", Color(169, 190, 112)),
+    ("tangerine", Color(255, 148, 8)),
+    ("taupe", Color(185, 162, 129)),
+    ("tea", Color(101, 171, 124)),
+    ("tea green", Color(189, 248, 163)),
+    ("teal", Color(2, 147, 134)),
+    ("teal blue", Color(1, 136, 159)),
+    ("teal green", Color(37, 163, 111)),
+    ("tealish", Color(36, 188, 168)),
+    ("tealish green", Color(12, 220, 115)),
+    ("terra cotta", Color(201, 100, 59)),
+    ("terracota", Color(203, 104, 67)),
+    ("terracotta", Color(202, 102, 65)),
+    ("tiffany blue", Color(123, 242, 218)),
+    ("tomato", Color(239, 64, 38)),
+    ("tomato red", Color(236, 45, 1)),
+    ("topaz", Color(19, 187, 175)),
+    ("toupe", Color(199, 172, 125)),
+    ("toxic green", Color(97, 222, 42)),
+    ("tree green", Color(42, 126, 25)),
+    ("true blue", Color(1, 15, 204)),
+    ("true green", Color(8, 148, 4)),
+    ("turquoise", Color(6, 194, 172)),
+    ("turquoise blue", Color(6, 177, 196)),
+    ("turquoise green", Color(4, 244, 137)),
+    ("turtle green", Color(117, 184, 79)),
+    ("twilight", Color(78, 81, 139)),
+    ("twilight blue", Color(10, 67, 122)),
+    ("ugly blue", Color(49, 102, 138)),
+    ("ugly brown", Color(125, 113, 3)),
+    ("ugly green", Color(122, 151, 3)),
+    ("ugly pink", Color(205, 117, 132)),
+    ("ugly purple", Color(164, 66, 160)),
+    ("ugly yellow", Color(208, 193, 1)),
+    ("ultramarine", Color(32, 0, 177)),
+    ("ultramarine blue", Color(24, 5, 219)),
+    ("umber", Color(178, 100, 0)),
+    ("velvet", Color(117, 8, 81)),
+    ("vermillion", Color(244, 50, 12)),
+    ("very dark blue", Color(0, 1, 51)),
+    ("very dark brown", Color(29, 2, 0)),
+    ("very dark green", Color(6, 46, 3)),
+    ("very dark purple", Color(42, 1, 52)),
+    ("very light blue", Color(213, 255, 255)),
+    ("very light brown", Color(211, 182, 131)),
+    ("very light green", Color(209, 255, 189)),
+    ("very light pink", Color(255, 244, 242)),
+    ("very light purple", Color(246, 206, 252)),
+    ("very pale blue", Color(214, 255, 254)),
+    ("very pale green", Color(207, 253, 188)),
+    ("vibrant blue", Color(3, 57, 248)),
+    ("vibrant green", Color(10, 221, 8)),
+    ("vibrant purple", Color(173, 3, 222)),
+    ("violet", Color(154, 14, 234)),
+    ("violet blue", Color(81, 10, 201)),
+    ("violet pink", Color(251, 95, 252)),
+    ("violet red", Color(165, 0, 85)),
+    ("viridian", Color(30, 145, 103)),
+    ("vivid blue", Color(21, 46, 255)),
+    ("vivid green", Color(47, 239, 16)),
+    ("vivid purple", Color(153, 0, 250)),
+    ("vomit", Color(162, 164, 21)),
+    ("vomit green", Color(137, 162, 3)),
+    ("vomit yellow", Color(199, 193, 12)),
+    ("warm blue", Color(75, 87, 219)),
+    ("warm brown", Color(150, 78, 2)),
+    ("warm grey", Color(151, 138, 132)),
+    ("warm pink", Color(251, 85, 129)),
+    ("warm purple", Color(149, 46, 143)),
+    ("washed out green", Color(188, 245, 166)),
+    ("water blue", Color(14, 135, 204)),
+    ("watermelon", Color(253, 70, 89)),
+    ("weird green", Color(58, 229, 127)),
+    ("wheat", Color(251, 221, 126)),
+    ("white", Color(255, 255, 255)),
+    ("windows blue", Color(55, 120, 191)),
+    ("wine", Color(128, 1, 63)),
+    ("wine red", Color(123, 3, 35)),
+    ("wintergreen", Color(32, 249, 134)),
+    ("wisteria", Color(168, 125, 194)),
+    ("yellow", Color(255, 255, 20)),
+    ("yellow brown", Color(183, 148, 0)),
+    ("yellow green", Color(192, 251, 45)),
+    ("yellow ochre", Color(203, 157, 6)),
+    ("yellow orange", Color(252, 176, 1)),
+    ("yellow tan", Color(255, 227, 110)),
+    ("yellow/green", Color(200, 253, 61)),
+    ("yellowgreen", Color(187, 249, 15)),
+    ("yellowish", Color(250, 238, 102)),
+    ("yellowish brown", Color(155, 122, 1)),
+    ("yellowish green", Color(176, 221, 22)),
+    ("yellowish orange", Color(255, 171, 15)),
+    ("yellowish tan", Color(252, 252, 129)),
+    ("yellowy brown", Color(174, 139, 12)),
+    ("yellowy green", Color(191, 241, 40)),
+];
+
+/// Gets the XKCD color named `name` (case-insensitive). Returns `None` when there is no such
+/// color.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{constants::xkcd, Color};
+///
+/// assert_eq!(xkcd::color("robin's egg blue"), Some(Color::new(152, 239, 249)));
+/// assert_eq!(xkcd::color("octarine"), None);
+/// ```
+pub fn color(name: &str) -> Option<Color> {
+    let lowercase = name.bytes().map(|b| b.to_ascii_lowercase());
+    COLORS
+        .binary_search_by(|(other, _)| other.bytes().cmp(lowercase.clone()))
+        .ok()
+        .map(|index| COLORS[index].1)
+}
+
+/// Gets the name of the XKCD color that is perceptually closest to `color`, along with the
+/// color.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{constants, constants::xkcd};
+///
+/// assert_eq!(xkcd::nearest(&constants::primary::WHITE), ("white", constants::primary::WHITE));
+/// ```
+pub fn nearest(color: &Color) -> (&'static str, Color) {
+    let lab = color.to_lab();
+    COLORS
+        .iter()
+        .map(|&(name, other)| ((name, other), ciede2000(other.to_lab(), lab)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(nearest, _)| nearest)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted() {
+        assert_eq!(COLORS.len(), 949);
+        assert!(COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(COLORS
+            .iter()
+            .all(|(name, _)| !name.bytes().any(|b| b.is_ascii_uppercase())));
+    }
+
+    #[test]
+    fn color() {
+        for (name, color) in COLORS {
+            assert_eq!(super::color(name), Some(*color));
+        }
+        assert_eq!(super::color("BLUE"), Some(Color::new(3, 67, 223)));
+        assert_eq!(super::color("green/yellow"), Some(Color::new(181, 206, 8)));
+        assert_eq!(super::color(""), None);
+        assert_eq!(super::color("puke greens"), None);
+    }
+
+    #[test]
+    fn nearest() {
+        for (name, color) in COLORS.iter().step_by(50) {
+            assert_eq!(super::nearest(color), (*name, *color));
+        }
+        assert_eq!(super::nearest(&Color::new(1, 1, 1)).0, "black");
+    }
+}
@@ -0,0 +1,316 @@
+//! Conversions and adjustments over whole slices of colors, such as the pixels of an image. These
+//! give the same results as calling the methods of [`Color`] on every color, but avoid the
+//! overhead of doing so one call at a time. With the `simd` feature, conversions between RGB and
+//! HSL or HSV are done four colors at a time with SSE2 on x86 and NEON on AArch64.
+//!
+//! # Example
+//!
+//! ```
+//! use octarine::{convert, Color};
+//!
+//! let mut pixels = [Color::new(255, 0, 0), Color::new(0, 0, 255)];
+//! convert::rotate_hue_slice(&mut pixels, 1.0 / 3.0);
+//!
+//! assert_eq!(pixels, [Color::new(0, 255, 0), Color::new(255, 0, 0)]);
+//! assert_eq!(convert::hex_of(&pixels), [0x00FF00, 0x0000FF]);
+//! ```
+
+use super::*;
+
+#[cfg(feature = "simd")]
+mod simd;
+
+// Without the `simd` feature, the batches are converted one color at a time.
+#[cfg(not(feature = "simd"))]
+mod simd {
+    use super::*;
+
+    pub(super) fn to_hsl(colors: &[Color; 4]) -> [(f32, f32, f32); 4] {
+        colors.map(|color| color.to_hsl())
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(super) fn to_hsv(colors: &[Color; 4]) -> [(f32, f32, f32); 4] {
+        colors.map(|color| color.to_hsv())
+    }
+
+    pub(super) fn from_hsl(values: &[(f32, f32, f32); 4]) -> [Color; 4] {
+        values.map(|(h, s, l)| Color::from_hsl(h, s, l))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(super) fn from_hsv(values: &[(f32, f32, f32); 4]) -> [Color; 4] {
+        values.map(|(h, s, v)| Color::from_hsv(h, s, v))
+    }
+}
+
+/// Converts `items` four at a time with `lanes`, and the rest one at a time with `one`.
+#[cfg(feature = "alloc")]
+fn map_chunks<T, U>(items: &[T], lanes: fn(&[T; 4]) -> [U; 4], one: fn(&T) -> U) -> Vec<U> {
+    let mut chunks = items.chunks_exact(4);
+    let mut mapped = Vec::with_capacity(items.len());
+    for chunk in &mut chunks {
+        mapped.extend(lanes(chunk.try_into().unwrap()));
+    }
+    mapped.extend(chunks.remainder().iter().map(one));
+    mapped
+}
+
+/// Replaces every color by converting it to HSL, applying `f`, and converting it back.
+fn map_hsl_in_place(colors: &mut [Color], f: impl Fn(Hsl) -> Hsl) {
+    let mut chunks = colors.chunks_exact_mut(4);
+    for chunk in &mut chunks {
+        let chunk: &mut [Color; 4] = chunk.try_into().unwrap();
+        *chunk = simd::from_hsl(&simd::to_hsl(chunk).map(|hsl| f(hsl.into()).into()));
+    }
+    for color in chunks.into_remainder() {
+        *color = f(color.hsl()).to_color();
+    }
+}
+
+/// Converts colors to HSL, the same as [`to_hsl()`](Color#method.to_hsl).
+#[cfg(feature = "alloc")]
+pub fn hsl_of(colors: &[Color]) -> Vec<(f32, f32, f32)> {
+    map_chunks(colors, simd::to_hsl, Color::to_hsl)
+}
+
+/// Converts colors to HSV, the same as [`to_hsv()`](Color#method.to_hsv).
+#[cfg(feature = "alloc")]
+pub fn hsv_of(colors: &[Color]) -> Vec<(f32, f32, f32)> {
+    map_chunks(colors, simd::to_hsv, Color::to_hsv)
+}
+
+/// Converts colors to hexadecimal, the same as [`to_hex()`](Color#method.to_hex), so the bytes
+/// are in the `0xBBGGRR` order.
+#[cfg(feature = "alloc")]
+pub fn hex_of(colors: &[Color]) -> Vec<u32> {
+    colors.iter().map(Color::to_hex).collect()
+}
+
+/// Fills `buffer` with `color` packed using `format`, such as clearing the framebuffer of a
+/// software renderer.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{convert, Color, PixelFormat};
+///
+/// let mut framebuffer = [0; 4];
+/// convert::fill(&mut framebuffer, &Color::new(255, 0, 0), PixelFormat::Rgb888);
+///
+/// assert_eq!(framebuffer, [0xFF0000; 4]);
+/// ```
+pub fn fill(buffer: &mut [u32], color: &Color, format: PixelFormat) {
+    buffer.fill(color.to_packed(format));
+}
+
+/// Packs every color of `colors` into `buffer` using `format`, the same as
+/// [`to_packed()`](Color#method.to_packed). When the lengths differ, only the shorter length is
+/// written.
+pub fn pack_into(colors: &[Color], buffer: &mut [u32], format: PixelFormat) {
+    for (pixel, color) in buffer.iter_mut().zip(colors) {
+        *pixel = color.to_packed(format);
+    }
+}
+
+/// Converts colors to linear RGB, the same as [`to_linear_rgb()`](Color#method.to_linear_rgb).
+#[cfg(feature = "alloc")]
+pub fn linear_rgb_of(colors: &[Color]) -> Vec<(f32, f32, f32)> {
+    colors.iter().map(Color::to_linear_rgb).collect()
+}
+
+/// Converts colors to OKLab, the same as [`to_oklab()`](Color#method.to_oklab).
+#[cfg(feature = "alloc")]
+pub fn oklab_of(colors: &[Color]) -> Vec<(f32, f32, f32)> {
+    colors.iter().map(Color::to_oklab).collect()
+}
+
+/// Gets the [`delta_e()`](Color#method.delta_e) between every color and `reference`.
+#[cfg(feature = "alloc")]
+pub fn delta_e_of(colors: &[Color], reference: &Color) -> Vec<f32> {
+    let lab = reference.to_lab();
+    colors
+        .iter()
+        .map(|color| ciede2000(color.to_lab(), lab))
+        .collect()
+}
+
+/// Same as [`delta_e_of()`], but uses multiple threads, which is faster for large buffers.
+/// Requires the `rayon` feature.
+#[cfg(feature = "rayon")]
+pub fn delta_e_of_par(colors: &[Color], reference: &Color) -> Vec<f32> {
+    use rayon::prelude::*;
+    let lab = reference.to_lab();
+    colors
+        .par_iter()
+        .map(|color| ciede2000(color.to_lab(), lab))
+        .collect()
+}
+
+/// Converts HSL values back to colors, the same as [`from_hsl()`](Color#method.from_hsl).
+///
+/// # Panics
+///
+/// Panics when S and L values are < 0.0 or > 1.0.
+#[cfg(feature = "alloc")]
+pub fn from_hsl_slice(values: &[(f32, f32, f32)]) -> Vec<Color> {
+    map_chunks(values, simd::from_hsl, |&(h, s, l)| {
+        Color::from_hsl(h, s, l)
+    })
+}
+
+/// Converts HSV values back to colors, the same as [`from_hsv()`](Color#method.from_hsv).
+///
+/// # Panics
+///
+/// Panics when S and V values are < 0.0 or > 1.0.
+#[cfg(feature = "alloc")]
+pub fn from_hsv_slice(values: &[(f32, f32, f32)]) -> Vec<Color> {
+    map_chunks(values, simd::from_hsv, |&(h, s, v)| {
+        Color::from_hsv(h, s, v)
+    })
+}
+
+/// Increases the lightness (L) of HSL of every color by `amount`, clamped to 1.0, the same as
+/// [`Hsl::lighten()`].
+pub fn lighten_slice(colors: &mut [Color], amount: f32) {
+    map_hsl_in_place(colors, |hsl| hsl.lighten(amount));
+}
+
+/// Decreases the lightness (L) of HSL of every color by `amount`, clamped to 0.0, the same as
+/// [`Hsl::darken()`].
+pub fn darken_slice(colors: &mut [Color], amount: f32) {
+    lighten_slice(colors, -amount);
+}
+
+/// Increases the saturation (S) of HSL of every color by `amount`, clamped to 1.0, the same as
+/// [`Hsl::saturate()`].
+pub fn saturate_slice(colors: &mut [Color], amount: f32) {
+    map_hsl_in_place(colors, |hsl| hsl.saturate(amount));
+}
+
+/// Decreases the saturation (S) of HSL of every color by `amount`, clamped to 0.0, the same as
+/// [`Hsl::desaturate()`].
+pub fn desaturate_slice(colors: &mut [Color], amount: f32) {
+    saturate_slice(colors, -amount);
+}
+
+/// Rotates the hue (H) of HSL of every color by `amount`, the same as
+/// [`rotate_hue()`](Color#method.rotate_hue).
+pub fn rotate_hue_slice(colors: &mut [Color], amount: f32) {
+    map_hsl_in_place(colors, |hsl| hsl.rotate_hue(amount));
+}
+
+/// Maps every color onto a gradient from `dark` to `light` by its luminance, the same as
+/// [`duotone()`](Color#method.duotone).
+pub fn duotone_slice(colors: &mut [Color], dark: &Color, light: &Color) {
+    for color in colors {
+        *color = color.duotone(dark, light);
+    }
+}
+
+/// Maps every color onto a gradient from `dark` through `mid` to `light` by its luminance, the
+/// same as [`tritone()`](Color#method.tritone).
+pub fn tritone_slice(colors: &mut [Color], dark: &Color, mid: &Color, light: &Color) {
+    for color in colors {
+        *color = color.tritone(dark, mid, light);
+    }
+}
+
+/// Inverts every color, the same as [`invert()`](Color#method.invert).
+pub fn invert_slice(colors: &mut [Color]) {
+    for color in colors {
+        *color = color.invert();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch() {
+        let colors = [
+            Color::new(100, 150, 200),
+            constants::OCTARINE,
+            Color::new(1, 2, 3),
+        ];
+        let hsl = hsl_of(&colors);
+        assert_eq!(hsl, colors.map(|color| color.to_hsl()));
+        assert_eq!(from_hsl_slice(&hsl), colors);
+        assert_eq!(from_hsv_slice(&hsv_of(&colors)), colors);
+        assert_eq!(hex_of(&colors), colors.map(|color| color.to_hex()));
+        assert_eq!(linear_rgb_of(&colors)[0], colors[0].to_linear_rgb());
+        assert_eq!(oklab_of(&colors)[1], colors[1].to_oklab());
+        assert!(hsl_of(&[]).is_empty());
+        let reference = colors[0];
+        assert_eq!(
+            delta_e_of(&colors, &reference),
+            colors.map(|color| color.delta_e(&reference))
+        );
+    }
+
+    #[test]
+    fn framebuffer() {
+        let mut buffer = [0; 3];
+        fill(&mut buffer, &constants::OCTARINE, PixelFormat::Argb8888);
+        assert_eq!(buffer, [constants::OCTARINE.to_argb(); 3]);
+        let colors = [constants::primary::RED, constants::primary::BLUE];
+        pack_into(&colors, &mut buffer, PixelFormat::Rgb888);
+        assert_eq!(buffer, [0xFF0000, 0x0000FF, constants::OCTARINE.to_argb()]);
+        assert_eq!(buffer[0], colors[0].to_0rgb());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par() {
+        let colors: Vec<_> = (0..=255).map(|i| Color::new(i, 255 - i, i / 2)).collect();
+        assert_eq!(
+            delta_e_of(&colors, &constants::OCTARINE),
+            delta_e_of_par(&colors, &constants::OCTARINE)
+        );
+    }
+
+    #[test]
+    fn in_place() {
+        let colors = [Color::new(100, 150, 200), constants::OCTARINE];
+        let mut lighter = colors;
+        lighten_slice(&mut lighter, 0.1);
+        assert_eq!(
+            lighter,
+            colors.map(|color| color.hsl().lighten(0.1).to_color())
+        );
+        darken_slice(&mut lighter, 0.1);
+        assert!(lighter.iter().zip(colors).all(|(a, b)| a.approx_eq(&b, 1)));
+        let mut gray = colors;
+        desaturate_slice(&mut gray, 1.0);
+        assert!(gray.iter().all(|color| color.get_hsl_saturation() == 0.0));
+        let mut saturated = colors;
+        saturate_slice(&mut saturated, 0.2);
+        assert_eq!(
+            saturated,
+            colors.map(|color| color.hsl().saturate(0.2).to_color())
+        );
+        let mut rotated = colors;
+        rotate_hue_slice(&mut rotated, 0.5);
+        assert_eq!(rotated, colors.map(|color| color.complementary()));
+        let mut inverted = colors;
+        invert_slice(&mut inverted);
+        invert_slice(&mut inverted);
+        assert_eq!(inverted, colors);
+        let (dark, mid, light) = (
+            constants::primary::BLUE,
+            Color::new(90, 90, 90),
+            constants::OCTARINE,
+        );
+        let mut duotone = colors;
+        duotone_slice(&mut duotone, &dark, &light);
+        assert_eq!(duotone, colors.map(|color| color.duotone(&dark, &light)));
+        let mut tritone = colors;
+        tritone_slice(&mut tritone, &dark, &mid, &light);
+        assert_eq!(
+            tritone,
+            colors.map(|color| color.tritone(&dark, &mid, &light))
+        );
+    }
+}
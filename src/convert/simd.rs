@@ -0,0 +1,503 @@
+//! Conversions of four colors at a time with SSE2 on x86 and NEON on AArch64, falling back to
+//! arrays on other targets. Every operation is done in the same order as the methods of
+//! [`Color`], so the results are identical to converting one color at a time.
+
+use super::*;
+use core::ops::{Add, Div, Mul, Sub};
+use lanes::F32x4;
+
+#[cfg(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse2")
+))]
+// SAFETY: SSE2 is part of the x86_64 baseline and is checked for on x86, so these intrinsics can
+// always be called.
+mod lanes {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    #[derive(Clone, Copy)]
+    pub(super) struct F32x4(pub(super) __m128);
+
+    #[derive(Clone, Copy)]
+    pub(super) struct Mask(__m128);
+
+    impl F32x4 {
+        pub(super) fn new([a, b, c, d]: [f32; 4]) -> Self {
+            Self(unsafe { _mm_set_ps(d, c, b, a) })
+        }
+
+        pub(super) fn splat(value: f32) -> Self {
+            Self(unsafe { _mm_set1_ps(value) })
+        }
+
+        pub(super) fn to_array(self) -> [f32; 4] {
+            let mut array = [0.0; 4];
+            // SAFETY: `array` has room for the four lanes, and `_mm_storeu_ps` doesn't need the
+            // pointer to be aligned.
+            unsafe { _mm_storeu_ps(array.as_mut_ptr(), self.0) };
+            array
+        }
+
+        pub(super) fn min(self, other: Self) -> Self {
+            Self(unsafe { _mm_min_ps(self.0, other.0) })
+        }
+
+        pub(super) fn max(self, other: Self) -> Self {
+            Self(unsafe { _mm_max_ps(self.0, other.0) })
+        }
+
+        /// Rounds towards zero, which is the same as `floor()` for positive values.
+        #[cfg(feature = "alloc")]
+        pub(super) fn trunc(self) -> Self {
+            Self(unsafe { _mm_cvtepi32_ps(_mm_cvttps_epi32(self.0)) })
+        }
+
+        pub(super) fn lt(self, other: Self) -> Mask {
+            Mask(unsafe { _mm_cmplt_ps(self.0, other.0) })
+        }
+
+        pub(super) fn gt(self, other: Self) -> Mask {
+            Mask(unsafe { _mm_cmpgt_ps(self.0, other.0) })
+        }
+
+        pub(super) fn eq(self, other: Self) -> Mask {
+            Mask(unsafe { _mm_cmpeq_ps(self.0, other.0) })
+        }
+    }
+
+    impl Mask {
+        /// Takes the lanes of `a` where the mask is set, and the lanes of `b` everywhere else.
+        pub(super) fn select(self, a: F32x4, b: F32x4) -> F32x4 {
+            F32x4(unsafe { _mm_or_ps(_mm_and_ps(self.0, a.0), _mm_andnot_ps(self.0, b.0)) })
+        }
+    }
+
+    pub(super) fn add(a: F32x4, b: F32x4) -> F32x4 {
+        F32x4(unsafe { _mm_add_ps(a.0, b.0) })
+    }
+
+    pub(super) fn sub(a: F32x4, b: F32x4) -> F32x4 {
+        F32x4(unsafe { _mm_sub_ps(a.0, b.0) })
+    }
+
+    pub(super) fn mul(a: F32x4, b: F32x4) -> F32x4 {
+        F32x4(unsafe { _mm_mul_ps(a.0, b.0) })
+    }
+
+    pub(super) fn div(a: F32x4, b: F32x4) -> F32x4 {
+        F32x4(unsafe { _mm_div_ps(a.0, b.0) })
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+// SAFETY: NEON is checked for, so these intrinsics can always be called.
+mod lanes {
+    use core::arch::aarch64::*;
+
+    #[derive(Clone, Copy)]
+    pub(super) struct F32x4(pub(super) float32x4_t);
+
+    #[derive(Clone, Copy)]
+    pub(super) struct Mask(uint32x4_t);
+
+    impl F32x4 {
+        pub(super) fn new(array: [f32; 4]) -> Self {
+            // SAFETY: `array` holds the four lanes, and `vld1q_f32` doesn't need the pointer to
+            // be aligned.
+            Self(unsafe { vld1q_f32(array.as_ptr()) })
+        }
+
+        pub(super) fn splat(value: f32) -> Self {
+            Self(unsafe { vdupq_n_f32(value) })
+        }
+
+        pub(super) fn to_array(self) -> [f32; 4] {
+            let mut array = [0.0; 4];
+            // SAFETY: `array` has room for the four lanes, and `vst1q_f32` doesn't need the
+            // pointer to be aligned.
+            unsafe { vst1q_f32(array.as_mut_ptr(), self.0) };
+            array
+        }
+
+        pub(super) fn min(self, other: Self) -> Self {
+            Self(unsafe { vminq_f32(self.0, other.0) })
+        }
+
+        pub(super) fn max(self, other: Self) -> Self {
+            Self(unsafe { vmaxq_f32(self.0, other.0) })
+        }
+
+        /// Rounds towards zero, which is the same as `floor()` for positive values.
+        #[cfg(feature = "alloc")]
+        pub(super) fn trunc(self) -> Self {
+            Self(unsafe { vcvtq_f32_s32(vcvtq_s32_f32(self.0)) })
+        }
+
+        pub(super) fn lt(self, other: Self) -> Mask {
+            Mask(unsafe { vcltq_f32(self.0, other.0) })
+        }
+
+        pub(super) fn gt(self, other: Self) -> Mask {
+            Mask(unsafe { vcgtq_f32(self.0, other.0) })
+        }
+
+        pub(super) fn eq(self, other: Self) -> Mask {
+            Mask(unsafe { vceqq_f32(self.0, other.0) })
+        }
+    }
+
+    impl Mask {
+        /// Takes the lanes of `a` where the mask is set, and the lanes of `b` everywhere else.
+        pub(super) fn select(self, a: F32x4, b: F32x4) -> F32x4 {
+            F32x4(unsafe { vbslq_f32(self.0, a.0, b.0) })
+        }
+    }
+
+    pub(super) fn add(a: F32x4, b: F32x4) -> F32x4 {
+        F32x4(unsafe { vaddq_f32(a.0, b.0) })
+    }
+
+    pub(super) fn sub(a: F32x4, b: F32x4) -> F32x4 {
+        F32x4(unsafe { vsubq_f32(a.0, b.0) })
+    }
+
+    pub(super) fn mul(a: F32x4, b: F32x4) -> F32x4 {
+        F32x4(unsafe { vmulq_f32(a.0, b.0) })
+    }
+
+    pub(super) fn div(a: F32x4, b: F32x4) -> F32x4 {
+        F32x4(unsafe { vdivq_f32(a.0, b.0) })
+    }
+}
+
+// The compiler can often still vectorize these loops on other targets.
+#[cfg(not(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+mod lanes {
+    #[derive(Clone, Copy)]
+    pub(super) struct F32x4(pub(super) [f32; 4]);
+
+    #[derive(Clone, Copy)]
+    pub(super) struct Mask([bool; 4]);
+
+    fn zip(a: [f32; 4], b: [f32; 4], f: impl Fn(f32, f32) -> f32) -> F32x4 {
+        F32x4([f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2]), f(a[3], b[3])])
+    }
+
+    fn compare(a: [f32; 4], b: [f32; 4], f: impl Fn(f32, f32) -> bool) -> Mask {
+        Mask([f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2]), f(a[3], b[3])])
+    }
+
+    impl F32x4 {
+        pub(super) fn new(array: [f32; 4]) -> Self {
+            Self(array)
+        }
+
+        pub(super) fn splat(value: f32) -> Self {
+            Self([value; 4])
+        }
+
+        pub(super) fn to_array(self) -> [f32; 4] {
+            self.0
+        }
+
+        pub(super) fn min(self, other: Self) -> Self {
+            zip(self.0, other.0, f32::min)
+        }
+
+        pub(super) fn max(self, other: Self) -> Self {
+            zip(self.0, other.0, f32::max)
+        }
+
+        /// Rounds towards zero, which is the same as `floor()` for positive values.
+        #[cfg(feature = "alloc")]
+        pub(super) fn trunc(self) -> Self {
+            Self(self.0.map(|v| v as i32 as f32))
+        }
+
+        pub(super) fn lt(self, other: Self) -> Mask {
+            compare(self.0, other.0, |a, b| a < b)
+        }
+
+        pub(super) fn gt(self, other: Self) -> Mask {
+            compare(self.0, other.0, |a, b| a > b)
+        }
+
+        pub(super) fn eq(self, other: Self) -> Mask {
+            compare(self.0, other.0, |a, b| a == b)
+        }
+    }
+
+    impl Mask {
+        /// Takes the lanes of `a` where the mask is set, and the lanes of `b` everywhere else.
+        pub(super) fn select(self, a: F32x4, b: F32x4) -> F32x4 {
+            let mut lanes = b.0;
+            for (i, lane) in lanes.iter_mut().enumerate() {
+                if self.0[i] {
+                    *lane = a.0[i];
+                }
+            }
+            F32x4(lanes)
+        }
+    }
+
+    pub(super) fn add(a: F32x4, b: F32x4) -> F32x4 {
+        zip(a.0, b.0, |a, b| a + b)
+    }
+
+    pub(super) fn sub(a: F32x4, b: F32x4) -> F32x4 {
+        zip(a.0, b.0, |a, b| a - b)
+    }
+
+    pub(super) fn mul(a: F32x4, b: F32x4) -> F32x4 {
+        zip(a.0, b.0, |a, b| a * b)
+    }
+
+    pub(super) fn div(a: F32x4, b: F32x4) -> F32x4 {
+        zip(a.0, b.0, |a, b| a / b)
+    }
+}
+
+macro_rules! impl_op {
+    ($op:ident, $method:ident) => {
+        impl $op for F32x4 {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, other: Self) -> Self {
+                lanes::$method(self, other)
+            }
+        }
+
+        impl $op<f32> for F32x4 {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, other: f32) -> Self {
+                lanes::$method(self, Self::splat(other))
+            }
+        }
+
+        impl $op<F32x4> for f32 {
+            type Output = F32x4;
+
+            #[inline]
+            fn $method(self, other: F32x4) -> F32x4 {
+                lanes::$method(F32x4::splat(self), other)
+            }
+        }
+    };
+}
+
+impl_op!(Add, add);
+impl_op!(Sub, sub);
+impl_op!(Mul, mul);
+impl_op!(Div, div);
+
+fn channels(colors: &[Color; 4]) -> (F32x4, F32x4, F32x4) {
+    (
+        F32x4::new(colors.map(|color| color.0 as f32)) / 255.0,
+        F32x4::new(colors.map(|color| color.1 as f32)) / 255.0,
+        F32x4::new(colors.map(|color| color.2 as f32)) / 255.0,
+    )
+}
+
+fn unzip(values: &[(f32, f32, f32); 4]) -> (F32x4, F32x4, F32x4) {
+    (
+        F32x4::new(values.map(|v| v.0)),
+        F32x4::new(values.map(|v| v.1)),
+        F32x4::new(values.map(|v| v.2)),
+    )
+}
+
+fn zip(a: F32x4, b: F32x4, c: F32x4) -> [(f32, f32, f32); 4] {
+    let (a, b, c) = (a.to_array(), b.to_array(), c.to_array());
+    [0, 1, 2, 3].map(|i| (a[i], b[i], c[i]))
+}
+
+fn to_colors(r: F32x4, g: F32x4, b: F32x4) -> [Color; 4] {
+    zip(r, g, b).map(|(r, g, b)| Color::from_rgb_float(r, g, b))
+}
+
+/// Whether every value is valid and has a hue between 0 and 1, which is all that the vectorized
+/// conversions back to RGB handle.
+fn in_range(values: &[(f32, f32, f32); 4]) -> bool {
+    values.iter().all(|&(h, s, v)| {
+        (0.0..=1.0).contains(&h) && (0.0..=1.0).contains(&s) && (0.0..=1.0).contains(&v)
+    })
+}
+
+/// The hue shared by [`to_hsl()`] and [`to_hsv()`].
+fn hue(r: F32x4, g: F32x4, b: F32x4, v_max: F32x4, diff: F32x4) -> F32x4 {
+    let delta = |c: F32x4| (((v_max - c) / 6.0) + (diff / 2.0)) / diff;
+    let (dr, dg, db) = (delta(r), delta(g), delta(b));
+    let h = r.eq(v_max).select(
+        db - dg,
+        g.eq(v_max)
+            .select((1.0 / 3.0) + dr - db, (2.0 / 3.0) + dg - dr),
+    );
+    let h = h.lt(F32x4::splat(0.0)).select(h + 1.0, h);
+    h.gt(F32x4::splat(1.0)).select(h - 1.0, h)
+}
+
+/// Converts four colors to HSL, the same as [`to_hsl()`](Color#method.to_hsl).
+pub(super) fn to_hsl(colors: &[Color; 4]) -> [(f32, f32, f32); 4] {
+    let (r, g, b) = channels(colors);
+    let v_min = r.min(g).min(b);
+    let v_max = r.max(g).max(b);
+    let diff = v_max - v_min;
+    let v_sum = v_min + v_max;
+    let l = v_sum / 2.0;
+    let s = l
+        .lt(F32x4::splat(0.5))
+        .select(diff / v_sum, diff / (2.0 - v_sum))
+        .min(F32x4::splat(1.0));
+    let h = hue(r, g, b, v_max, diff);
+    let gray = diff.lt(F32x4::splat(f32::EPSILON));
+    let zero = F32x4::splat(0.0);
+    zip(gray.select(zero, h), gray.select(zero, s), l)
+}
+
+/// Converts four colors to HSV, the same as [`to_hsv()`](Color#method.to_hsv).
+#[cfg(feature = "alloc")]
+pub(super) fn to_hsv(colors: &[Color; 4]) -> [(f32, f32, f32); 4] {
+    let (r, g, b) = channels(colors);
+    let v_min = r.min(g).min(b);
+    let v_max = r.max(g).max(b);
+    let diff = v_max - v_min;
+    let s = diff / v_max;
+    let h = hue(r, g, b, v_max, diff);
+    let gray = diff.lt(F32x4::splat(f32::EPSILON));
+    let zero = F32x4::splat(0.0);
+    zip(gray.select(zero, h), gray.select(zero, s), v_max)
+}
+
+fn hue_to_rgb(v1: F32x4, v2: F32x4, v_h: F32x4) -> F32x4 {
+    // The hues are between 0 and 1, so they are at most one rotation away.
+    let v_h = v_h.lt(F32x4::splat(0.0)).select(v_h + 1.0, v_h);
+    let v_h = v_h.gt(F32x4::splat(1.0)).select(v_h - 1.0, v_h);
+    (6.0 * v_h).lt(F32x4::splat(1.0)).select(
+        v1 + (v2 - v1) * 6.0 * v_h,
+        (2.0 * v_h).lt(F32x4::splat(1.0)).select(
+            v2,
+            (3.0 * v_h)
+                .lt(F32x4::splat(2.0))
+                .select(v1 + (v2 - v1) * ((2.0 / 3.0) - v_h) * 6.0, v1),
+        ),
+    )
+}
+
+/// Converts four HSL values to colors, the same as [`from_hsl()`](Color#method.from_hsl).
+///
+/// # Panics
+///
+/// Panics when S and L values are < 0.0 or > 1.0.
+pub(super) fn from_hsl(values: &[(f32, f32, f32); 4]) -> [Color; 4] {
+    if !in_range(values) {
+        return values.map(|(h, s, l)| Color::from_hsl(h, s, l));
+    }
+    let (h, s, l) = unzip(values);
+    let v2 = l
+        .lt(F32x4::splat(0.5))
+        .select(l * (1.0 + s), (l + s) - (s * l));
+    let v1 = 2.0 * l - v2;
+    let gray = s.eq(F32x4::splat(0.0));
+    to_colors(
+        gray.select(l, hue_to_rgb(v1, v2, h + (1.0 / 3.0))),
+        gray.select(l, hue_to_rgb(v1, v2, h)),
+        gray.select(l, hue_to_rgb(v1, v2, h - (1.0 / 3.0))),
+    )
+}
+
+/// Converts four HSV values to colors, the same as [`from_hsv()`](Color#method.from_hsv).
+///
+/// # Panics
+///
+/// Panics when S and V values are < 0.0 or > 1.0.
+#[cfg(feature = "alloc")]
+pub(super) fn from_hsv(values: &[(f32, f32, f32); 4]) -> [Color; 4] {
+    if !in_range(values) {
+        return values.map(|(h, s, v)| Color::from_hsv(h, s, v));
+    }
+    let (h, s, v) = unzip(values);
+    let v_h = h * 6.0;
+    let v_h = v_h.eq(F32x4::splat(6.0)).select(F32x4::splat(0.0), v_h);
+    let v_i = v_h.trunc();
+    let v1 = v * (1.0 - s);
+    let v2 = v * (1.0 - s * (v_h - v_i));
+    let v3 = v * (1.0 - s * (1.0 - (v_h - v_i)));
+    // Picks one of the six sectors of the hue for every lane.
+    let sector = |values: [F32x4; 6]| {
+        (0..5).rev().fold(values[5], |rest, i| {
+            v_i.eq(F32x4::splat(i as f32)).select(values[i], rest)
+        })
+    };
+    let gray = s.eq(F32x4::splat(0.0));
+    to_colors(
+        gray.select(v, sector([v, v2, v1, v1, v3, v])),
+        gray.select(v, sector([v3, v, v, v2, v1, v1])),
+        gray.select(v, sector([v1, v1, v3, v, v, v2])),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_as_scalar() {
+        let values = (0..=255).step_by(15);
+        let colors: Vec<_> = values
+            .clone()
+            .flat_map(|r| {
+                let values = values.clone();
+                values
+                    .clone()
+                    .flat_map(move |g| values.clone().map(move |b| Color::new(r, g, b)))
+            })
+            .collect();
+        for chunk in colors.chunks_exact(4) {
+            let chunk = chunk.try_into().unwrap();
+            let hsl = to_hsl(chunk);
+            let hsv = to_hsv(chunk);
+            assert_eq!(hsl, chunk.map(|color| color.to_hsl()));
+            assert_eq!(hsv, chunk.map(|color| color.to_hsv()));
+            assert_eq!(from_hsl(&hsl), *chunk);
+            assert_eq!(from_hsv(&hsv), *chunk);
+            let rotated = hsl.map(|(h, s, l)| ((h + 0.37) % 1.0, s, l));
+            assert_eq!(
+                from_hsl(&rotated),
+                rotated.map(|(h, s, l)| Color::from_hsl(h, s, l))
+            );
+            let rotated = hsv.map(|(h, s, v)| ((h + 0.37) % 1.0, s, v));
+            assert_eq!(
+                from_hsv(&rotated),
+                rotated.map(|(h, s, v)| Color::from_hsv(h, s, v))
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range_hues() {
+        let values = [
+            (1.5, 0.5, 0.5),
+            (-0.25, 1.0, 0.5),
+            (0.0, 0.0, 1.0),
+            (0.5, 1.0, 0.0),
+        ];
+        assert_eq!(
+            from_hsl(&values),
+            values.map(|(h, s, l)| Color::from_hsl(h, s, l))
+        );
+        assert_eq!(
+            from_hsv(&values),
+            values.map(|(h, s, v)| Color::from_hsv(h, s, v))
+        );
+    }
+}
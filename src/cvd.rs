@@ -0,0 +1,243 @@
+use super::*;
+
+/// The types of color vision deficiency (color blindness), with the cones which are missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CvdType {
+    /// Missing red (L) cones, so reds look darker and are confused with greens.
+    Protanopia,
+    /// Missing green (M) cones, the most common type. Reds and greens are confused.
+    Deuteranopia,
+    /// Missing blue (S) cones, so blues are confused with greens and yellows with violets.
+    Tritanopia,
+}
+
+impl CvdType {
+    /// The simulation matrices for linear RGB from Machado, Oliveira, and Fernandes (2009), at
+    /// full severity.
+    fn simulation(self) -> Matrix {
+        match self {
+            Self::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            Self::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            Self::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+
+    /// Shifts the information which cannot be seen into the channels which can, from Fidaner,
+    /// Lin, and Ozguven (2005).
+    fn correction(self) -> Matrix {
+        match self {
+            Self::Protanopia | Self::Deuteranopia => {
+                [[0.0, 0.0, 0.0], [0.7, 1.0, 0.0], [0.7, 0.0, 1.0]]
+            }
+            Self::Tritanopia => [[1.0, 0.0, 0.7], [0.0, 1.0, 0.7], [0.0, 0.0, 0.0]],
+        }
+    }
+
+    /// Daltonizes linear RGB values without clamping them.
+    fn daltonize(self, rgb: (f32, f32, f32), strength: f32) -> (f32, f32, f32) {
+        let simulated = transform(&self.simulation(), rgb);
+        let error = (
+            rgb.0 - simulated.0,
+            rgb.1 - simulated.1,
+            rgb.2 - simulated.2,
+        );
+        let shift = transform(&self.correction(), error);
+        (
+            rgb.0 + shift.0 * strength,
+            rgb.1 + shift.1 * strength,
+            rgb.2 + shift.2 * strength,
+        )
+    }
+}
+
+fn test_strength(strength: f32) {
+    if !(0.0..=1.0).contains(&strength) {
+        panic!("Unsupported daltonization strength: {strength}");
+    }
+}
+
+impl Color {
+    /// Simulates how the color looks to someone with the color vision deficiency `cvd`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, CvdType};
+    ///
+    /// let red = Color::new(255, 0, 0).simulate_cvd(CvdType::Deuteranopia);
+    /// let green = Color::new(0, 128, 0).simulate_cvd(CvdType::Deuteranopia);
+    ///
+    /// assert!(red.delta_e(&green) < Color::new(255, 0, 0).delta_e(&Color::new(0, 128, 0)));
+    /// ```
+    pub fn simulate_cvd(&self, cvd: CvdType) -> Self {
+        let (r, g, b) = transform(&cvd.simulation(), self.to_linear_rgb());
+        Self::from_linear_rgb(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0))
+    }
+
+    /// Shifts the color so it is easier to tell apart from other colors for someone with the
+    /// color vision deficiency `cvd`, by moving the differences they cannot see into the channels
+    /// they can. `strength` goes from 0.0 (unchanged) to 1.0 (fully corrected).
+    ///
+    /// # Panics
+    ///
+    /// Panics when `strength` is < 0.0 or > 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, CvdType};
+    ///
+    /// let (red, green) = (Color::new(200, 60, 60), Color::new(60, 160, 60));
+    /// let seen = |color: Color| color.simulate_cvd(CvdType::Deuteranopia);
+    /// let before = seen(red).delta_e(&seen(green));
+    /// let after = seen(red.daltonize(CvdType::Deuteranopia, 1.0))
+    ///     .delta_e(&seen(green.daltonize(CvdType::Deuteranopia, 1.0)));
+    ///
+    /// assert!(after > before);
+    /// assert_eq!(red.daltonize(CvdType::Deuteranopia, 0.0), red);
+    /// ```
+    pub fn daltonize(&self, cvd: CvdType, strength: f32) -> Self {
+        test_strength(strength);
+        let (r, g, b) = cvd.daltonize(self.to_linear_rgb(), strength);
+        Self::from_linear_rgb(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Palette {
+    /// Daltonizes every color in the palette, the same as
+    /// [`daltonize()`](Color#method.daltonize), while keeping the relationships between them.
+    /// Instead of clamping each color on its own, which would flatten the differences between
+    /// colors that end up outside of the RGB gamut, all colors are scaled into the gamut
+    /// together. Names are kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `strength` is < 0.0 or > 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, CvdType, Palette};
+    ///
+    /// let mut palette = Palette::from(vec![Color::new(200, 60, 60), Color::new(60, 160, 60)]);
+    /// palette.daltonize(CvdType::Protanopia, 1.0);
+    ///
+    /// assert_eq!(palette.len(), 2);
+    /// assert_ne!(palette[0], Color::new(200, 60, 60));
+    /// ```
+    pub fn daltonize(&mut self, cvd: CvdType, strength: f32) {
+        test_strength(strength);
+        let corrected: Vec<_> = self
+            .iter()
+            .map(|color| cvd.daltonize(color.to_linear_rgb(), strength))
+            .collect();
+        let (min, max) = corrected
+            .iter()
+            .flat_map(|&(r, g, b)| [r, g, b])
+            .fold((0.0f32, 1.0f32), |(min, max), v| (min.min(v), max.max(v)));
+        let scale = |v: f32| ((v - min) / (max - min)).clamp(0.0, 1.0);
+        for (color, (r, g, b)) in self.iter_mut().zip(corrected) {
+            *color = Color::from_linear_rgb(scale(r), scale(g), scale(b));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TYPES: [CvdType; 3] = [
+        CvdType::Protanopia,
+        CvdType::Deuteranopia,
+        CvdType::Tritanopia,
+    ];
+
+    #[test]
+    fn simulate_cvd() {
+        for cvd in TYPES {
+            assert_eq!(
+                constants::primary::WHITE.simulate_cvd(cvd),
+                constants::primary::WHITE
+            );
+            assert_eq!(
+                constants::primary::BLACK.simulate_cvd(cvd),
+                constants::primary::BLACK
+            );
+            let gray = Color::new(128, 128, 128);
+            assert!(gray.simulate_cvd(cvd).approx_eq(&gray, 1));
+        }
+        let red = constants::primary::RED.simulate_cvd(CvdType::Protanopia);
+        assert!(red.to_lab().0 < constants::primary::RED.to_lab().0);
+    }
+
+    #[test]
+    fn daltonize() {
+        let pairs = [
+            (
+                CvdType::Protanopia,
+                Color::new(200, 60, 60),
+                Color::new(60, 160, 60),
+            ),
+            (
+                CvdType::Deuteranopia,
+                Color::new(200, 60, 60),
+                Color::new(60, 160, 60),
+            ),
+            (
+                CvdType::Tritanopia,
+                Color::new(60, 60, 200),
+                Color::new(60, 160, 60),
+            ),
+        ];
+        for (cvd, a, b) in pairs {
+            let seen = |color: Color| color.simulate_cvd(cvd);
+            let before = seen(a).delta_e(&seen(b));
+            let after = seen(a.daltonize(cvd, 1.0)).delta_e(&seen(b.daltonize(cvd, 1.0)));
+            assert!(after > before, "{cvd:?}: {after} <= {before}");
+            assert_eq!(a.daltonize(cvd, 0.0), a);
+            let gray = Color::new(100, 100, 100);
+            assert!(gray.daltonize(cvd, 1.0).approx_eq(&gray, 1));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported daltonization strength: 1.5")]
+    fn daltonize_strength() {
+        constants::primary::RED.daltonize(CvdType::Protanopia, 1.5);
+    }
+
+    #[test]
+    fn daltonize_palette() {
+        let colors = vec![
+            Color::new(100, 150, 200),
+            Color::new(120, 130, 140),
+            Color::new(90, 90, 90),
+        ];
+        let mut palette = Palette::from(colors.clone());
+        palette.push_named("accent", Color::new(180, 80, 80));
+        palette.daltonize(CvdType::Deuteranopia, 0.5);
+        assert_eq!(palette.get_name(3), Some("accent"));
+        for (color, other) in palette.iter().zip(&colors) {
+            assert_eq!(*color, other.daltonize(CvdType::Deuteranopia, 0.5));
+        }
+        let mut saturated = Palette::from(vec![constants::primary::RED, Color::new(240, 0, 0)]);
+        saturated.daltonize(CvdType::Deuteranopia, 1.0);
+        assert_ne!(saturated[0], saturated[1]);
+        let mut unchanged = Palette::from(colors.clone());
+        unchanged.daltonize(CvdType::Tritanopia, 0.0);
+        assert_eq!(unchanged.into_colors(), colors);
+    }
+}
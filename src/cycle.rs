@@ -0,0 +1,151 @@
+use super::*;
+
+/// An infinite iterator which rotates the hue (H) of HSV of a color by the same amount every
+/// step, while keeping its saturation and value, such as for breathing LEDs and loading
+/// indicators. This is created with [`cycle_hue()`](Color#method.cycle_hue).
+#[derive(Debug, Clone, PartialEq)]
+pub struct HueCycle {
+    hsv: (f32, f32, f32),
+    period_steps: usize,
+    step: usize,
+    ping_pong: bool,
+}
+
+impl HueCycle {
+    /// Sets whether the hue goes back the way it came after each full turn, instead of carrying
+    /// on around the color wheel. With ping-pong, the cycle repeats every `2 * period_steps`
+    /// steps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants;
+    ///
+    /// let colors: Vec<_> = constants::primary::RED
+    ///     .cycle_hue(4)
+    ///     .with_ping_pong(true)
+    ///     .map(|color| color.get_hsv_hue_degrees().round())
+    ///     .take(9)
+    ///     .collect();
+    ///
+    /// assert_eq!(colors, [0.0, 90.0, 180.0, 270.0, 0.0, 270.0, 180.0, 90.0, 0.0]);
+    /// ```
+    pub fn with_ping_pong(mut self, ping_pong: bool) -> Self {
+        self.ping_pong = ping_pong;
+        self
+    }
+
+    fn cycle_len(&self) -> usize {
+        if self.ping_pong {
+            self.period_steps.saturating_mul(2)
+        } else {
+            self.period_steps
+        }
+    }
+}
+
+impl Iterator for HueCycle {
+    type Item = Color;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let phase = if self.ping_pong && self.step > self.period_steps {
+            self.cycle_len() - self.step
+        } else {
+            self.step
+        };
+        let (h, s, v) = self.hsv;
+        let hue = (h + phase as f32 / self.period_steps as f32).rem_euclid(1.0);
+        self.step = (self.step + 1) % self.cycle_len();
+        Some(Color::from_hsv(hue, s, v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl core::iter::FusedIterator for HueCycle {}
+
+impl Color {
+    /// Gets an infinite iterator which starts at this color and rotates its hue (H) of HSV by
+    /// `1 / period_steps` of a full turn every step, keeping its saturation and value. The
+    /// colors are computed from the starting color every step, so they don't drift over time.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `period_steps` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants;
+    ///
+    /// let mut colors = constants::primary::RED.cycle_hue(3);
+    ///
+    /// assert_eq!(colors.next(), Some(constants::primary::RED));
+    /// assert_eq!(colors.next(), Some(constants::primary::GREEN));
+    /// assert_eq!(colors.next(), Some(constants::primary::BLUE));
+    /// assert_eq!(colors.next(), Some(constants::primary::RED));
+    /// ```
+    pub fn cycle_hue(&self, period_steps: usize) -> HueCycle {
+        if period_steps == 0 {
+            panic!("Unsupported period steps: 0");
+        }
+        HueCycle {
+            hsv: self.to_hsv(),
+            period_steps,
+            step: 0,
+            ping_pong: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycle_hue() {
+        let color = Color::new(200, 120, 60);
+        let (_, s, v) = color.to_hsv();
+        let mut cycle = color.cycle_hue(360);
+        let first = cycle.clone().take(360).collect::<Vec<_>>();
+        for (i, next) in first.iter().enumerate() {
+            let (h, next_s, next_v) = next.to_hsv();
+            assert!((next_s - s).abs() < 0.01 && (next_v - v).abs() < 0.01);
+            let expected = (color.to_hsv().0 + i as f32 / 360.0).rem_euclid(1.0);
+            let distance = (h - expected).abs();
+            assert!(distance.min(1.0 - distance) < 0.01, "{i}");
+        }
+        assert_eq!(cycle.nth(360), Some(color));
+        assert_eq!(cycle.size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn ping_pong() {
+        let cycle = constants::primary::RED.cycle_hue(3).with_ping_pong(true);
+        assert_eq!(
+            cycle.take(7).collect::<Vec<_>>(),
+            [
+                constants::primary::RED,
+                constants::primary::GREEN,
+                constants::primary::BLUE,
+                constants::primary::RED,
+                constants::primary::BLUE,
+                constants::primary::GREEN,
+                constants::primary::RED,
+            ]
+        );
+        let single = constants::primary::BLUE.cycle_hue(1).with_ping_pong(true);
+        assert!(single
+            .take(4)
+            .all(|color| color == constants::primary::BLUE));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported period steps")]
+    fn zero_period() {
+        constants::primary::RED.cycle_hue(0);
+    }
+}
@@ -0,0 +1,147 @@
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+use core::fmt::Debug;
+
+/// An easing function which is applied to the interpolation parameter of a
+/// [`Gradient`](crate::Gradient) or a [`ColorRange`](crate::ColorRange), which changes how quickly
+/// the colors transition. The named easings match the timing functions of CSS.
+///
+/// # Example
+///
+/// ```
+/// use octarine::Easing;
+///
+/// assert_eq!(Easing::Linear.apply(0.25), 0.25);
+/// assert!(Easing::EaseIn.apply(0.25) < 0.25);
+/// assert!(Easing::EaseOut.apply(0.25) > 0.25);
+///
+/// let quadratic = Easing::custom(|t| t * t);
+/// assert_eq!(quadratic.apply(0.5), 0.25);
+/// ```
+#[derive(Clone, Default)]
+pub enum Easing {
+    /// Transitions at a constant rate.
+    #[default]
+    Linear,
+    /// Starts slowly and speeds up, the same as `cubic-bezier(0.42, 0, 1, 1)`.
+    EaseIn,
+    /// Starts quickly and slows down, the same as `cubic-bezier(0, 0, 0.58, 1)`.
+    EaseOut,
+    /// Starts and ends slowly, the same as `cubic-bezier(0.42, 0, 0.58, 1)`.
+    EaseInOut,
+    /// A cubic Bézier curve from (0, 0) to (1, 1) with the control points (x1, y1) and (x2, y2).
+    /// The x coordinates are clamped between 0.0 and 1.0 so that the curve is a function of `t`.
+    CubicBezier(f32, f32, f32, f32),
+    /// A custom easing function. Use [`Easing::custom()`] to create this.
+    #[cfg(feature = "alloc")]
+    Custom(Arc<dyn Fn(f32) -> f32 + Send + Sync>),
+}
+
+impl Easing {
+    /// Creates an easing from a function that maps `t` between 0.0 and 1.0 to an eased value.
+    #[cfg(feature = "alloc")]
+    pub fn custom<F>(f: F) -> Self
+    where
+        F: Fn(f32) -> f32 + Send + Sync + 'static,
+    {
+        Self::Custom(Arc::new(f))
+    }
+
+    /// Applies the easing to `t`, which is clamped between 0.0 and 1.0.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, t),
+            Self::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, t),
+            Self::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, t),
+            Self::CubicBezier(x1, y1, x2, y2) => {
+                cubic_bezier(x1.clamp(0.0, 1.0), *y1, x2.clamp(0.0, 1.0), *y2, t)
+            }
+            #[cfg(feature = "alloc")]
+            Self::Custom(f) => f(t),
+        }
+    }
+}
+
+impl Debug for Easing {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Linear => write!(f, "Linear"),
+            Self::EaseIn => write!(f, "EaseIn"),
+            Self::EaseOut => write!(f, "EaseOut"),
+            Self::EaseInOut => write!(f, "EaseInOut"),
+            Self::CubicBezier(x1, y1, x2, y2) => f
+                .debug_tuple("CubicBezier")
+                .field(x1)
+                .field(y1)
+                .field(x2)
+                .field(y2)
+                .finish(),
+            #[cfg(feature = "alloc")]
+            Self::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+impl PartialEq for Easing {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::CubicBezier(a0, a1, a2, a3), Self::CubicBezier(b0, b1, b2, b3)) => {
+                (a0, a1, a2, a3) == (b0, b1, b2, b3)
+            }
+            #[cfg(feature = "alloc")]
+            (Self::Custom(a), Self::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => core::mem::discriminant(self) == core::mem::discriminant(other),
+        }
+    }
+}
+
+/// Evaluates one coordinate of a cubic Bézier curve from 0.0 to 1.0 at `s`.
+fn bezier(p1: f32, p2: f32, s: f32) -> f32 {
+    let inverse = 1.0 - s;
+    3.0 * inverse * inverse * s * p1 + 3.0 * inverse * s * s * p2 + s * s * s
+}
+
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, t: f32) -> f32 {
+    // Solve for the curve parameter `s` where x(s) = t, which is monotonic as the x coordinates
+    // are between 0.0 and 1.0, then evaluate y(s).
+    let mut low = 0.0;
+    let mut high = 1.0;
+    let mut s = t;
+    for _ in 0..32 {
+        let x = bezier(x1, x2, s);
+        if (x - t).abs() < 1e-6 {
+            break;
+        }
+        if x < t {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.0;
+    }
+    bezier(y1, y2, s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply() {
+        for easing in [Easing::EaseIn, Easing::EaseOut, Easing::EaseInOut] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-5);
+        }
+        assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < 1e-5);
+        let linear = Easing::CubicBezier(0.25, 0.25, 0.75, 0.75);
+        assert!((linear.apply(0.3) - 0.3).abs() < 1e-5);
+        assert_eq!(Easing::Linear.apply(2.0), 1.0);
+        let custom = Easing::custom(|t| 1.0 - t);
+        assert_eq!(custom.apply(0.25), 0.75);
+        assert_eq!(custom, custom.clone());
+        assert_ne!(custom, Easing::custom(|t| 1.0 - t));
+        assert_ne!(Easing::EaseIn, Easing::EaseOut);
+    }
+}
@@ -0,0 +1,294 @@
+use super::*;
+
+/// The smallest difference in OKLab which is noticeable, as used by CSS Color 4.
+const JND: f32 = 0.02;
+
+/// An RGB color space, for checking whether a color can be shown by a display. All of these
+/// use the D65 white point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RgbSpace {
+    /// sRGB, the color space of [`Color`] and most displays.
+    Srgb,
+    /// Display P3, the wide gamut of recent phones and laptops.
+    DisplayP3,
+    /// Adobe RGB (1998), which has more greens than sRGB and is used for photography.
+    AdobeRgb,
+    /// Rec. 2020, the very wide gamut of HDR video.
+    Rec2020,
+}
+
+impl RgbSpace {
+    /// Gets the matrix from CIE XYZ to linear RGB in this color space.
+    fn xyz_to_rgb(self) -> Matrix {
+        match self {
+            Self::Srgb => [
+                [3.240_454_2, -1.537_138_5, -0.498_531_4],
+                [-0.969_266, 1.876_010_8, 0.041_556],
+                [0.055_643_4, -0.204_025_9, 1.057_225_2],
+            ],
+            Self::DisplayP3 => [
+                [2.493_497, -0.931_383_6, -0.402_710_8],
+                [-0.829_489, 1.762_664_1, 0.023_624_7],
+                [0.035_845_8, -0.076_172_4, 0.956_884_5],
+            ],
+            Self::AdobeRgb => [
+                [2.041_369, -0.564_946_4, -0.344_694_4],
+                [-0.969_266, 1.876_010_8, 0.041_556],
+                [0.013_447_4, -0.118_389_7, 1.015_409_6],
+            ],
+            Self::Rec2020 => [
+                [1.716_651_2, -0.355_670_8, -0.253_366_3],
+                [-0.666_684_4, 1.616_481_2, 0.015_768_5],
+                [0.017_639_9, -0.042_770_6, 0.942_103_1],
+            ],
+        }
+    }
+
+    /// Checks whether linear sRGB values are within this color space.
+    fn contains(self, (r, g, b): (f32, f32, f32)) -> bool {
+        let rgb = match self {
+            Self::Srgb => (r, g, b),
+            _ => transform(&self.xyz_to_rgb(), linear_rgb_to_xyz(r, g, b)),
+        };
+        [rgb.0, rgb.1, rgb.2]
+            .iter()
+            .all(|v| (-0.0001..=1.0001).contains(v))
+    }
+}
+
+/// How colors which fall outside of the sRGB gamut are brought into it by
+/// [`Oklch::gamut_map()`], [`Lab::gamut_map()`], and [`ColorF::gamut_map()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GamutMapping {
+    /// Reduces the chroma in OKLCH until the color is within the gamut, which keeps the lightness
+    /// and hue, as specified by CSS Color 4.
+    #[default]
+    ChromaReduction,
+    /// Clamps every channel, which is fast but can shift the hue and lightness.
+    Clip,
+}
+
+/// Maps OKLab values into the sRGB gamut, and returns them as linear sRGB.
+fn map_oklab((l, a, b): (f32, f32, f32), method: GamutMapping) -> (f32, f32, f32) {
+    let clip =
+        |(r, g, b): (f32, f32, f32)| (r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0));
+    let origin = oklab_to_linear_rgb(l, a, b);
+    if method == GamutMapping::Clip || RgbSpace::Srgb.contains(origin) {
+        return clip(origin);
+    }
+    if l >= 1.0 {
+        return (1.0, 1.0, 1.0);
+    }
+    if l <= 0.0 {
+        return (0.0, 0.0, 0.0);
+    }
+    // Finds the largest chroma whose clipped color is within a JND of it, so that clipping only
+    // removes differences which can't be seen.
+    let distance = |(l2, a2, b2): (f32, f32, f32), (r, g, b): (f32, f32, f32)| {
+        let (l1, a1, b1) = linear_rgb_to_oklab(r, g, b);
+        ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+    };
+    let chroma = a.hypot(b);
+    let at = |c: f32| (l, a * c / chroma, b * c / chroma);
+    let mut clipped = clip(origin);
+    if distance((l, a, b), clipped) < JND {
+        return clipped;
+    }
+    let (mut min, mut max) = (0.0, chroma);
+    let mut min_in_gamut = true;
+    while max - min > 0.0001 {
+        let c = (min + max) / 2.0;
+        let current = at(c);
+        let rgb = oklab_to_linear_rgb(current.0, current.1, current.2);
+        if min_in_gamut && RgbSpace::Srgb.contains(rgb) {
+            min = c;
+            continue;
+        }
+        clipped = clip(rgb);
+        let e = distance(current, clipped);
+        if e < JND {
+            if JND - e < 0.0001 {
+                break;
+            }
+            min_in_gamut = false;
+            min = c;
+        } else {
+            max = c;
+        }
+    }
+    clipped
+}
+
+impl ColorF {
+    /// Checks whether the color can be shown in the color space `space`. Channels outside of
+    /// 0.0 - 1.0 are treated as extended sRGB, so wide gamut colors can be outside of sRGB but
+    /// still within [`RgbSpace::DisplayP3`] or [`RgbSpace::Rec2020`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{ColorF, RgbSpace};
+    ///
+    /// let red = ColorF::new(1.05, -0.15, -0.1);
+    ///
+    /// assert!(!red.in_gamut(RgbSpace::Srgb));
+    /// assert!(red.in_gamut(RgbSpace::DisplayP3));
+    /// assert!(ColorF::new(0.5, 0.5, 0.5).in_gamut(RgbSpace::Srgb));
+    /// ```
+    pub fn in_gamut(&self, space: RgbSpace) -> bool {
+        let linear = self.srgb_to_linear();
+        space.contains((linear.r, linear.g, linear.b))
+    }
+
+    /// Brings the color into the sRGB gamut using `method`, so that all channels are between
+    /// 0.0 and 1.0. Colors which are already in the gamut are not changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{ColorF, GamutMapping, RgbSpace};
+    ///
+    /// let red = ColorF::new(1.05, -0.15, -0.1).gamut_map(GamutMapping::ChromaReduction);
+    ///
+    /// assert!(red.in_gamut(RgbSpace::Srgb));
+    /// assert!(red.r > red.g && red.r > red.b);
+    /// ```
+    pub fn gamut_map(&self, method: GamutMapping) -> Self {
+        if self.in_gamut(RgbSpace::Srgb) {
+            return *self;
+        }
+        let linear = self.srgb_to_linear();
+        let (r, g, b) = map_oklab(linear_rgb_to_oklab(linear.r, linear.g, linear.b), method);
+        Self::new(r, g, b).linear_to_srgb()
+    }
+}
+
+impl Oklch {
+    /// Checks whether the color can be shown in the color space `space`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Oklch, RgbSpace};
+    ///
+    /// let vivid = Oklch::new(0.7, 0.3, 0.4);
+    ///
+    /// assert!(!vivid.in_gamut(RgbSpace::Srgb));
+    /// assert!(Oklch::new(0.7, 0.05, 0.4).in_gamut(RgbSpace::Srgb));
+    /// ```
+    pub fn in_gamut(&self, space: RgbSpace) -> bool {
+        let (a, b) = oklch_to_oklab_ab(self.c, self.h);
+        space.contains(oklab_to_linear_rgb(self.l, a, b))
+    }
+
+    /// Converts to a [`Color`], bringing colors which fall outside of the RGB gamut into it with
+    /// `method`. Unlike [`to_color()`](Oklch::to_color), [`GamutMapping::ChromaReduction`] keeps
+    /// the hue and lightness.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{GamutMapping, Oklch};
+    ///
+    /// let vivid = Oklch::new(0.7, 0.3, 0.4);
+    /// let mapped = vivid.gamut_map(GamutMapping::ChromaReduction).oklch();
+    ///
+    /// assert!((mapped.h - vivid.h).abs() < 0.01);
+    /// assert!((mapped.l - vivid.l).abs() < 0.01);
+    /// ```
+    pub fn gamut_map(&self, method: GamutMapping) -> Color {
+        let (a, b) = oklch_to_oklab_ab(self.c, self.h);
+        let (r, g, b) = map_oklab((self.l, a, b), method);
+        Color::from_linear_rgb(r, g, b)
+    }
+}
+
+impl Lab {
+    /// Checks whether the color can be shown in the color space `space`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Lab, RgbSpace};
+    ///
+    /// assert!(!Lab::new(50.0, 0.0, -110.0).in_gamut(RgbSpace::Srgb));
+    /// assert!(Lab::new(50.0, 10.0, -10.0).in_gamut(RgbSpace::Srgb));
+    /// ```
+    pub fn in_gamut(&self, space: RgbSpace) -> bool {
+        let (x, y, z) = lab_to_xyz(self.l, self.a, self.b);
+        space.contains(xyz_to_linear_rgb(x, y, z))
+    }
+
+    /// Converts to a [`Color`], bringing colors which fall outside of the RGB gamut into it with
+    /// `method`. Unlike [`to_color()`](Lab::to_color), [`GamutMapping::ChromaReduction`] keeps
+    /// the hue and lightness in OKLCH.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{GamutMapping, Lab};
+    ///
+    /// let blue = Lab::new(50.0, 0.0, -110.0).gamut_map(GamutMapping::ChromaReduction);
+    ///
+    /// assert!(blue.get_blue() > blue.get_red() && blue.get_blue() > blue.get_green());
+    /// ```
+    pub fn gamut_map(&self, method: GamutMapping) -> Color {
+        let (x, y, z) = lab_to_xyz(self.l, self.a, self.b);
+        let (r, g, b) = xyz_to_linear_rgb(x, y, z);
+        let (r, g, b) = map_oklab(linear_rgb_to_oklab(r, g, b), method);
+        Color::from_linear_rgb(r, g, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spaces() {
+        let white = constants::primary::WHITE.to_xyz();
+        for space in [
+            RgbSpace::Srgb,
+            RgbSpace::DisplayP3,
+            RgbSpace::AdobeRgb,
+            RgbSpace::Rec2020,
+        ] {
+            let (r, g, b) = transform(&space.xyz_to_rgb(), white);
+            assert!(
+                (r - 1.0).abs() < 0.001 && (g - 1.0).abs() < 0.001 && (b - 1.0).abs() < 0.001,
+                "{space:?}"
+            );
+        }
+        let p3_red = ColorF::new(1.05, -0.15, -0.1);
+        assert!(!p3_red.in_gamut(RgbSpace::Srgb));
+        assert!(p3_red.in_gamut(RgbSpace::DisplayP3));
+        assert!(p3_red.in_gamut(RgbSpace::Rec2020));
+        assert!(!ColorF::new(2.0, 2.0, 2.0).in_gamut(RgbSpace::Rec2020));
+    }
+
+    #[test]
+    fn gamut_map() {
+        for v in [0, 64, 128, 255] {
+            let color = Color::new(v, 255 - v, v / 2);
+            let oklch = color.oklch();
+            assert!(oklch.in_gamut(RgbSpace::Srgb));
+            assert_eq!(oklch.gamut_map(GamutMapping::ChromaReduction), color);
+            assert_eq!(color.lab().gamut_map(GamutMapping::Clip), color);
+        }
+        for h in 0..20 {
+            let vivid = Oklch::new(0.6, 0.4, h as f32 / 20.0);
+            let mapped = vivid.gamut_map(GamutMapping::ChromaReduction).oklch();
+            assert!(hue_distance(mapped.h, vivid.h) < 0.02, "{h}: {mapped:?}");
+            assert!((mapped.l - vivid.l).abs() < 0.02, "{h}: {mapped:?}");
+            assert!(mapped.c < vivid.c);
+        }
+        assert_eq!(
+            Oklch::new(1.2, 0.3, 0.5).gamut_map(GamutMapping::ChromaReduction),
+            constants::primary::WHITE
+        );
+        let clipped = ColorF::new(1.5, 0.2, -0.5).gamut_map(GamutMapping::Clip);
+        assert!(clipped.in_gamut(RgbSpace::Srgb));
+        let hdr = ColorF::new(0.2, 0.4, 0.6);
+        assert_eq!(hdr.gamut_map(GamutMapping::ChromaReduction), hdr);
+    }
+}
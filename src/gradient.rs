@@ -0,0 +1,783 @@
+use super::*;
+
+/// A gradient made up of color stops, each positioned between 0.0 and 1.0. Unlike
+/// [`ColorRange`], a gradient can have any number of stops and can be sampled at any position.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{Color, constants, Gradient};
+///
+/// let mut gradient = Gradient::new(constants::primary::RED, constants::primary::BLUE);
+/// gradient.add_stop(0.5, constants::primary::WHITE);
+///
+/// assert_eq!(gradient.at(0.0), constants::primary::RED);
+/// assert_eq!(gradient.at(0.25), Color::new(255, 128, 128));
+/// assert_eq!(gradient.at(0.5), constants::primary::WHITE);
+/// assert_eq!(gradient.at(1.0), constants::primary::BLUE);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    stops: Vec<(f32, Color)>,
+    space: ColorSpace,
+    hue_direction: HueDirection,
+    easing: Easing,
+    interpolation: Interpolation,
+    dither: DitherMode,
+    cyclic: bool,
+}
+
+impl Gradient {
+    /// Creates a gradient which goes from `start` (at 0.0) to `end` (at 1.0).
+    pub fn new(start: Color, end: Color) -> Self {
+        Self {
+            stops: vec![(0.0, start), (1.0, end)],
+            space: ColorSpace::Srgb,
+            hue_direction: HueDirection::Specified,
+            easing: Easing::Linear,
+            interpolation: Interpolation::Linear,
+            dither: DitherMode::None,
+            cyclic: false,
+        }
+    }
+
+    /// Creates a gradient from `colors` which are evenly spaced from 0.0 to 1.0.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `colors` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Gradient};
+    ///
+    /// let gradient = Gradient::from_colors(&[
+    ///     constants::primary::RED,
+    ///     constants::primary::GREEN,
+    ///     constants::primary::BLUE,
+    /// ]);
+    ///
+    /// assert_eq!(gradient.at(0.5), constants::primary::GREEN);
+    /// ```
+    pub fn from_colors(colors: &[Color]) -> Self {
+        if colors.is_empty() {
+            panic!("Unsupported number of colors: 0");
+        }
+        let last = (colors.len() - 1).max(1) as f32;
+        let stops = colors
+            .iter()
+            .enumerate()
+            .map(|(i, color)| (i as f32 / last, *color))
+            .collect();
+        Self {
+            stops,
+            space: ColorSpace::Srgb,
+            hue_direction: HueDirection::Specified,
+            easing: Easing::Linear,
+            interpolation: Interpolation::Linear,
+            dither: DitherMode::None,
+            cyclic: false,
+        }
+    }
+
+    /// Creates a cyclic gradient from `colors` which are evenly spaced from 0.0 to 1.0, where the
+    /// last color blends back into the first color. Sampling it with [`at()`](Gradient::at) wraps
+    /// `t` around, so there is no seam between 1.0 and 0.0, which is useful for hue wheels, angles
+    /// and animations.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `colors` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants, Gradient};
+    ///
+    /// let gradient = Gradient::cyclic(&[constants::primary::BLACK, constants::primary::WHITE]);
+    ///
+    /// assert_eq!(gradient.at(0.5), constants::primary::WHITE);
+    /// assert_eq!(gradient.at(0.75), Color::new(128, 128, 128));
+    /// assert_eq!(gradient.at(1.0), gradient.at(0.0));
+    /// assert_eq!(gradient.at(-0.25), gradient.at(0.75));
+    /// ```
+    pub fn cyclic(colors: &[Color]) -> Self {
+        if colors.is_empty() {
+            panic!("Unsupported number of colors: 0");
+        }
+        let stops = colors
+            .iter()
+            .enumerate()
+            .map(|(i, color)| (i as f32 / colors.len() as f32, *color))
+            .collect();
+        Self {
+            stops,
+            cyclic: true,
+            ..Self::new(Color::new(0, 0, 0), Color::new(0, 0, 0))
+        }
+    }
+
+    /// Sets the [`ColorSpace`] the colors are interpolated in, which is [`ColorSpace::Srgb`] by
+    /// default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ColorSpace, constants, Gradient};
+    ///
+    /// let gradient = Gradient::new(constants::primary::BLACK, constants::primary::WHITE)
+    ///     .with_space(ColorSpace::LinearRgb);
+    ///
+    /// assert_eq!(gradient.at(0.5), Color::new(188, 188, 188));
+    /// ```
+    pub fn with_space(mut self, space: ColorSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Sets the [`HueDirection`] the hue is interpolated in, which is
+    /// [`HueDirection::Specified`] by default. This only applies to color spaces with a hue.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ColorSpace, constants, Gradient, HueDirection};
+    ///
+    /// let gradient = Gradient::new(constants::primary::RED, constants::primary::BLUE)
+    ///     .with_space(ColorSpace::Hsl)
+    ///     .with_hue_direction(HueDirection::Increasing);
+    ///
+    /// assert_eq!(gradient.at(0.5), Color::from_web_color("lime").unwrap());
+    /// ```
+    pub fn with_hue_direction(mut self, direction: HueDirection) -> Self {
+        self.hue_direction = direction;
+        self
+    }
+
+    /// Sets the [`Easing`] applied to the interpolation between each pair of adjacent stops,
+    /// which is [`Easing::Linear`] by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants, Easing, Gradient};
+    ///
+    /// let gradient = Gradient::new(constants::primary::BLACK, constants::primary::WHITE)
+    ///     .with_easing(Easing::EaseInOut);
+    ///
+    /// assert_eq!(gradient.at(0.5), Color::new(128, 128, 128));
+    /// assert!(gradient.at(0.25).get_red() < 64);
+    /// ```
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the [`Interpolation`] used between the stops, which is [`Interpolation::Linear`] by
+    /// default. Splines make gradients with more than two stops smooth at the stops.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants, Gradient, Interpolation};
+    ///
+    /// let colors = [
+    ///     constants::primary::BLACK,
+    ///     constants::primary::RED,
+    ///     constants::primary::WHITE,
+    /// ];
+    /// let linear = Gradient::from_colors(&colors);
+    /// let spline = Gradient::from_colors(&colors).with_interpolation(Interpolation::CatmullRom);
+    ///
+    /// assert_eq!(spline.at(0.5), constants::primary::RED);
+    /// assert_ne!(spline.at(0.25), linear.at(0.25));
+    /// ```
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Sets the [`DitherMode`] used by [`render()`](Gradient::render) and
+    /// [`render_into()`](Gradient::render_into), which is [`DitherMode::None`] by default.
+    pub fn with_dither(mut self, mode: DitherMode) -> Self {
+        self.dither = mode;
+        self
+    }
+
+    /// Adds a color stop at `position`, which is clamped between 0.0 and 1.0. When a stop already
+    /// exists at the same position, the new stop is placed after it, which creates a hard edge.
+    pub fn add_stop(&mut self, position: f32, color: Color) {
+        let position = position.clamp(0.0, 1.0);
+        let index = self.stops.partition_point(|(p, _)| *p <= position);
+        self.stops.insert(index, (position, color));
+    }
+
+    /// Removes and returns the color stop at `index`, where stops are ordered by position.
+    /// Returns `None` when `index` is out of bounds or when it is the only stop left, as a
+    /// gradient always has at least one stop.
+    pub fn remove_stop(&mut self, index: usize) -> Option<(f32, Color)> {
+        if index >= self.stops.len() || self.stops.len() == 1 {
+            return None;
+        }
+        Some(self.stops.remove(index))
+    }
+
+    /// Gets the color stops ordered by position.
+    #[inline]
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// Gets the color at `t`, where `t` is between 0.0 and 1.0. Positions before the first stop
+    /// or after the last stop take the color of that stop, unless the gradient is
+    /// [cyclic](Gradient::cyclic), where `t` wraps around instead.
+    pub fn at(&self, t: f32) -> Color {
+        let (r, g, b) = self.sample(t);
+        Color::from_rgb_float(r, g, b)
+    }
+
+    /// Gets the color of the point (`x`, `y`) in a linear gradient which goes from the point
+    /// `start` (at 0.0) to the point `end` (at 1.0). The point is projected onto the line between
+    /// `start` and `end`, so the color is constant along lines perpendicular to it. When `start`
+    /// and `end` are the same point, the color at 0.0 is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants, Gradient};
+    ///
+    /// let gradient = Gradient::new(constants::primary::BLACK, constants::primary::WHITE);
+    /// let horizontal = |x, y| gradient.sample_linear(x, y, (0.0, 0.0), (10.0, 0.0));
+    ///
+    /// assert_eq!(horizontal(5.0, 0.0), Color::new(128, 128, 128));
+    /// assert_eq!(horizontal(5.0, 7.0), Color::new(128, 128, 128));
+    /// assert_eq!(horizontal(20.0, 0.0), constants::primary::WHITE);
+    /// ```
+    pub fn sample_linear(&self, x: f32, y: f32, start: (f32, f32), end: (f32, f32)) -> Color {
+        let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+        let length = dx * dx + dy * dy;
+        if length == 0.0 {
+            return self.at(0.0);
+        }
+        self.at(((x - start.0) * dx + (y - start.1) * dy) / length)
+    }
+
+    /// Gets the color of the point (`x`, `y`) in a radial gradient, which goes from `center` (at
+    /// 0.0) to the circle of `radius` around it (at 1.0). When `radius` is not positive, the color
+    /// at 1.0 is returned everywhere except for `center` itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants, Gradient};
+    ///
+    /// let gradient = Gradient::new(constants::primary::WHITE, constants::primary::BLACK);
+    /// let circle = |x, y| gradient.sample_radial(x, y, (0.0, 0.0), 10.0);
+    ///
+    /// assert_eq!(circle(0.0, 0.0), constants::primary::WHITE);
+    /// assert_eq!(circle(3.0, 4.0), Color::new(128, 128, 128));
+    /// assert_eq!(circle(0.0, -15.0), constants::primary::BLACK);
+    /// ```
+    pub fn sample_radial(&self, x: f32, y: f32, center: (f32, f32), radius: f32) -> Color {
+        let distance = (x - center.0).hypot(y - center.1);
+        if radius <= 0.0 {
+            return self.at(if distance == 0.0 { 0.0 } else { 1.0 });
+        }
+        self.at(distance / radius)
+    }
+
+    /// Gets the color of the point (`x`, `y`) in a conic gradient around `center`, like
+    /// `conic-gradient()` in CSS. The gradient goes clockwise from `angle` in degrees (at 0.0) all
+    /// the way around (at 1.0), where 0 degrees points up. As in screen coordinates, `y` increases
+    /// downwards. Conic gradients have a seam at `angle` unless the gradient is
+    /// [cyclic](Gradient::cyclic).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Gradient};
+    ///
+    /// let gradient = Gradient::cyclic(&[
+    ///     constants::primary::RED,
+    ///     constants::primary::GREEN,
+    ///     constants::primary::BLUE,
+    /// ]);
+    ///
+    /// assert_eq!(gradient.sample_conic(0.0, -1.0, (0.0, 0.0), 0.0), constants::primary::RED);
+    /// assert_eq!(gradient.sample_conic(0.0, -1.0, (0.0, 0.0), 240.0), constants::primary::GREEN);
+    /// ```
+    pub fn sample_conic(&self, x: f32, y: f32, center: (f32, f32), angle: f32) -> Color {
+        // Measured clockwise from up, which is atan2 with the axes swapped and `y` flipped.
+        let turns = (x - center.0).atan2(center.1 - y) / core::f32::consts::TAU;
+        self.at((turns - angle / 360.0).rem_euclid(1.0))
+    }
+
+    /// Gets the color at `t` as sRGB floats, before it is rounded to 8 bits.
+    fn sample(&self, t: f32) -> (f32, f32, f32) {
+        let len = self.stops.len();
+        let (start, end, t) = if self.cyclic {
+            let t = t.rem_euclid(1.0);
+            let index = self.stops.partition_point(|(p, _)| *p <= t);
+            if index == 0 || index == len {
+                // Blend from the last stop back into the first stop.
+                let (p0, p1) = (self.stops[len - 1].0, self.stops[0].0 + 1.0);
+                let t = if index == 0 { t + 1.0 } else { t };
+                (len - 1, 0, (t - p0) / (p1 - p0))
+            } else {
+                let (p0, p1) = (self.stops[index - 1].0, self.stops[index].0);
+                (index - 1, index, (t - p0) / (p1 - p0))
+            }
+        } else {
+            let index = self.stops.partition_point(|(p, _)| *p <= t);
+            if index == 0 {
+                return self.stops[0].1.to_rgb_float();
+            }
+            if index == len {
+                return self.stops[index - 1].1.to_rgb_float();
+            }
+            let (p0, p1) = (self.stops[index - 1].0, self.stops[index].0);
+            (index - 1, index, (t - p0) / (p1 - p0))
+        };
+        if len == 1 {
+            return self.stops[0].1.to_rgb_float();
+        }
+        let t = self.easing.apply(t);
+        if self.interpolation == Interpolation::Linear {
+            let (c0, c1) = (&self.stops[start].1, &self.stops[end].1);
+            let (a, b) = self.space.endpoints(c0, c1, self.hue_direction);
+            return self.space.to_rgb_float((
+                a.0 + (b.0 - a.0) * t,
+                a.1 + (b.1 - a.1) * t,
+                a.2 + (b.2 - a.2) * t,
+            ));
+        }
+        let points = self.spline_points(start, end);
+        let weights = match self.interpolation {
+            Interpolation::Linear => unreachable!(),
+            Interpolation::CatmullRom => [
+                (-t * t * t + 2.0 * t * t - t) / 2.0,
+                (3.0 * t * t * t - 5.0 * t * t + 2.0) / 2.0,
+                (-3.0 * t * t * t + 4.0 * t * t + t) / 2.0,
+                (t * t * t - t * t) / 2.0,
+            ],
+            Interpolation::BSpline => [
+                (1.0 - t).powi(3) / 6.0,
+                (3.0 * t * t * t - 6.0 * t * t + 4.0) / 6.0,
+                (-3.0 * t * t * t + 3.0 * t * t + 3.0 * t + 1.0) / 6.0,
+                t * t * t / 6.0,
+            ],
+        };
+        let mut components = [0.0; 3];
+        for (point, weight) in points.iter().zip(weights) {
+            for (component, value) in components.iter_mut().zip(point) {
+                *component += value * weight;
+            }
+        }
+        self.space
+            .to_rgb_float((components[0], components[1], components[2]))
+    }
+
+    /// Converts the four stops around the segment from `start` to `end` to the color space of the
+    /// gradient, unwrapping the hues so that each pair goes around the color wheel in the hue
+    /// direction. At the ends of a gradient which is not cyclic, the missing neighbour is the
+    /// reflection of the stop next to it.
+    fn spline_points(&self, start: usize, end: usize) -> [[f32; 3]; 4] {
+        let len = self.stops.len();
+        let (previous, next) = if self.cyclic {
+            ((start + len - 1) % len, (end + 1) % len)
+        } else {
+            (start.saturating_sub(1), (end + 1).min(len - 1))
+        };
+        let colors = [
+            &self.stops[previous].1,
+            &self.stops[start].1,
+            &self.stops[end].1,
+            &self.stops[next].1,
+        ];
+        let pair = |a: &Color, b: &Color| {
+            let (a, b) = self.space.endpoints(a, b, self.hue_direction);
+            ([a.0, a.1, a.2], [b.0, b.1, b.2])
+        };
+        let (p1, p2) = pair(colors[1], colors[2]);
+        let (before, first) = pair(colors[0], colors[1]);
+        let (last, after) = pair(colors[2], colors[3]);
+        let hue = match self.space {
+            ColorSpace::Hsl | ColorSpace::Hsv => Some(0),
+            ColorSpace::Oklch => Some(2),
+            _ => None,
+        };
+        let mut p0 = before;
+        let mut p3 = after;
+        if let Some(hue) = hue {
+            p0[hue] = p1[hue] - (first[hue] - before[hue]);
+            p3[hue] = p2[hue] + (after[hue] - last[hue]);
+        }
+        let reflect =
+            |a: [f32; 3], b: [f32; 3]| [2.0 * a[0] - b[0], 2.0 * a[1] - b[1], 2.0 * a[2] - b[2]];
+        if previous == start {
+            p0 = reflect(p1, p2);
+        }
+        if next == end {
+            p3 = reflect(p2, p1);
+        }
+        [p0, p1, p2, p3]
+    }
+
+    /// Gets `n` evenly spaced colors from the gradient, including both ends. When `n` is 1, the
+    /// middle of the gradient is returned. As both ends of a cyclic gradient are the same color,
+    /// only the start is included for those.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants, Gradient};
+    ///
+    /// let gradient = Gradient::new(constants::primary::BLACK, constants::primary::WHITE);
+    ///
+    /// assert_eq!(
+    ///     gradient.colors(3),
+    ///     vec![constants::primary::BLACK, Color::new(128, 128, 128), constants::primary::WHITE]
+    /// );
+    /// ```
+    pub fn colors(&self, n: usize) -> Vec<Color> {
+        self.positions(n).map(|t| self.at(t)).collect()
+    }
+
+    /// Gets `n` evenly spaced colors from the gradient like [`colors()`](Gradient::colors), but
+    /// dithers the colors while rounding them to 8 bits. The gradient is sampled at full precision
+    /// and a small offset which depends on the index is added to each channel before it is
+    /// rounded, so that long and smooth gradients don't show visible bands.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, DitherMode, Gradient};
+    ///
+    /// let gradient = Gradient::new(Color::new(0, 0, 0), Color::new(4, 4, 4));
+    /// let colors = gradient.to_u8_dithered(16, DitherMode::Ordered);
+    ///
+    /// assert_eq!(colors.len(), 16);
+    /// assert_eq!(colors[0], Color::new(0, 0, 0));
+    /// assert_eq!(colors[15], Color::new(4, 4, 4));
+    /// assert_eq!(gradient.to_u8_dithered(16, DitherMode::None), gradient.colors(16));
+    /// ```
+    pub fn to_u8_dithered(&self, n: usize, mode: DitherMode) -> Vec<Color> {
+        self.positions(n)
+            .enumerate()
+            .map(|(i, t)| self.quantize(t, mode.threshold(i)))
+            .collect()
+    }
+
+    /// Renders the gradient to a row of `width` pixels, such as for a strip in a user interface
+    /// or a row of an image. The first and last pixels are exactly the colors at 0.0 and 1.0,
+    /// except for cyclic gradients, where the last pixel stops short of 1.0 so that the row tiles
+    /// without a seam. The pixels are dithered with the mode set with
+    /// [`with_dither()`](Gradient::with_dither).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants, DitherMode, Gradient};
+    ///
+    /// let gradient = Gradient::new(constants::primary::BLACK, constants::primary::WHITE);
+    /// let row = gradient.render(256);
+    ///
+    /// assert_eq!(row[0], constants::primary::BLACK);
+    /// assert_eq!(row[128], Color::new(128, 128, 128));
+    /// assert_eq!(row[255], constants::primary::WHITE);
+    ///
+    /// let dithered = gradient.with_dither(DitherMode::GoldenRatio).render(1024);
+    ///
+    /// assert_eq!(dithered[1023], constants::primary::WHITE);
+    /// ```
+    pub fn render(&self, width: usize) -> Vec<Color> {
+        let mut pixels = vec![Color::new(0, 0, 0); width];
+        self.render_into(&mut pixels);
+        pixels
+    }
+
+    /// Same as [`render()`](Gradient::render), but renders into `pixels`, so that a buffer can be
+    /// reused between frames.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants, Gradient};
+    ///
+    /// let gradient = Gradient::new(constants::primary::RED, constants::primary::BLUE);
+    /// let mut row = [Color::new(0, 0, 0); 3];
+    /// gradient.render_into(&mut row);
+    ///
+    /// assert_eq!(row[0], constants::primary::RED);
+    /// assert_eq!(row[1], Color::new(128, 0, 128));
+    /// assert_eq!(row[2], constants::primary::BLUE);
+    /// ```
+    pub fn render_into(&self, pixels: &mut [Color]) {
+        let positions = self.positions(pixels.len());
+        for (i, (pixel, t)) in pixels.iter_mut().zip(positions).enumerate() {
+            *pixel = self.quantize(t, self.dither.threshold(i));
+        }
+    }
+
+    /// Gets the color at `t`, with `offset` added to each channel before it is rounded.
+    fn quantize(&self, t: f32, offset: f32) -> Color {
+        let (r, g, b) = self.sample(t);
+        let quantize = |v: f32| (v * 255.0 + 0.5 + offset).clamp(0.0, 255.0) as u8;
+        Color::new(quantize(r), quantize(g), quantize(b))
+    }
+
+    fn positions(&self, n: usize) -> impl Iterator<Item = f32> {
+        let cyclic = self.cyclic;
+        (0..n).map(move |i| {
+            if cyclic {
+                i as f32 / n as f32
+            } else if n == 1 {
+                0.5
+            } else {
+                i as f32 / (n - 1) as f32
+            }
+        })
+    }
+}
+
+/// The dithering applied by [`Gradient::to_u8_dithered()`] and [`Gradient::render()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DitherMode {
+    /// Rounds each color without dithering.
+    #[default]
+    None,
+    /// Ordered dithering using a repeating pattern of 16 thresholds, which is fast and regular.
+    Ordered,
+    /// Dithering using the golden ratio low discrepancy sequence, whose consecutive thresholds
+    /// are spread far apart without repeating, so it has no visible pattern. This is not blue
+    /// noise, but it is similarly even and much cheaper to compute.
+    GoldenRatio,
+}
+
+impl DitherMode {
+    /// Gets the offset between -0.5 and 0.5 added to the channels of the `index`th color.
+    fn threshold(self, index: usize) -> f32 {
+        match self {
+            Self::None => 0.0,
+            Self::Ordered => {
+                // Reversing the bits of the index gives the 1D Bayer pattern.
+                let level = (index % 16) as u8;
+                let level = level.reverse_bits() >> 4;
+                (level as f32 + 0.5) / 16.0 - 0.5
+            }
+            Self::GoldenRatio => {
+                // Multiples of the golden ratio fill the unit interval as evenly as possible.
+                let golden = 0.618_034;
+                (index as f32 * golden).fract() - 0.5
+            }
+        }
+    }
+}
+
+/// The curve used to interpolate between the stops of a [`Gradient`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// Straight lines between adjacent stops, which can have visible kinks at the stops.
+    #[default]
+    Linear,
+    /// A Catmull-Rom spline, which is smooth and goes through every stop.
+    CatmullRom,
+    /// A uniform cubic B-spline, which is smoother than [`Interpolation::CatmullRom`] but only
+    /// goes through the first and last stops.
+    BSpline,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn at() {
+        let mut gradient = Gradient::new(constants::primary::BLACK, constants::primary::WHITE);
+        assert_eq!(gradient.at(-1.0), constants::primary::BLACK);
+        assert_eq!(gradient.at(0.5), Color::new(128, 128, 128));
+        assert_eq!(gradient.at(2.0), constants::primary::WHITE);
+        gradient.add_stop(0.5, constants::primary::RED);
+        gradient.add_stop(0.5, constants::primary::BLUE);
+        assert_eq!(gradient.at(0.25), Color::new(128, 0, 0));
+        assert_eq!(gradient.at(0.5), constants::primary::BLUE);
+        assert_eq!(gradient.at(0.75), Color::new(128, 128, 255));
+        let gradient = Gradient::from_colors(&[constants::primary::RED]);
+        assert_eq!(gradient.at(0.3), constants::primary::RED);
+    }
+
+    #[test]
+    fn sample_2d() {
+        let black = constants::primary::BLACK;
+        let white = constants::primary::WHITE;
+        let gray = Color::new(128, 128, 128);
+        let gradient = Gradient::new(black, white);
+        let diagonal = |x, y| gradient.sample_linear(x, y, (0.0, 0.0), (4.0, 4.0));
+        assert_eq!(diagonal(2.0, 2.0), gray);
+        assert_eq!(diagonal(4.0, 0.0), gray);
+        assert_eq!(diagonal(-1.0, -1.0), black);
+        assert_eq!(
+            gradient.sample_linear(9.0, 9.0, (1.0, 1.0), (1.0, 1.0)),
+            black
+        );
+        assert_eq!(gradient.sample_radial(-4.0, 3.0, (1.0, 3.0), 10.0), gray);
+        assert_eq!(gradient.sample_radial(1.0, 3.0, (1.0, 3.0), 0.0), black);
+        assert_eq!(gradient.sample_radial(2.0, 3.0, (1.0, 3.0), 0.0), white);
+        let conic = |x, y, angle| gradient.sample_conic(x, y, (0.0, 0.0), angle);
+        assert_eq!(conic(0.0, -1.0, 0.0), black);
+        assert_eq!(conic(0.0, 1.0, 0.0), gray);
+        assert_eq!(conic(-1.0, 0.0, 90.0), gray);
+        assert!(conic(-0.001, -1.0, 0.0).get_red() > 250);
+        assert_eq!(conic(1.0, 0.0, -90.0), gray);
+    }
+
+    #[test]
+    fn with_space() {
+        let red = constants::primary::RED;
+        let blue = constants::primary::BLUE;
+        let gray = Color::new(128, 128, 128);
+        let middle = |space| Gradient::new(red, blue).with_space(space).at(0.5);
+        assert_eq!(middle(ColorSpace::Srgb), Color::new(128, 0, 128));
+        assert_eq!(middle(ColorSpace::LinearRgb), Color::new(188, 0, 188));
+        assert_eq!(middle(ColorSpace::Hsl), Color::new(0, 255, 0));
+        assert_eq!(middle(ColorSpace::Hsv), Color::new(0, 255, 0));
+        assert_eq!(middle(ColorSpace::Oklab), Color::new(140, 83, 162));
+        let oklch = middle(ColorSpace::Oklch);
+        assert!(oklch.to_oklch().1 > middle(ColorSpace::Oklab).to_oklch().1);
+        let gradient = Gradient::new(gray, red).with_space(ColorSpace::Oklch);
+        assert!((gradient.at(0.5).to_oklch().2 - red.to_oklch().2).abs() < 0.01);
+    }
+
+    #[test]
+    fn with_easing() {
+        let mut gradient = Gradient::new(constants::primary::BLACK, constants::primary::WHITE)
+            .with_easing(Easing::custom(|t| t * t));
+        gradient.add_stop(0.5, constants::primary::RED);
+        assert_eq!(gradient.at(0.25), Color::new(64, 0, 0));
+        assert_eq!(gradient.at(0.75), Color::new(255, 64, 64));
+        assert_eq!(gradient.at(1.0), constants::primary::WHITE);
+    }
+
+    #[test]
+    fn with_interpolation() {
+        let colors = [
+            constants::primary::BLACK,
+            Color::new(200, 0, 0),
+            constants::primary::WHITE,
+            constants::primary::BLUE,
+        ];
+        let linear = Gradient::from_colors(&colors).with_space(ColorSpace::Oklab);
+        for interpolation in [Interpolation::CatmullRom, Interpolation::BSpline] {
+            let spline = linear.clone().with_interpolation(interpolation);
+            assert_eq!(spline.at(0.0), colors[0]);
+            assert_eq!(spline.at(1.0), colors[3]);
+            assert_ne!(spline.at(0.2), linear.at(0.2));
+        }
+        let spline = linear.with_interpolation(Interpolation::CatmullRom);
+        assert_eq!(spline.at(1.0 / 3.0), colors[1]);
+        assert_eq!(spline.at(2.0 / 3.0), colors[2]);
+        let hues = Gradient::from_colors(&[
+            Color::from_hsl(0.9, 1.0, 0.5),
+            Color::from_hsl(0.0, 1.0, 0.5),
+            Color::from_hsl(0.1, 1.0, 0.5),
+        ])
+        .with_space(ColorSpace::Hsl)
+        .with_hue_direction(HueDirection::Shorter)
+        .with_interpolation(Interpolation::CatmullRom);
+        let hue = hues.at(0.25).get_hsl_hue();
+        assert!(hue > 0.9 && hue < 1.0);
+    }
+
+    #[test]
+    fn cyclic() {
+        let colors = [
+            constants::primary::RED,
+            constants::primary::GREEN,
+            constants::primary::BLUE,
+        ];
+        let gradient = Gradient::cyclic(&colors);
+        assert_eq!(gradient.at(1.0 / 3.0), colors[1]);
+        let wrapped = gradient.at(5.0 / 6.0);
+        assert_eq!(wrapped.get_green(), 0);
+        assert!(wrapped.get_red().abs_diff(wrapped.get_blue()) <= 1);
+        assert_eq!(gradient.at(1.0), colors[0]);
+        assert_eq!(gradient.at(-1.0 / 6.0), wrapped);
+        let mut gradient = Gradient::cyclic(&colors[..1]);
+        assert_eq!(gradient.at(0.7), colors[0]);
+        gradient.add_stop(0.5, colors[2]);
+        assert_eq!(gradient.at(0.75), Color::new(128, 0, 128));
+        assert_eq!(gradient.at(0.25), Color::new(128, 0, 128));
+        assert_eq!(Gradient::cyclic(&colors).colors(3), colors);
+        let spline = Gradient::cyclic(&colors)
+            .with_space(ColorSpace::Oklab)
+            .with_interpolation(Interpolation::CatmullRom);
+        assert_eq!(spline.at(0.999), spline.at(-0.001));
+    }
+
+    #[test]
+    fn to_u8_dithered() {
+        let black = constants::primary::BLACK;
+        let gradient = Gradient::new(black, Color::new(2, 2, 2));
+        assert_eq!(
+            gradient.to_u8_dithered(5, DitherMode::None),
+            gradient.colors(5)
+        );
+        for mode in [DitherMode::Ordered, DitherMode::GoldenRatio] {
+            let colors = gradient.to_u8_dithered(64, mode);
+            let average = colors.iter().map(|c| c.get_red() as f32).sum::<f32>() / 64.0;
+            assert!((average - 1.0).abs() < 0.1);
+            assert!(colors.iter().all(|c| c.get_red() == c.get_blue()));
+            assert_eq!(colors[0], black);
+        }
+        let white = Gradient::new(constants::primary::WHITE, constants::primary::WHITE);
+        let colors = white.to_u8_dithered(16, DitherMode::Ordered);
+        assert!(colors.iter().all(|c| *c == constants::primary::WHITE));
+    }
+
+    #[test]
+    fn render() {
+        let gradient = Gradient::new(constants::primary::BLACK, Color::new(2, 2, 2));
+        assert_eq!(gradient.render(7), gradient.colors(7));
+        assert!(gradient.render(0).is_empty());
+        let dithered = gradient.clone().with_dither(DitherMode::Ordered);
+        assert_eq!(
+            dithered.render(64),
+            gradient.to_u8_dithered(64, DitherMode::Ordered)
+        );
+        let mut pixels = [constants::primary::RED; 64];
+        dithered.render_into(&mut pixels);
+        assert_eq!(pixels.to_vec(), dithered.render(64));
+        let cyclic = Gradient::cyclic(&[constants::primary::BLACK, constants::primary::WHITE]);
+        let row = cyclic.render(4);
+        assert_eq!(row[0], constants::primary::BLACK);
+        assert_eq!(row[2], constants::primary::WHITE);
+        assert_eq!(row[1], row[3]);
+    }
+
+    #[test]
+    fn stops() {
+        let mut gradient = Gradient::new(constants::primary::RED, constants::primary::BLUE);
+        gradient.add_stop(2.0, constants::primary::GREEN);
+        gradient.add_stop(0.3, constants::primary::WHITE);
+        let positions: Vec<f32> = gradient.stops().iter().map(|(p, _)| *p).collect();
+        assert_eq!(positions, vec![0.0, 0.3, 1.0, 1.0]);
+        assert_eq!(
+            gradient.remove_stop(1),
+            Some((0.3, constants::primary::WHITE))
+        );
+        assert_eq!(gradient.remove_stop(5), None);
+        assert!(gradient.remove_stop(0).is_some());
+        assert!(gradient.remove_stop(0).is_some());
+        assert_eq!(gradient.remove_stop(0), None);
+        assert_eq!(gradient.stops().len(), 1);
+    }
+
+    #[test]
+    fn colors() {
+        let gradient = Gradient::new(constants::primary::BLACK, constants::primary::WHITE);
+        assert_eq!(gradient.colors(6)[1], Color::from_hex(0x333333));
+        assert_eq!(gradient.colors(1), vec![Color::new(128, 128, 128)]);
+        assert!(gradient.colors(0).is_empty());
+    }
+}
@@ -0,0 +1,264 @@
+use super::*;
+
+/// The luminance of SDR reference white in cd/m², from ITU-R BT.2408, which linear values of 1.0
+/// are mapped to.
+const REFERENCE_WHITE: f32 = 203.0;
+
+/// The peak luminance of PQ in cd/m².
+const PQ_PEAK: f32 = 10000.0;
+
+const PQ_M1: f32 = 2610.0 / 16384.0;
+const PQ_M2: f32 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f32 = 3424.0 / 4096.0;
+const PQ_C2: f32 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f32 = 2392.0 / 4096.0 * 32.0;
+
+const HLG_A: f32 = 0.178_832_77;
+const HLG_B: f32 = 1.0 - 4.0 * HLG_A;
+const HLG_C: f32 = 0.559_910_7;
+
+/// The scene light of HLG reference white, which is at 75% of the signal.
+const HLG_REFERENCE_WHITE: f32 = 0.264_962_56;
+
+fn pq_to_linear(v: f32) -> f32 {
+    let p = v.clamp(0.0, 1.0).powf(1.0 / PQ_M2);
+    let y = ((p - PQ_C1).max(0.0) / (PQ_C2 - PQ_C3 * p)).powf(1.0 / PQ_M1);
+    y * PQ_PEAK / REFERENCE_WHITE
+}
+
+fn linear_to_pq(v: f32) -> f32 {
+    let y = (v * REFERENCE_WHITE / PQ_PEAK).clamp(0.0, 1.0).powf(PQ_M1);
+    ((PQ_C1 + PQ_C2 * y) / (1.0 + PQ_C3 * y)).powf(PQ_M2)
+}
+
+fn hlg_to_linear(v: f32) -> f32 {
+    let v = v.clamp(0.0, 1.0);
+    let e = if v <= 0.5 {
+        v * v / 3.0
+    } else {
+        (((v - HLG_C) / HLG_A).exp() + HLG_B) / 12.0
+    };
+    e / HLG_REFERENCE_WHITE
+}
+
+fn linear_to_hlg(v: f32) -> f32 {
+    let e = (v * HLG_REFERENCE_WHITE).clamp(0.0, 1.0);
+    if e <= 1.0 / 12.0 {
+        (3.0 * e).sqrt()
+    } else {
+        HLG_A * (12.0 * e - HLG_B).ln() + HLG_C
+    }
+}
+
+/// An operator which compresses HDR linear light into the 0.0 - 1.0 range of a display, as used
+/// by [`ColorF::tonemap()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToneMapper {
+    /// `x / (1 + x)`, which is simple and never clips, but makes highlights look washed out.
+    Reinhard,
+    /// The fit of the ACES filmic curve by Krzysztof Narkowicz, which has more contrast and
+    /// saturated highlights.
+    Aces,
+    /// The filmic curve by John Hable from Uncharted 2, which keeps more detail in the shadows.
+    Hable,
+}
+
+impl ToneMapper {
+    /// Maps a channel of linear light to 0.0 - 1.0.
+    fn map(self, x: f32) -> f32 {
+        let x = x.max(0.0);
+        match self {
+            Self::Reinhard => x / (1.0 + x),
+            Self::Aces => {
+                // The fit is for ACES with an exposure of 1.0, which is brighter than other
+                // operators, so it is scaled down first.
+                let x = x * 0.6;
+                (x * (2.51 * x + 0.03) / (x * (2.43 * x + 0.59) + 0.14)).clamp(0.0, 1.0)
+            }
+            Self::Hable => {
+                let curve = |x: f32| {
+                    let (a, b, c, d, e, f) = (0.15, 0.5, 0.1, 0.2, 0.02, 0.3);
+                    (x * (a * x + c * b) + d * e) / (x * (a * x + b) + d * f) - e / f
+                };
+                // Linear white is the input which is mapped to 1.0.
+                let white = 11.2;
+                (curve(x * 2.0) / curve(white)).min(1.0)
+            }
+        }
+    }
+}
+
+impl ColorF {
+    /// Applies `f` to every channel.
+    fn map(&self, f: fn(f32) -> f32) -> Self {
+        Self::new(f(self.r), f(self.g), f(self.b))
+    }
+
+    /// Decodes sRGB channels to linear light, the same as
+    /// [`to_linear_rgb()`](Color#method.to_linear_rgb). Channels outside of 0.0 - 1.0 are
+    /// extended symmetrically, so HDR and wide gamut colors keep their values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ColorF};
+    ///
+    /// let (r, g, b) = Color::new(100, 150, 200).to_linear_rgb();
+    /// let linear = ColorF::from(Color::new(100, 150, 200)).srgb_to_linear();
+    ///
+    /// assert!((linear.r - r).abs() < 0.0001 && (linear.b - b).abs() < 0.0001);
+    /// ```
+    pub fn srgb_to_linear(&self) -> Self {
+        self.map(|v| v.signum() * srgb_to_linear(v.abs()))
+    }
+
+    /// Encodes linear light to sRGB channels, the inverse of
+    /// [`srgb_to_linear()`](ColorF::srgb_to_linear).
+    pub fn linear_to_srgb(&self) -> Self {
+        self.map(|v| v.signum() * linear_to_srgb(v.abs()))
+    }
+
+    /// Decodes SMPTE ST 2084 (PQ) signal values, as used by HDR10, to linear light. Linear
+    /// values of 1.0 are SDR reference white (203 cd/m²), so the peak of PQ (10000 cd/m²) is
+    /// about 49.3. Only the transfer function is converted, so the channels still have the
+    /// primaries of the signal, which are usually Rec. 2020.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::ColorF;
+    ///
+    /// let white = ColorF::new(0.58, 0.58, 0.58).pq_to_linear();
+    ///
+    /// assert!((white.r - 1.0).abs() < 0.01);
+    /// ```
+    pub fn pq_to_linear(&self) -> Self {
+        self.map(pq_to_linear)
+    }
+
+    /// Encodes linear light, where 1.0 is SDR reference white, to SMPTE ST 2084 (PQ) signal
+    /// values. This is the inverse of [`pq_to_linear()`](ColorF::pq_to_linear), and values
+    /// brighter than the peak of PQ are clamped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::ColorF;
+    ///
+    /// let highlight = ColorF::new(4.0, 2.0, 1.0);
+    ///
+    /// assert!(highlight.linear_to_pq().r < 1.0);
+    /// assert!((highlight.linear_to_pq().pq_to_linear().r - 4.0).abs() < 0.001);
+    /// ```
+    pub fn linear_to_pq(&self) -> Self {
+        self.map(linear_to_pq)
+    }
+
+    /// Decodes Hybrid Log-Gamma (HLG) signal values, as used by HDR broadcasts, to linear scene
+    /// light. Linear values of 1.0 are reference white, which is at 75% of the signal, so the
+    /// peak of HLG is about 3.8. Only the transfer function is converted, so the channels still
+    /// have the primaries of the signal, which are usually Rec. 2020.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::ColorF;
+    ///
+    /// let white = ColorF::new(0.75, 0.75, 0.75).hlg_to_linear();
+    ///
+    /// assert!((white.r - 1.0).abs() < 0.001);
+    /// ```
+    pub fn hlg_to_linear(&self) -> Self {
+        self.map(hlg_to_linear)
+    }
+
+    /// Encodes linear scene light, where 1.0 is reference white, to Hybrid Log-Gamma (HLG)
+    /// signal values. This is the inverse of [`hlg_to_linear()`](ColorF::hlg_to_linear), and
+    /// values brighter than the peak of HLG are clamped.
+    pub fn linear_to_hlg(&self) -> Self {
+        self.map(linear_to_hlg)
+    }
+
+    /// Compresses HDR linear light, where 1.0 is reference white, into sRGB channels between 0.0
+    /// and 1.0 with `mapper`, so it can be converted to [`Color`] without clipping highlights.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ColorF, ToneMapper};
+    ///
+    /// let sun = ColorF::new(20.0, 16.0, 8.0);
+    /// let mapped = sun.tonemap(ToneMapper::Aces);
+    ///
+    /// assert!(mapped.r <= 1.0 && mapped.r > mapped.b);
+    /// let black = ColorF::new(0.0, 0.0, 0.0).tonemap(ToneMapper::Hable);
+    /// assert_eq!(Color::from(black), Color::new(0, 0, 0));
+    /// ```
+    pub fn tonemap(&self, mapper: ToneMapper) -> Self {
+        let f = |v: f32| linear_to_srgb(mapper.map(v));
+        Self::new(f(self.r), f(self.g), f(self.b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pq() {
+        assert_eq!(pq_to_linear(0.0), 0.0);
+        assert!((pq_to_linear(1.0) * REFERENCE_WHITE - PQ_PEAK).abs() < 1.0);
+        assert!((linear_to_pq(1.0) - 0.5807).abs() < 0.001);
+        // 100 cd/m² is at about 50.8% of the signal.
+        assert!((linear_to_pq(100.0 / REFERENCE_WHITE) - 0.508).abs() < 0.001);
+        for i in 0..=100 {
+            let v = i as f32 / 100.0;
+            assert!((linear_to_pq(pq_to_linear(v)) - v).abs() < 0.001, "{v}");
+        }
+        assert_eq!(linear_to_pq(100.0), 1.0);
+        assert_eq!(linear_to_pq(-1.0), linear_to_pq(0.0));
+    }
+
+    #[test]
+    fn hlg() {
+        assert_eq!(hlg_to_linear(0.0), 0.0);
+        assert!((hlg_to_linear(0.75) - 1.0).abs() < 0.0001);
+        assert!((hlg_to_linear(1.0) * HLG_REFERENCE_WHITE - 1.0).abs() < 0.0001);
+        assert!((hlg_to_linear(0.5) * HLG_REFERENCE_WHITE - 1.0 / 12.0).abs() < 0.0001);
+        for i in 0..=100 {
+            let v = i as f32 / 100.0;
+            assert!((linear_to_hlg(hlg_to_linear(v)) - v).abs() < 0.0001, "{v}");
+        }
+    }
+
+    #[test]
+    fn tonemap() {
+        for mapper in [ToneMapper::Reinhard, ToneMapper::Aces, ToneMapper::Hable] {
+            let mut previous = 0.0;
+            for i in 1..=1000 {
+                let v = mapper.map(i as f32 / 10.0);
+                assert!(v >= previous && v <= 1.0, "{mapper:?}: {i}");
+                previous = v;
+            }
+            assert!(mapper.map(0.0) < 0.000_001, "{mapper:?}");
+            assert_eq!(mapper.map(-1.0), mapper.map(0.0), "{mapper:?}");
+            let mapped = ColorF::new(100.0, 1.0, 0.1).tonemap(mapper);
+            assert!(mapped.r <= 1.0 && mapped.r > mapped.g && mapped.g > mapped.b);
+        }
+        assert_eq!(ToneMapper::Reinhard.map(1.0), 0.5);
+        assert!((ToneMapper::Hable.map(11.2 / 2.0) - 1.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn srgb() {
+        let color = ColorF::new(1.5, -0.2, 0.5);
+        let back = color.srgb_to_linear().linear_to_srgb();
+        assert!((back.r - color.r).abs() < 0.0001);
+        assert!((back.g - color.g).abs() < 0.0001);
+        assert!((back.b - color.b).abs() < 0.0001);
+        assert_eq!(
+            ColorF::new(1.0, 0.0, 1.0).srgb_to_linear(),
+            ColorF::new(1.0, 0.0, 1.0)
+        );
+    }
+}
@@ -0,0 +1,23 @@
+//! Conversions between [`Color`](crate::Color) and the color types of other crates, each behind
+//! a feature of the same name as the crate (except for `palette-interop`).
+
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "bevy")]
+mod bevy;
+#[cfg(feature = "crossterm")]
+mod crossterm;
+#[cfg(feature = "egui")]
+mod egui;
+#[cfg(feature = "image")]
+mod image;
+#[cfg(feature = "macroquad")]
+mod macroquad;
+#[cfg(feature = "palette-interop")]
+mod palette;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "termcolor")]
+mod termcolor;
@@ -0,0 +1,86 @@
+use crate::{hue_distance, Hsl, Hsv, Lab, Oklch};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+// Hues are compared by their distance around the chromatic circle with an absolute tolerance in
+// every trait, as the relative size of a rotation has no meaning.
+macro_rules! impl_approx {
+    ($model:ident, [$($field:ident),*], [$($hue:ident),*]) => {
+        impl AbsDiffEq for $model {
+            type Epsilon = f32;
+
+            fn default_epsilon() -> Self::Epsilon {
+                f32::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                $(self.$field.abs_diff_eq(&other.$field, epsilon) &&)*
+                $(hue_distance(self.$hue, other.$hue) <= epsilon &&)*
+                true
+            }
+        }
+
+        impl RelativeEq for $model {
+            fn default_max_relative() -> Self::Epsilon {
+                f32::default_max_relative()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                $(self.$field.relative_eq(&other.$field, epsilon, max_relative) &&)*
+                $(hue_distance(self.$hue, other.$hue) <= epsilon &&)*
+                true
+            }
+        }
+
+        impl UlpsEq for $model {
+            fn default_max_ulps() -> u32 {
+                f32::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                $(self.$field.ulps_eq(&other.$field, epsilon, max_ulps) &&)*
+                $(hue_distance(self.$hue, other.$hue) <= epsilon &&)*
+                true
+            }
+        }
+    };
+}
+
+impl_approx!(Hsl, [s, l], [h]);
+impl_approx!(Hsv, [s, v], [h]);
+impl_approx!(Lab, [l, a, b], []);
+impl_approx!(Oklch, [l, c], [h]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+    use approx::{assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq, assert_ulps_eq};
+
+    #[test]
+    fn approx() {
+        let color = Color::new(100, 150, 200);
+        assert_abs_diff_eq!(
+            color.hsl(),
+            Hsl::new(0.5833, 0.4762, 0.5882),
+            epsilon = 1e-4
+        );
+        assert_abs_diff_ne!(color.hsl(), Hsl::new(0.59, 0.4762, 0.5882), epsilon = 1e-4);
+        assert_relative_eq!(
+            color.lab(),
+            Lab::new(60.5, -2.8, -30.9),
+            max_relative = 0.01
+        );
+        assert_ulps_eq!(color.hsv(), color.hsv());
+        assert_abs_diff_eq!(
+            Hsv::new(0.999, 1.0, 1.0),
+            Hsv::new(0.001, 1.0, 1.0),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(Oklch::new(0.5, 0.1, 0.0), Oklch::new(0.5, 0.1, 1.0));
+    }
+}
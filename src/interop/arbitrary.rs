@@ -0,0 +1,26 @@
+use crate::Color;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Generates any color, taking one byte of input per channel.
+impl<'a> Arbitrary<'a> for Color {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self(u.arbitrary()?, u.arbitrary()?, u.arbitrary()?))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (3, Some(3))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arbitrary() {
+        let mut u = Unstructured::new(&[255, 128, 0, 1]);
+        assert_eq!(Color::arbitrary(&mut u).unwrap(), Color::new(255, 128, 0));
+        assert_eq!(u.len(), 1);
+        assert_eq!(Color::size_hint(0), (3, Some(3)));
+    }
+}
@@ -0,0 +1,50 @@
+use crate::Color;
+use bevy_color::{ColorToPacked, Srgba};
+
+/// Converts to [`Color`] through sRGB, discarding the alpha channel and clamping colors which fall
+/// outside of the RGB gamut.
+impl From<bevy_color::Color> for Color {
+    fn from(color: bevy_color::Color) -> Self {
+        Self::from(color.to_srgba())
+    }
+}
+
+impl From<Color> for bevy_color::Color {
+    fn from(color: Color) -> Self {
+        Self::srgb_u8(color.0, color.1, color.2)
+    }
+}
+
+/// Converts to [`Color`], discarding the alpha channel and clamping channels which are out of
+/// range.
+impl From<Srgba> for Color {
+    fn from(color: Srgba) -> Self {
+        let [r, g, b] = color.to_u8_array_no_alpha();
+        Self(r, g, b)
+    }
+}
+
+/// Converts to a fully opaque color.
+impl From<Color> for Srgba {
+    fn from(color: Color) -> Self {
+        Self::rgb_u8(color.0, color.1, color.2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let color = Color::new(100, 150, 200);
+        assert_eq!(Color::from(bevy_color::Color::from(color)), color);
+        assert_eq!(Color::from(Srgba::from(color)), color);
+        let linear = bevy_color::Color::from(color).to_linear();
+        assert_eq!(Color::from(bevy_color::Color::from(linear)), color);
+        assert_eq!(
+            Color::from(bevy_color::Color::srgb(2.0, 0.0, -1.0)),
+            Color::new(255, 0, 0)
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{term::ColorLevel, Color};
+
+impl Color {
+    /// Converts to the closest [`crossterm::style::Color`] a terminal which supports `level` can
+    /// show. Requires the `crossterm` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use crossterm::style::Color as TermColor;
+    /// use octarine::{term::ColorLevel, Color};
+    ///
+    /// let orange = Color::new(255, 128, 0);
+    ///
+    /// assert_eq!(orange.to_crossterm(ColorLevel::Ansi256), TermColor::AnsiValue(208));
+    /// assert_eq!(orange.to_crossterm(ColorLevel::Ansi16), TermColor::Red);
+    /// ```
+    pub fn to_crossterm(&self, level: ColorLevel) -> crossterm::style::Color {
+        use crossterm::style::Color::*;
+        match level {
+            ColorLevel::Ansi16 => [
+                Black,
+                DarkRed,
+                DarkGreen,
+                DarkYellow,
+                DarkBlue,
+                DarkMagenta,
+                DarkCyan,
+                Grey,
+                DarkGrey,
+                Red,
+                Green,
+                Yellow,
+                Blue,
+                Magenta,
+                Cyan,
+                White,
+            ][self.to_ansi16() as usize],
+            ColorLevel::Ansi256 => AnsiValue(self.to_ansi256()),
+            ColorLevel::TrueColor => Rgb {
+                r: self.0,
+                g: self.1,
+                b: self.2,
+            },
+        }
+    }
+}
+
+/// Converts to [`crossterm::style::Color::Rgb`], which needs a terminal with truecolor support.
+impl From<Color> for crossterm::style::Color {
+    fn from(color: Color) -> Self {
+        color.to_crossterm(ColorLevel::TrueColor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::style::Color as TermColor;
+
+    #[test]
+    fn conversions() {
+        let color = Color::new(1, 2, 3);
+        assert_eq!(TermColor::from(color), TermColor::Rgb { r: 1, g: 2, b: 3 });
+        assert_eq!(color.to_crossterm(ColorLevel::Ansi16), TermColor::Black);
+        assert_eq!(
+            crate::constants::primary::WHITE.to_crossterm(ColorLevel::Ansi16),
+            TermColor::White
+        );
+        assert_eq!(
+            color.to_crossterm(ColorLevel::Ansi256),
+            TermColor::AnsiValue(232)
+        );
+    }
+}
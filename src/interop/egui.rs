@@ -0,0 +1,34 @@
+use crate::Color;
+use ecolor::Color32;
+
+/// Converts to [`Color`], discarding the alpha channel. As [`Color32`] is premultiplied, the
+/// color is unmultiplied first.
+impl From<Color32> for Color {
+    fn from(color: Color32) -> Self {
+        let [r, g, b, _] = color.to_srgba_unmultiplied();
+        Self(r, g, b)
+    }
+}
+
+/// Converts to a fully opaque color.
+impl From<Color> for Color32 {
+    fn from(color: Color) -> Self {
+        Self::from_rgb(color.0, color.1, color.2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let color = Color::new(100, 150, 200);
+        assert_eq!(Color::from(Color32::from(color)), color);
+        assert_eq!(Color32::from(color), Color32::from_rgb(100, 150, 200));
+        let translucent = Color32::from_rgba_unmultiplied(100, 150, 200, 128);
+        let unmultiplied = Color::from(translucent);
+        assert!(unmultiplied.get_red().abs_diff(100) <= 1);
+        assert!(unmultiplied.get_blue().abs_diff(200) <= 1);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::Color;
+use image::{Rgb, Rgba};
+
+impl From<Rgb<u8>> for Color {
+    fn from(Rgb([r, g, b]): Rgb<u8>) -> Self {
+        Self(r, g, b)
+    }
+}
+
+impl From<Color> for Rgb<u8> {
+    fn from(color: Color) -> Self {
+        Self([color.0, color.1, color.2])
+    }
+}
+
+/// Converts to [`Color`], discarding the alpha channel.
+impl From<Rgba<u8>> for Color {
+    fn from(Rgba([r, g, b, _]): Rgba<u8>) -> Self {
+        Self(r, g, b)
+    }
+}
+
+/// Converts to a fully opaque pixel.
+impl From<Color> for Rgba<u8> {
+    fn from(color: Color) -> Self {
+        Self([color.0, color.1, color.2, u8::MAX])
+    }
+}
+
+impl Color {
+    /// Views pixels of the [`image`] crate as colors without copying, e.g. the pixels of an
+    /// [`RgbImage`](image::RgbImage). Requires the `image` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use image::Rgb;
+    /// use octarine::Color;
+    ///
+    /// let pixels = [Rgb([255, 0, 0]), Rgb([0, 0, 255])];
+    ///
+    /// assert_eq!(
+    ///     Color::from_image_pixels(&pixels),
+    ///     &[Color::new(255, 0, 0), Color::new(0, 0, 255)]
+    /// );
+    /// ```
+    pub fn from_image_pixels(pixels: &[Rgb<u8>]) -> &[Self] {
+        // SAFETY: `Rgb<u8>` is `repr(transparent)` over `[u8; 3]`, which has the same layout as
+        // `Color`.
+        unsafe { core::slice::from_raw_parts(pixels.as_ptr().cast(), pixels.len()) }
+    }
+
+    /// Views colors as pixels of the [`image`] crate without copying. Requires the `image`
+    /// feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use image::Rgb;
+    /// use octarine::{constants, Color};
+    ///
+    /// let colors = [constants::primary::RED, constants::primary::BLUE];
+    ///
+    /// assert_eq!(Color::as_image_pixels(&colors), &[Rgb([255, 0, 0]), Rgb([0, 0, 255])]);
+    /// ```
+    pub fn as_image_pixels(colors: &[Self]) -> &[Rgb<u8>] {
+        // SAFETY: see `from_image_pixels()`.
+        unsafe { core::slice::from_raw_parts(colors.as_ptr().cast(), colors.len()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::RgbImage;
+
+    #[test]
+    fn conversions() {
+        let color = Color::new(10, 20, 30);
+        assert_eq!(Color::from(Rgb::from(color)), color);
+        assert_eq!(Rgba::from(color), Rgba([10, 20, 30, 255]));
+        assert_eq!(Color::from(Rgba([10, 20, 30, 0])), color);
+        let image = RgbImage::from_pixel(2, 1, Rgb([1, 2, 3]));
+        let pixels: Vec<_> = image.pixels().copied().collect();
+        assert_eq!(Color::from_image_pixels(&pixels), &[Color::new(1, 2, 3); 2]);
+        assert_eq!(
+            Color::as_image_pixels(Color::from_image_pixels(&pixels)),
+            &pixels[..]
+        );
+    }
+}
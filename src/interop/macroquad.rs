@@ -0,0 +1,34 @@
+use crate::Color;
+
+/// Converts to [`Color`], discarding the alpha channel and clamping channels which are out of
+/// range.
+impl From<macroquad::color::Color> for Color {
+    fn from(color: macroquad::color::Color) -> Self {
+        Self::from([color.r, color.g, color.b])
+    }
+}
+
+/// Converts to a fully opaque color.
+impl From<Color> for macroquad::color::Color {
+    fn from(color: Color) -> Self {
+        Self::from_rgba(color.0, color.1, color.2, u8::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use macroquad::color::{Color as MacroquadColor, SKYBLUE};
+
+    #[test]
+    fn conversions() {
+        let color = Color::new(100, 150, 200);
+        assert_eq!(Color::from(MacroquadColor::from(color)), color);
+        assert_eq!(MacroquadColor::from(color).a, 1.0);
+        assert_eq!(Color::from(SKYBLUE), Color::new(102, 191, 255));
+        assert_eq!(
+            Color::from(MacroquadColor::new(2.0, 0.0, -1.0, 0.5)),
+            Color::new(255, 0, 0)
+        );
+    }
+}
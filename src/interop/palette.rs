@@ -0,0 +1,87 @@
+use crate::Color;
+use palette_crate::{Hsl, LinSrgb, RgbHue, Srgb};
+
+impl From<Srgb<u8>> for Color {
+    fn from(color: Srgb<u8>) -> Self {
+        Self(color.red, color.green, color.blue)
+    }
+}
+
+impl From<Color> for Srgb<u8> {
+    fn from(color: Color) -> Self {
+        Self::new(color.0, color.1, color.2)
+    }
+}
+
+/// Converts to [`Color`], clamping channels which are out of range.
+impl From<Srgb> for Color {
+    fn from(color: Srgb) -> Self {
+        Self::from([color.red, color.green, color.blue])
+    }
+}
+
+impl From<Color> for Srgb {
+    fn from(color: Color) -> Self {
+        let (r, g, b) = color.to_rgb_float();
+        Self::new(r, g, b)
+    }
+}
+
+/// Converts to [`Color`], clamping channels which are out of range.
+impl From<LinSrgb> for Color {
+    fn from(color: LinSrgb) -> Self {
+        Self::from_linear_rgb(
+            color.red.clamp(0.0, 1.0),
+            color.green.clamp(0.0, 1.0),
+            color.blue.clamp(0.0, 1.0),
+        )
+    }
+}
+
+impl From<Color> for LinSrgb {
+    fn from(color: Color) -> Self {
+        let (r, g, b) = color.to_linear_rgb();
+        Self::new(r, g, b)
+    }
+}
+
+/// Converts to [`Color`], clamping the saturation and lightness when they are out of range.
+impl From<Hsl> for Color {
+    fn from(color: Hsl) -> Self {
+        Self::from_hsl_deg(
+            color.hue.into_positive_degrees(),
+            color.saturation.clamp(0.0, 1.0),
+            color.lightness.clamp(0.0, 1.0),
+        )
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        let (h, s, l) = color.to_hsl();
+        Self::new(RgbHue::from_degrees(h * 360.0), s, l)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use palette_crate::{FromColor, Oklch};
+
+    #[test]
+    fn conversions() {
+        let color = Color::new(100, 150, 200);
+        assert_eq!(Color::from(Srgb::<u8>::from(color)), color);
+        assert_eq!(Color::from(Srgb::<f32>::from(color)), color);
+        assert_eq!(Color::from(LinSrgb::<f32>::from(color)), color);
+        assert_eq!(Color::from(Hsl::from(color)), color);
+        assert_eq!(
+            Color::from(Srgb::new(2.0, 0.5, -1.0)),
+            Color::new(255, 128, 0)
+        );
+        let hsl: Hsl = Hsl::from_color(Srgb::<f32>::from(color));
+        assert!((hsl.hue.into_positive_degrees() - color.get_hsl_hue_degrees()).abs() < 1e-3);
+        let oklch: Oklch = Oklch::from_color(Srgb::<f32>::from(color));
+        assert!((oklch.l - color.to_oklch().0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::Color;
+use core::fmt::{self, Formatter};
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+/// Parses `#rgb` or `#rrggbb` (the `#` is optional), returning `None` for anything else.
+fn parse_hex(hex: &str) -> Option<Color> {
+    let digits = hex.strip_prefix('#').unwrap_or(hex);
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) || (digits.len() != 3 && digits.len() != 6) {
+        return None;
+    }
+    let rgb = u32::from_str_radix(digits, 16).ok()?;
+    if digits.len() == 3 {
+        let (r, g, b) = (rgb >> 8, (rgb >> 4) & 0xF, rgb & 0xF);
+        Some(Color::from_hex(
+            (r * 0x11) << 16 | (g * 0x11) << 8 | (b * 0x11),
+        ))
+    } else {
+        Some(Color::from_hex(rgb))
+    }
+}
+
+/// Serializes as a `#rrggbb` string, the same as
+/// [`to_css_string()`](Color#method.to_css_string).
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let mut hex = [b'#'; 7];
+        for (i, channel) in [self.0, self.1, self.2].into_iter().enumerate() {
+            hex[1 + i * 2] = DIGITS[(channel >> 4) as usize];
+            hex[2 + i * 2] = DIGITS[(channel & 0xF) as usize];
+        }
+        serializer.serialize_str(core::str::from_utf8(&hex).unwrap())
+    }
+}
+
+struct ColorVisitor;
+
+impl Visitor<'_> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("a #rgb or #rrggbb hex color, or a web color name")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Color, E> {
+        parse_hex(value)
+            .or_else(|| Color::from_web_color(value))
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+/// Deserializes from a `#rgb` or `#rrggbb` string, or the name of a web color (refer to
+/// [`from_web_color()`](Color#method.from_web_color)).
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ColorVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde() {
+        let color = Color::new(255, 128, 0);
+        assert_eq!(serde_json::to_string(&color).unwrap(), "\"#ff8000\"");
+        for json in ["\"#ff8000\"", "\"FF8000\"", "\"#f80\""] {
+            let parsed: Color = serde_json::from_str(json).unwrap();
+            assert!(parsed.approx_eq(&color, 0x11), "{json}");
+        }
+        assert_eq!(
+            serde_json::from_str::<Color>("\"teal\"").unwrap(),
+            Color::new(0, 128, 128)
+        );
+        assert!(serde_json::from_str::<Color>("\"#ff80\"").is_err());
+        assert!(serde_json::from_str::<Color>("\"+ff\"").is_err());
+        assert!(serde_json::from_str::<Color>("16744448").is_err());
+    }
+}
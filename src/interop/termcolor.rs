@@ -0,0 +1,70 @@
+use crate::{term::ColorLevel, Color};
+
+impl Color {
+    /// Converts to the closest [`termcolor::Color`] a terminal which supports `level` can show.
+    /// As [`termcolor::Color`] only names the 8 normal basic colors, the bright ones are given by
+    /// their index in the 256 color palette. Requires the `termcolor` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{term::ColorLevel, Color};
+    ///
+    /// let orange = Color::new(255, 128, 0);
+    ///
+    /// assert_eq!(orange.to_termcolor(ColorLevel::Ansi256), termcolor::Color::Ansi256(208));
+    /// assert_eq!(orange.to_termcolor(ColorLevel::Ansi16), termcolor::Color::Ansi256(9));
+    /// ```
+    pub fn to_termcolor(&self, level: ColorLevel) -> termcolor::Color {
+        use termcolor::Color::*;
+        match level {
+            ColorLevel::Ansi16 => match self.to_ansi16() {
+                0 => Black,
+                1 => Red,
+                2 => Green,
+                3 => Yellow,
+                4 => Blue,
+                5 => Magenta,
+                6 => Cyan,
+                7 => White,
+                index => Ansi256(index),
+            },
+            ColorLevel::Ansi256 => Ansi256(self.to_ansi256()),
+            ColorLevel::TrueColor => Rgb(self.0, self.1, self.2),
+        }
+    }
+}
+
+/// Converts to [`termcolor::Color::Rgb`], which needs a terminal with truecolor support.
+impl From<Color> for termcolor::Color {
+    fn from(color: Color) -> Self {
+        color.to_termcolor(ColorLevel::TrueColor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let color = Color::new(1, 2, 3);
+        assert_eq!(
+            termcolor::Color::from(color),
+            termcolor::Color::Rgb(1, 2, 3)
+        );
+        assert_eq!(
+            color.to_termcolor(ColorLevel::Ansi16),
+            termcolor::Color::Black
+        );
+        let blue = Color::new(0, 0, 230);
+        assert_eq!(
+            blue.to_termcolor(ColorLevel::Ansi16),
+            termcolor::Color::Blue
+        );
+        assert_eq!(
+            color.to_termcolor(ColorLevel::Ansi256),
+            termcolor::Color::Ansi256(232)
+        );
+    }
+}
@@ -0,0 +1,184 @@
+//! Output for addressable LED strips, such as WS2812 (NeoPixel) and APA102 (DotStar).
+//!
+//! The brightness of LEDs is linear in the value they are sent, so colors are gamma corrected to
+//! look like they do on screens. The brightness is limited by scaling the channels together after
+//! gamma correction, which keeps the hue of colors while saving power.
+//!
+//! # Example
+//!
+//! ```
+//! use octarine::{led, led::Led, Color};
+//!
+//! let led = Led::new().with_brightness(0.5);
+//! let strip = [Color::new(255, 128, 0); 3];
+//!
+//! let mut bytes = Vec::new();
+//! bytes.extend(led::APA102_START_FRAME);
+//! for color in strip {
+//!     bytes.extend(led.apa102(color));
+//! }
+//! bytes.extend(core::iter::repeat(0xFF).take(led::apa102_end_frame_len(strip.len())));
+//!
+//! assert_eq!(led.ws2812(strip[0]), [19, 128, 0]);
+//! ```
+
+use super::*;
+
+/// The gamma which is usually used for LEDs, as the default of [`Led`].
+pub const DEFAULT_GAMMA: f32 = 2.8;
+
+/// The frame which is sent before the LEDs of an APA102 strip.
+pub const APA102_START_FRAME: [u8; 4] = [0; 4];
+
+/// The maximum of the 5-bit global brightness of an APA102 LED.
+const APA102_MAX_LEVEL: u8 = 31;
+
+/// Gets the number of bytes (of `0xFF`) which need to be sent after the LEDs of an APA102 strip
+/// with `leds` LEDs, so that the data is clocked through to the last LED.
+pub const fn apa102_end_frame_len(leds: usize) -> usize {
+    leds.div_ceil(16)
+}
+
+/// Converts colors to the bytes sent to LEDs, with gamma correction and a brightness limit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Led {
+    gamma: f32,
+    brightness: f32,
+}
+
+impl Led {
+    /// Creates a converter with a gamma of [`DEFAULT_GAMMA`] and full brightness.
+    pub const fn new() -> Self {
+        Self {
+            gamma: DEFAULT_GAMMA,
+            brightness: 1.0,
+        }
+    }
+
+    /// Sets the gamma used to correct colors. Use 1.0 to send colors without correction.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `gamma` is <= 0.0.
+    pub fn with_gamma(mut self, gamma: f32) -> Self {
+        if gamma.is_nan() || gamma <= 0.0 {
+            panic!("Unsupported gamma: {gamma}");
+        }
+        self.gamma = gamma;
+        self
+    }
+
+    /// Sets the maximum brightness (0.0 - 1.0) of the LEDs, which scales every channel by the
+    /// same amount, so colors keep their hue.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `brightness` is NaN, < 0.0, or > 1.0.
+    pub fn with_brightness(mut self, brightness: f32) -> Self {
+        if brightness.is_nan() || out_of_range(brightness) {
+            panic!("Unsupported brightness: {brightness}");
+        }
+        self.brightness = brightness.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Gets the bytes of `color` for a WS2812 LED, which are in the order green, red, blue.
+    pub fn ws2812(&self, color: Color) -> [u8; 3] {
+        let [r, g, b] = self.scaled(color, self.brightness);
+        [g, r, b]
+    }
+
+    /// Gets the frame of `color` for an APA102 LED, which is the 5-bit global brightness (with
+    /// the 3 high bits set), then blue, green, and red. The brightness limit is applied with the
+    /// global brightness where possible, so dim colors keep more of their precision.
+    pub fn apa102(&self, color: Color) -> [u8; 4] {
+        let level = (self.brightness * APA102_MAX_LEVEL as f32).ceil() as u8;
+        if level == 0 {
+            return [0xE0, 0, 0, 0];
+        }
+        let scale = self.brightness * APA102_MAX_LEVEL as f32 / level as f32;
+        let [r, g, b] = self.scaled(color, scale);
+        [0xE0 | level, b, g, r]
+    }
+
+    fn scaled(&self, color: Color, scale: f32) -> [u8; 3] {
+        let (r, g, b) = color.to_rgb_float();
+        [r, g, b].map(|value| (value.powf(self.gamma) * scale * 255.0).round() as u8)
+    }
+}
+
+impl Default for Led {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ws2812() {
+        let led = Led::new();
+        assert_eq!(led.ws2812(Color::new(255, 0, 0)), [0, 255, 0]);
+        assert_eq!(led.ws2812(Color::new(0, 0, 255)), [0, 0, 255]);
+        assert_eq!(led.ws2812(Color::new(128, 128, 128)), [37, 37, 37]);
+        assert_eq!(
+            led.with_gamma(1.0).ws2812(Color::new(10, 20, 30)),
+            [20, 10, 30]
+        );
+        assert_eq!(
+            led.with_brightness(0.0).ws2812(constants::primary::WHITE),
+            [0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn brightness() {
+        let color = Color::new(255, 200, 100);
+        let full = Led::new().with_gamma(1.0).ws2812(color);
+        let half = Led::new()
+            .with_gamma(1.0)
+            .with_brightness(0.5)
+            .ws2812(color);
+        for (full, half) in full.into_iter().zip(half) {
+            assert!((full as f32 / 2.0 - half as f32).abs() <= 0.5);
+        }
+        let dim = Color::new(half[1], half[0], half[2]);
+        assert!((dim.get_hsl_hue() - color.get_hsl_hue()).abs() < 0.01);
+    }
+
+    #[test]
+    fn apa102() {
+        let led = Led::new();
+        assert_eq!(led.apa102(Color::new(255, 0, 0)), [0xFF, 0, 0, 255]);
+        assert_eq!(
+            led.with_brightness(0.5).apa102(Color::new(0, 255, 0)),
+            [0xF0, 0, 247, 0]
+        );
+        assert_eq!(
+            led.with_brightness(0.0).apa102(Color::new(0, 255, 0)),
+            [0xE0, 0, 0, 0]
+        );
+        assert_eq!(
+            led.with_brightness(1.0 + f32::EPSILON)
+                .apa102(Color::new(255, 0, 0)),
+            [0xFF, 0, 0, 255]
+        );
+        assert_eq!(apa102_end_frame_len(0), 0);
+        assert_eq!(apa102_end_frame_len(16), 1);
+        assert_eq!(apa102_end_frame_len(17), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported brightness")]
+    fn out_of_range() {
+        Led::new().with_brightness(1.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported brightness: NaN")]
+    fn nan_brightness() {
+        Led::new().with_brightness(f32::NAN);
+    }
+}
@@ -26,6 +26,40 @@
 //! - Perform arithmetic, blend modes, and generate random colors within boundaries.
 //! - [`Octarine`](constants::OCTARINE).
 //!
+//! # Crate features
+//!
+//! - `std` (default): Enables `alloc` and the randomly seeded generators ([`Color::random_color()`],
+//!   [`ColorWheel`], and [`RandomColor`]).
+//! - `alloc`: Enables everything which needs to allocate, such as [`Palette`], [`Gradient`], and
+//!   [`ColorRange`].
+//! - `rand` (default): Enables random colors with a [`rand::Rng`] you provide.
+//! - `libm`: Uses [libm](https://docs.rs/libm) for floating point math, which is needed when
+//!   `std` is disabled.
+//! - `rayon`: Enables the parallel versions of palette extraction, quantization, and batch Delta E
+//!   (the functions ending in `_par`).
+//! - `simd`: Vectorizes the batch conversions between RGB and HSL or HSV in [`convert`].
+//! - `bytemuck`: Implements `Pod` of [bytemuck](https://docs.rs/bytemuck) for [`Color`],
+//!   [`Color16`], and [`ColorF`].
+//! - `image`: Converts between [`Color`] and the pixels of the [image](https://docs.rs/image)
+//!   crate.
+//! - `palette-interop`: Converts between [`Color`] and the `Srgb`, `LinSrgb`, and `Hsl` types of
+//!   the [palette](https://docs.rs/palette) crate.
+//! - `bevy`, `egui`, and `macroquad`: Convert between [`Color`] and the color types of these
+//!   engines (`bevy::color::Color` and `Srgba`, `egui::Color32`, and `macroquad::color::Color`).
+//! - `crossterm` and `termcolor`: Convert [`Color`] to the terminal colors of these crates,
+//!   choosing the closest color a terminal supports (refer to [`term::ColorLevel`]).
+//! - `approx`: Implements the traits of [approx](https://docs.rs/approx) for [`Hsl`], [`Hsv`],
+//!   [`Lab`], and [`Oklch`], for comparing them with a tolerance.
+//! - `arbitrary`: Implements `Arbitrary` of [arbitrary](https://docs.rs/arbitrary) for [`Color`],
+//!   for fuzzing.
+//! - `proptest`: Adds strategies for property testing with [proptest](https://docs.rs/proptest)
+//!   (refer to the `proptest` module).
+//! - `wasm`: Exports functions for JavaScript with `wasm-bindgen` (refer to the `wasm` module).
+//!   The crate also builds for `wasm32-unknown-unknown` without this feature.
+//!
+//! Without `std`, this crate is `no_std`, so [`Color`] and its conversions and arithmetic can be
+//! used on embedded targets with `default-features = false, features = ["libm"]`.
+//!
 //! # Examples
 //!
 //! ```
@@ -57,53 +91,229 @@
 //! [^note]: Bri'ish amirite. Sorry, I use British English myself. But just to keep things consistent,
 //! I will be using American English from now on.
 
-use rand::{prelude::SmallRng, Rng, SeedableRng};
-use std::{
-    fmt::Debug,
-    ops::{Add, Div, Mul, Sub},
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(all(not(feature = "std"), not(feature = "libm")))]
+compile_error!(
+    "either the `std` or the `libm` feature needs to be enabled for floating point math"
+);
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::iter::FusedIterator;
+use core::{
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
 };
+#[cfg(not(feature = "std"))]
+// Tests link `std`, whose inherent float methods are used instead.
+#[cfg_attr(test, allow(unused_imports))]
+use math::Float;
+#[cfg(feature = "rand")]
+use rand::{rngs::SmallRng, Rng, SeedableRng};
 
+mod adaptation;
+#[cfg(feature = "std")]
+mod assigner;
+#[cfg(feature = "alloc")]
+mod audit;
+mod color_matrix;
+pub mod colormap;
 pub mod constants;
+pub mod convert;
+mod cvd;
+mod cycle;
+mod easing;
+mod gamut;
+#[cfg(feature = "alloc")]
+mod gradient;
+mod hdr;
+mod interop;
+pub mod led;
+mod lut;
+#[cfg(not(feature = "std"))]
+mod math;
+mod models;
+mod munsell;
+#[cfg(feature = "alloc")]
+mod palette;
+mod precision;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "spot-colors")]
+mod reference;
+mod ryb;
+mod scheme;
+mod spectrum;
+#[cfg(feature = "alloc")]
+mod stats;
+mod temperature;
+pub mod term;
+#[cfg(feature = "alloc")]
+mod theme;
+mod tween;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod web_color;
+
+pub use adaptation::{ChromaticAdaptation, Illuminant};
+#[cfg(feature = "std")]
+pub use assigner::ColorAssigner;
+#[cfg(feature = "alloc")]
+pub use audit::{AuditReport, ContrastFailure, ContrastGrid, DistinguishabilityFailure, WcagLevel};
+pub use color_matrix::{ColorMatrix, Filter};
+pub use cvd::CvdType;
+pub use cycle::HueCycle;
+pub use easing::Easing;
+pub use gamut::{GamutMapping, RgbSpace};
+#[cfg(feature = "alloc")]
+pub use gradient::{DitherMode, Gradient, Interpolation};
+pub use hdr::ToneMapper;
+pub use lut::{linear_to_srgb_u8, srgb_to_linear_u8};
+pub use models::{Hsl, Hsv, Lab, Oklch};
+pub use munsell::{Munsell, MunsellError};
+#[cfg(feature = "alloc")]
+pub use palette::{Palette, SortBy};
+pub use precision::{Color16, ColorF};
+#[cfg(all(feature = "rand", feature = "std"))]
+pub use random::RandomColor;
+#[cfg(feature = "rand")]
+pub use random::UniformColor;
+#[cfg(feature = "spot-colors")]
+pub use reference::ReferenceSet;
+pub use scheme::Scheme;
+#[cfg(feature = "alloc")]
+pub use stats::ColorStats;
+pub use temperature::TemperatureClass;
+#[cfg(feature = "alloc")]
+pub use theme::{Theme, ThemeError};
+pub use tween::Tween;
+pub use web_color::{WebColor, WebColorError};
 
 macro_rules! test_color_value_range {
     ($r:expr, $g:expr, $b:expr) => {
-        let mut range_error = false;
-        let mut bad_component_string = String::new();
-        if $r + f32::EPSILON < 0.0 || $r - f32::EPSILON > 1.0 {
-            range_error = true;
-            bad_component_string += " Red";
-        }
-        if $g + f32::EPSILON < 0.0 || $g - f32::EPSILON > 1.0 {
-            range_error = true;
-            bad_component_string += " Green";
-        }
-        if $b + f32::EPSILON < 0.0 || $b - f32::EPSILON > 1.0 {
-            range_error = true;
-            bad_component_string += " Blue";
-        }
-        if range_error {
-            panic!("Color parameter outside of expected range:{bad_component_string}");
+        let red = out_of_range($r);
+        let green = out_of_range($g);
+        let blue = out_of_range($b);
+        if red || green || blue {
+            panic!(
+                "Color parameter outside of expected range:{}{}{}",
+                if red { " Red" } else { "" },
+                if green { " Green" } else { "" },
+                if blue { " Blue" } else { "" },
+            );
         }
     };
     ($s:expr, $x:expr) => {
-        let mut range_error = false;
-        let mut bad_component_string = String::new();
-        if $s + f32::EPSILON < 0.0 || $s - f32::EPSILON > 1.0 {
-            range_error = true;
-            bad_component_string += " Saturation";
-        }
-        if $x + f32::EPSILON < 0.0 || $x - f32::EPSILON > 1.0 {
-            range_error = true;
-            bad_component_string += " Lightness/Value";
-        }
-        if range_error {
-            panic!("Color parameter outside of expected range:{bad_component_string}");
+        let saturation = out_of_range($s);
+        let other = out_of_range($x);
+        if saturation || other {
+            panic!(
+                "Color parameter outside of expected range:{}{}",
+                if saturation { " Saturation" } else { "" },
+                if other { " Lightness/Value" } else { "" },
+            );
         }
     };
 }
 
-/// Color type used to convert and manipulate colors.
-#[derive(Clone)]
+/// Creates a [`Color`] from RGB values (0 - 255) at compile time, which can be used in `const`
+/// and `static` items.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{rgb, Color};
+///
+/// const ORANGE: Color = rgb!(255, 153, 0);
+///
+/// assert_eq!(ORANGE, Color::new(255, 153, 0));
+/// ```
+///
+/// Values outside of the range fail to compile.
+///
+/// ```compile_fail
+/// let color = octarine::rgb!(256, 0, 0);
+/// ```
+#[macro_export]
+macro_rules! rgb {
+    ($r:expr, $g:expr, $b:expr $(,)?) => {{
+        const COLOR: $crate::Color = $crate::Color::new($r, $g, $b);
+        COLOR
+    }};
+}
+
+/// Creates a [`Color`] from a hex string such as `"#ff9900"` or `"f90"` at compile time, which can
+/// be used in `const` and `static` items. The leading `#` is optional, and both the 6 digit and
+/// the 3 digit short forms are supported.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{hex, Color};
+///
+/// const ORANGE: Color = hex!("#ff9900");
+///
+/// assert_eq!(ORANGE, Color::from_hex(0xFF9900));
+/// assert_eq!(hex!("F90"), ORANGE);
+/// ```
+///
+/// Invalid strings fail to compile.
+///
+/// ```compile_fail
+/// let color = octarine::hex!("#ff99zz");
+/// ```
+#[macro_export]
+macro_rules! hex {
+    ($hex:expr $(,)?) => {{
+        const COLOR: $crate::Color = $crate::__parse_hex($hex);
+        COLOR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn __parse_hex(hex: &str) -> Color {
+    let bytes = hex.as_bytes();
+    let start = if !bytes.is_empty() && bytes[0] == b'#' {
+        1
+    } else {
+        0
+    };
+    let len = bytes.len() - start;
+    if len != 3 && len != 6 {
+        panic!("Unsupported hex color: expected 3 or 6 hex digits");
+    }
+    let mut rgb = 0u32;
+    let mut i = start;
+    while i < bytes.len() {
+        let digit = match bytes[i] {
+            b'0'..=b'9' => bytes[i] - b'0',
+            b'a'..=b'f' => bytes[i] - b'a' + 10,
+            b'A'..=b'F' => bytes[i] - b'A' + 10,
+            _ => panic!("Unsupported hex color: invalid hex digit"),
+        } as u32;
+        rgb = if len == 3 {
+            (rgb << 8) | (digit << 4) | digit
+        } else {
+            (rgb << 4) | digit
+        };
+        i += 1;
+    }
+    Color::from_hex(rgb)
+}
+
+/// Color type used to convert and manipulate colors. The default color is black.
+///
+/// A color is laid out in memory as its R, G, and B values, so a `&[Color]` is the same as a
+/// packed RGB buffer (refer to [`as_bytes()`](Color#method.as_bytes)).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(C)]
 pub struct Color(u8, u8, u8);
 
 impl Color {
@@ -118,7 +328,7 @@ impl Color {
     /// println!("{color:?}");
     /// ```
     #[inline]
-    pub fn new(r: u8, g: u8, b: u8) -> Self {
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
         Self(r, g, b)
     }
 
@@ -144,6 +354,52 @@ impl Color {
         Self(r, g, b)
     }
 
+    /// Create a color object from RGB floats (0.0 - 1.0), returning an error instead of
+    /// panicking when the R, G, or B values are < 0.0 or > 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ColorError, Component};
+    ///
+    /// assert_eq!(Color::try_from_rgb_float(0.0, 0.5, 0.0), Ok(Color::new(0, 128, 0)));
+    /// assert_eq!(
+    ///     Color::try_from_rgb_float(0.0, 1.5, 0.0),
+    ///     Err(ColorError::OutOfRange { component: Component::Green, value: 1.5 })
+    /// );
+    /// ```
+    pub fn try_from_rgb_float(r: f32, g: f32, b: f32) -> Result<Self, ColorError> {
+        check_range(Component::Red, r)?;
+        check_range(Component::Green, g)?;
+        check_range(Component::Blue, b)?;
+        Ok(Self::from_rgb_float(r, g, b))
+    }
+
+    /// Create a color object from linear RGB floats (0.0 - 1.0), i.e. RGB values without the
+    /// sRGB transfer function (gamma) applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the R, G, or B values are < 0.0 or > 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color = Color::from_linear_rgb(0.21404114, 0.21404114, 0.21404114);
+    ///
+    /// assert_eq!(color, Color::new(128, 128, 128));
+    /// ```
+    pub fn from_linear_rgb(r: f32, g: f32, b: f32) -> Self {
+        test_color_value_range!(r, g, b);
+        Self(
+            linear_to_srgb_u8(r),
+            linear_to_srgb_u8(g),
+            linear_to_srgb_u8(b),
+        )
+    }
+
     /// Creates a color object from hexadecimal (which is essentially an unsigned integer).
     ///
     /// Use the [`hex` crate](https://crates.io/crates/hex) if you want to convert a hex string
@@ -158,7 +414,7 @@ impl Color {
     ///
     /// assert_eq!(color, constants::primary::RED);
     /// ```
-    pub fn from_hex(rgb: u32) -> Self {
+    pub const fn from_hex(rgb: u32) -> Self {
         let r = ((rgb & 0xFFFFFF) >> 16) as u8;
         let g = ((rgb & 0xFFFF) >> 8) as u8;
         let b = (rgb & 0xFF) as u8;
@@ -188,21 +444,30 @@ impl Color {
     /// ```
     pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
         test_color_value_range!(s, l);
-        if s == 0.0 {
-            return Self::from_rgb_float(l, l, l);
-        }
-        let v2 = if l < 0.5 {
-            l * (1.0 + s)
-        } else {
-            (l + s) - (s * l)
-        };
-        let v1 = 2.0 * l - v2;
-        let r = hue_to_rgb(v1, v2, h + (1.0 / 3.0));
-        let g = hue_to_rgb(v1, v2, h);
-        let b = hue_to_rgb(v1, v2, h - (1.0 / 3.0));
+        let (r, g, b) = hsl_to_rgb_float(h, s, l);
         Self::from_rgb_float(r, g, b)
     }
 
+    /// Creates a color object from HSL values, returning an error instead of panicking when the
+    /// S and L values are < 0.0 or > 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ColorError, Component};
+    ///
+    /// assert_eq!(Color::try_from_hsl(0.0, 1.0, 0.5), Ok(Color::new(255, 0, 0)));
+    /// assert_eq!(
+    ///     Color::try_from_hsl(0.0, 1.0, -0.5),
+    ///     Err(ColorError::OutOfRange { component: Component::Lightness, value: -0.5 })
+    /// );
+    /// ```
+    pub fn try_from_hsl(h: f32, s: f32, l: f32) -> Result<Self, ColorError> {
+        check_range(Component::Saturation, s)?;
+        check_range(Component::Lightness, l)?;
+        Ok(Self::from_hsl(h, s, l))
+    }
+
     /// Creates a color object from HSV values.
     ///
     /// # Panics
@@ -226,474 +491,2438 @@ impl Color {
     /// ```
     pub fn from_hsv(h: f32, s: f32, v: f32) -> Self {
         test_color_value_range!(s, v);
-        if s == 0.0 {
-            return Self::from_rgb_float(v, v, v);
-        }
-        let mut v_h = h * 6.0;
-        if v_h == 6.0 {
-            v_h = 0.0;
-        }
-        let v_i = v_h.floor();
-        let v1 = v * (1.0 - s);
-        let v2 = v * (1.0 - s * (v_h - v_i));
-        let v3 = v * (1.0 - s * (1.0 - (v_h - v_i)));
-        let (r, g, b) = if v_i == 0.0 {
-            (v, v3, v1)
-        } else if v_i == 1.0 {
-            (v2, v, v1)
-        } else if v_i == 2.0 {
-            (v1, v, v3)
-        } else if v_i == 3.0 {
-            (v1, v2, v)
-        } else if v_i == 4.0 {
-            (v3, v1, v)
-        } else {
-            (v, v1, v2)
-        };
+        let (r, g, b) = hsv_to_rgb_float(h, s, v);
         Self::from_rgb_float(r, g, b)
     }
 
-    /// Creates a color object from web colors. Returns `None` when the color cannot be found.
+    /// Creates a color object from HSV values, returning an error instead of panicking when the
+    /// S and V values are < 0.0 or > 1.0.
     ///
     /// # Example
     ///
     /// ```
-    /// use octarine::{Color, constants};
-    ///
-    /// let color = Color::from_web_color("red");
+    /// use octarine::{Color, ColorError, Component};
     ///
-    /// assert_eq!(color, Some(constants::primary::RED));
+    /// assert_eq!(Color::try_from_hsv(0.0, 1.0, 1.0), Ok(Color::new(255, 0, 0)));
+    /// assert_eq!(
+    ///     Color::try_from_hsv(0.0, 2.0, 1.0),
+    ///     Err(ColorError::OutOfRange { component: Component::Saturation, value: 2.0 })
+    /// );
     /// ```
-    pub fn from_web_color(name: &str) -> Option<Self> {
-        let name = name.to_lowercase();
-        constants::RGB_TO_COLOR_NAMES.get(&name).cloned()
+    pub fn try_from_hsv(h: f32, s: f32, v: f32) -> Result<Self, ColorError> {
+        check_range(Component::Saturation, s)?;
+        check_range(Component::Value, v)?;
+        Ok(Self::from_hsv(h, s, v))
     }
 
+    /// Creates a color object from HSL values, where the hue is in degrees (0 - 360) as in CSS
+    /// and most design tools. Any hue outside of this range wraps around the chromatic circle.
+    ///
+    /// # Panics
+    ///
+    /// Panics when S and L values are < 0.0 or > 1.0.
+    ///
     /// # Example
     ///
     /// ```
-    /// use octarine::Color;
+    /// use octarine::{Color, constants};
     ///
-    /// let color1 = Color::from_hex(0xFF9D9D);
-    /// let color2 = Color::from_hex(0xFF9999).screen(Color::new(10, 10, 10));
+    /// assert_eq!(Color::from_hsl_deg(120.0, 1.0, 0.5), constants::primary::GREEN);
+    /// assert_eq!(Color::from_hsl_deg(-120.0, 1.0, 0.5), constants::primary::BLUE);
+    /// ```
+    pub fn from_hsl_deg(h: f32, s: f32, l: f32) -> Self {
+        Self::from_hsl(degrees_to_hue(h), s, l)
+    }
+
+    /// Creates a color object from HSV values, where the hue is in degrees (0 - 360). Any hue
+    /// outside of this range wraps around the chromatic circle.
+    ///
+    /// # Panics
+    ///
+    /// Panics when S and V values are < 0.0 or > 1.0.
+    ///
+    /// # Example
     ///
-    /// assert_eq!(color1, color2);
     /// ```
-    pub fn screen(&self, other: Self) -> Self {
-        let r = 255 - (((255 - self.0) as usize * (255 - other.0) as usize) / 255) as u8;
-        let g = 255 - (((255 - self.1) as usize * (255 - other.1) as usize) / 255) as u8;
-        let b = 255 - (((255 - self.2) as usize * (255 - other.2) as usize) / 255) as u8;
-        Self(r, g, b)
+    /// use octarine::{Color, constants};
+    ///
+    /// assert_eq!(Color::from_hsv_deg(240.0, 1.0, 1.0), constants::primary::BLUE);
+    /// ```
+    pub fn from_hsv_deg(h: f32, s: f32, v: f32) -> Self {
+        Self::from_hsv(degrees_to_hue(h), s, v)
     }
 
+    /// Creates a color object from OKLab values, a perceptual color space where L is the
+    /// perceived lightness (0.0 - 1.0), and A and B describe how green/red and blue/yellow the
+    /// color is.
+    ///
+    /// # Note
+    ///
+    /// Not every OKLab color can be represented in RGB. Components that fall outside of the RGB
+    /// gamut are clamped.
+    ///
     /// # Example
     ///
     /// ```
-    /// use octarine::Color;
+    /// use octarine::{Color, constants};
     ///
-    /// let color1 = Color::from_hex(0xF58F8F);
-    /// let color2 = Color::from_hex(0xFF9999).difference(Color::new(10, 10, 10));
+    /// let color = Color::from_oklab(1.0, 0.0, 0.0);
     ///
-    /// assert_eq!(color1, color2);
+    /// assert_eq!(color, constants::primary::WHITE);
     /// ```
-    pub fn difference(&self, other: Self) -> Self {
-        let r = self.0.abs_diff(other.0);
-        let g = self.1.abs_diff(other.1);
-        let b = self.2.abs_diff(other.2);
-        Self(r, g, b)
+    pub fn from_oklab(l: f32, a: f32, b: f32) -> Self {
+        let (r, g, b) = oklab_to_linear_rgb(l, a, b);
+        Self::from_linear_rgb(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0))
     }
 
+    /// Creates a color object from OKLCH values, which is the polar form of OKLab. L is the
+    /// perceived lightness (0.0 - 1.0), C is the chroma, and H is the hue.
+    ///
+    /// # Note
+    ///
+    /// Just like [`from_hsl()`](Color#method.from_hsl), hue is a rotation around the chromatic
+    /// circle expressed as a value between 0 and 1. Colors that fall outside of the RGB gamut are
+    /// clamped.
+    ///
     /// # Example
     ///
     /// ```
     /// use octarine::Color;
     ///
-    /// let color1 = Color::new(255, 156, 156);
-    /// let color2 = Color::from_hex(0xFF9999).overlay(Color::new(10, 10, 10));
+    /// let (l, c, h) = Color::new(100, 150, 200).to_oklch();
+    /// let color = Color::from_oklch(l, c, h);
     ///
-    /// assert_eq!(color1, color2);
+    /// assert_eq!(color, Color::new(100, 150, 200));
     /// ```
-    #[inline]
-    pub fn overlay(&self, other: Self) -> Self {
-        self.screen(self.clone() * other)
+    pub fn from_oklch(l: f32, c: f32, h: f32) -> Self {
+        let (a, b) = oklch_to_oklab_ab(c, h);
+        Self::from_oklab(l, a, b)
     }
 
+    /// Creates a color object from CIE XYZ values (D65 white point, Y of white = 1.0).
+    ///
+    /// # Note
+    ///
+    /// Colors that fall outside of the RGB gamut are clamped.
+    ///
     /// # Example
     ///
     /// ```
-    /// use octarine::constants;
+    /// use octarine::{Color, constants};
     ///
-    /// let color = constants::primary::BLACK.invert();
+    /// let color = Color::from_xyz(0.95047, 1.0, 1.08883);
     ///
     /// assert_eq!(color, constants::primary::WHITE);
     /// ```
-    #[inline]
-    pub fn invert(&self) -> Self {
-        self.difference(Self(255, 255, 255))
+    pub fn from_xyz(x: f32, y: f32, z: f32) -> Self {
+        let (r, g, b) = xyz_to_linear_rgb(x, y, z);
+        Self::from_linear_rgb(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0))
     }
 
-    /// Get a random color.
+    /// Creates a color object from CIELAB values (D65 white point), where L is the lightness
+    /// (0.0 - 100.0).
+    ///
+    /// # Note
+    ///
+    /// Colors that fall outside of the RGB gamut are clamped.
+    ///
+    /// # Example
     ///
     /// ```
     /// use octarine::Color;
     ///
-    /// let random_color = Color::random_color();
-    /// println!("{random_color:?}");
+    /// let (l, a, b) = Color::new(100, 150, 200).to_lab();
+    /// let color = Color::from_lab(l, a, b);
+    ///
+    /// assert_eq!(color, Color::new(100, 150, 200));
     /// ```
-    pub fn random_color() -> Self {
-        let mut rng = rand::rngs::SmallRng::from_entropy();
-        let r: u8 = rng.gen();
-        let g: u8 = rng.gen();
-        let b: u8 = rng.gen();
-        Self(r, g, b)
+    pub fn from_lab(l: f32, a: f32, b: f32) -> Self {
+        let (x, y, z) = lab_to_xyz(l, a, b);
+        Self::from_xyz(x, y, z)
     }
 
-    /// Get the hexadecimal representation of a color.
-    ///
-    /// Use the [`hex` crate](https://crates.io/crates/hex) if you want to convert an integer
-    /// to a hex string.
+    /// Creates a color object from web colors, ignoring ASCII case. Returns `None` when the color
+    /// cannot be found. This doesn't allocate, so it can be called every frame.
     ///
     /// # Example
     ///
     /// ```
-    /// use octarine::Color;
+    /// use octarine::{Color, constants};
     ///
-    /// let hex = Color::new(100, 100, 100).to_hex();
+    /// let color = Color::from_web_color("red");
     ///
-    /// assert_eq!(hex, 0x646464);
+    /// assert_eq!(color, Some(constants::primary::RED));
     /// ```
-    pub fn to_hex(&self) -> u32 {
-        let b = (self.2 as u32) << 16;
-        let g = (self.1 as u32) << 8;
-        let r = self.0 as u32;
-        r | g | b
+    pub fn from_web_color(name: &str) -> Option<Self> {
+        constants::web_color(name)
     }
 
-    /// Converts a color to HSL.
+    /// Blends the current color with another color using the specified [`BlendMode`]. This is
+    /// useful when the blend mode is only known at runtime (e.g. user-configurable layers).
     ///
     /// # Example
     ///
     /// ```
-    /// use octarine::Color;
+    /// use octarine::{BlendMode, Color};
     ///
-    /// let hsl = Color::new(100, 100, 100).to_hsl();
+    /// let color1 = Color::from_hex(0xFF9D9D);
+    /// let color2 = Color::from_hex(0xFF9999).blend(&Color::new(10, 10, 10), BlendMode::Screen);
     ///
-    /// assert_eq!(hsl, (0.0, 0.0, 0.39215687));
+    /// assert_eq!(color1, color2);
     /// ```
-    pub fn to_hsl(&self) -> (f32, f32, f32) {
-        let v_min = std::cmp::min(std::cmp::min(self.0, self.1), self.2) as f32 / 255.0;
-        let v_max = std::cmp::max(std::cmp::max(self.0, self.1), self.2) as f32 / 255.0;
-        let diff = v_max - v_min;
-        let v_sum = v_min + v_max;
-        let l = v_sum / 2.0;
-        if diff < f32::EPSILON {
-            return (0.0, 0.0, l);
-        }
-        let s = if l < 0.5 {
-            diff / v_sum
-        } else {
-            diff / (2.0 - v_sum)
-        };
-        let (r, g, b) = self.to_rgb_float();
-        let dr = (((v_max - r) / 6.0) + (diff / 2.0)) / diff;
-        let dg = (((v_max - g) / 6.0) + (diff / 2.0)) / diff;
-        let db = (((v_max - b) / 6.0) + (diff / 2.0)) / diff;
-        let mut h = if r == v_max {
-            db - dg
-        } else if g == v_max {
-            (1.0 / 3.0) + dr - db
-        } else {
-            (2.0 / 3.0) + dg - dr
-        };
-        if h < 0.0 {
-            h += 1.0;
-        }
-        if h > 1.0 {
-            h -= 1.0;
-        }
-        (h, s, l)
+    pub fn blend(&self, other: &Self, mode: BlendMode) -> Self {
+        let r = blend_channel(self.0, other.0, mode);
+        let g = blend_channel(self.1, other.1, mode);
+        let b = blend_channel(self.2, other.2, mode);
+        Self(r, g, b)
     }
 
-    /// Converts a color to HSV.
+    /// Same as [`blend()`](Color#method.blend), but instead of blending the gamma-encoded sRGB
+    /// values directly, both colors are converted to linear RGB first, blended, and then converted
+    /// back. This gives physically correct results, while blending in gamma space tends to
+    /// produce colors that are darker or more washed out than expected.
     ///
     /// # Example
     ///
     /// ```
-    /// use octarine::Color;
+    /// use octarine::{BlendMode, Color};
     ///
-    /// let hsl = Color::from_hex(0x646464).to_hsv();
+    /// let gray = Color::new(128, 128, 128);
+    /// let color = gray.blend_linear(&gray, BlendMode::Multiply);
     ///
-    /// assert_eq!(hsl, (0.0, 0.0, 0.392156862745));
+    /// assert_eq!(color, Color::new(61, 61, 61));
     /// ```
-    pub fn to_hsv(&self) -> (f32, f32, f32) {
-        let v_min = std::cmp::min(std::cmp::min(self.0, self.1), self.2) as f32 / 255.0;
-        let v_max = std::cmp::max(std::cmp::max(self.0, self.1), self.2) as f32 / 255.0;
-        let diff = v_max - v_min;
-        let v = v_max;
-        if diff < f32::EPSILON {
-            return (0.0, 0.0, v);
-        }
-        let s = diff / v_max;
-        let (r, g, b) = self.to_rgb_float();
-        let dr = (((v_max - r) / 6.0) + (diff / 2.0)) / diff;
-        let dg = (((v_max - g) / 6.0) + (diff / 2.0)) / diff;
-        let db = (((v_max - b) / 6.0) + (diff / 2.0)) / diff;
-        let mut h = if r == v_max {
-            db - dg
-        } else if g == v_max {
-            (1.0 / 3.0) + dr - db
-        } else {
-            (2.0 / 3.0) + dg - dr
-        };
-        if h < 0.0 {
-            h += 1.0;
-        }
-        if h > 1.0 {
-            h -= 1.0;
-        }
-        (h, s, v)
+    pub fn blend_linear(&self, other: &Self, mode: BlendMode) -> Self {
+        let (r1, g1, b1) = self.to_linear_rgb();
+        let (r2, g2, b2) = other.to_linear_rgb();
+        let r = blend_channel_float(r1, r2, mode);
+        let g = blend_channel_float(g1, g2, mode);
+        let b = blend_channel_float(b1, b2, mode);
+        Self::from_linear_rgb(r, g, b)
     }
 
-    /// Converts a color back to its RGB representation.
+    /// Maps the luminance of the color onto a gradient from `dark` to `light`, so that black
+    /// becomes `dark` and white becomes `light`. Applied to every pixel of an image, this gives
+    /// the classic two-color poster look.
     ///
     /// # Example
     ///
     /// ```
-    /// use octarine::Color;
+    /// use octarine::{Color, constants};
     ///
-    /// let color = Color::new(100, 100, 100).to_rgb();
+    /// let (dark, light) = (Color::new(30, 20, 90), Color::new(250, 200, 60));
     ///
-    /// assert_eq!(color, (100, 100, 100));
+    /// assert_eq!(constants::primary::BLACK.duotone(&dark, &light), dark);
+    /// assert_eq!(constants::primary::WHITE.duotone(&dark, &light), light);
     /// ```
-    #[inline]
-    pub fn to_rgb(&self) -> (u8, u8, u8) {
-        (self.0, self.1, self.2)
+    pub fn duotone(&self, dark: &Self, light: &Self) -> Self {
+        lerp_rgb(dark, light, self.gray_level())
     }
 
-    /// Converts a color back to its RGB float representation.
+    /// Same as [`duotone()`](Color#method.duotone), but with a third color `mid` which colors
+    /// with a medium luminance are mapped to.
     ///
     /// # Example
     ///
     /// ```
-    /// use octarine::Color;
+    /// use octarine::{Color, constants};
     ///
-    /// let color = Color::new(0, 255, 0).to_rgb_float();
+    /// let dark = Color::new(20, 20, 60);
+    /// let mid = Color::new(200, 40, 80);
+    /// let light = Color::new(255, 240, 200);
     ///
-    /// assert_eq!(color, (0.0, 1.0, 0.0));
+    /// assert_eq!(Color::new(128, 128, 128).tritone(&dark, &mid, &light), Color::new(200, 41, 80));
+    /// assert_eq!(constants::primary::WHITE.tritone(&dark, &mid, &light), light);
     /// ```
-    #[inline]
-    pub fn to_rgb_float(&self) -> (f32, f32, f32) {
-        (
-            self.0 as f32 / 255.0,
-            self.1 as f32 / 255.0,
-            self.2 as f32 / 255.0,
-        )
+    pub fn tritone(&self, dark: &Self, mid: &Self, light: &Self) -> Self {
+        let t = self.gray_level();
+        if t < 0.5 {
+            lerp_rgb(dark, mid, t * 2.0)
+        } else {
+            lerp_rgb(mid, light, t * 2.0 - 1.0)
+        }
     }
 
-    /// Get the red value of RGB.
-    #[inline]
-    pub fn get_red(&self) -> u8 {
-        self.0
+    /// Reduces every channel to `levels` evenly spaced values, which gives flat bands of color
+    /// instead of smooth gradients.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `levels` is < 2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color = Color::new(100, 150, 200).posterize(3);
+    ///
+    /// assert_eq!(color, Color::new(128, 128, 255));
+    /// assert_eq!(Color::new(100, 150, 200).posterize(256), Color::new(100, 150, 200));
+    /// ```
+    pub fn posterize(&self, levels: u16) -> Self {
+        if levels < 2 {
+            panic!("Unsupported posterize levels: {levels}");
+        }
+        let steps = (levels - 1) as f32;
+        let posterize = |v: u8| {
+            let level = (v as f32 / 255.0 * steps).round();
+            (level / steps * 255.0).round() as u8
+        };
+        Self(posterize(self.0), posterize(self.1), posterize(self.2))
     }
 
-    /// Get the green value of RGB.
-    #[inline]
-    pub fn get_green(&self) -> u8 {
-        self.1
+    /// Turns the color into black or white, depending on whether its relative luminance (refer to
+    /// [`relative_luminance()`](Color#method.relative_luminance)) is below `cutoff` (0.0 - 1.0).
+    /// The luminance is compared as the sRGB value of the 8-bit gray with the same luminance, so
+    /// a cutoff of 0.5 splits the colors at middle gray rather than at 50% of the light.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `cutoff` is < 0.0 or > 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// assert_eq!(Color::new(255, 220, 0).threshold(0.5), constants::primary::WHITE);
+    /// assert_eq!(Color::new(40, 40, 200).threshold(0.5), constants::primary::BLACK);
+    /// ```
+    pub fn threshold(&self, cutoff: f32) -> Self {
+        if !(0.0..=1.0).contains(&cutoff) {
+            panic!("Unsupported threshold cutoff: {cutoff}");
+        }
+        if self.gray_level_u8() < cutoff {
+            constants::primary::BLACK
+        } else {
+            constants::primary::WHITE
+        }
     }
 
-    /// Get the blue value of RGB.
-    #[inline]
-    pub fn get_blue(&self) -> u8 {
-        self.2
+    /// Gets the sRGB value (0.0 - 1.0) of the gray with the same luminance as the color.
+    fn gray_level(&self) -> f32 {
+        linear_to_srgb(self.relative_luminance())
     }
 
-    /// Get the hue (H) of HSL.
+    /// Same as [`gray_level()`](Color::gray_level), but rounded to the nearest 8-bit gray, so
+    /// that grays compare exactly against their own value.
+    fn gray_level_u8(&self) -> f32 {
+        linear_to_srgb_u8(self.relative_luminance()) as f32 / 255.0
+    }
+
+    /// Scales the brightness of the color by `factor` in linear light, like dimming a light,
+    /// which keeps the hue and the ratios of the channels, unlike multiplying the sRGB channels.
+    /// When brightening, the color stops at the brightness where its brightest channel is fully
+    /// on, so it doesn't shift towards white.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `factor` is < 0.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let orange = Color::new(255, 128, 0);
+    ///
+    /// assert_eq!(orange.scale_brightness_preserve_hue(0.5), Color::new(188, 92, 0));
+    /// assert_eq!(orange.scale_brightness_preserve_hue(2.0), orange);
+    /// ```
+    pub fn scale_brightness_preserve_hue(&self, factor: f32) -> Self {
+        if factor.is_nan() || factor < 0.0 {
+            panic!("Unsupported brightness factor: {factor}");
+        }
+        let (r, g, b) = self.to_linear_rgb();
+        let max = r.max(g).max(b);
+        if max == 0.0 {
+            return *self;
+        }
+        let factor = factor.min(1.0 / max);
+        Self::from_linear_rgb(
+            (r * factor).min(1.0),
+            (g * factor).min(1.0),
+            (b * factor).min(1.0),
+        )
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color1 = Color::from_hex(0xFF9D9D);
+    /// let color2 = Color::from_hex(0xFF9999).screen(Color::new(10, 10, 10));
+    ///
+    /// assert_eq!(color1, color2);
+    /// ```
     #[inline]
-    pub fn get_hsl_hue(&self) -> f32 {
-        self.to_hsl().0
+    pub fn screen(&self, other: Self) -> Self {
+        self.blend(&other, BlendMode::Screen)
     }
 
-    /// Get the saturation (S) of HSL.
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color1 = Color::from_hex(0xF58F8F);
+    /// let color2 = Color::from_hex(0xFF9999).difference(Color::new(10, 10, 10));
+    ///
+    /// assert_eq!(color1, color2);
+    /// ```
     #[inline]
-    pub fn get_hsl_saturation(&self) -> f32 {
-        self.to_hsl().1
+    pub fn difference(&self, other: Self) -> Self {
+        self.blend(&other, BlendMode::Difference)
     }
 
-    /// Get the luminance (L) of HSL.
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color1 = Color::new(255, 156, 156);
+    /// let color2 = Color::from_hex(0xFF9999).overlay(Color::new(10, 10, 10));
+    ///
+    /// assert_eq!(color1, color2);
+    /// ```
     #[inline]
-    pub fn get_hsl_luminance(&self) -> f32 {
-        self.to_hsl().2
+    pub fn overlay(&self, other: Self) -> Self {
+        self.blend(&other, BlendMode::Overlay)
     }
 
-    /// Get the hue (H) of HSV.
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants;
+    ///
+    /// let color = constants::primary::BLACK.invert();
+    ///
+    /// assert_eq!(color, constants::primary::WHITE);
+    /// ```
     #[inline]
-    pub fn get_hsv_hue(&self) -> f32 {
-        self.to_hsv().0
+    pub fn invert(&self) -> Self {
+        self.difference(Self(255, 255, 255))
     }
 
-    /// Get the saturation (S) of HSV.
+    /// Applies `f` to each channel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color = Color::new(100, 150, 200).map(|c| c / 2);
+    ///
+    /// assert_eq!(color, Color::new(50, 75, 100));
+    /// ```
+    pub fn map(&self, f: impl Fn(u8) -> u8) -> Self {
+        Self(f(self.0), f(self.1), f(self.2))
+    }
+
+    /// Applies `f` to each channel of this color and the same channel of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color = Color::new(100, 150, 200).zip_map(&Color::new(50, 50, 50), |a, b| a - b);
+    ///
+    /// assert_eq!(color, Color::new(50, 100, 150));
+    /// ```
+    pub fn zip_map(&self, other: &Self, f: impl Fn(u8, u8) -> u8) -> Self {
+        Self(f(self.0, other.0), f(self.1, other.1), f(self.2, other.2))
+    }
+
+    /// Gets the minimum of each channel of two colors, which is the same as the darken blend mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color = Color::new(100, 0, 200).min(&Color::new(50, 50, 250));
+    ///
+    /// assert_eq!(color, Color::new(50, 0, 200));
+    /// ```
+    pub fn min(&self, other: &Self) -> Self {
+        self.zip_map(other, u8::min)
+    }
+
+    /// Gets the maximum of each channel of two colors, which is the same as the lighten blend
+    /// mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color = Color::new(100, 0, 200).max(&Color::new(50, 50, 250));
+    ///
+    /// assert_eq!(color, Color::new(100, 50, 250));
+    /// ```
+    pub fn max(&self, other: &Self) -> Self {
+        self.zip_map(other, u8::max)
+    }
+
+    /// Clamps each channel between the same channels of `low` and `high`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when any channel of `low` is greater than the same channel of `high`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let low = Color::new(50, 50, 50);
+    /// let high = Color::new(200, 200, 200);
+    ///
+    /// assert_eq!(Color::new(0, 100, 255).clamp(&low, &high), Color::new(50, 100, 200));
+    /// ```
+    pub fn clamp(&self, low: &Self, high: &Self) -> Self {
+        Self(
+            self.0.clamp(low.0, high.0),
+            self.1.clamp(low.1, high.1),
+            self.2.clamp(low.2, high.2),
+        )
+    }
+
+    /// Reorders the channels in `order`, so that the channels of the new color are the channels
+    /// of this color named by `order`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{ChannelOrder, Color};
+    ///
+    /// let color = Color::new(1, 2, 3);
+    ///
+    /// assert_eq!(color.swizzle(ChannelOrder::Brg), Color::new(3, 1, 2));
+    /// assert_eq!(color.swizzle(ChannelOrder::Rgb), color);
+    /// ```
+    pub fn swizzle(&self, order: ChannelOrder) -> Self {
+        let (r, g, b) = (self.0, self.1, self.2);
+        match order {
+            ChannelOrder::Rgb => Self(r, g, b),
+            ChannelOrder::Rbg => Self(r, b, g),
+            ChannelOrder::Grb => Self(g, r, b),
+            ChannelOrder::Gbr => Self(g, b, r),
+            ChannelOrder::Brg => Self(b, r, g),
+            ChannelOrder::Bgr => Self(b, g, r),
+        }
+    }
+
+    /// Swaps the red and blue channels, which converts between RGB and BGR.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// assert_eq!(Color::new(1, 2, 3).bgr(), Color::new(3, 2, 1));
+    /// ```
     #[inline]
-    pub fn get_hsv_saturation(&self) -> f32 {
-        self.to_hsv().1
+    pub fn bgr(&self) -> Self {
+        self.swizzle(ChannelOrder::Bgr)
     }
 
-    /// Get the value (V) of HSV.
+    /// Swaps the red and green channels, which converts between RGB and GRB.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// assert_eq!(Color::new(1, 2, 3).grb(), Color::new(2, 1, 3));
+    /// ```
     #[inline]
-    pub fn get_hsv_value(&self) -> f32 {
-        self.to_hsv().2
+    pub fn grb(&self) -> Self {
+        self.swizzle(ChannelOrder::Grb)
     }
 
-    /// Gets the W3C web color. Returns `None` if no web color matches the current color.
+    /// Adds the channels of two colors, returning `None` when any channel overflows.
     ///
     /// # Example
+    ///
     /// ```
-    /// use octarine::{Color, constants};
+    /// use octarine::Color;
     ///
-    /// let web_color = constants::primary::RED.get_web_color();
+    /// let color = Color::new(100, 100, 100);
     ///
-    /// assert_eq!(web_color, Some("red"));
+    /// assert_eq!(color.checked_add(&Color::new(1, 2, 3)), Some(Color::new(101, 102, 103)));
+    /// assert_eq!(color.checked_add(&Color::new(200, 0, 0)), None);
     /// ```
-    #[inline]
-    pub fn get_web_color(&self) -> Option<&'static str> {
-        constants::RGB_TO_COLOR_NAMES
-            .into_iter()
-            .find_map(|(name, color)| if self == color { Some(*name) } else { None })
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self(
+            self.0.checked_add(other.0)?,
+            self.1.checked_add(other.1)?,
+            self.2.checked_add(other.2)?,
+        ))
     }
 
-    /// Sets the red value of RGB.
-    pub fn set_red(&mut self, red: u8) {
-        self.0 = red;
+    /// Subtracts the channels of two colors, returning `None` when any channel underflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color = Color::new(100, 100, 100);
+    ///
+    /// assert_eq!(color.checked_sub(&Color::new(1, 2, 3)), Some(Color::new(99, 98, 97)));
+    /// assert_eq!(color.checked_sub(&Color::new(200, 0, 0)), None);
+    /// ```
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Self(
+            self.0.checked_sub(other.0)?,
+            self.1.checked_sub(other.1)?,
+            self.2.checked_sub(other.2)?,
+        ))
     }
 
-    /// Sets the green value of RGB.
-    pub fn set_green(&mut self, green: u8) {
-        self.1 = green;
+    /// Divides the channels of two colors, returning `None` when any channel of `other` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color = Color::new(100, 100, 100);
+    ///
+    /// assert_eq!(color.checked_div(&Color::new(10, 20, 50)), Some(Color::new(10, 5, 2)));
+    /// assert_eq!(color.checked_div(&Color::new(10, 0, 50)), None);
+    /// ```
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        Some(Self(
+            self.0.checked_div(other.0)?,
+            self.1.checked_div(other.1)?,
+            self.2.checked_div(other.2)?,
+        ))
     }
 
-    /// Sets the blue value of RGB.
-    pub fn set_blue(&mut self, blue: u8) {
-        self.2 = blue;
+    /// Gets the average of `colors`. The average is done in linear light, as averaging the
+    /// gamma-encoded RGB values directly gives colors that are too dark. Returns `None` when
+    /// `colors` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let average = Color::average(&[constants::primary::BLACK, constants::primary::WHITE]);
+    ///
+    /// assert_eq!(average, Some(Color::new(188, 188, 188)));
+    /// ```
+    pub fn average(colors: &[Self]) -> Option<Self> {
+        if colors.is_empty() {
+            return None;
+        }
+        let (r, g, b) = colors.iter().fold((0.0, 0.0, 0.0), |(r, g, b), color| {
+            let (lr, lg, lb) = color.to_linear_rgb();
+            (r + lr, g + lg, b + lb)
+        });
+        let len = colors.len() as f32;
+        Some(Self::from_linear_rgb(
+            (r / len).clamp(0.0, 1.0),
+            (g / len).clamp(0.0, 1.0),
+            (b / len).clamp(0.0, 1.0),
+        ))
     }
 
-    /// Sets the hue (H) of HSL.
-    pub fn set_hsl_hue(&mut self, hue: f32) {
-        let hsl = self.to_hsl();
-        let color = Self::from_hsl(hue, hsl.1, hsl.2);
-        self.0 = color.0;
-        self.1 = color.1;
-        self.2 = color.2;
+    /// Gets the most common color of `colors`. Similar colors are grouped together by using 4 bits
+    /// per RGB channel, and the [`average()`](Color#method.average) of the largest group is
+    /// returned. Returns `None` when `colors` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let colors = [
+    ///     Color::new(255, 0, 0),
+    ///     Color::new(250, 0, 0),
+    ///     Color::new(0, 0, 255),
+    /// ];
+    ///
+    /// assert_eq!(Color::dominant(&colors), Some(Color::new(253, 0, 0)));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn dominant(colors: &[Self]) -> Option<Self> {
+        let bucket = |color: &Self| {
+            (color.0 as usize >> 4) << 8 | (color.1 as usize >> 4) << 4 | color.2 as usize >> 4
+        };
+        let mut counts = vec![0usize; 1 << 12];
+        for color in colors {
+            counts[bucket(color)] += 1;
+        }
+        let (dominant, _) = counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .max_by_key(|(index, count)| (**count, core::cmp::Reverse(*index)))?;
+        let members: Vec<Self> = colors
+            .iter()
+            .filter(|color| bucket(color) == dominant)
+            .cloned()
+            .collect();
+        Self::average(&members)
     }
 
-    /// Sets the saturation (S) of HSL.
-    pub fn set_hsl_saturation(&mut self, saturation: f32) {
-        let hsl = self.to_hsl();
-        let color = Self::from_hsl(hsl.0, saturation, hsl.2);
-        self.0 = color.0;
-        self.1 = color.1;
-        self.2 = color.2;
-    }
+    /// Get a random color.
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let random_color = Color::random_color();
+    /// println!("{random_color:?}");
+    /// ```
+    #[cfg(all(feature = "rand", feature = "std"))]
+    pub fn random_color() -> Self {
+        let mut rng = rand::rngs::SmallRng::from_entropy();
+        Self::random_with(&mut rng)
+    }
+
+    /// Get a random color using `rng`, which allows you to control where the randomness comes
+    /// from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let random_color = Color::random_with(&mut rng);
+    /// println!("{random_color:?}");
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_with(rng: &mut impl Rng) -> Self {
+        rng.gen()
+    }
+
+    /// Get a random color from `seed`, which always returns the same color for the same seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// assert_eq!(Color::random_seeded(42), Color::random_seeded(42));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_seeded(seed: u64) -> Self {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        Self::random_with(&mut rng)
+    }
+
+    /// Get the CSS representation of a color as a `#rrggbb` hex string, which can be used
+    /// anywhere CSS and the DOM accept a color (including `<input type="color">`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// assert_eq!(Color::new(255, 128, 0).to_css_string(), "#ff8000");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_css_string(&self) -> alloc::string::String {
+        alloc::format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+
+    /// Get the hexadecimal representation of a color.
+    ///
+    /// Use the [`hex` crate](https://crates.io/crates/hex) if you want to convert an integer
+    /// to a hex string.
+    ///
+    /// # Note
+    ///
+    /// The bytes are in the `0xBBGGRR` order, which is the reverse of
+    /// [`from_hex()`](Color#method.from_hex). Use [`to_0rgb()`](Color#method.to_0rgb) for the
+    /// `0x00RRGGBB` order of framebuffers, or [`to_packed()`](Color#method.to_packed) when you
+    /// need another byte order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let hex = Color::new(100, 100, 100).to_hex();
+    ///
+    /// assert_eq!(hex, 0x646464);
+    /// ```
+    pub const fn to_hex(&self) -> u32 {
+        let b = (self.2 as u32) << 16;
+        let g = (self.1 as u32) << 8;
+        let r = self.0 as u32;
+        r | g | b
+    }
+
+    /// Packs a color into a [`u32`] in the `0x00RRGGBB` format, which is what software rendering
+    /// crates such as minifb and softbuffer expect, the same as
+    /// [`to_packed(PixelFormat::Rgb888)`](Color#method.to_packed).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// assert_eq!(Color::new(0x11, 0x22, 0x33).to_0rgb(), 0x00112233);
+    /// ```
+    pub const fn to_0rgb(&self) -> u32 {
+        (self.0 as u32) << 16 | (self.1 as u32) << 8 | self.2 as u32
+    }
+
+    /// Packs a color into a [`u32`] in the `0xAARRGGBB` format with an opaque alpha, the same as
+    /// [`to_packed(PixelFormat::Argb8888)`](Color#method.to_packed).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// assert_eq!(Color::new(0x11, 0x22, 0x33).to_argb(), 0xFF112233);
+    /// ```
+    pub const fn to_argb(&self) -> u32 {
+        0xFF << 24 | self.to_0rgb()
+    }
+
+    /// Packs a color into a [`u32`] using `format`. Formats with an alpha channel are packed as
+    /// fully opaque.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, PixelFormat};
+    ///
+    /// let color = Color::new(0x11, 0x22, 0x33);
+    ///
+    /// assert_eq!(color.to_packed(PixelFormat::Rgba8888), 0x112233FF);
+    /// assert_eq!(color.to_packed(PixelFormat::Argb8888), 0xFF112233);
+    /// assert_eq!(color.to_packed(PixelFormat::Bgra8888), 0x332211FF);
+    /// assert_eq!(Color::new(255, 0, 0).to_packed(PixelFormat::Rgb565), 0xF800);
+    /// ```
+    pub fn to_packed(&self, format: PixelFormat) -> u32 {
+        let (r, g, b) = (self.0 as u32, self.1 as u32, self.2 as u32);
+        let scale = |v: u32, max: u32| (v * max + 127) / 255;
+        match format {
+            PixelFormat::Rgb888 => r << 16 | g << 8 | b,
+            PixelFormat::Bgr888 => b << 16 | g << 8 | r,
+            PixelFormat::Rgba8888 => r << 24 | g << 16 | b << 8 | 0xFF,
+            PixelFormat::Argb8888 => 0xFF << 24 | r << 16 | g << 8 | b,
+            PixelFormat::Abgr8888 => 0xFF << 24 | b << 16 | g << 8 | r,
+            PixelFormat::Bgra8888 => b << 24 | g << 16 | r << 8 | 0xFF,
+            PixelFormat::Rgb565 => scale(r, 31) << 11 | scale(g, 63) << 5 | scale(b, 31),
+            PixelFormat::Rgb555 => scale(r, 31) << 10 | scale(g, 31) << 5 | scale(b, 31),
+        }
+    }
+
+    /// Unpacks a color from `value` using `format`. The alpha channel and any unused bits are
+    /// ignored. The 5 and 6 bit channels of 16-bit formats are scaled to the full 8-bit range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, PixelFormat};
+    ///
+    /// let color = Color::from_packed(PixelFormat::Argb8888, 0x80112233);
+    ///
+    /// assert_eq!(color, Color::new(0x11, 0x22, 0x33));
+    /// assert_eq!(Color::from_packed(PixelFormat::Rgb565, 0xFFFF), Color::new(255, 255, 255));
+    /// ```
+    pub fn from_packed(format: PixelFormat, value: u32) -> Self {
+        let byte = |shift: u32| (value >> shift) as u8;
+        let expand = |shift: u32, bits: u32| {
+            let max = (1 << bits) - 1;
+            (((value >> shift) & max) * 255 + max / 2) / max
+        };
+        match format {
+            PixelFormat::Rgb888 => Self(byte(16), byte(8), byte(0)),
+            PixelFormat::Bgr888 => Self(byte(0), byte(8), byte(16)),
+            PixelFormat::Rgba8888 => Self(byte(24), byte(16), byte(8)),
+            PixelFormat::Argb8888 => Self(byte(16), byte(8), byte(0)),
+            PixelFormat::Abgr8888 => Self(byte(0), byte(8), byte(16)),
+            PixelFormat::Bgra8888 => Self(byte(8), byte(16), byte(24)),
+            PixelFormat::Rgb565 => {
+                Self(expand(11, 5) as u8, expand(5, 6) as u8, expand(0, 5) as u8)
+            }
+            PixelFormat::Rgb555 => {
+                Self(expand(10, 5) as u8, expand(5, 5) as u8, expand(0, 5) as u8)
+            }
+        }
+    }
+
+    /// Views `colors` as packed RGB bytes without copying, e.g. to upload a buffer to a GPU or
+    /// a framebuffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let colors = [Color::new(1, 2, 3), Color::new(4, 5, 6)];
+    ///
+    /// assert_eq!(Color::as_bytes(&colors), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn as_bytes(colors: &[Self]) -> &[u8] {
+        // SAFETY: `Color` is `repr(C)` with three `u8`s, so it has a size of 3, an alignment of
+        // 1, and no padding.
+        unsafe { core::slice::from_raw_parts(colors.as_ptr().cast(), colors.len() * 3) }
+    }
+
+    /// Views packed RGB bytes as colors without copying. Returns `None` when the length of
+    /// `bytes` is not a multiple of 3.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let bytes = [255, 0, 0, 0, 0, 255];
+    ///
+    /// assert_eq!(
+    ///     Color::from_bytes(&bytes),
+    ///     Some(&[Color::new(255, 0, 0), Color::new(0, 0, 255)][..])
+    /// );
+    /// assert_eq!(Color::from_bytes(&bytes[..4]), None);
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+        if bytes.len() % 3 != 0 {
+            return None;
+        }
+        // SAFETY: every three bytes are a valid `Color`, which has an alignment of 1.
+        Some(unsafe { core::slice::from_raw_parts(bytes.as_ptr().cast(), bytes.len() / 3) })
+    }
+
+    /// Converts a color to HSL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let hsl = Color::new(100, 100, 100).to_hsl();
+    ///
+    /// assert_eq!(hsl, (0.0, 0.0, 0.39215687));
+    /// ```
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let v_min = core::cmp::min(core::cmp::min(self.0, self.1), self.2) as f32 / 255.0;
+        let v_max = core::cmp::max(core::cmp::max(self.0, self.1), self.2) as f32 / 255.0;
+        let diff = v_max - v_min;
+        let v_sum = v_min + v_max;
+        let l = v_sum / 2.0;
+        if diff < f32::EPSILON {
+            return (0.0, 0.0, l);
+        }
+        // Rounding can put the saturation of very light colors just above 1.0.
+        let s = if l < 0.5 {
+            diff / v_sum
+        } else {
+            diff / (2.0 - v_sum)
+        }
+        .min(1.0);
+        let (r, g, b) = self.to_rgb_float();
+        let dr = (((v_max - r) / 6.0) + (diff / 2.0)) / diff;
+        let dg = (((v_max - g) / 6.0) + (diff / 2.0)) / diff;
+        let db = (((v_max - b) / 6.0) + (diff / 2.0)) / diff;
+        let mut h = if r == v_max {
+            db - dg
+        } else if g == v_max {
+            (1.0 / 3.0) + dr - db
+        } else {
+            (2.0 / 3.0) + dg - dr
+        };
+        if h < 0.0 {
+            h += 1.0;
+        }
+        if h > 1.0 {
+            h -= 1.0;
+        }
+        (h, s, l)
+    }
+
+    /// Converts a color to HSV.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let hsl = Color::from_hex(0x646464).to_hsv();
+    ///
+    /// assert_eq!(hsl, (0.0, 0.0, 0.392156862745));
+    /// ```
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let v_min = core::cmp::min(core::cmp::min(self.0, self.1), self.2) as f32 / 255.0;
+        let v_max = core::cmp::max(core::cmp::max(self.0, self.1), self.2) as f32 / 255.0;
+        let diff = v_max - v_min;
+        let v = v_max;
+        if diff < f32::EPSILON {
+            return (0.0, 0.0, v);
+        }
+        let s = diff / v_max;
+        let (r, g, b) = self.to_rgb_float();
+        let dr = (((v_max - r) / 6.0) + (diff / 2.0)) / diff;
+        let dg = (((v_max - g) / 6.0) + (diff / 2.0)) / diff;
+        let db = (((v_max - b) / 6.0) + (diff / 2.0)) / diff;
+        let mut h = if r == v_max {
+            db - dg
+        } else if g == v_max {
+            (1.0 / 3.0) + dr - db
+        } else {
+            (2.0 / 3.0) + dg - dr
+        };
+        if h < 0.0 {
+            h += 1.0;
+        }
+        if h > 1.0 {
+            h -= 1.0;
+        }
+        (h, s, v)
+    }
+
+    /// Converts a color back to its RGB representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color = Color::new(100, 100, 100).to_rgb();
+    ///
+    /// assert_eq!(color, (100, 100, 100));
+    /// ```
+    #[inline]
+    pub const fn to_rgb(&self) -> (u8, u8, u8) {
+        (self.0, self.1, self.2)
+    }
+
+    /// Converts a color back to its RGB float representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color = Color::new(0, 255, 0).to_rgb_float();
+    ///
+    /// assert_eq!(color, (0.0, 1.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn to_rgb_float(&self) -> (f32, f32, f32) {
+        (
+            self.0 as f32 / 255.0,
+            self.1 as f32 / 255.0,
+            self.2 as f32 / 255.0,
+        )
+    }
+
+    /// Converts a color to its linear RGB float representation, i.e. with the sRGB transfer
+    /// function (gamma) removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color = Color::new(0, 255, 0).to_linear_rgb();
+    ///
+    /// assert_eq!(color, (0.0, 1.0, 0.0));
+    /// ```
+    pub fn to_linear_rgb(&self) -> (f32, f32, f32) {
+        (
+            srgb_to_linear_u8(self.0),
+            srgb_to_linear_u8(self.1),
+            srgb_to_linear_u8(self.2),
+        )
+    }
+
+    /// Converts a color to linear RGB with an alpha of 1.0, for the `vec4<f32>` uniforms and
+    /// vertex attributes of shaders which work in linear light, such as those rendering to an
+    /// sRGB surface in wgpu.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let [r, g, b, a] = Color::new(255, 128, 0).to_vec4_linear();
+    ///
+    /// assert_eq!((r, b, a), (1.0, 0.0, 1.0));
+    /// assert!((g - 0.2158605).abs() < 1e-6);
+    /// ```
+    pub fn to_vec4_linear(&self) -> [f32; 4] {
+        let (r, g, b) = self.to_linear_rgb();
+        [r, g, b, 1.0]
+    }
+
+    /// Converts a color to sRGB floats (0.0 - 1.0), for the `vec3<f32>` uniforms of shaders which
+    /// output to a surface without sRGB encoding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// assert_eq!(Color::new(0, 255, 0).to_vec3_srgb(), [0.0, 1.0, 0.0]);
+    /// ```
+    pub fn to_vec3_srgb(&self) -> [f32; 3] {
+        let (r, g, b) = self.to_rgb_float();
+        [r, g, b]
+    }
+
+    /// Converts a color to OKLab.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants;
+    ///
+    /// let (l, _, _) = constants::primary::BLACK.to_oklab();
+    ///
+    /// assert_eq!(l, 0.0);
+    /// ```
+    pub fn to_oklab(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_linear_rgb();
+        linear_rgb_to_oklab(r, g, b)
+    }
+
+    /// Converts a color to OKLCH. The hue is expressed as a value between 0 and 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let (l, c, _) = Color::new(100, 100, 100).to_oklch();
+    ///
+    /// assert!(c < 0.0001);
+    /// assert!((l - 0.5032).abs() < 0.0001);
+    /// ```
+    pub fn to_oklch(&self) -> (f32, f32, f32) {
+        let (l, a, b) = self.to_oklab();
+        let c = (a * a + b * b).sqrt();
+        let h = (b.atan2(a) / core::f32::consts::TAU).rem_euclid(1.0);
+        (l, c, h)
+    }
+
+    /// Converts a color to CIE XYZ (D65 white point, Y of white = 1.0).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants;
+    ///
+    /// let (_, y, _) = constants::primary::WHITE.to_xyz();
+    ///
+    /// assert!((y - 1.0).abs() < 0.0001);
+    /// ```
+    pub fn to_xyz(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_linear_rgb();
+        linear_rgb_to_xyz(r, g, b)
+    }
+
+    /// Converts a color to CIELAB (D65 white point).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants;
+    ///
+    /// let (l, _, _) = constants::primary::WHITE.to_lab();
+    ///
+    /// assert!((l - 100.0).abs() < 0.01);
+    /// ```
+    pub fn to_lab(&self) -> (f32, f32, f32) {
+        let (x, y, z) = self.to_xyz();
+        xyz_to_lab(x, y, z)
+    }
+
+    /// Converts a color to [`Hsl`], which is the same as [`to_hsl()`](Color#method.to_hsl) but
+    /// with named fields.
+    #[inline]
+    pub fn hsl(&self) -> Hsl {
+        Hsl::from(*self)
+    }
+
+    /// Converts a color to [`Hsv`], which is the same as [`to_hsv()`](Color#method.to_hsv) but
+    /// with named fields.
+    #[inline]
+    pub fn hsv(&self) -> Hsv {
+        Hsv::from(*self)
+    }
+
+    /// Converts a color to [`Lab`], which is the same as [`to_lab()`](Color#method.to_lab) but
+    /// with named fields.
+    #[inline]
+    pub fn lab(&self) -> Lab {
+        Lab::from(*self)
+    }
+
+    /// Converts a color to [`Oklch`], which is the same as [`to_oklch()`](Color#method.to_oklch)
+    /// but with named fields.
+    #[inline]
+    pub fn oklch(&self) -> Oklch {
+        Oklch::from(*self)
+    }
+
+    /// Get the red value of RGB.
+    #[inline]
+    pub const fn get_red(&self) -> u8 {
+        self.0
+    }
+
+    /// Get the green value of RGB.
+    #[inline]
+    pub const fn get_green(&self) -> u8 {
+        self.1
+    }
+
+    /// Get the blue value of RGB.
+    #[inline]
+    pub const fn get_blue(&self) -> u8 {
+        self.2
+    }
+
+    /// Get the hue (H) of HSL.
+    #[inline]
+    pub fn get_hsl_hue(&self) -> f32 {
+        self.to_hsl().0
+    }
+
+    /// Get the hue (H) of HSL in degrees (0 - 360).
+    #[inline]
+    pub fn get_hsl_hue_degrees(&self) -> f32 {
+        self.get_hsl_hue() * 360.0
+    }
+
+    /// Get the saturation (S) of HSL.
+    #[inline]
+    pub fn get_hsl_saturation(&self) -> f32 {
+        self.to_hsl().1
+    }
+
+    /// Get the luminance (L) of HSL.
+    #[inline]
+    pub fn get_hsl_luminance(&self) -> f32 {
+        self.to_hsl().2
+    }
+
+    /// Get the hue (H) of HSV.
+    #[inline]
+    pub fn get_hsv_hue(&self) -> f32 {
+        self.to_hsv().0
+    }
+
+    /// Get the hue (H) of HSV in degrees (0 - 360).
+    #[inline]
+    pub fn get_hsv_hue_degrees(&self) -> f32 {
+        self.get_hsv_hue() * 360.0
+    }
+
+    /// Get the saturation (S) of HSV.
+    #[inline]
+    pub fn get_hsv_saturation(&self) -> f32 {
+        self.to_hsv().1
+    }
+
+    /// Get the value (V) of HSV.
+    #[inline]
+    pub fn get_hsv_value(&self) -> f32 {
+        self.to_hsv().2
+    }
+
+    /// Gets the W3C web color. Returns `None` if no web color matches the current color. This is a
+    /// constant time lookup in a table built at compile time, so it is cheap to call for every
+    /// swatch of a large palette.
+    ///
+    /// # Example
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let web_color = constants::primary::RED.get_web_color();
+    ///
+    /// assert_eq!(web_color, Some("red"));
+    /// ```
+    #[inline]
+    pub fn get_web_color(&self) -> Option<&'static str> {
+        constants::web_color_name(*self)
+    }
+
+    /// Gets the family of the color, for grouping colors such as the blues. Every color belongs
+    /// to exactly one family, unlike [`HueFamily::contains()`] where the families overlap: black,
+    /// white, and gray are chosen first, then pink and brown, and the rest of the colors by their
+    /// hue. Colors with a lightness above 0.94 or below 0.08 count as white or black even when they
+    /// are tinted, and dark and light colors which are nearly gray count as black and white too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, HueFamily};
+    ///
+    /// assert_eq!(Color::new(30, 60, 200).hue_family(), HueFamily::Blue);
+    /// assert_eq!(Color::new(139, 69, 19).hue_family(), HueFamily::Brown);
+    /// assert_eq!(Color::new(250, 250, 250).hue_family(), HueFamily::White);
+    /// ```
+    pub fn hue_family(&self) -> HueFamily {
+        if let Some(family) = HueFamily::neutral(self) {
+            return family;
+        }
+        [
+            HueFamily::Pink,
+            HueFamily::Brown,
+            HueFamily::Red,
+            HueFamily::Orange,
+            HueFamily::Yellow,
+            HueFamily::Green,
+            HueFamily::Cyan,
+            HueFamily::Blue,
+            HueFamily::Purple,
+            HueFamily::Magenta,
+        ]
+        .into_iter()
+        .find(|family| family.contains(self))
+        // The hue families cover the whole color wheel, so this is only reached for NaN hues.
+        .unwrap_or(HueFamily::Red)
+    }
+
+    /// Sets the red value of RGB.
+    pub fn set_red(&mut self, red: u8) {
+        self.0 = red;
+    }
+
+    /// Sets the green value of RGB.
+    pub fn set_green(&mut self, green: u8) {
+        self.1 = green;
+    }
+
+    /// Sets the blue value of RGB.
+    pub fn set_blue(&mut self, blue: u8) {
+        self.2 = blue;
+    }
+
+    /// Sets the hue (H) of HSL.
+    pub fn set_hsl_hue(&mut self, hue: f32) {
+        let hsl = self.to_hsl();
+        let color = Self::from_hsl(hue, hsl.1, hsl.2);
+        self.0 = color.0;
+        self.1 = color.1;
+        self.2 = color.2;
+    }
+
+    /// Sets the hue (H) of HSL in degrees (0 - 360).
+    pub fn set_hsl_hue_degrees(&mut self, hue: f32) {
+        self.set_hsl_hue(degrees_to_hue(hue));
+    }
+
+    /// Sets the saturation (S) of HSL.
+    pub fn set_hsl_saturation(&mut self, saturation: f32) {
+        let hsl = self.to_hsl();
+        let color = Self::from_hsl(hsl.0, saturation, hsl.2);
+        self.0 = color.0;
+        self.1 = color.1;
+        self.2 = color.2;
+    }
+
+    /// Sets the luminance (L) of HSL.
+    pub fn set_hsl_luminance(&mut self, luminance: f32) {
+        let hsl = self.to_hsl();
+        let color = Self::from_hsl(hsl.0, hsl.1, luminance);
+        self.0 = color.0;
+        self.1 = color.1;
+        self.2 = color.2;
+    }
+
+    /// Sets the hue (H) of HSV.
+    pub fn set_hsv_hue(&mut self, hue: f32) {
+        let hsv = self.to_hsv();
+        let color = Self::from_hsv(hue, hsv.1, hsv.2);
+        self.0 = color.0;
+        self.1 = color.1;
+        self.2 = color.2;
+    }
+
+    /// Sets the hue (H) of HSV in degrees (0 - 360).
+    pub fn set_hsv_hue_degrees(&mut self, hue: f32) {
+        self.set_hsv_hue(degrees_to_hue(hue));
+    }
+
+    /// Sets the saturation (S) of HSV.
+    pub fn set_hsv_saturation(&mut self, saturation: f32) {
+        let hsv = self.to_hsv();
+        let color = Self::from_hsv(hsv.0, saturation, hsv.2);
+        self.0 = color.0;
+        self.1 = color.1;
+        self.2 = color.2;
+    }
+
+    /// Sets the value (V) of HSV.
+    pub fn set_hsv_value(&mut self, value: f32) {
+        let hsv = self.to_hsv();
+        let color = Self::from_hsv(hsv.0, hsv.1, value);
+        self.0 = color.0;
+        self.1 = color.1;
+        self.2 = color.2;
+    }
+
+    /// Rotates the hue (H) of HSL by `amount` and returns the resulting color. As hue is a
+    /// rotation around the chromatic circle, `amount` is expressed as a fraction of a full turn
+    /// (e.g. `0.5` is 180°), and can be negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants;
+    ///
+    /// let color = constants::primary::RED.rotate_hue(1.0 / 3.0);
+    ///
+    /// assert_eq!(color, constants::primary::GREEN);
+    /// ```
+    pub fn rotate_hue(&self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl((h + amount).rem_euclid(1.0), s, l)
+    }
+
+    /// Rotates the hue (H) of HSL by `degrees` and returns the resulting color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants;
+    ///
+    /// assert_eq!(constants::primary::RED.rotate_hue_degrees(240.0), constants::primary::BLUE);
+    /// ```
+    pub fn rotate_hue_degrees(&self, degrees: f32) -> Self {
+        self.rotate_hue(degrees_to_hue(degrees))
+    }
+
+    /// Gets the complementary color, which sits opposite the current color on the color wheel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let complementary = constants::primary::RED.complementary();
+    ///
+    /// assert_eq!(complementary, Color::from_web_color("cyan").unwrap());
+    /// ```
+    #[inline]
+    pub fn complementary(&self) -> Self {
+        self.rotate_hue(0.5)
+    }
+
+    /// Gets the current color along with the two colors adjacent to its complement
+    /// (150° and 210° away).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let [red, c1, c2] = constants::primary::RED.split_complementary();
+    ///
+    /// assert_eq!(red, constants::primary::RED);
+    /// assert_eq!(c1, Color::new(0, 255, 127));
+    /// assert_eq!(c2, Color::new(0, 128, 255));
+    /// ```
+    pub fn split_complementary(&self) -> [Self; 3] {
+        [
+            *self,
+            self.rotate_hue(5.0 / 12.0),
+            self.rotate_hue(7.0 / 12.0),
+        ]
+    }
+
+    /// Gets the current color along with the two colors that are evenly spaced (120°) around the
+    /// color wheel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants;
+    ///
+    /// let triadic = constants::primary::RED.triadic();
+    ///
+    /// assert_eq!(
+    ///     triadic,
+    ///     [constants::primary::RED, constants::primary::GREEN, constants::primary::BLUE]
+    /// );
+    /// ```
+    pub fn triadic(&self) -> [Self; 3] {
+        [
+            *self,
+            self.rotate_hue(1.0 / 3.0),
+            self.rotate_hue(2.0 / 3.0),
+        ]
+    }
+
+    /// Gets the current color along with three other colors which form a rectangle on the color
+    /// wheel (two complementary pairs 60° apart).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let tetradic = constants::primary::RED.tetradic();
+    ///
+    /// assert_eq!(tetradic[1], Color::from_web_color("yellow").unwrap());
+    /// assert_eq!(tetradic[2], Color::from_web_color("cyan").unwrap());
+    /// assert_eq!(tetradic[3], constants::primary::BLUE);
+    /// ```
+    pub fn tetradic(&self) -> [Self; 4] {
+        [
+            *self,
+            self.rotate_hue(1.0 / 6.0),
+            self.rotate_hue(0.5),
+            self.rotate_hue(2.0 / 3.0),
+        ]
+    }
+
+    /// Gets the current color along with three other colors which are evenly spaced (90°)
+    /// around the color wheel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let square = constants::primary::RED.square();
+    ///
+    /// assert_eq!(square[1], Color::new(127, 255, 0));
+    /// assert_eq!(square[2], Color::from_web_color("cyan").unwrap());
+    /// assert_eq!(square[3], Color::new(128, 0, 255));
+    /// ```
+    pub fn square(&self) -> [Self; 4] {
+        [
+            *self,
+            self.rotate_hue(0.25),
+            self.rotate_hue(0.5),
+            self.rotate_hue(0.75),
+        ]
+    }
+
+    /// Gets `count` analogous colors, which are colors next to each other on the color wheel
+    /// centered around the current color. `spread` is the hue distance between two neighboring
+    /// colors as a fraction of a full turn (e.g. `1.0 / 12.0` is 30°).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let analogous = constants::primary::RED.analogous(3, 1.0 / 12.0);
+    ///
+    /// assert_eq!(
+    ///     analogous.into_colors(),
+    ///     vec![Color::new(255, 0, 127), constants::primary::RED, Color::new(255, 128, 0)]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn analogous(&self, count: usize, spread: f32) -> Palette {
+        let center = (count as f32 - 1.0) / 2.0;
+        (0..count)
+            .map(|i| {
+                let offset = i as f32 - center;
+                if offset == 0.0 {
+                    *self
+                } else {
+                    self.rotate_hue(offset * spread)
+                }
+            })
+            .collect()
+    }
+
+    /// Gets `n` tints and shades of the current color, ordered from darkest to lightest. The
+    /// colors share the hue of the current color and have evenly spaced perceived lightness
+    /// (OKLab L), which excludes pure black and white. The chroma of a color is reduced when
+    /// needed to keep its hue within the RGB gamut.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let shades = Color::from_hex(0x3366CC).monochromatic(5);
+    /// let lightness: Vec<f32> = shades.iter().map(|c| c.to_oklab().0).collect();
+    ///
+    /// assert_eq!(shades.len(), 5);
+    /// assert!(lightness.windows(2).all(|l| l[0] < l[1]));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn monochromatic(&self, n: usize) -> Palette {
+        let (_, c, h) = self.to_oklch();
+        (1..=n)
+            .map(|i| {
+                let l = i as f32 / (n + 1) as f32;
+                oklch_to_color_in_gamut(l, c, h)
+            })
+            .collect()
+    }
+
+    /// Gets all of the common color harmonies of the current color at once. Refer to
+    /// [`Harmonies`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants;
+    ///
+    /// let color = constants::primary::RED;
+    /// let harmonies = color.harmonies();
+    ///
+    /// assert_eq!(harmonies.complementary, color.complementary());
+    /// assert_eq!(harmonies.triadic, color.triadic());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn harmonies(&self) -> Harmonies {
+        Harmonies {
+            complementary: self.complementary(),
+            split_complementary: self.split_complementary(),
+            triadic: self.triadic(),
+            tetradic: self.tetradic(),
+            square: self.square(),
+            analogous: self.analogous(3, 1.0 / 12.0),
+        }
+    }
+
+    /// Returns a [`ColorRange`] which is an iterator that returns some color scales
+    /// of variation between the current color and another color specified. Refer to
+    /// [`ColorRange`] for more information on how it works.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `steps` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let c0 = Color::from_web_color("red").unwrap();
+    /// let c1 = Color::from_hex(0xFF7F00);
+    /// let c2 = Color::from_web_color("yellow").unwrap();
+    /// let c3 = Color::new(128, 255, 0);
+    /// let c4 = Color::from_web_color("lime").unwrap();
+    /// let mut range_to = c0.range_to(c4.clone(), 5);
+    ///
+    /// assert_eq!(Some(c0), range_to.next());
+    /// assert_eq!(Some(c1), range_to.next());
+    /// assert_eq!(Some(c2), range_to.next());
+    /// assert_eq!(Some(c3), range_to.next());
+    /// assert_eq!(Some(c4), range_to.next());
+    /// assert_eq!(None, range_to.next());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn range_to(&self, value: Self, steps: usize) -> ColorRange {
+        ColorRange::new(vec![*self, value], steps)
+    }
+
+    /// Returns a [`ColorRange`] like [`range_to()`](Color::range_to), but returns an error instead
+    /// of panicking when `steps` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, RangeError};
+    ///
+    /// let black = constants::primary::BLACK;
+    ///
+    /// assert_eq!(black.try_range_to(constants::primary::WHITE, 3).unwrap().len(), 3);
+    /// assert_eq!(
+    ///     black.try_range_to(constants::primary::WHITE, 0).unwrap_err(),
+    ///     RangeError::ZeroSteps
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn try_range_to(&self, value: Self, steps: usize) -> Result<ColorRange, RangeError> {
+        ColorRange::try_new(vec![*self, value], steps)
+    }
+
+    /// Returns a [`ColorRange`] which spreads `steps` colors evenly across all of `colors`, going
+    /// through each of them in order. Unlike chaining multiple ranges, the colors in between are
+    /// only returned once.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `colors` is empty or when `steps` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let green = Color::from_web_color("lime").unwrap();
+    /// let yellow = Color::from_web_color("yellow").unwrap();
+    /// let red = constants::primary::RED;
+    /// let range: Vec<Color> =
+    ///     Color::range_through(&[green.clone(), yellow.clone(), red.clone()], 5).collect();
+    ///
+    /// assert_eq!(
+    ///     range,
+    ///     vec![green, Color::new(127, 255, 0), yellow, Color::new(255, 128, 0), red]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn range_through(colors: &[Self], steps: usize) -> ColorRange {
+        ColorRange::new(colors.to_vec(), steps)
+    }
+
+    /// Gets the perceptual difference between two colors using the CIEDE2000 Delta E formula.
+    /// A difference of around 1.0 is the smallest difference the human eye can notice, while
+    /// 0.0 means the colors are identical.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let delta_e = constants::primary::RED.delta_e(&Color::new(254, 0, 0));
+    ///
+    /// assert!(delta_e < 1.0);
+    /// ```
+    pub fn delta_e(&self, other: &Self) -> f32 {
+        ciede2000(self.to_lab(), other.to_lab())
+    }
+
+    /// Gets the relative luminance of a color as defined by WCAG, from 0.0 for black to 1.0 for
+    /// white.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants;
+    ///
+    /// assert_eq!(constants::primary::WHITE.relative_luminance(), 1.0);
+    /// assert_eq!(constants::primary::BLACK.relative_luminance(), 0.0);
+    /// ```
+    pub fn relative_luminance(&self) -> f32 {
+        let (r, g, b) = self.to_linear_rgb();
+        (0.2126 * r + 0.7152 * g + 0.0722 * b).min(1.0)
+    }
+
+    /// Gets the WCAG contrast ratio between two colors, from 1.0 for identical colors to 21.0 for
+    /// black and white. WCAG AA requires a ratio of at least 4.5 for normal text and 3.0 for
+    /// large text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let ratio = constants::primary::BLACK.contrast_ratio(&constants::primary::WHITE);
+    ///
+    /// assert!((ratio - 21.0).abs() < 0.001);
+    /// assert!(Color::new(119, 119, 119).contrast_ratio(&constants::primary::WHITE) < 4.5);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Self) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// This method offers a way to equate colors using [`Equivalence`], in which a color is
+    /// equated using its RGB, HSL, or HSV values, either exactly or within a tolerance.
+    ///
+    /// # Note
+    ///
+    /// By default octarine uses `[Equivalence::RGB]` for its [`PartialEq`] representation, so
+    /// if such equality operations are not required, you can simply
+    /// equate two color objects using `==`.
+    ///
+    /// # Example
+    /// ```
+    /// use octarine::{Color, Equivalence};
+    ///
+    /// let color = Color::new(100, 100, 100);
+    /// let color2 = color.clone();
+    ///
+    /// assert_eq!(color == color2, color.complex_eq(&color2, Equivalence::RGB));
+    /// assert!(color.complex_eq(&Color::new(101, 100, 99), Equivalence::DeltaE(1.0)));
+    /// ```
+    pub fn complex_eq(&self, other: &Self, equivalence: Equivalence) -> bool {
+        match equivalence {
+            Equivalence::RGB => self.0 == other.0 && self.1 == other.1 && self.2 == other.2,
+            Equivalence::HSL => self.to_hsl() == other.to_hsl(),
+            Equivalence::HSV => self.to_hsv() == other.to_hsv(),
+            Equivalence::RgbWithin(tolerance) => self.approx_eq(other, tolerance),
+            Equivalence::HslWithin(tolerance) => {
+                let (h1, s1, l1) = self.to_hsl();
+                let (h2, s2, l2) = other.to_hsl();
+                hue_distance(h1, h2) <= tolerance
+                    && (s1 - s2).abs() <= tolerance
+                    && (l1 - l2).abs() <= tolerance
+            }
+            Equivalence::DeltaE(max) => self.delta_e(other) <= max,
+        }
+    }
+
+    /// Checks whether every channel of two colors differs by at most `tolerance`, which is useful
+    /// for comparing the results of conversions that can be off by one due to rounding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let color = Color::new(100, 150, 200);
+    ///
+    /// assert!(color.approx_eq(&Color::new(101, 149, 200), 1));
+    /// assert!(!color.approx_eq(&Color::new(102, 150, 200), 1));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, tolerance: u8) -> bool {
+        self.0.abs_diff(other.0) <= tolerance
+            && self.1.abs_diff(other.1) <= tolerance
+            && self.2.abs_diff(other.2) <= tolerance
+    }
+}
+
+/// The common color harmonies of a color, as returned by [`harmonies()`](Color#method.harmonies).
+///
+/// Every set except `complementary` starts with the original color. `analogous` contains three
+/// colors 30° apart, use [`analogous()`](Color#method.analogous) for other counts and spreads.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Harmonies {
+    pub complementary: Color,
+    pub split_complementary: [Color; 3],
+    pub triadic: [Color; 3],
+    pub tetradic: [Color; 4],
+    pub square: [Color; 4],
+    pub analogous: Palette,
+}
+
+/// Specifies the order of the channels for [`swizzle()`](Color#method.swizzle).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChannelOrder {
+    Rgb,
+    Rbg,
+    Grb,
+    Gbr,
+    Brg,
+    Bgr,
+}
+
+/// Specifies how [`to_packed()`](Color#method.to_packed) and
+/// [`from_packed()`](Color#method.from_packed) lay out the channels in a [`u32`]. The channels in
+/// the names are ordered from the most significant bits to the least significant bits, so
+/// [`PixelFormat::Rgba8888`] is `0xRRGGBBAA`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PixelFormat {
+    /// `0x00RRGGBB`, the same as [`from_hex()`](Color#method.from_hex).
+    Rgb888,
+    /// `0x00BBGGRR`, the same as [`to_hex()`](Color#method.to_hex).
+    Bgr888,
+    /// `0xRRGGBBAA`.
+    Rgba8888,
+    /// `0xAARRGGBB`.
+    Argb8888,
+    /// `0xAABBGGRR`, which is RGBA in memory on little-endian machines.
+    Abgr8888,
+    /// `0xBBGGRRAA`.
+    Bgra8888,
+    /// 16 bits with 5 bits of red, 6 bits of green, and 5 bits of blue.
+    Rgb565,
+    /// 15 bits with 5 bits of each channel, where the most significant bit is unused.
+    Rgb555,
+}
+
+/// Specifies the methods in which [`complex_eq()`](Color#method.complex_eq) compares colors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Equivalence {
+    RGB,
+    HSL,
+    HSV,
+    /// Every RGB channel differs by at most this much, the same as
+    /// [`approx_eq()`](Color#method.approx_eq).
+    RgbWithin(u8),
+    /// The hue, saturation, and lightness each differ by at most this much, where hues just above
+    /// 0 and just below 1 are close to each other.
+    HslWithin(f32),
+    /// The [`delta_e()`](Color#method.delta_e) is at most this much. Colors with a Delta E below
+    /// 1.0 can't be told apart by the human eye.
+    DeltaE(f32),
+}
+
+/// Specifies the color spaces that a [`ColorRange`] or [`Gradient`] can interpolate colors in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// Gamma-encoded RGB.
+    Srgb,
+    /// RGB with the sRGB transfer function (gamma) removed.
+    LinearRgb,
+    Hsl,
+    Hsv,
+    Oklab,
+    /// The polar form of OKLab.
+    Oklch,
+}
+
+impl ColorSpace {
+    fn components(self, color: &Color) -> (f32, f32, f32) {
+        match self {
+            Self::Srgb => color.to_rgb_float(),
+            Self::LinearRgb => color.to_linear_rgb(),
+            Self::Hsl => color.to_hsl(),
+            Self::Hsv => color.to_hsv(),
+            Self::Oklab => color.to_oklab(),
+            Self::Oklch => color.to_oklch(),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_color(self, components: (f32, f32, f32)) -> Color {
+        let (r, g, b) = self.to_rgb_float(components);
+        Color::from_rgb_float(r, g, b)
+    }
+
+    /// Converts components in this color space to sRGB floats without rounding them to 8 bits.
+    /// Components outside of the gamut are clamped and hues are wrapped around.
+    fn to_rgb_float(self, (v0, v1, v2): (f32, f32, f32)) -> (f32, f32, f32) {
+        let clamp = |v: f32| v.clamp(0.0, 1.0);
+        let from_linear = |(r, g, b): (f32, f32, f32)| {
+            (
+                clamp(linear_to_srgb(clamp(r))),
+                clamp(linear_to_srgb(clamp(g))),
+                clamp(linear_to_srgb(clamp(b))),
+            )
+        };
+        match self {
+            Self::Srgb => (clamp(v0), clamp(v1), clamp(v2)),
+            Self::LinearRgb => from_linear((v0, v1, v2)),
+            Self::Hsl => hsl_to_rgb_float(v0.rem_euclid(1.0), clamp(v1), clamp(v2)),
+            Self::Hsv => hsv_to_rgb_float(v0.rem_euclid(1.0), clamp(v1), clamp(v2)),
+            Self::Oklab => from_linear(oklab_to_linear_rgb(v0, v1, v2)),
+            Self::Oklch => {
+                let (a, b) = oklch_to_oklab_ab(v1, v2.rem_euclid(1.0));
+                from_linear(oklab_to_linear_rgb(v0, a, b))
+            }
+        }
+    }
+
+    /// Converts both endpoints of an interpolation to this color space. When one of the colors
+    /// has no hue (e.g. gray), it takes the hue of the other color so that the interpolation does
+    /// not pass through unrelated hues. The hue of the end color is then adjusted so that
+    /// interpolating goes around the color wheel in the specified [`HueDirection`].
+    fn endpoints(
+        self,
+        a: &Color,
+        b: &Color,
+        direction: HueDirection,
+    ) -> ((f32, f32, f32), (f32, f32, f32)) {
+        let mut a = self.components(a);
+        let mut b = self.components(b);
+        let (ha, hb) = match self {
+            Self::Hsl | Self::Hsv => {
+                if a.1 == 0.0 && b.1 != 0.0 {
+                    a.0 = b.0;
+                } else if b.1 == 0.0 && a.1 != 0.0 {
+                    b.0 = a.0;
+                }
+                (&mut a.0, &mut b.0)
+            }
+            Self::Oklch => {
+                if a.1 < ACHROMATIC_CHROMA && b.1 >= ACHROMATIC_CHROMA {
+                    a.2 = b.2;
+                } else if b.1 < ACHROMATIC_CHROMA && a.1 >= ACHROMATIC_CHROMA {
+                    b.2 = a.2;
+                }
+                (&mut a.2, &mut b.2)
+            }
+            _ => return (a, b),
+        };
+        let difference = *hb - *ha;
+        match direction {
+            HueDirection::Shorter if difference > 0.5 => *hb -= 1.0,
+            HueDirection::Shorter if difference < -0.5 => *hb += 1.0,
+            HueDirection::Longer if 0.0 < difference && difference < 0.5 => *hb -= 1.0,
+            HueDirection::Longer if -0.5 < difference && difference <= 0.0 => *hb += 1.0,
+            HueDirection::Increasing if difference < 0.0 => *hb += 1.0,
+            HueDirection::Decreasing if difference > 0.0 => *hb -= 1.0,
+            _ => {}
+        }
+        (a, b)
+    }
+}
+
+/// Specifies which way around the color wheel the hue is interpolated when a [`ColorRange`] or
+/// [`Gradient`] uses a color space with a hue ([`ColorSpace::Hsl`], [`ColorSpace::Hsv`], or
+/// [`ColorSpace::Oklch`]). Apart from [`HueDirection::Specified`], these match the hue
+/// interpolation methods of CSS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HueDirection {
+    /// Interpolates the hue values as they are, so the hue increases when the end hue is larger
+    /// and decreases otherwise.
+    #[default]
+    Specified,
+    /// Takes the shorter way around the color wheel (at most 180°).
+    Shorter,
+    /// Takes the longer way around the color wheel (at least 180°).
+    Longer,
+    /// Always goes in the direction of increasing hue.
+    Increasing,
+    /// Always goes in the direction of decreasing hue.
+    Decreasing,
+}
+
+/// A named group of similar colors, such as all of the blues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HueFamily {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+    Purple,
+    Magenta,
+    Pink,
+    Brown,
+    Gray,
+    Black,
+    White,
+}
+
+impl HueFamily {
+    /// Checks whether `color` belongs to the family. The families overlap, as
+    /// [`HueFamily::Pink`] and [`HueFamily::Brown`] are light and dark colors of the families next
+    /// to them, so a color can belong to more than one family. Colors which are nearly gray,
+    /// black, or white belong to exactly one of those three families, the same as
+    /// [`hue_family()`](Color::hue_family), and don't belong to any of the families defined by hue.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, HueFamily};
+    ///
+    /// let chocolate = Color::new(210, 105, 30);
+    ///
+    /// assert!(HueFamily::Orange.contains(&chocolate));
+    /// assert!(HueFamily::Brown.contains(&Color::new(139, 69, 19)));
+    /// assert!(!HueFamily::Red.contains(&Color::new(128, 128, 128)));
+    /// ```
+    pub fn contains(self, color: &Color) -> bool {
+        if matches!(self, Self::Gray | Self::Black | Self::White) {
+            return Self::neutral(color) == Some(self);
+        }
+        if Self::neutral(color).is_some() {
+            return false;
+        }
+        let (h, s, l) = color.to_hsl();
+        let (hue, saturation, lightness) = self.bounds();
+        let (start, end) = (*hue.start(), *hue.end());
+        let in_hue = if start <= end {
+            (start..=end).contains(&h)
+        } else {
+            h >= start || h <= end
+        };
+        in_hue
+            && saturation.map_or(true, |saturation| saturation.contains(&s))
+            && lightness.map_or(true, |lightness| lightness.contains(&l))
+    }
+
+    /// Gets the neutral family (black, white, or gray) of `color`, which every color that is
+    /// very dark, very light, or nearly gray belongs to. Dark and light colors which are nearly
+    /// gray count as black and white.
+    fn neutral(color: &Color) -> Option<Self> {
+        let (_, s, l) = color.to_hsl();
+        if l >= 0.94 {
+            Some(Self::White)
+        } else if l <= 0.08 {
+            Some(Self::Black)
+        } else if s > 0.1 {
+            None
+        } else if l < 0.2 {
+            Some(Self::Black)
+        } else if l > 0.8 {
+            Some(Self::White)
+        } else {
+            Some(Self::Gray)
+        }
+    }
+
+    /// Gets the HSL hue range of the family, and the saturation and lightness ranges for families
+    /// which are also defined by them.
+    fn bounds(
+        self,
+    ) -> (
+        core::ops::RangeInclusive<f32>,
+        Option<core::ops::RangeInclusive<f32>>,
+        Option<core::ops::RangeInclusive<f32>>,
+    ) {
+        let degrees = |start: f32, end: f32| start / 360.0..=end / 360.0;
+        match self {
+            Self::Red => (degrees(345.0, 15.0), None, None),
+            Self::Orange => (degrees(15.0, 45.0), None, None),
+            Self::Yellow => (degrees(45.0, 70.0), None, None),
+            Self::Green => (degrees(70.0, 165.0), None, None),
+            Self::Cyan => (degrees(165.0, 195.0), None, None),
+            Self::Blue => (degrees(195.0, 255.0), None, None),
+            Self::Purple => (degrees(255.0, 285.0), None, None),
+            Self::Magenta => (degrees(285.0, 345.0), None, None),
+            Self::Pink => (degrees(320.0, 360.0), Some(0.4..=1.0), Some(0.7..=0.9)),
+            Self::Brown => (degrees(10.0, 45.0), Some(0.3..=0.8), Some(0.15..=0.35)),
+            Self::Gray => (0.0..=1.0, Some(0.0..=0.1), Some(0.2..=0.8)),
+            Self::Black => (0.0..=1.0, Some(0.0..=0.2), Some(0.0..=0.08)),
+            Self::White => (0.0..=1.0, Some(0.0..=0.2), Some(0.94..=1.0)),
+        }
+    }
+}
+
+/// An error returned when a [`Color`] cannot be created.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorError {
+    /// A component is outside of its expected range of 0.0 - 1.0. NaN is also out of range.
+    OutOfRange { component: Component, value: f32 },
+}
+
+impl Display for ColorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfRange { component, value } => write!(
+                f,
+                "color parameter outside of expected range: {component:?} is {value}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ColorError {}
+
+/// A component of a color, which is reported by [`ColorError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Component {
+    Red,
+    Green,
+    Blue,
+    Saturation,
+    Lightness,
+    Value,
+}
+
+/// Checks that `value` is between 0.0 and 1.0, with the same tolerance as
+/// `test_color_value_range!`.
+fn out_of_range(value: f32) -> bool {
+    value + f32::EPSILON < 0.0 || value - f32::EPSILON > 1.0
+}
+
+fn check_range(component: Component, value: f32) -> Result<(), ColorError> {
+    if (value + f32::EPSILON >= 0.0) && (value - f32::EPSILON <= 1.0) {
+        Ok(())
+    } else {
+        Err(ColorError::OutOfRange { component, value })
+    }
+}
+
+/// An error returned when a [`ColorRange`] cannot be created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeError {
+    /// The number of steps is 0, while a range needs at least one color.
+    ZeroSteps,
+}
+
+impl Display for RangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ZeroSteps => write!(f, "a color range needs at least 1 step"),
+        }
+    }
+}
+
+impl core::error::Error for RangeError {}
+
+/// OKLCH colors with a chroma below this are considered to have no hue.
+const ACHROMATIC_CHROMA: f32 = 0.0001;
+
+/// Specifies the blend modes that can be used with [`blend()`](Color#method.blend).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    Multiply,
+    Screen,
+    Overlay,
+    Difference,
+    Add,
+    Subtract,
+}
+
+fn blend_channel(a: u8, b: u8, mode: BlendMode) -> u8 {
+    match mode {
+        BlendMode::Multiply => (a as usize * b as usize / 255) as u8,
+        BlendMode::Screen => 255 - (((255 - a) as usize * (255 - b) as usize) / 255) as u8,
+        BlendMode::Overlay => blend_channel(
+            a,
+            blend_channel(a, b, BlendMode::Multiply),
+            BlendMode::Screen,
+        ),
+        BlendMode::Difference => a.abs_diff(b),
+        BlendMode::Add => a.saturating_add(b),
+        BlendMode::Subtract => a.saturating_sub(b),
+    }
+}
+
+fn blend_channel_float(a: f32, b: f32, mode: BlendMode) -> f32 {
+    match mode {
+        BlendMode::Multiply => a * b,
+        BlendMode::Screen => 1.0 - (1.0 - a) * (1.0 - b),
+        BlendMode::Overlay => blend_channel_float(a, a * b, BlendMode::Screen),
+        BlendMode::Difference => (a - b).abs(),
+        BlendMode::Add => (a + b).min(1.0),
+        BlendMode::Subtract => (a - b).max(0.0),
+    }
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn linear_rgb_to_oklab(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+    (
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    )
+}
+
+fn oklab_to_linear_rgb(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+    (
+        4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_,
+        -1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_,
+        -0.004_196_086 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_,
+    )
+}
+
+fn oklch_to_oklab_ab(c: f32, h: f32) -> (f32, f32) {
+    let (sin, cos) = (h * core::f32::consts::TAU).sin_cos();
+    (c * cos, c * sin)
+}
+
+/// Converts OKLCH to a color, reducing the chroma until the color fits within the RGB gamut so
+/// that the hue is preserved.
+fn oklch_to_color_in_gamut(l: f32, c: f32, h: f32) -> Color {
+    let in_gamut = |c: f32| {
+        let (a, b) = oklch_to_oklab_ab(c, h);
+        let (r, g, b) = oklab_to_linear_rgb(l, a, b);
+        [r, g, b].iter().all(|v| (-0.0001..=1.0001).contains(v))
+    };
+    let mut c = c;
+    if !in_gamut(c) {
+        let mut low = 0.0;
+        let mut high = c;
+        for _ in 0..24 {
+            let mid = (low + high) / 2.0;
+            if in_gamut(mid) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        c = low;
+    }
+    Color::from_oklch(l, c, h)
+}
+
+const D65_WHITE: (f32, f32, f32) = (0.95047, 1.0, 1.08883);
+
+fn linear_rgb_to_xyz(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    (
+        0.4124564 * r + 0.3575761 * g + 0.1804375 * b,
+        0.2126729 * r + 0.7151522 * g + 0.0721750 * b,
+        0.0193339 * r + 0.119_192 * g + 0.9503041 * b,
+    )
+}
+
+fn xyz_to_linear_rgb(x: f32, y: f32, z: f32) -> (f32, f32, f32) {
+    (
+        3.2404542 * x - 1.5371385 * y - 0.4985314 * z,
+        -0.969_266 * x + 1.8760108 * y + 0.0415560 * z,
+        0.0556434 * x - 0.2040259 * y + 1.0572252 * z,
+    )
+}
+
+fn xyz_to_lab(x: f32, y: f32, z: f32) -> (f32, f32, f32) {
+    let f = |t: f32| {
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    };
+    let fx = f(x / D65_WHITE.0);
+    let fy = f(y / D65_WHITE.1);
+    let fz = f(z / D65_WHITE.2);
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+fn lab_to_xyz(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let f_inv = |t: f32| {
+        if t > 6.0 / 29.0 {
+            t.powi(3)
+        } else {
+            (116.0 * t - 16.0) * 27.0 / 24389.0
+        }
+    };
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+    (
+        f_inv(fx) * D65_WHITE.0,
+        f_inv(fy) * D65_WHITE.1,
+        f_inv(fz) * D65_WHITE.2,
+    )
+}
+
+fn ciede2000(lab1: (f32, f32, f32), lab2: (f32, f32, f32)) -> f32 {
+    let (l1, a1, b1) = (lab1.0 as f64, lab1.1 as f64, lab1.2 as f64);
+    let (l2, a2, b2) = (lab2.0 as f64, lab2.1 as f64, lab2.2 as f64);
+    let pow25_7 = 25f64.powi(7);
+    let c_bar = ((a1 * a1 + b1 * b1).sqrt() + (a2 * a2 + b2 * b2).sqrt()) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt());
+    let a1p = a1 * (1.0 + g);
+    let a2p = a2 * (1.0 + g);
+    let c1p = a1p.hypot(b1);
+    let c2p = a2p.hypot(b2);
+    let hue = |b: f64, a: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let h1p = hue(b1, a1p);
+    let h2p = hue(b2, a2p);
+    let delta_l = l2 - l1;
+    let delta_c = c2p - c1p;
+    let delta_h = if c1p * c2p == 0.0 {
+        0.0
+    } else if (h2p - h1p).abs() <= 180.0 {
+        h2p - h1p
+    } else if h2p <= h1p {
+        h2p - h1p + 360.0
+    } else {
+        h2p - h1p - 360.0
+    };
+    let delta_h = 2.0 * (c1p * c2p).sqrt() * (delta_h.to_radians() / 2.0).sin();
+    let l_bar = (l1 + l2) / 2.0;
+    let c_bar = (c1p + c2p) / 2.0;
+    let h_bar = if c1p * c2p == 0.0 {
+        h1p + h2p
+    } else if (h1p - h2p).abs() <= 180.0 {
+        (h1p + h2p) / 2.0
+    } else if h1p + h2p < 360.0 {
+        (h1p + h2p + 360.0) / 2.0
+    } else {
+        (h1p + h2p - 360.0) / 2.0
+    };
+    let t = 1.0 - 0.17 * (h_bar - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar).to_radians().cos()
+        + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+    let delta_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt();
+    let s_l = 1.0 + (0.015 * (l_bar - 50.0).powi(2)) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar;
+    let s_h = 1.0 + 0.015 * c_bar * t;
+    let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+    let l = delta_l / s_l;
+    let c = delta_c / s_c;
+    let h = delta_h / s_h;
+    (l * l + c * c + h * h + r_t * c * h).sqrt() as f32
+}
+
+fn oklab_distance(a: (f32, f32, f32), b: (f32, f32, f32)) -> f32 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2) + (a.2 - b.2).powi(2)).sqrt()
+}
 
-    /// Sets the luminance (L) of HSL.
-    pub fn set_hsl_luminance(&mut self, luminance: f32) {
-        let hsl = self.to_hsl();
-        let color = Self::from_hsl(hsl.0, hsl.1, luminance);
-        self.0 = color.0;
-        self.1 = color.1;
-        self.2 = color.2;
-    }
+fn lerp_rgb(a: &Color, b: &Color, t: f32) -> Color {
+    let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t + 0.5) as u8;
+    Color(lerp(a.0, b.0), lerp(a.1, b.1), lerp(a.2, b.2))
+}
 
-    /// Sets the hue (H) of HSV.
-    pub fn set_hsv_hue(&mut self, hue: f32) {
-        let hsv = self.to_hsv();
-        let color = Self::from_hsv(hue, hsv.1, hsv.2);
-        self.0 = color.0;
-        self.1 = color.1;
-        self.2 = color.2;
+fn hsl_to_rgb_float(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+    if s == 0.0 {
+        return (l, l, l);
     }
+    let v2 = if l < 0.5 {
+        l * (1.0 + s)
+    } else {
+        (l + s) - (s * l)
+    };
+    let v1 = 2.0 * l - v2;
+    let r = hue_to_rgb(v1, v2, h + (1.0 / 3.0));
+    let g = hue_to_rgb(v1, v2, h);
+    let b = hue_to_rgb(v1, v2, h - (1.0 / 3.0));
+    (r, g, b)
+}
 
-    /// Sets the saturation (S) of HSV.
-    pub fn set_hsv_saturation(&mut self, saturation: f32) {
-        let hsv = self.to_hsv();
-        let color = Self::from_hsv(hsv.0, saturation, hsv.2);
-        self.0 = color.0;
-        self.1 = color.1;
-        self.2 = color.2;
-    }
+/// Converts a hue in degrees to a rotation around the chromatic circle between 0 and 1.
+fn degrees_to_hue(degrees: f32) -> f32 {
+    (degrees / 360.0).rem_euclid(1.0)
+}
 
-    /// Sets the value (V) of HSV.
-    pub fn set_hsv_value(&mut self, value: f32) {
-        let hsv = self.to_hsv();
-        let color = Self::from_hsv(hsv.0, hsv.1, value);
-        self.0 = color.0;
-        self.1 = color.1;
-        self.2 = color.2;
-    }
+/// The distance between two hues, going the shorter way around the chromatic circle, so that hues
+/// just above 0 and just below 1 are close to each other.
+fn hue_distance(a: f32, b: f32) -> f32 {
+    let diff = (a - b).abs() % 1.0;
+    diff.min(1.0 - diff)
+}
 
-    /// Returns a [`ColorRange`] which is an iterator that returns some color scales
-    /// of variation between the current color and another color specified. Refer to
-    /// [`ColorRange`] for more information on how it works.
-    ///
-    /// # Panics
-    ///
-    /// Panics when `steps` is 0.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use octarine::Color;
-    ///
-    /// let c0 = Color::from_web_color("red").unwrap();
-    /// let c1 = Color::from_hex(0xFF7F00);
-    /// let c2 = Color::from_web_color("yellow").unwrap();
-    /// let c3 = Color::new(128, 255, 0);
-    /// let c4 = Color::from_web_color("lime").unwrap();
-    /// let mut range_to = c0.range_to(c4.clone(), 5);
-    ///
-    /// assert_eq!(Some(c0), range_to.next());
-    /// assert_eq!(Some(c1), range_to.next());
-    /// assert_eq!(Some(c2), range_to.next());
-    /// assert_eq!(Some(c3), range_to.next());
-    /// assert_eq!(Some(c4), range_to.next());
-    /// assert_eq!(None, range_to.next());
-    /// ```
-    #[inline]
-    pub fn range_to(&self, value: Self, steps: usize) -> ColorRange {
-        ColorRange::new(self.clone(), value, steps)
-    }
+/// A 3x3 matrix in row-major order, for transforming between color spaces.
+type Matrix = [[f32; 3]; 3];
 
-    /// This method offers a way to equate colors using [`Equivalence`], in which a color is
-    /// equated using its RGB, HSL, or HSV values.
-    ///
-    /// # Note
-    ///
-    /// By default octarine uses `[Equivalence::RGB]` for its [`PartialEq`] representation, so
-    /// if such equality operations are not required, you can simply
-    /// equate two color objects using `==`.
-    ///
-    /// # Example
-    /// ```
-    /// use octarine::{Color, Equivalence};
-    ///
-    /// let color = Color::new(100, 100, 100);
-    /// let color2 = color.clone();
-    ///
-    /// assert_eq!(color == color2, color.complex_eq(&color2, Equivalence::RGB));
-    /// ```
-    pub fn complex_eq(&self, other: &Self, equivalence: Equivalence) -> bool {
-        match equivalence {
-            Equivalence::RGB => self.0 == other.0 && self.1 == other.1 && self.2 == other.2,
-            Equivalence::HSL => self.to_hsl() == other.to_hsl(),
-            Equivalence::HSV => self.to_hsv() == other.to_hsv(),
-        }
-    }
+/// Multiplies `matrix` by the vector `(a, b, c)`.
+fn transform(matrix: &Matrix, (a, b, c): (f32, f32, f32)) -> (f32, f32, f32) {
+    let row = |i: usize| matrix[i][0] * a + matrix[i][1] * b + matrix[i][2] * c;
+    (row(0), row(1), row(2))
 }
 
-/// Specifies the methods in which [`complex_eq()`](Color#method.complex_eq) compares colors.
-pub enum Equivalence {
-    RGB,
-    HSL,
-    HSV,
+fn hsv_to_rgb_float(h: f32, s: f32, v: f32) -> (f32, f32, f32) {
+    if s == 0.0 {
+        return (v, v, v);
+    }
+    let mut v_h = h * 6.0;
+    if v_h == 6.0 {
+        v_h = 0.0;
+    }
+    let v_i = v_h.floor();
+    let v1 = v * (1.0 - s);
+    let v2 = v * (1.0 - s * (v_h - v_i));
+    let v3 = v * (1.0 - s * (1.0 - (v_h - v_i)));
+    if v_i == 0.0 {
+        (v, v3, v1)
+    } else if v_i == 1.0 {
+        (v2, v, v1)
+    } else if v_i == 2.0 {
+        (v1, v, v3)
+    } else if v_i == 3.0 {
+        (v1, v2, v)
+    } else if v_i == 4.0 {
+        (v3, v1, v)
+    } else {
+        (v, v1, v2)
+    }
 }
 
 fn hue_to_rgb(v1: f32, v2: f32, mut v_h: f32) -> f32 {
@@ -714,20 +2943,11 @@ fn hue_to_rgb(v1: f32, v2: f32, mut v_h: f32) -> f32 {
     }
 }
 
-impl PartialEq for Color {
-    fn eq(&self, other: &Self) -> bool {
-        self.complex_eq(other, Equivalence::RGB)
-    }
-}
-
 impl Mul for Color {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let r = (self.0 as usize * rhs.0 as usize / 255) as u8;
-        let g = (self.1 as usize * rhs.1 as usize / 255) as u8;
-        let b = (self.2 as usize * rhs.2 as usize / 255) as u8;
-        Self::new(r, g, b)
+        self.blend(&rhs, BlendMode::Multiply)
     }
 }
 
@@ -735,10 +2955,7 @@ impl Add for Color {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let r = self.0.saturating_add(rhs.0);
-        let g = self.1.saturating_add(rhs.1);
-        let b = self.2.saturating_add(rhs.2);
-        Self::new(r, g, b)
+        self.blend(&rhs, BlendMode::Add)
     }
 }
 
@@ -746,32 +2963,126 @@ impl Sub for Color {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let r = self.0.saturating_sub(rhs.0);
-        let g = self.1.saturating_sub(rhs.1);
-        let b = self.2.saturating_sub(rhs.2);
-        Self::new(r, g, b)
+        self.blend(&rhs, BlendMode::Subtract)
     }
 }
 
+/// Divides each channel by the same channel of another color. Dividing by a channel which is 0
+/// gives 255, or 0 when both channels are 0. Use [`checked_div()`](Color#method.checked_div) to
+/// detect division by 0 instead.
 impl Div for Color {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        let r = self.0 / rhs.0;
-        let g = self.1 / rhs.1;
-        let b = self.2 / rhs.2;
-        Self::new(r, g, b)
+        let divide = |a: u8, b: u8| match (a, b) {
+            (0, 0) => 0,
+            (_, 0) => 255,
+            _ => a / b,
+        };
+        Self::new(
+            divide(self.0, rhs.0),
+            divide(self.1, rhs.1),
+            divide(self.2, rhs.2),
+        )
+    }
+}
+
+impl<T> AddAssign<T> for Color
+where
+    Color: Add<T, Output = Color>,
+{
+    fn add_assign(&mut self, rhs: T) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T> SubAssign<T> for Color
+where
+    Color: Sub<T, Output = Color>,
+{
+    fn sub_assign(&mut self, rhs: T) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T> MulAssign<T> for Color
+where
+    Color: Mul<T, Output = Color>,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T> DivAssign<T> for Color
+where
+    Color: Div<T, Output = Color>,
+{
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
     }
 }
 
-impl ToString for Color {
-    fn to_string(&self) -> String {
-        format!("{}, {}, {}", self.0, self.1, self.2)
+/// Scales each channel by a factor, rounding it and clamping it between 0 and 255.
+impl Mul<f32> for Color {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        let scale = |v: u8| (v as f32 * rhs).round().clamp(0.0, 255.0) as u8;
+        Self(scale(self.0), scale(self.1), scale(self.2))
+    }
+}
+
+/// Divides each channel by a divisor, rounding it and clamping it between 0 and 255. Dividing by
+/// 0.0 gives 255 for channels which are not 0.
+impl Div<f32> for Color {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        let scale = |v: u8| {
+            if v == 0 {
+                return 0;
+            }
+            (v as f32 / rhs).round().clamp(0.0, 255.0) as u8
+        };
+        Self(scale(self.0), scale(self.1), scale(self.2))
+    }
+}
+
+/// Adds a value to each channel, saturating at 255.
+impl Add<u8> for Color {
+    type Output = Self;
+
+    fn add(self, rhs: u8) -> Self::Output {
+        Self(
+            self.0.saturating_add(rhs),
+            self.1.saturating_add(rhs),
+            self.2.saturating_add(rhs),
+        )
+    }
+}
+
+/// Subtracts a value from each channel, saturating at 0.
+impl Sub<u8> for Color {
+    type Output = Self;
+
+    fn sub(self, rhs: u8) -> Self::Output {
+        Self(
+            self.0.saturating_sub(rhs),
+            self.1.saturating_sub(rhs),
+            self.2.saturating_sub(rhs),
+        )
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}, {}, {}", self.0, self.1, self.2)
     }
 }
 
 impl Debug for Color {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "Color red: {}, green: {}, blue: {}",
@@ -780,55 +3091,330 @@ impl Debug for Color {
     }
 }
 
+// SAFETY: `Color` is `repr(C)` with three `u8`s, so it has no padding, and any bit pattern
+// (including all zeros) is a valid color.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Color {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Color {}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self(r, g, b)
+    }
+}
+
+impl From<[u8; 3]> for Color {
+    fn from([r, g, b]: [u8; 3]) -> Self {
+        Self(r, g, b)
+    }
+}
+
+/// Converts from hexadecimal in the `0xRRGGBB` format, the same as
+/// [`from_hex()`](Color#method.from_hex).
+impl From<u32> for Color {
+    fn from(rgb: u32) -> Self {
+        Self::from_hex(rgb)
+    }
+}
+
+/// Converts from RGB floats (0.0 - 1.0), clamping values which are out of range.
+impl From<[f32; 3]> for Color {
+    fn from([r, g, b]: [f32; 3]) -> Self {
+        Self::from_rgb_float(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0))
+    }
+}
+
+impl From<Color> for (u8, u8, u8) {
+    fn from(color: Color) -> Self {
+        color.to_rgb()
+    }
+}
+
+impl From<Color> for [u8; 3] {
+    fn from(color: Color) -> Self {
+        [color.0, color.1, color.2]
+    }
+}
+
+/// Converts to hexadecimal in the `0xRRGGBB` format, so that converting to [`u32`] and back gives
+/// the same color.
+impl From<Color> for u32 {
+    fn from(color: Color) -> Self {
+        (color.0 as u32) << 16 | (color.1 as u32) << 8 | color.2 as u32
+    }
+}
+
+/// Converts to RGB floats (0.0 - 1.0).
+impl From<Color> for [f32; 3] {
+    fn from(color: Color) -> Self {
+        color.to_vec3_srgb()
+    }
+}
+
+/// Converts to RGBA floats (0.0 - 1.0) with an alpha of 1.0, keeping the sRGB encoding. Use
+/// [`to_vec4_linear()`](Color#method.to_vec4_linear) for shaders which work in linear light.
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> Self {
+        let [r, g, b] = color.to_vec3_srgb();
+        [r, g, b, 1.0]
+    }
+}
+
 /// The color wheel allows you to randomly choose colors while keeping the colors relatively evenly distributed. Think
 /// generating random colors without pooling in one hue, e.g., not 50 green, and 1 red.
 ///
 /// ColorWheel is an iterable, but be careful if using inside any type of loop. It will iterate forever until you interject.
+///
+/// The RNG used for the random shifts can be replaced with [`with_rng()`](ColorWheel::with_rng),
+/// which is [`SmallRng`] by default.
+#[cfg(all(feature = "rand", feature = "std"))]
 #[derive(Debug, Clone)]
-pub struct ColorWheel {
+pub struct ColorWheel<R = SmallRng> {
     phase: f32,
-    rng: SmallRng,
+    shift: Shift,
+    saturation: f32,
+    value: f32,
+    rng: R,
+}
+
+/// How far the phase of a [`ColorWheel`] moves for each color.
+#[cfg(all(feature = "rand", feature = "std"))]
+#[derive(Debug, Clone, Copy)]
+enum Shift {
+    Random,
+    Fixed(f32),
+}
+
+/// The golden ratio conjugate, which spreads hues evenly however many colors are taken.
+#[cfg(all(feature = "rand", feature = "std"))]
+const GOLDEN_RATIO_CONJUGATE: f32 = 0.618_034;
+
+#[cfg(all(feature = "rand", feature = "std"))]
+impl ColorWheel {
+    /// Creates a new [`ColorWheel`] using the default parameters.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`ColorWheel`] with a custom starting point.
+    pub fn with_starting_point(mut start: f32) -> Self {
+        if start >= 1.0 {
+            start -= 1.0;
+        }
+        Self {
+            phase: start,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new deterministic [`ColorWheel`] which advances by the golden ratio conjugate
+    /// for each color, so that the colors are always the same and stay evenly spread out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::ColorWheel;
+    ///
+    /// let first: Vec<_> = ColorWheel::golden_ratio().take(5).collect();
+    /// let second: Vec<_> = ColorWheel::golden_ratio().take(5).collect();
+    ///
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn golden_ratio() -> Self {
+        Self::fixed_step(GOLDEN_RATIO_CONJUGATE)
+    }
+
+    /// Creates a new deterministic [`ColorWheel`] which advances by `step` for each color, where
+    /// 1.0 is a full rotation around the chromatic circle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{ColorWheel, Color};
+    ///
+    /// let mut wheel = ColorWheel::fixed_step(1.0 / 3.0);
+    ///
+    /// assert_eq!(wheel.next(), Some(Color::new(0, 204, 0)));
+    /// assert_eq!(wheel.next(), Some(Color::new(0, 0, 204)));
+    /// assert_eq!(wheel.next(), Some(Color::new(204, 0, 0)));
+    /// ```
+    pub fn fixed_step(step: f32) -> Self {
+        Self {
+            shift: Shift::Fixed(step),
+            ..Self::default()
+        }
+    }
 }
 
-impl ColorWheel {
-    /// Creates a new [`ColorWheel`] using the default parameters.
-    #[inline]
-    pub fn new() -> Self {
-        Self::default()
+#[cfg(all(feature = "rand", feature = "std"))]
+impl<R: Rng> ColorWheel<R> {
+    /// Sets the HSV saturation of the colors, which is 1.0 by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `saturation` is < 0.0 or > 1.0.
+    pub fn with_saturation(mut self, saturation: f32) -> Self {
+        test_color_value_range!(saturation, self.value);
+        self.saturation = saturation;
+        self
+    }
+
+    /// Sets the HSV value of the colors, which is 0.8 by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `value` is < 0.0 or > 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ColorWheel};
+    ///
+    /// let mut wheel = ColorWheel::fixed_step(0.5)
+    ///     .with_saturation(0.5)
+    ///     .with_value(1.0);
+    ///
+    /// assert_eq!(wheel.next(), Some(Color::new(128, 255, 255)));
+    /// ```
+    pub fn with_value(mut self, value: f32) -> Self {
+        test_color_value_range!(self.saturation, value);
+        self.value = value;
+        self
+    }
+
+    /// Replaces the RNG used for the random shifts with `rng`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::ColorWheel;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let first: Vec<_> = ColorWheel::new()
+    ///     .with_rng(StdRng::seed_from_u64(7))
+    ///     .take(5)
+    ///     .collect();
+    /// let second: Vec<_> = ColorWheel::new()
+    ///     .with_rng(StdRng::seed_from_u64(7))
+    ///     .take(5)
+    ///     .collect();
+    ///
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn with_rng<T: Rng>(self, rng: T) -> ColorWheel<T> {
+        ColorWheel {
+            phase: self.phase,
+            shift: self.shift,
+            saturation: self.saturation,
+            value: self.value,
+            rng,
+        }
+    }
+
+    /// Takes `n` colors whose hues are all at least `1 / (2 * n)` apart around the chromatic
+    /// circle, so that no two colors look nearly identical. Hues which are too close to a color
+    /// that was already taken are skipped, and when that happens too often, the next hue is placed
+    /// in the middle of the largest gap instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::ColorWheel;
+    ///
+    /// let palette = ColorWheel::new().take_distinct(6);
+    ///
+    /// assert_eq!(palette.len(), 6);
+    /// ```
+    pub fn take_distinct(&mut self, n: usize) -> Palette {
+        let minimum = 1.0 / (2 * n.max(1)) as f32;
+        let distance = |a: f32, b: f32| {
+            let d = (a - b).abs();
+            d.min(1.0 - d)
+        };
+        let mut hues: Vec<f32> = Vec::with_capacity(n);
+        while hues.len() < n {
+            let mut phase = self.phase;
+            let mut hue = None;
+            for _ in 0..32 {
+                phase = self.advance(phase);
+                if hues.iter().all(|h| distance(*h, phase) >= minimum) {
+                    hue = Some(phase);
+                    break;
+                }
+            }
+            let hue = hue.unwrap_or_else(|| {
+                let mut sorted = hues.clone();
+                sorted.sort_by(|a, b| a.total_cmp(b));
+                let mut gap = (
+                    sorted[0] + 1.0 - sorted[sorted.len() - 1],
+                    sorted[sorted.len() - 1],
+                );
+                for pair in sorted.windows(2) {
+                    if pair[1] - pair[0] > gap.0 {
+                        gap = (pair[1] - pair[0], pair[0]);
+                    }
+                }
+                (gap.1 + gap.0 / 2.0).rem_euclid(1.0)
+            });
+            self.phase = hue;
+            hues.push(hue);
+        }
+        hues.into_iter()
+            .map(|hue| Color::from_hsv(hue, self.saturation, self.value))
+            .collect()
+    }
+
+    /// Moves `phase` to the next hue.
+    fn advance(&mut self, phase: f32) -> f32 {
+        let shift: f32 = match self.shift {
+            Shift::Random => self.rng.gen_range(0.1..0.2),
+            Shift::Fixed(step) => step,
+        };
+        (phase + shift).rem_euclid(1.0)
     }
+}
 
-    /// Creates a new [`ColorWheel`] with a custom starting point.
-    pub fn with_starting_point(mut start: f32) -> Self {
-        if start >= 1.0 {
-            start -= 1.0;
-        }
-        let rng = rand::rngs::SmallRng::from_entropy();
-        Self { phase: start, rng }
+#[cfg(all(feature = "rand", feature = "std"))]
+impl<R: SeedableRng> ColorWheel<R> {
+    /// Seeds the RNG used for the random shifts, so that the same colors are returned every time.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = R::seed_from_u64(seed);
+        self
     }
 }
 
-impl Iterator for ColorWheel {
+#[cfg(all(feature = "rand", feature = "std"))]
+impl<R: Rng> Iterator for ColorWheel<R> {
     type Item = Color;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let shift: f32 = self.rng.gen_range(0.1..0.2);
-        self.phase += shift;
-        if self.phase >= 1.0 {
-            self.phase -= 1.0;
-        }
-        Some(Color::from_hsv(self.phase, 1.0, 0.8))
+        self.phase = self.advance(self.phase);
+        Some(Color::from_hsv(self.phase, self.saturation, self.value))
     }
 }
 
+#[cfg(all(feature = "rand", feature = "std"))]
 impl Default for ColorWheel {
     fn default() -> Self {
         let rng = rand::rngs::SmallRng::from_entropy();
-        Self { phase: 0.0, rng }
+        Self {
+            phase: 0.0,
+            shift: Shift::Random,
+            saturation: 1.0,
+            value: 0.8,
+            rng,
+        }
     }
 }
 
+#[cfg(feature = "alloc")]
+type Components = (f32, f32, f32);
+
 /// This is an iterator that allows you to iterate over minor color variation between a starting
-/// color and an ending color. You can only initialize this with [`range_to()`](Color#method.range_to).
+/// color and an ending color, or through several colors. You can only initialize this with
+/// [`range_to()`](Color#method.range_to) or [`range_through()`](Color#method.range_through).
 ///
 /// # Example
 ///
@@ -873,60 +3459,215 @@ impl Default for ColorWheel {
 /// assert_eq!(Some(c5), range_to.next());
 /// assert_eq!(None, range_to.next());
 /// ```
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone)]
 pub struct ColorRange {
     total_steps: usize,
     current_step: usize,
-    step: (f32, f32, f32),
-    start_color_hsl: (f32, f32, f32),
+    end_step: usize,
+    colors: Vec<Color>,
+    space: ColorSpace,
+    hue_direction: HueDirection,
+    easing: Easing,
+    /// The start and the step of each segment between adjacent colors.
+    segments: Vec<(Components, Components)>,
 }
 
+#[cfg(feature = "alloc")]
 impl ColorRange {
-    fn new(start_color: Color, end_color: Color, steps: usize) -> Self {
-        let nb = steps.checked_sub(1).expect(&format!(
-            "Unsupported negative number of colors: {steps} - 1"
-        ));
-        let start_color = start_color.to_hsl();
-        let end_color = end_color.to_hsl();
-        let s0 = (end_color.0 - start_color.0) / nb as f32;
-        let s1 = (end_color.1 - start_color.1) / nb as f32;
-        let s2 = (end_color.2 - start_color.2) / nb as f32;
-        let step = if nb > 0 {
-            (s0, s1, s2)
-        } else {
-            (0.0, 0.0, 0.0)
-        };
-        Self {
+    fn new(colors: Vec<Color>, steps: usize) -> Self {
+        if colors.is_empty() {
+            panic!("Unsupported number of colors: 0");
+        }
+        Self::try_new(colors, steps)
+            .unwrap_or_else(|_| panic!("Unsupported negative number of colors: {steps} - 1"))
+    }
+
+    fn try_new(colors: Vec<Color>, steps: usize) -> Result<Self, RangeError> {
+        if steps == 0 {
+            return Err(RangeError::ZeroSteps);
+        }
+        Ok(Self {
             total_steps: steps,
             current_step: 0,
-            step,
-            start_color_hsl: start_color,
+            end_step: steps,
+            colors,
+            space: ColorSpace::Hsl,
+            hue_direction: HueDirection::Specified,
+            easing: Easing::Linear,
+            segments: Vec::new(),
+        }
+        .with_segments())
+    }
+
+    /// Sets the [`ColorSpace`] the colors are interpolated in, which is [`ColorSpace::Hsl`] by
+    /// default. Interpolating in HSL can pass through gray or unexpected hues for colors that are
+    /// far apart, while [`ColorSpace::Oklab`] gives perceptually even steps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ColorSpace, constants};
+    ///
+    /// let mut range = constants::primary::BLACK
+    ///     .range_to(constants::primary::WHITE, 3)
+    ///     .with_space(ColorSpace::LinearRgb);
+    ///
+    /// assert_eq!(range.nth(1), Some(Color::new(188, 188, 188)));
+    /// ```
+    pub fn with_space(mut self, space: ColorSpace) -> Self {
+        self.space = space;
+        self.with_segments()
+    }
+
+    /// Sets the [`HueDirection`] the hue is interpolated in, which is
+    /// [`HueDirection::Specified`] by default. This only applies to color spaces with a hue.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, HueDirection};
+    ///
+    /// let mut shorter = constants::primary::RED
+    ///     .range_to(constants::primary::BLUE, 3)
+    ///     .with_hue_direction(HueDirection::Shorter);
+    /// let mut longer = constants::primary::RED
+    ///     .range_to(constants::primary::BLUE, 3)
+    ///     .with_hue_direction(HueDirection::Longer);
+    ///
+    /// assert_eq!(shorter.nth(1).unwrap().get_web_color(), Some("fuchsia"));
+    /// assert_eq!(longer.nth(1).unwrap().get_web_color(), Some("lime"));
+    /// ```
+    pub fn with_hue_direction(mut self, direction: HueDirection) -> Self {
+        self.hue_direction = direction;
+        self.with_segments()
+    }
+
+    /// Sets the [`Easing`] applied to the interpolation, which is [`Easing::Linear`] by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ColorSpace, constants, Easing};
+    ///
+    /// let mut range = constants::primary::BLACK
+    ///     .range_to(constants::primary::WHITE, 3)
+    ///     .with_space(ColorSpace::Srgb)
+    ///     .with_easing(Easing::custom(|t| t * t));
+    ///
+    /// assert_eq!(range.nth(1), Some(Color::new(64, 64, 64)));
+    /// ```
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Computes the start and the step of each segment between adjacent colors, where each step
+    /// is a fraction `1 / (total_steps - 1)` of the segment.
+    fn with_segments(mut self) -> Self {
+        let nb = (self.total_steps - 1).max(1) as f32;
+        self.segments = self
+            .colors
+            .windows(2)
+            .map(|pair| {
+                let (start, end) = self.space.endpoints(&pair[0], &pair[1], self.hue_direction);
+                let step = (
+                    (end.0 - start.0) / nb,
+                    (end.1 - start.1) / nb,
+                    (end.2 - start.2) / nb,
+                );
+                (start, step)
+            })
+            .collect();
+        self
+    }
+
+    fn color_at(&self, index: usize) -> Color {
+        if self.segments.is_empty() || self.total_steps == 1 {
+            return self.colors[0];
         }
+        // The position along the colors is `index * segments / nb`, which is split into a segment
+        // and the number of steps into that segment without losing precision.
+        let nb = self.total_steps - 1;
+        let position = index * self.segments.len();
+        let (segment, i) = if position / nb >= self.segments.len() {
+            (self.segments.len() - 1, nb)
+        } else {
+            (position / nb, position % nb)
+        };
+        let i = match self.easing {
+            Easing::Linear => i as f32,
+            _ => self.easing.apply(i as f32 / nb as f32) * nb as f32,
+        };
+        let (start, step) = self.segments[segment];
+        self.space.to_color((
+            start.0 + step.0 * i,
+            start.1 + step.1 * i,
+            start.2 + step.2 * i,
+        ))
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Iterator for ColorRange {
     type Item = Color;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_step == self.total_steps {
+        if self.current_step == self.end_step {
             return None;
         }
-        let m0 = self.step.0 * self.current_step as f32;
-        let m1 = self.step.1 * self.current_step as f32;
-        let m2 = self.step.2 * self.current_step as f32;
-        let v0 = self.start_color_hsl.0 + m0;
-        let v1 = self.start_color_hsl.1 + m1;
-        let v2 = self.start_color_hsl.2 + m2;
-        let color = Color::from_hsl(v0, v1, v2);
+        let color = self.color_at(self.current_step);
         self.current_step += 1;
         Some(color)
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end_step - self.current_step;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.current_step = self.current_step.saturating_add(n).min(self.end_step);
+        self.next()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl DoubleEndedIterator for ColorRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.current_step == self.end_step {
+            return None;
+        }
+        self.end_step -= 1;
+        Some(self.color_at(self.end_step))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.end_step = self.end_step.saturating_sub(n).max(self.current_step);
+        self.next_back()
+    }
 }
 
+#[cfg(feature = "alloc")]
+impl ExactSizeIterator for ColorRange {}
+
+#[cfg(feature = "alloc")]
+impl FusedIterator for ColorRange {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
 
     #[test]
     fn from_rgb_float() {
@@ -949,6 +3690,14 @@ mod tests {
         assert_eq!(canonical, from_hsl);
     }
 
+    #[test]
+    fn to_hsl_saturation() {
+        let color = Color::new(255, 1, 1);
+        let (h, s, l) = color.to_hsl();
+        assert_eq!(s, 1.0);
+        assert_eq!(Color::from_hsl(h, s, l), color);
+    }
+
     #[test]
     fn from_hsv() {
         let canonical = constants::primary::RED;
@@ -961,6 +3710,122 @@ mod tests {
         let canonical = constants::primary::RED;
         let from_web_color = Color::from_web_color("red");
         assert_eq!(Some(canonical), from_web_color);
+        for (name, color) in constants::WEB_COLORS {
+            assert_eq!(Color::from_web_color(name), Some(*color));
+        }
+        assert_eq!(
+            Color::from_web_color("DarkSlateGray"),
+            Some(Color::new(47, 79, 79))
+        );
+        assert_eq!(Color::from_web_color("teal"), Some(constants::css::TEAL));
+        assert_eq!(
+            Color::from_web_color("navyblue"),
+            Some(constants::x11::NAVYBLUE)
+        );
+        assert_eq!(Color::from_web_color("reds"), None);
+        assert_eq!(Color::from_web_color(""), None);
+    }
+
+    #[test]
+    fn get_web_color() {
+        for (i, (name, color)) in constants::WEB_COLORS.iter().enumerate() {
+            let canonical = constants::WEB_COLORS[..i]
+                .iter()
+                .find(|(_, other)| other == color)
+                .map_or(*name, |(canonical, _)| canonical);
+            assert_eq!(color.get_web_color(), Some(canonical));
+        }
+        assert_eq!(Color::new(1, 2, 3).get_web_color(), None);
+        assert_eq!(
+            Color::from_web_color("magenta").unwrap().get_web_color(),
+            Some("fuchsia")
+        );
+    }
+
+    #[test]
+    fn hue_family() {
+        let families = [
+            ("red", HueFamily::Red),
+            ("crimson", HueFamily::Red),
+            ("darkorange", HueFamily::Orange),
+            ("gold", HueFamily::Yellow),
+            ("forestgreen", HueFamily::Green),
+            ("darkturquoise", HueFamily::Cyan),
+            ("navy", HueFamily::Blue),
+            ("rebeccapurple", HueFamily::Purple),
+            ("fuchsia", HueFamily::Magenta),
+            ("hotpink", HueFamily::Pink),
+            ("saddlebrown", HueFamily::Brown),
+            ("gray", HueFamily::Gray),
+            ("black", HueFamily::Black),
+            ("white", HueFamily::White),
+        ];
+        for (name, family) in families {
+            let color = Color::from_web_color(name).unwrap();
+            assert_eq!(color.hue_family(), family, "{name}");
+            assert!(family.contains(&color), "{name}");
+        }
+        let deep_pink = Color::from_web_color("deeppink").unwrap();
+        assert_eq!(deep_pink.hue_family(), HueFamily::Magenta);
+        assert_eq!(Color::new(255, 250, 250).hue_family(), HueFamily::White);
+        assert_eq!(Color::new(20, 0, 0).hue_family(), HueFamily::Black);
+        assert_eq!(Color::new(20, 20, 22).hue_family(), HueFamily::Black);
+        assert_eq!(Color::new(230, 230, 232).hue_family(), HueFamily::White);
+        assert!(HueFamily::Black.contains(&Color::new(25, 25, 25)));
+        assert!(HueFamily::White.contains(&Color::new(220, 220, 222)));
+    }
+
+    #[test]
+    fn hue_family_contains() {
+        let families = [
+            HueFamily::Red,
+            HueFamily::Orange,
+            HueFamily::Yellow,
+            HueFamily::Green,
+            HueFamily::Cyan,
+            HueFamily::Blue,
+            HueFamily::Purple,
+            HueFamily::Magenta,
+            HueFamily::Pink,
+            HueFamily::Brown,
+            HueFamily::Gray,
+            HueFamily::Black,
+            HueFamily::White,
+        ];
+        let neutral = [HueFamily::Gray, HueFamily::Black, HueFamily::White];
+        for (name, color) in constants::web_colors() {
+            let family = color.hue_family();
+            assert!(family.contains(&color), "{name}");
+            for other in families {
+                if neutral.contains(&family) || neutral.contains(&other) {
+                    assert_eq!(other.contains(&color), other == family, "{name}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn web_colors() {
+        assert_eq!(constants::web_colors().count(), constants::WEB_COLORS.len());
+        let reds: Vec<_> = constants::web_colors_by_hue_family(HueFamily::Red)
+            .map(|(name, _)| name)
+            .collect();
+        assert!(reds.contains(&"red") && reds.contains(&"crimson"));
+        assert!(!reds.contains(&"gray") && !reds.contains(&"white"));
+        let grays: Vec<_> = constants::web_colors_by_hue_family(HueFamily::Gray)
+            .map(|(name, _)| name)
+            .collect();
+        assert!(grays.contains(&"gray") && grays.contains(&"dimgray"));
+        assert!(!grays.contains(&"slategray"));
+        assert!(
+            constants::web_colors_with_prefix("LIGHT").all(|(name, _)| name.starts_with("light"))
+        );
+        assert_eq!(constants::web_colors_with_prefix("rebecca").count(), 1);
+        assert_eq!(
+            constants::web_colors_with_prefix("").count(),
+            constants::WEB_COLORS.len()
+        );
+        assert_eq!(constants::web_colors_with_prefix("blackest").count(), 0);
     }
 
     #[test]
@@ -984,6 +3849,105 @@ mod tests {
         assert_eq!(canonical, overlay);
     }
 
+    #[test]
+    fn blend() {
+        let color = Color::from_hex(0xFF9999);
+        let other = Color::new(10, 10, 10);
+        assert_eq!(color.blend(&other, BlendMode::Screen), color.screen(other));
+        assert_eq!(
+            color.blend(&other, BlendMode::Difference),
+            color.difference(other)
+        );
+        assert_eq!(
+            color.blend(&other, BlendMode::Overlay),
+            color.overlay(other)
+        );
+        assert_eq!(color.blend(&other, BlendMode::Multiply), color * other);
+        assert_eq!(color.blend(&other, BlendMode::Add), color + other);
+        assert_eq!(color.blend(&other, BlendMode::Subtract), color - other);
+    }
+
+    #[test]
+    fn blend_linear() {
+        let gray = Color::new(128, 128, 128);
+        assert_eq!(
+            gray.blend_linear(&gray, BlendMode::Multiply),
+            Color::new(61, 61, 61)
+        );
+        assert_eq!(
+            gray.blend_linear(&constants::primary::BLACK, BlendMode::Screen),
+            gray
+        );
+        assert_eq!(
+            constants::primary::WHITE.blend_linear(&gray, BlendMode::Difference),
+            Color::new(229, 229, 229)
+        );
+    }
+
+    #[test]
+    fn duotone() {
+        let (dark, light) = (Color::new(30, 20, 90), Color::new(250, 200, 60));
+        for v in 0..=255 {
+            let gray = Color::new(v, v, v);
+            assert_eq!(
+                gray.duotone(&dark, &light),
+                lerp_rgb(&dark, &light, v as f32 / 255.0)
+            );
+            assert_eq!(gray.tritone(&dark, &light, &light), {
+                let t = (v as f32 / 255.0 * 2.0).min(1.0);
+                lerp_rgb(&dark, &light, t)
+            });
+        }
+        let red = constants::primary::RED.duotone(&dark, &light);
+        let green = constants::primary::GREEN.duotone(&dark, &light);
+        assert!(green.get_red() > red.get_red());
+    }
+
+    #[test]
+    fn posterize() {
+        for v in 0..=255 {
+            let color = Color::new(v, 255 - v, v / 2);
+            assert_eq!(color.posterize(256), color);
+            let two = color.posterize(2);
+            assert!([two.0, two.1, two.2].iter().all(|&c| c == 0 || c == 255));
+            assert_eq!(color.posterize(5).posterize(5), color.posterize(5));
+        }
+        assert_eq!(
+            Color::new(63, 64, 191).posterize(3),
+            Color::new(0, 128, 128)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported posterize levels: 1")]
+    fn posterize_levels() {
+        constants::OCTARINE.posterize(1);
+    }
+
+    #[test]
+    fn threshold() {
+        let gray = Color::new(128, 128, 128);
+        assert_eq!(gray.threshold(0.5), constants::primary::WHITE);
+        assert_eq!(gray.threshold(0.51), constants::primary::BLACK);
+        assert_eq!(
+            constants::primary::BLACK.threshold(0.0),
+            constants::primary::WHITE
+        );
+        assert_eq!(
+            constants::primary::WHITE.threshold(1.0),
+            constants::primary::WHITE
+        );
+    }
+
+    #[test]
+    fn linear_rgb() {
+        for i in 0..=255 {
+            let color = Color::new(i, i, i);
+            let (r, g, b) = color.to_linear_rgb();
+            assert_eq!(color, Color::from_linear_rgb(r, g, b));
+        }
+    }
+
     #[test]
     fn invert() {
         let canonical = constants::primary::BLACK;
@@ -991,11 +3955,48 @@ mod tests {
         assert_eq!(canonical, invert);
     }
 
+    #[test]
+    fn average() {
+        let canonical = Color::new(188, 188, 188);
+        let average = Color::average(&[constants::primary::BLACK, constants::primary::WHITE]);
+        assert_eq!(Some(canonical), average);
+        let color = Color::from_hex(0x3366CC);
+        assert_eq!(Some(color), Color::average(&[color, color]));
+        assert_eq!(None, Color::average(&[]));
+    }
+
+    #[test]
+    fn dominant() {
+        let mut colors = vec![constants::primary::BLUE; 3];
+        colors.extend([Color::new(255, 0, 0), Color::new(250, 3, 0)]);
+        colors.extend([Color::new(0, 255, 0), Color::new(1, 250, 0)]);
+        colors.push(Color::new(252, 1, 1));
+        assert_eq!(Some(constants::primary::BLUE), Color::dominant(&colors));
+        colors.push(Color::new(253, 0, 0));
+        assert_eq!(Some(Color::new(253, 1, 0)), Color::dominant(&colors));
+        assert_eq!(
+            Some(constants::primary::BLUE),
+            Color::dominant(&[constants::primary::BLUE])
+        );
+        assert_eq!(None, Color::dominant(&[]));
+    }
+
+    #[cfg(all(feature = "rand", feature = "std"))]
     #[test]
     fn random_color() {
         Color::random_color();
     }
 
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_seeded() {
+        let canonical = Color::random_seeded(7);
+        let mut rng = SmallRng::seed_from_u64(7);
+        assert_eq!(canonical, Color::random_with(&mut rng));
+        assert_eq!(canonical, Color::random_seeded(7));
+        assert_ne!(canonical, Color::random_seeded(8));
+    }
+
     #[test]
     fn to_hex() {
         let canonical = 0x646464;
@@ -1012,7 +4013,7 @@ mod tests {
 
     #[test]
     fn to_hsv() {
-        let canonical = (0.0, 0.0, 0.392156862745);
+        let canonical = (0.0, 0.0, 0.39215687);
         let to_hsv = Color::from_hex(0x646464).to_hsv();
         assert_eq!(canonical, to_hsv);
     }
@@ -1041,6 +4042,26 @@ mod tests {
         assert_eq!(canonical_web_color, None);
     }
 
+    #[test]
+    fn hue_degrees() {
+        let color = Color::new(100, 150, 200);
+        assert_eq!(color.get_hsl_hue_degrees(), color.get_hsl_hue() * 360.0);
+        assert_eq!(color.get_hsv_hue_degrees(), color.get_hsv_hue() * 360.0);
+        assert_eq!(
+            Color::from_hsl_deg(210.0, 0.5, 0.5),
+            Color::from_hsl(210.0 / 360.0, 0.5, 0.5)
+        );
+        let mut degrees = color;
+        degrees.set_hsl_hue_degrees(90.0);
+        let mut fraction = color;
+        fraction.set_hsl_hue(0.25);
+        assert_eq!(degrees, fraction);
+        degrees.set_hsv_hue_degrees(450.0);
+        fraction.set_hsv_hue(0.25);
+        assert_eq!(degrees, fraction);
+        assert_eq!(color.rotate_hue_degrees(-180.0), color.rotate_hue(0.5));
+    }
+
     #[test]
     fn range_to() {
         let c0 = Color::from_web_color("red").unwrap();
@@ -1048,7 +4069,7 @@ mod tests {
         let c2 = Color::from_web_color("yellow").unwrap();
         let c3 = Color::new(128, 255, 0);
         let c4 = Color::from_web_color("lime").unwrap();
-        let mut range_to = c0.range_to(c4.clone(), 5);
+        let mut range_to = c0.range_to(c4, 5);
         assert_eq!(Some(c0), range_to.next());
         assert_eq!(Some(c1), range_to.next());
         assert_eq!(Some(c2), range_to.next());
@@ -1061,7 +4082,7 @@ mod tests {
         let c3 = Color::from_hex(0x999999);
         let c4 = Color::from_hex(0xCCCCCC);
         let c5 = Color::from_web_color("white").unwrap();
-        let mut range_to = c0.range_to(c5.clone(), 6);
+        let mut range_to = c0.range_to(c5, 6);
         assert_eq!(Some(c0), range_to.next());
         assert_eq!(Some(c1), range_to.next());
         assert_eq!(Some(c2), range_to.next());
@@ -1071,6 +4092,223 @@ mod tests {
         assert_eq!(None, range_to.next());
     }
 
+    #[test]
+    fn harmonies() {
+        let red = constants::primary::RED;
+        let cyan = Color::from_web_color("cyan").unwrap();
+        assert_eq!(red.rotate_hue(-0.5), cyan);
+        assert_eq!(red.complementary(), cyan);
+        assert_eq!(
+            red.triadic(),
+            [red, constants::primary::GREEN, constants::primary::BLUE]
+        );
+        assert_eq!(red.square()[2], cyan);
+        assert_eq!(red.tetradic()[2], cyan);
+        assert_eq!(red.split_complementary()[0], red);
+        assert_eq!(red.analogous(4, 0.1).len(), 4);
+        assert_eq!(red.analogous(1, 0.1).into_colors(), vec![red]);
+        assert!(red.analogous(0, 0.1).is_empty());
+        let gray = Color::new(100, 100, 100);
+        assert_eq!(gray.complementary(), gray);
+    }
+
+    #[test]
+    fn oklab() {
+        let (l, a, b) = constants::primary::WHITE.to_oklab();
+        assert!((l - 1.0).abs() < 0.0001 && a.abs() < 0.0001 && b.abs() < 0.0001);
+        let (l, a, b) = constants::primary::RED.to_oklab();
+        assert!((l - 0.62796).abs() < 0.0001);
+        assert!((a - 0.22486).abs() < 0.0001);
+        assert!((b - 0.12585).abs() < 0.0001);
+        for hex in [0x000000, 0xFF9999, 0x3366CC, 0xAAFFCC, 0xCCDD00, 0xFFFFFF] {
+            let color = Color::from_hex(hex);
+            let (l, a, b) = color.to_oklab();
+            assert_eq!(color, Color::from_oklab(l, a, b));
+            let (l, c, h) = color.to_oklch();
+            assert_eq!(color, Color::from_oklch(l, c, h));
+        }
+    }
+
+    #[test]
+    fn lab() {
+        let (l, a, b) = constants::primary::RED.to_lab();
+        assert!((l - 53.2408).abs() < 0.01);
+        assert!((a - 80.0925).abs() < 0.01);
+        assert!((b - 67.2032).abs() < 0.01);
+        for hex in [0x000000, 0xFF9999, 0x3366CC, 0xAAFFCC, 0xCCDD00, 0xFFFFFF] {
+            let color = Color::from_hex(hex);
+            let (x, y, z) = color.to_xyz();
+            assert_eq!(color, Color::from_xyz(x, y, z));
+            let (l, a, b) = color.to_lab();
+            assert_eq!(color, Color::from_lab(l, a, b));
+        }
+    }
+
+    #[test]
+    fn delta_e() {
+        let pairs = [
+            ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+            ((50.0, 2.5, 0.0), (50.0, 0.0, -2.5), 4.3065),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+            (
+                (60.2574, -34.0099, 36.2677),
+                (60.4626, -34.1751, 39.4387),
+                1.2644,
+            ),
+            ((2.0776, 0.0795, -1.135), (0.9033, -0.0636, -0.5514), 0.9082),
+        ];
+        for (lab1, lab2, canonical) in pairs {
+            assert!((ciede2000(lab1, lab2) - canonical).abs() < 0.0001);
+            assert!((ciede2000(lab2, lab1) - canonical).abs() < 0.0001);
+        }
+        let color = Color::from_hex(0x3366CC);
+        assert_eq!(color.delta_e(&color), 0.0);
+    }
+
+    #[test]
+    fn contrast_ratio() {
+        let white = constants::primary::WHITE;
+        assert_eq!(white.contrast_ratio(&white), 1.0);
+        let gray = Color::new(118, 118, 118);
+        assert!((gray.contrast_ratio(&white) - 4.54).abs() < 0.01);
+        assert_eq!(gray.contrast_ratio(&white), white.contrast_ratio(&gray));
+    }
+
+    #[test]
+    fn monochromatic() {
+        let color = Color::from_hex(0x3366CC);
+        let hue = color.get_hsl_hue();
+        let shades = color.monochromatic(4);
+        assert_eq!(shades.len(), 4);
+        for (i, shade) in shades.iter().enumerate() {
+            assert!((shade.to_oklab().0 - (i + 1) as f32 / 5.0).abs() < 0.01);
+            assert!((shade.get_hsl_hue() - hue).abs() < 0.02);
+        }
+        assert!(color.monochromatic(0).is_empty());
+    }
+
+    #[test]
+    fn range_to_with_space() {
+        let c0 = constants::primary::RED;
+        let c1 = constants::primary::BLUE;
+        let mut range = c0.range_to(c1, 3).with_space(ColorSpace::Oklab);
+        assert_eq!(Some(c0), range.next());
+        assert_eq!(Some(Color::new(140, 83, 162)), range.next());
+        assert_eq!(Some(c1), range.next());
+        assert_eq!(None, range.next());
+        for space in [
+            ColorSpace::Srgb,
+            ColorSpace::LinearRgb,
+            ColorSpace::Hsl,
+            ColorSpace::Hsv,
+            ColorSpace::Oklab,
+            ColorSpace::Oklch,
+        ] {
+            let colors: Vec<_> = c0.range_to(c1, 4).with_space(space).collect();
+            assert_eq!(colors.len(), 4);
+            assert_eq!(colors[0], c0);
+            assert_eq!(colors[3], c1);
+            let gradient = Gradient::new(c0, c1).with_space(space);
+            assert_eq!(gradient.colors(4), colors);
+        }
+    }
+
+    #[test]
+    fn range_through() {
+        let colors = [
+            constants::primary::BLACK,
+            constants::primary::WHITE,
+            constants::primary::BLACK,
+        ];
+        let range: Vec<Color> = Color::range_through(&colors, 4)
+            .with_space(ColorSpace::Srgb)
+            .collect();
+        let canonical = vec![
+            constants::primary::BLACK,
+            Color::from_hex(0xAAAAAA),
+            Color::from_hex(0xAAAAAA),
+            constants::primary::BLACK,
+        ];
+        assert_eq!(range, canonical);
+        let range: Vec<Color> = Color::range_through(&colors[..1], 3).collect();
+        assert_eq!(range, vec![constants::primary::BLACK; 3]);
+        let mut range = Color::range_through(&colors, 1);
+        assert_eq!(range.next(), Some(constants::primary::BLACK));
+        assert_eq!(range.next(), None);
+    }
+
+    #[test]
+    fn range_to_iterator() {
+        let range = constants::primary::BLACK.range_to(constants::primary::WHITE, 6);
+        let mut forward: Vec<Color> = range.clone().collect();
+        let backward: Vec<Color> = range.clone().rev().collect();
+        forward.reverse();
+        assert_eq!(forward, backward);
+        let mut range = range.with_space(ColorSpace::Srgb);
+        assert_eq!(range.len(), 6);
+        assert_eq!(range.nth(1), Some(Color::from_hex(0x333333)));
+        assert_eq!(range.nth_back(1), Some(Color::from_hex(0xCCCCCC)));
+        assert_eq!(range.len(), 2);
+        assert_eq!(range.clone().last(), Some(Color::from_hex(0x999999)));
+        assert_eq!(range.next_back(), Some(Color::from_hex(0x999999)));
+        assert_eq!(range.next(), Some(Color::from_hex(0x666666)));
+        assert_eq!(range.len(), 0);
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+        assert_eq!(range.nth(usize::MAX), None);
+    }
+
+    #[test]
+    fn try_range_to() {
+        let black = constants::primary::BLACK;
+        let white = constants::primary::WHITE;
+        let canonical: Vec<Color> = black.range_to(white, 4).collect();
+        let range: Vec<Color> = black.try_range_to(white, 4).unwrap().collect();
+        assert_eq!(canonical, range);
+        let error = black.try_range_to(white, 0).unwrap_err();
+        assert_eq!(error, RangeError::ZeroSteps);
+        assert_eq!(error.to_string(), "a color range needs at least 1 step");
+    }
+
+    #[test]
+    fn range_to_with_easing() {
+        let black = constants::primary::BLACK;
+        let white = constants::primary::WHITE;
+        let colors: Vec<Color> = black
+            .range_to(white, 5)
+            .with_easing(Easing::EaseIn)
+            .collect();
+        assert_eq!(colors.len(), 5);
+        assert_eq!(colors[0], black);
+        assert_eq!(colors[4], white);
+        assert!(colors[1].get_red() < 64);
+        let mut single = black.range_to(white, 1).with_easing(Easing::EaseOut);
+        assert_eq!(single.next(), Some(black));
+    }
+
+    #[test]
+    fn hue_direction() {
+        let hue = |from: f32, to: f32, direction| {
+            let start = Color::from_hsl(from, 1.0, 0.5);
+            let end = Color::from_hsl(to, 1.0, 0.5);
+            let mut range = start.range_to(end, 3).with_hue_direction(direction);
+            (range.nth(1).unwrap().get_hsl_hue() * 360.0).round()
+        };
+        assert_eq!(hue(0.0, 0.25, HueDirection::Shorter), 45.0);
+        assert_eq!(hue(0.0, 0.75, HueDirection::Shorter), 315.0);
+        assert_eq!(hue(0.0, 0.25, HueDirection::Longer), 225.0);
+        assert_eq!(hue(0.0, 0.75, HueDirection::Longer), 135.0);
+        assert_eq!(hue(0.75, 0.25, HueDirection::Increasing), 0.0);
+        assert_eq!(hue(0.25, 0.75, HueDirection::Increasing), 180.0);
+        assert_eq!(hue(0.25, 0.75, HueDirection::Decreasing), 0.0);
+        assert_eq!(hue(0.75, 0.25, HueDirection::Decreasing), 180.0);
+        let red = constants::primary::RED;
+        let mut range = red
+            .range_to(red, 3)
+            .with_hue_direction(HueDirection::Longer);
+        assert_eq!(range.nth(1), Some(Color::from_web_color("cyan").unwrap()));
+    }
+
     #[test]
     fn equality() {
         let color = Color::new(100, 100, 100);
@@ -1078,6 +4316,16 @@ mod tests {
         assert_eq!(Color::from_hsv(0.0, 1.0, 1.0), Color::new(255, 0, 0));
         assert!(color.complex_eq(&color, Equivalence::HSL));
         assert!(color.complex_eq(&color, Equivalence::HSV));
+        assert!(color.approx_eq(&Color::new(98, 102, 100), 2));
+        assert!(!color.approx_eq(&Color::new(97, 100, 100), 2));
+        assert!(color.complex_eq(&Color::new(98, 102, 100), Equivalence::RgbWithin(2)));
+        assert!(!color.complex_eq(&Color::new(97, 100, 100), Equivalence::RgbWithin(2)));
+        let red = Color::new(255, 0, 0);
+        let pink_red = Color::new(255, 0, 3);
+        assert!(red.complex_eq(&pink_red, Equivalence::HslWithin(0.01)));
+        assert!(!red.complex_eq(&pink_red, Equivalence::HslWithin(0.001)));
+        assert!(red.complex_eq(&pink_red, Equivalence::DeltaE(2.0)));
+        assert!(!red.complex_eq(&Color::new(200, 0, 0), Equivalence::DeltaE(2.0)));
     }
 
     #[test]
@@ -1118,8 +4366,208 @@ mod tests {
         let canonical = Color::new(17, 25, 20);
         let divide = Color::from_hex(0xAAFFCC) / Color::new(10, 10, 10);
         assert_eq!(canonical, divide);
+        let canonical = Color::new(255, 0, 1);
+        let divide = Color::new(10, 0, 10) / Color::new(0, 0, 10);
+        assert_eq!(canonical, divide);
+    }
+
+    #[test]
+    fn channel_combinators() {
+        let a = Color::new(10, 200, 30);
+        let b = Color::new(100, 20, 30);
+        assert_eq!(a.map(|c| 255 - c), a.invert());
+        assert_eq!(a.zip_map(&b, u8::saturating_add), a + b);
+        assert_eq!(a.min(&b), Color::new(10, 20, 30));
+        assert_eq!(a.max(&b), Color::new(100, 200, 30));
+        assert_eq!(a.clamp(&Color::new(20, 0, 0), &b), Color::new(20, 20, 30));
+    }
+
+    #[test]
+    fn swizzle() {
+        let color = Color::new(1, 2, 3);
+        assert_eq!(color.swizzle(ChannelOrder::Rbg), Color::new(1, 3, 2));
+        assert_eq!(color.swizzle(ChannelOrder::Gbr), Color::new(2, 3, 1));
+        assert_eq!(color.bgr().bgr(), color);
+        assert_eq!(color.grb(), Color::new(2, 1, 3));
+        let round_trip = color.swizzle(ChannelOrder::Gbr).swizzle(ChannelOrder::Brg);
+        assert_eq!(round_trip, color);
+    }
+
+    #[test]
+    fn try_from() {
+        assert_eq!(
+            Color::try_from_rgb_float(1.0, 0.0, 1.0),
+            Ok(Color::from_rgb_float(1.0, 0.0, 1.0))
+        );
+        assert_eq!(
+            Color::try_from_hsl(0.3, 0.5, 0.5),
+            Ok(Color::from_hsl(0.3, 0.5, 0.5))
+        );
+        assert_eq!(
+            Color::try_from_hsv(0.3, 0.5, 0.5),
+            Ok(Color::from_hsv(0.3, 0.5, 0.5))
+        );
+        let error = Color::try_from_rgb_float(2.0, -1.0, 0.0).unwrap_err();
+        assert_eq!(
+            error,
+            ColorError::OutOfRange {
+                component: Component::Red,
+                value: 2.0
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "color parameter outside of expected range: Red is 2"
+        );
+        assert!(Color::try_from_hsv(0.0, f32::NAN, 0.0).is_err());
+    }
+
+    #[test]
+    fn assign() {
+        let mut color = Color::new(100, 100, 100);
+        color += 10;
+        assert_eq!(color, Color::new(110, 110, 110));
+        color -= Color::new(10, 20, 30);
+        assert_eq!(color, Color::new(100, 90, 80));
+        color *= 0.5;
+        assert_eq!(color, Color::new(50, 45, 40));
+        color /= Color::new(5, 5, 5);
+        assert_eq!(color, Color::new(10, 9, 8));
+        color *= constants::primary::WHITE;
+        assert_eq!(color, Color::new(10, 9, 8));
+    }
+
+    #[test]
+    fn macros() {
+        const CANONICAL: Color = Color::from_hex(0xFF9900);
+        assert_eq!(rgb!(255, 153, 0), CANONICAL);
+        assert_eq!(hex!("#ff9900"), CANONICAL);
+        assert_eq!(hex!("FF9900"), CANONICAL);
+        assert_eq!(hex!("#f90"), CANONICAL);
+        assert_eq!(__parse_hex("000"), constants::primary::BLACK);
+    }
+
+    #[test]
+    fn scale_brightness_preserve_hue() {
+        let color = Color::new(200, 120, 40);
+        for factor in [0.1, 0.25, 0.5, 0.9, 1.5] {
+            let scaled = color.scale_brightness_preserve_hue(factor);
+            assert!((scaled.get_hsl_hue() - color.get_hsl_hue()).abs() < 0.01);
+        }
+        assert_eq!(color.scale_brightness_preserve_hue(1.0), color);
+        assert_eq!(
+            color.scale_brightness_preserve_hue(0.0),
+            constants::primary::BLACK
+        );
+        assert_eq!(color.scale_brightness_preserve_hue(10.0).get_red(), 255);
+        assert_eq!(
+            constants::primary::BLACK.scale_brightness_preserve_hue(2.0),
+            constants::primary::BLACK
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let canonical = Color::new(255, 153, 0);
+        assert_eq!(Color::from((255, 153, 0)), canonical);
+        assert_eq!(Color::from([255, 153, 0]), canonical);
+        assert_eq!(Color::from(0xFF9900), canonical);
+        assert_eq!(Color::from([1.0, 0.6, 0.0]), canonical);
+        assert_eq!(Color::from([2.0, 0.6, -1.0]), canonical);
+        assert_eq!(<(u8, u8, u8)>::from(canonical), (255, 153, 0));
+        assert_eq!(<[u8; 3]>::from(canonical), [255, 153, 0]);
+        assert_eq!(u32::from(canonical), 0xFF9900);
+        assert_eq!(<[f32; 3]>::from(canonical), [1.0, 0.6, 0.0]);
+        assert_eq!(<[f32; 4]>::from(canonical), [1.0, 0.6, 0.0, 1.0]);
+        assert_eq!(canonical.to_vec4_linear()[3], 1.0);
+        let color: Color = u32::from(canonical).into();
+        assert_eq!(color, canonical);
+    }
+
+    #[test]
+    fn packed() {
+        let formats = [
+            PixelFormat::Rgb888,
+            PixelFormat::Bgr888,
+            PixelFormat::Rgba8888,
+            PixelFormat::Argb8888,
+            PixelFormat::Abgr8888,
+            PixelFormat::Bgra8888,
+        ];
+        let canonical = Color::new(0x12, 0x34, 0x56);
+        for format in formats {
+            let packed = canonical.to_packed(format);
+            assert_eq!(Color::from_packed(format, packed), canonical);
+        }
+        assert_eq!(canonical.to_packed(PixelFormat::Abgr8888), 0xFF563412);
+        assert_eq!(canonical.to_packed(PixelFormat::Bgr888), canonical.to_hex());
+        assert_eq!(
+            canonical.to_packed(PixelFormat::Rgb888),
+            canonical.to_0rgb()
+        );
+        assert_eq!(
+            canonical.to_packed(PixelFormat::Argb8888),
+            canonical.to_argb()
+        );
+        for format in [PixelFormat::Rgb565, PixelFormat::Rgb555] {
+            for color in [constants::primary::WHITE, constants::primary::BLACK] {
+                assert_eq!(Color::from_packed(format, color.to_packed(format)), color);
+            }
+            let color = Color::from_packed(format, canonical.to_packed(format));
+            assert!(color.get_red().abs_diff(canonical.get_red()) <= 4);
+            assert!(color.get_green().abs_diff(canonical.get_green()) <= 4);
+        }
+        assert_eq!(Color::new(0, 255, 0).to_packed(PixelFormat::Rgb565), 0x07E0);
+        assert_eq!(Color::new(0, 0, 255).to_packed(PixelFormat::Rgb555), 0x001F);
+    }
+
+    #[test]
+    fn bytes() {
+        let colors = [Color::new(1, 2, 3), Color::new(4, 5, 6)];
+        assert_eq!(core::mem::size_of::<Color>(), 3);
+        assert_eq!(
+            Color::from_bytes(Color::as_bytes(&colors)),
+            Some(&colors[..])
+        );
+        assert_eq!(Color::from_bytes(&[]), Some(&[][..]));
+        assert_eq!(Color::from_bytes(&[0; 5]), None);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck() {
+        let colors = [Color::new(1, 2, 3), Color::new(4, 5, 6)];
+        let bytes: &[u8] = bytemuck::cast_slice(&colors);
+        assert_eq!(bytes, Color::as_bytes(&colors));
+        let high = [Color16::new(1, 2, 3)];
+        assert_eq!(bytemuck::cast_slice::<_, u16>(&high), &[1, 2, 3]);
+        assert_eq!(<ColorF as bytemuck::Zeroable>::zeroed(), ColorF::default());
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(constants::primary::RED);
+        set.insert(Color::new(255, 0, 0));
+        set.insert(Color::default());
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&constants::primary::BLACK));
+    }
+
+    #[test]
+    fn scalar_arithmetic() {
+        let color = Color::new(100, 200, 0);
+        assert_eq!(color * 0.5, Color::new(50, 100, 0));
+        assert_eq!(color * 2.0, Color::new(200, 255, 0));
+        assert_eq!(color * -1.0, Color::new(0, 0, 0));
+        assert_eq!(color / 2.0, Color::new(50, 100, 0));
+        assert_eq!(color / 0.0, Color::new(255, 255, 0));
+        assert_eq!(color + 100, Color::new(200, 255, 100));
+        assert_eq!(color - 150, Color::new(0, 50, 0));
+    }
+
+    #[cfg(all(feature = "rand", feature = "std"))]
     #[test]
     fn color_wheel() {
         let mut color_wheel = ColorWheel::new();
@@ -1128,6 +4576,57 @@ mod tests {
         color_wheel.next();
     }
 
+    #[cfg(all(feature = "rand", feature = "std"))]
+    #[test]
+    fn color_wheel_fixed_step() {
+        let canonical: Vec<Color> = ColorWheel::fixed_step(0.25).take(4).collect();
+        let wheel: Vec<Color> = ColorWheel::fixed_step(-0.75).take(4).collect();
+        assert_eq!(canonical, wheel);
+        assert_eq!(canonical[3], Color::new(204, 0, 0));
+        let hues: Vec<f32> = ColorWheel::golden_ratio()
+            .take(3)
+            .map(|color| color.get_hsv_hue())
+            .collect();
+        assert!((hues[0] - 0.618).abs() < 0.01);
+        assert!((hues[1] - 0.236).abs() < 0.01);
+        assert!((hues[2] - 0.854).abs() < 0.01);
+    }
+
+    #[cfg(all(feature = "rand", feature = "std"))]
+    #[test]
+    fn color_wheel_with_seed() {
+        let canonical: Vec<Color> = ColorWheel::new().with_seed(3).take(10).collect();
+        let wheel: Vec<Color> = ColorWheel::new().with_seed(3).take(10).collect();
+        assert_eq!(canonical, wheel);
+        let wheel: Vec<Color> = ColorWheel::new()
+            .with_rng(SmallRng::seed_from_u64(3))
+            .take(10)
+            .collect();
+        assert_eq!(canonical, wheel);
+        let mut wheel = ColorWheel::new().with_saturation(0.0).with_value(0.5);
+        assert_eq!(wheel.next(), Some(Color::new(128, 128, 128)));
+    }
+
+    #[cfg(all(feature = "rand", feature = "std"))]
+    #[test]
+    fn color_wheel_take_distinct() {
+        for n in [1, 5, 12, 40] {
+            let palette = ColorWheel::new().with_seed(n as u64).take_distinct(n);
+            assert_eq!(palette.len(), n);
+            let hues: Vec<f32> = palette.iter().map(|color| color.get_hsv_hue()).collect();
+            for (i, a) in hues.iter().enumerate() {
+                for b in &hues[i + 1..] {
+                    let d = (a - b).abs();
+                    assert!(d.min(1.0 - d) >= 1.0 / (2 * n) as f32 - 0.01);
+                }
+            }
+        }
+        let mut wheel = ColorWheel::fixed_step(0.0);
+        assert_eq!(wheel.take_distinct(4).len(), 4);
+        assert!(wheel.take_distinct(0).is_empty());
+    }
+
+    #[cfg(all(feature = "rand", feature = "std"))]
     #[test]
     fn setter() {
         let mut color = Color::random_color();
@@ -0,0 +1,145 @@
+//! Lookup tables for the sRGB transfer function, so that converting 8-bit channels to and from
+//! linear RGB doesn't need [`powf()`](f32::powf).
+
+// The tables hold the bits of the `f32` values, so that they are exact.
+
+/// The linear value of every 8-bit sRGB channel value.
+static SRGB_TO_LINEAR: [u32; 256] = [
+    0x00000000, 0x399F22B4, 0x3A1F22B4, 0x3A6EB40E, 0x3A9F22B4, 0x3AC6EB61, 0x3AEEB40E, 0x3B0B3E5D,
+    0x3B1F22B4, 0x3B33070B, 0x3B46EB61, 0x3B5B518D, 0x3B70F18D, 0x3B83E1C6, 0x3B8FE616, 0x3B9C87FD,
+    0x3BA9C9B7, 0x3BB7AD6F, 0x3BC63549, 0x3BD56361, 0x3BE539C1, 0x3BF5BA70, 0x3C0373B5, 0x3C0C6152,
+    0x3C15A703, 0x3C1F45BE, 0x3C293E6B, 0x3C3391F7, 0x3C3E4149, 0x3C494D43, 0x3C54B6C7, 0x3C607EB1,
+    0x3C6CA5DF, 0x3C792D22, 0x3C830AA8, 0x3C89AF9F, 0x3C9085DB, 0x3C978DC5, 0x3C9EC7C2, 0x3CA63433,
+    0x3CADD37D, 0x3CB5A601, 0x3CBDAC20, 0x3CC5E639, 0x3CCE54AB, 0x3CD6F7D5, 0x3CDFD010, 0x3CE8DDB9,
+    0x3CF2212C, 0x3CFB9AC1, 0x3D02A569, 0x3D0798DC, 0x3D0CA7E6, 0x3D11D2AF, 0x3D171963, 0x3D1C7C2E,
+    0x3D21FB3C, 0x3D2796B2, 0x3D2D4EBB, 0x3D332380, 0x3D39152B, 0x3D3F23E3, 0x3D454FD1, 0x3D4B991C,
+    0x3D51FFEF, 0x3D58846A, 0x3D5F26B7, 0x3D65E6FE, 0x3D6CC564, 0x3D73C20F, 0x3D7ADD29, 0x3D810B67,
+    0x3D84B795, 0x3D887330, 0x3D8C3E4A, 0x3D9018F6, 0x3D940345, 0x3D97FD4A, 0x3D9C0716, 0x3DA020BB,
+    0x3DA44A4B, 0x3DA883D7, 0x3DACCD70, 0x3DB12728, 0x3DB59112, 0x3DBA0B3B, 0x3DBE95B5, 0x3DC33092,
+    0x3DC7DBE2, 0x3DCC97B6, 0x3DD1641F, 0x3DD6412C, 0x3DDB2EEF, 0x3DE02D77, 0x3DE53CD5, 0x3DEA5D19,
+    0x3DEF8E52, 0x3DF4D091, 0x3DFA23E8, 0x3DFF8861, 0x3E027F07, 0x3E054280, 0x3E080EA3, 0x3E0AE378,
+    0x3E0DC105, 0x3E10A754, 0x3E13966B, 0x3E168E52, 0x3E198F10, 0x3E1C98AD, 0x3E1FAB30, 0x3E22C6A3,
+    0x3E25EB09, 0x3E29186C, 0x3E2C4ED0, 0x3E2F8E41, 0x3E32D6C4, 0x3E362861, 0x3E39831E, 0x3E3CE703,
+    0x3E405416, 0x3E43CA5F, 0x3E4749E4, 0x3E4AD2AE, 0x3E4E64C2, 0x3E520027, 0x3E55A4E6, 0x3E595303,
+    0x3E5D0A8B, 0x3E60CB7C, 0x3E6495E0, 0x3E6869BF, 0x3E6C4720, 0x3E702E0C, 0x3E741E84, 0x3E781890,
+    0x3E7C1C38, 0x3E8014C2, 0x3E82203C, 0x3E84308D, 0x3E8645BA, 0x3E885FC5, 0x3E8A7EB2, 0x3E8CA283,
+    0x3E8ECB3D, 0x3E90F8E1, 0x3E932B74, 0x3E9562F8, 0x3E979F71, 0x3E99E0E2, 0x3E9C274E, 0x3E9E72B7,
+    0x3EA0C322, 0x3EA31892, 0x3EA57308, 0x3EA7D289, 0x3EAA3718, 0x3EACA0B7, 0x3EAF0F69, 0x3EB18333,
+    0x3EB3FC18, 0x3EB67A18, 0x3EB8FD37, 0x3EBB8579, 0x3EBE12E1, 0x3EC0A571, 0x3EC33D2D, 0x3EC5DA17,
+    0x3EC87C33, 0x3ECB2383, 0x3ECDD00B, 0x3ED081CD, 0x3ED338CC, 0x3ED5F50B, 0x3ED8B68D, 0x3EDB7D54,
+    0x3EDE4965, 0x3EE11AC1, 0x3EE3F16B, 0x3EE6CD67, 0x3EE9AEB6, 0x3EEC955D, 0x3EEF815D, 0x3EF272BA,
+    0x3EF56976, 0x3EF86594, 0x3EFB6717, 0x3EFE6E02, 0x3F00BD2D, 0x3F02460E, 0x3F03D1A7, 0x3F055FF9,
+    0x3F06F106, 0x3F0884CF, 0x3F0A1B56, 0x3F0BB49B, 0x3F0D50A0, 0x3F0EEF67, 0x3F1090F1, 0x3F12353E,
+    0x3F13DC51, 0x3F15862B, 0x3F1732CD, 0x3F18E239, 0x3F1A946F, 0x3F1C4971, 0x3F1E0141, 0x3F1FBBDF,
+    0x3F21794E, 0x3F23398E, 0x3F24FCA0, 0x3F26C286, 0x3F288B41, 0x3F2A56D3, 0x3F2C253D, 0x3F2DF680,
+    0x3F2FCA9E, 0x3F31A197, 0x3F337B6C, 0x3F355820, 0x3F3737B3, 0x3F391A26, 0x3F3AFF7C, 0x3F3CE7B5,
+    0x3F3ED2D2, 0x3F40C0D4, 0x3F42B1BE, 0x3F44A590, 0x3F469C4B, 0x3F4895F1, 0x3F4A9282, 0x3F4C9201,
+    0x3F4E946E, 0x3F5099CB, 0x3F52A218, 0x3F54AD57, 0x3F56BB8A, 0x3F58CCB0, 0x3F5AE0CD, 0x3F5CF7E0,
+    0x3F5F11EC, 0x3F612EEE, 0x3F634EEF, 0x3F6571E9, 0x3F6797E3, 0x3F69C0D6, 0x3F6BECCD, 0x3F6E1BBF,
+    0x3F704DB8, 0x3F7282AF, 0x3F74BAAE, 0x3F76F5AE, 0x3F7933B9, 0x3F7B74C6, 0x3F7DB8E0, 0x3F800000,
+];
+
+/// The smallest linear value which rounds to each 8-bit sRGB channel value from 1 to 255, so the
+/// number of values at most a linear value is the channel value it rounds to.
+static LINEAR_TO_SRGB: [u32; 255] = [
+    0x391F22B3, 0x39EEB40E, 0x3A46EB61, 0x3A8B3E5D, 0x3AB3070B, 0x3ADACFB7, 0x3B014C32, 0x3B153089,
+    0x3B2914DF, 0x3B3CF936, 0x3B50F2D1, 0x3B65FB9A, 0x3B7C3404, 0x3B89D060, 0x3B962333, 0x3BA314BE,
+    0x3BB0A731, 0x3BBEDCB6, 0x3BCDB76C, 0x3BDD3966, 0x3BED64AE, 0x3BFE3B44, 0x3C07DF90, 0x3C10F919,
+    0x3C1A6B32, 0x3C2436C7, 0x3C2E5CC7, 0x3C38DE19, 0x3C43BBA3, 0x3C4EF646, 0x3C5A8EE2, 0x3C668654,
+    0x3C72DD73, 0x3C7F9512, 0x3C865703, 0x3C8D1490, 0x3C940396, 0x3C9B247C, 0x3CA277A8, 0x3CA9FD79,
+    0x3CB1B654, 0x3CB9A299, 0x3CC1C2A9, 0x3CCA16E3, 0x3CD29FA4, 0x3CDB5D4D, 0x3CE45034, 0x3CED78B6,
+    0x3CF6D72F, 0x3D0035FC, 0x3D051BB6, 0x3D0A1CEE, 0x3D0F39D1, 0x3D14728A, 0x3D19C745, 0x3D1F382B,
+    0x3D24C56A, 0x3D2A6F24, 0x3D303586, 0x3D3618B9, 0x3D3C18E6, 0x3D423634, 0x3D4870CB, 0x3D4EC8D3,
+    0x3D553E74, 0x3D5BD1D3, 0x3D628319, 0x3D69526A, 0x3D703FEE, 0x3D774BCE, 0x3D7E7627, 0x3D82DF92,
+    0x3D869374, 0x3D8A56CC, 0x3D8E29AD, 0x3D920C28, 0x3D95FE50, 0x3D9A0036, 0x3D9E11EC, 0x3DA23384,
+    0x3DA66510, 0x3DAAA6A0, 0x3DAEF847, 0x3DB35A17, 0x3DB7CC1D, 0x3DBC4E6C, 0x3DC0E116, 0x3DC5842A,
+    0x3DCA37BA, 0x3DCEFBD7, 0x3DD3D090, 0x3DD8B5F6, 0x3DDDAC19, 0x3DE2B30A, 0x3DE7CAD9, 0x3DECF395,
+    0x3DF22D4F, 0x3DF7781A, 0x3DFCD3FE, 0x3E012088, 0x3E03DFAF, 0x3E06A77C, 0x3E0977F7, 0x3E0C5127,
+    0x3E0F3314, 0x3E121DC6, 0x3E151144, 0x3E180D95, 0x3E1B12C2, 0x3E1E20D1, 0x3E2137CB, 0x3E2457B7,
+    0x3E27809A, 0x3E2AB27C, 0x3E2DED67, 0x3E313160, 0x3E347E6F, 0x3E37D49D, 0x3E3B33ED, 0x3E3E9C68,
+    0x3E420E14, 0x3E4588FA, 0x3E490D21, 0x3E4C9A8F, 0x3E50314B, 0x3E53D15C, 0x3E577AC9, 0x3E5B2D99,
+    0x3E5EE9D2, 0x3E62AF7C, 0x3E667E9D, 0x3E6A5740, 0x3E6E3963, 0x3E722512, 0x3E761A53, 0x3E7A192D,
+    0x3E7E21A6, 0x3E8119E2, 0x3E8327C8, 0x3E853A87, 0x3E875222, 0x3E896E9E, 0x3E8B8FFC, 0x3E8DB641,
+    0x3E8FE170, 0x3E92118B, 0x3E944696, 0x3E968094, 0x3E98BF89, 0x3E9B0377, 0x3E9D4C61, 0x3E9F9A4B,
+    0x3EA1ED38, 0x3EA4452A, 0x3EA6A225, 0x3EA9042D, 0x3EAB6B43, 0x3EADD76B, 0x3EB048A9, 0x3EB2BF01,
+    0x3EB53A72, 0x3EB7BB01, 0x3EBA40B2, 0x3EBCCB86, 0x3EBF5B82, 0x3EC1F0A7, 0x3EC48AFA, 0x3EC72A7D,
+    0x3EC9CF32, 0x3ECC791E, 0x3ECF2842, 0x3ED1DCA2, 0x3ED49641, 0x3ED75521, 0x3EDA1945, 0x3EDCE2B1,
+    0x3EDFB167, 0x3EE2856A, 0x3EE55EBC, 0x3EE83D62, 0x3EEB215C, 0x3EEE0AAF, 0x3EF0F95D, 0x3EF3ED69,
+    0x3EF6E6D6, 0x3EF9E5A6, 0x3EFCE9E0, 0x3EFFF37F, 0x3F018145, 0x3F030B82, 0x3F049878, 0x3F062827,
+    0x3F07BA92, 0x3F094FBA, 0x3F0AE79F, 0x3F0C8244, 0x3F0E1FAA, 0x3F0FBFD2, 0x3F1162BE, 0x3F13086E,
+    0x3F14B0E4, 0x3F165C22, 0x3F180A29, 0x3F19BAF9, 0x3F1B6E95, 0x3F1D24FE, 0x3F1EDE35, 0x3F209A3B,
+    0x3F225912, 0x3F241ABB, 0x3F25DF37, 0x3F27A688, 0x3F2970AE, 0x3F2B3DAC, 0x3F2D0D84, 0x3F2EE033,
+    0x3F30B5BE, 0x3F328E25, 0x3F346969, 0x3F36478C, 0x3F38288F, 0x3F3A0C73, 0x3F3BF33A, 0x3F3DDCE5,
+    0x3F3FC975, 0x3F41B8EB, 0x3F43AB48, 0x3F45A08F, 0x3F4798BF, 0x3F4993DA, 0x3F4B91E2, 0x3F4D92D8,
+    0x3F4F96BD, 0x3F519D91, 0x3F53A758, 0x3F55B410, 0x3F57C3BD, 0x3F59D65E, 0x3F5BEBF6, 0x3F5E0485,
+    0x3F60200D, 0x3F623E91, 0x3F64600B, 0x3F668486, 0x3F68ABFA, 0x3F6AD671, 0x3F6D03E3, 0x3F6F345B,
+    0x3F7167D0, 0x3F739E4D, 0x3F75D7CA, 0x3F781452, 0x3F7A53DB, 0x3F7C9671, 0x3F7EDC0E,
+];
+
+/// Converts an 8-bit sRGB channel to linear RGB with a lookup table, which gives the same result
+/// as [`to_linear_rgb()`](crate::Color#method.to_linear_rgb) without calling
+/// [`powf()`](f32::powf). This is useful for doing linear-light math in your own pixel loops.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{srgb_to_linear_u8, Color};
+///
+/// let (r, _, _) = Color::new(128, 0, 0).to_linear_rgb();
+///
+/// assert_eq!(srgb_to_linear_u8(128), r);
+/// assert_eq!(srgb_to_linear_u8(255), 1.0);
+/// ```
+#[inline]
+pub fn srgb_to_linear_u8(value: u8) -> f32 {
+    f32::from_bits(SRGB_TO_LINEAR[value as usize])
+}
+
+/// Converts a linear RGB channel to 8-bit sRGB by searching a lookup table, which gives the same
+/// result as [`from_linear_rgb()`](crate::Color#method.from_linear_rgb) without calling
+/// [`powf()`](f32::powf). Values which are < 0.0 or > 1.0 are clamped.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{linear_to_srgb_u8, srgb_to_linear_u8};
+///
+/// assert_eq!(linear_to_srgb_u8(srgb_to_linear_u8(100)), 100);
+/// assert_eq!(linear_to_srgb_u8(2.0), 255);
+/// ```
+pub fn linear_to_srgb_u8(value: f32) -> u8 {
+    LINEAR_TO_SRGB.partition_point(|&threshold| f32::from_bits(threshold) <= value) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+
+    // `powf()` isn't correctly rounded, so the tables are compared to it with a tolerance, and
+    // channels which land right next to a rounding boundary may round either way.
+    #[test]
+    fn lookup_tables() {
+        for value in 0..=255 {
+            let c = value as f32 / 255.0;
+            let linear = crate::srgb_to_linear(c);
+            assert!((srgb_to_linear_u8(value) - linear).abs() < 1e-6, "{value}");
+            assert_eq!(linear_to_srgb_u8(linear), value);
+            assert_eq!(
+                Color::from_linear_rgb(linear, 0.0, 0.0),
+                Color::new(value, 0, 0)
+            );
+        }
+        for i in 0..=100_000 {
+            let linear = i as f32 / 100_000.0;
+            let srgb = crate::linear_to_srgb(linear).clamp(0.0, 1.0) * 255.0;
+            let difference = linear_to_srgb_u8(linear).abs_diff(srgb.round() as u8);
+            if (srgb.fract() - 0.5).abs() < 1e-3 {
+                assert!(difference <= 1, "{linear}");
+            } else {
+                assert_eq!(difference, 0, "{linear}");
+            }
+        }
+        assert_eq!(linear_to_srgb_u8(-1.0), 0);
+    }
+}
@@ -0,0 +1,162 @@
+//! Floating point functions for `no_std` targets, where [`f32`] and [`f64`] don't have the
+//! methods that need the standard library. These use [`libm`] and have the same names as the
+//! methods in `std`, so the rest of the crate doesn't need to know which one it is using.
+
+// Not every method is used with every combination of features.
+#[allow(dead_code)]
+pub(crate) trait Float: Sized {
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn fract(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn atan2(self, other: Self) -> Self;
+}
+
+macro_rules! impl_float {
+    (
+        $float:ty,
+        $floor:ident,
+        $ceil:ident,
+        $round:ident,
+        $trunc:ident,
+        $fmod:ident,
+        $sqrt:ident,
+        $cbrt:ident,
+        $pow:ident,
+        $exp:ident,
+        $ln:ident,
+        $hypot:ident,
+        $sin:ident,
+        $cos:ident,
+        $sincos:ident,
+        $atan2:ident
+    ) => {
+        impl Float for $float {
+            fn floor(self) -> Self {
+                libm::$floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                libm::$ceil(self)
+            }
+
+            fn round(self) -> Self {
+                libm::$round(self)
+            }
+
+            fn fract(self) -> Self {
+                self - libm::$trunc(self)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                let r = libm::$fmod(self, rhs);
+                if r < 0.0 {
+                    r + rhs.abs()
+                } else {
+                    r
+                }
+            }
+
+            fn sqrt(self) -> Self {
+                libm::$sqrt(self)
+            }
+
+            fn cbrt(self) -> Self {
+                libm::$cbrt(self)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                libm::$pow(self, n as $float)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                libm::$pow(self, n)
+            }
+
+            fn exp(self) -> Self {
+                libm::$exp(self)
+            }
+
+            fn ln(self) -> Self {
+                libm::$ln(self)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                libm::$hypot(self, other)
+            }
+
+            fn sin(self) -> Self {
+                libm::$sin(self)
+            }
+
+            fn cos(self) -> Self {
+                libm::$cos(self)
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                libm::$sincos(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                libm::$atan2(self, other)
+            }
+        }
+    };
+}
+
+impl_float!(
+    f32, floorf, ceilf, roundf, truncf, fmodf, sqrtf, cbrtf, powf, expf, logf, hypotf, sinf, cosf,
+    sincosf, atan2f
+);
+impl_float!(
+    f64, floor, ceil, round, trunc, fmod, sqrt, cbrt, pow, exp, log, hypot, sin, cos, sincos, atan2
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The test harness links `std`, whose inherent methods take precedence over `Float` in the
+    // rest of the crate, so the shim is tested against them directly.
+    #[test]
+    fn matches_std() {
+        let close = |a: f32, b: f32| {
+            a == b || (a.is_nan() && b.is_nan()) || (a - b).abs() <= 1e-6 * b.abs().max(1.0)
+        };
+        for x in [-2.5_f32, -1.0, -0.3, 0.0, 0.04, 0.5, 1.0, 2.2, 100.0] {
+            assert!(close(Float::floor(x), x.floor()), "{x}");
+            assert!(close(Float::ceil(x), x.ceil()), "{x}");
+            assert!(close(Float::round(x), x.round()), "{x}");
+            assert!(close(Float::fract(x), x.fract()), "{x}");
+            assert!(close(Float::rem_euclid(x, 1.0), x.rem_euclid(1.0)), "{x}");
+            assert!(
+                close(Float::rem_euclid(x, -0.75), x.rem_euclid(-0.75)),
+                "{x}"
+            );
+            assert!(close(Float::sqrt(x), x.sqrt()), "{x}");
+            assert!(close(Float::cbrt(x), x.cbrt()), "{x}");
+            assert!(close(Float::powi(x, 3), x.powi(3)), "{x}");
+            assert!(close(Float::powf(x, 2.4), x.powf(2.4)), "{x}");
+            assert!(close(Float::exp(x), x.exp()), "{x}");
+            assert!(close(Float::ln(x), x.ln()), "{x}");
+            assert!(close(Float::hypot(x, 0.7), x.hypot(0.7)), "{x}");
+            assert!(close(Float::sin(x), x.sin()), "{x}");
+            assert!(close(Float::cos(x), x.cos()), "{x}");
+            let (sin, cos) = Float::sin_cos(x);
+            assert!(close(sin, x.sin()) && close(cos, x.cos()), "{x}");
+            assert!(close(Float::atan2(x, -0.7), x.atan2(-0.7)), "{x}");
+        }
+        assert_eq!(Float::sqrt(2.0_f64), 2.0_f64.sqrt());
+    }
+}
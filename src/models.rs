@@ -0,0 +1,281 @@
+use super::*;
+
+/// A color as HSL values with named fields, which is harder to misuse than the tuple returned by
+/// [`to_hsl()`](Color#method.to_hsl). The hue is a rotation around the chromatic circle between 0
+/// and 1, and the saturation and lightness are between 0.0 and 1.0.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{Color, Hsl};
+///
+/// let hsl = Color::new(255, 0, 0).hsl();
+///
+/// assert_eq!(hsl, Hsl { h: 0.0, s: 1.0, l: 0.5 });
+/// assert_eq!(hsl.lighten(0.25).to_color(), Color::new(255, 128, 128));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+impl Hsl {
+    /// Creates HSL values.
+    #[inline]
+    pub const fn new(h: f32, s: f32, l: f32) -> Self {
+        Self { h, s, l }
+    }
+
+    /// Converts to a [`Color`].
+    ///
+    /// # Panics
+    ///
+    /// Panics when S and L values are < 0.0 or > 1.0.
+    pub fn to_color(&self) -> Color {
+        Color::from_hsl(self.h, self.s, self.l)
+    }
+
+    /// Rotates the hue by `amount`, where 1.0 is a full rotation.
+    pub fn rotate_hue(&self, amount: f32) -> Self {
+        Self::new((self.h + amount).rem_euclid(1.0), self.s, self.l)
+    }
+
+    /// Increases the lightness by `amount`, clamped to 1.0.
+    pub fn lighten(&self, amount: f32) -> Self {
+        Self::new(self.h, self.s, (self.l + amount).clamp(0.0, 1.0))
+    }
+
+    /// Decreases the lightness by `amount`, clamped to 0.0.
+    pub fn darken(&self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Increases the saturation by `amount`, clamped to 1.0.
+    pub fn saturate(&self, amount: f32) -> Self {
+        Self::new(self.h, (self.s + amount).clamp(0.0, 1.0), self.l)
+    }
+
+    /// Decreases the saturation by `amount`, clamped to 0.0.
+    pub fn desaturate(&self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+}
+
+/// A color as HSV values with named fields, which is harder to misuse than the tuple returned by
+/// [`to_hsv()`](Color#method.to_hsv). The hue is a rotation around the chromatic circle between 0
+/// and 1, and the saturation and value are between 0.0 and 1.0.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{Color, Hsv};
+///
+/// let hsv = Color::new(255, 0, 0).hsv();
+///
+/// assert_eq!(hsv, Hsv { h: 0.0, s: 1.0, v: 1.0 });
+/// assert_eq!(hsv.rotate_hue(0.5).to_color(), Color::new(0, 255, 255));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+}
+
+impl Hsv {
+    /// Creates HSV values.
+    #[inline]
+    pub const fn new(h: f32, s: f32, v: f32) -> Self {
+        Self { h, s, v }
+    }
+
+    /// Converts to a [`Color`].
+    ///
+    /// # Panics
+    ///
+    /// Panics when S and V values are < 0.0 or > 1.0.
+    pub fn to_color(&self) -> Color {
+        Color::from_hsv(self.h, self.s, self.v)
+    }
+
+    /// Rotates the hue by `amount`, where 1.0 is a full rotation.
+    pub fn rotate_hue(&self, amount: f32) -> Self {
+        Self::new((self.h + amount).rem_euclid(1.0), self.s, self.v)
+    }
+
+    /// Increases the value by `amount`, clamped to 1.0.
+    pub fn brighten(&self, amount: f32) -> Self {
+        Self::new(self.h, self.s, (self.v + amount).clamp(0.0, 1.0))
+    }
+
+    /// Decreases the value by `amount`, clamped to 0.0.
+    pub fn darken(&self, amount: f32) -> Self {
+        self.brighten(-amount)
+    }
+
+    /// Increases the saturation by `amount`, clamped to 1.0.
+    pub fn saturate(&self, amount: f32) -> Self {
+        Self::new(self.h, (self.s + amount).clamp(0.0, 1.0), self.v)
+    }
+
+    /// Decreases the saturation by `amount`, clamped to 0.0.
+    pub fn desaturate(&self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+}
+
+/// A color as CIELAB values with named fields, as returned by [`lab()`](Color#method.lab).
+/// L is the lightness (0.0 - 100.0), and A and B describe how green/red and blue/yellow the color
+/// is.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Lab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl Lab {
+    /// Creates CIELAB values.
+    #[inline]
+    pub const fn new(l: f32, a: f32, b: f32) -> Self {
+        Self { l, a, b }
+    }
+
+    /// Converts to a [`Color`], clamping colors which fall outside of the RGB gamut.
+    pub fn to_color(&self) -> Color {
+        Color::from_lab(self.l, self.a, self.b)
+    }
+
+    /// Increases the lightness by `amount`, clamped between 0.0 and 100.0.
+    pub fn lighten(&self, amount: f32) -> Self {
+        Self::new((self.l + amount).clamp(0.0, 100.0), self.a, self.b)
+    }
+
+    /// Decreases the lightness by `amount`, clamped between 0.0 and 100.0.
+    pub fn darken(&self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+}
+
+/// A color as OKLCH values with named fields, as returned by [`oklch()`](Color#method.oklch).
+/// L is the perceived lightness (0.0 - 1.0), C is the chroma, and H is the hue as a rotation
+/// around the chromatic circle between 0 and 1.
+///
+/// # Example
+///
+/// ```
+/// use octarine::Color;
+///
+/// let oklch = Color::new(100, 150, 200).oklch();
+///
+/// assert_eq!(oklch.to_color(), Color::new(100, 150, 200));
+/// assert!(oklch.with_chroma(0.0).to_color().to_hsl().1 < 0.01);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+impl Oklch {
+    /// Creates OKLCH values.
+    #[inline]
+    pub const fn new(l: f32, c: f32, h: f32) -> Self {
+        Self { l, c, h }
+    }
+
+    /// Converts to a [`Color`], clamping colors which fall outside of the RGB gamut.
+    pub fn to_color(&self) -> Color {
+        Color::from_oklch(self.l, self.c, self.h)
+    }
+
+    /// Rotates the hue by `amount`, where 1.0 is a full rotation.
+    pub fn rotate_hue(&self, amount: f32) -> Self {
+        Self::new(self.l, self.c, (self.h + amount).rem_euclid(1.0))
+    }
+
+    /// Replaces the chroma with `chroma`.
+    pub fn with_chroma(&self, chroma: f32) -> Self {
+        Self::new(self.l, chroma.max(0.0), self.h)
+    }
+
+    /// Replaces the lightness with `lightness`, clamped between 0.0 and 1.0.
+    pub fn with_lightness(&self, lightness: f32) -> Self {
+        Self::new(lightness.clamp(0.0, 1.0), self.c, self.h)
+    }
+}
+
+macro_rules! impl_model_conversions {
+    ($model:ident, $to:ident, $a:ident, $b:ident, $c:ident) => {
+        impl From<Color> for $model {
+            fn from(color: Color) -> Self {
+                let ($a, $b, $c) = color.$to();
+                Self::new($a, $b, $c)
+            }
+        }
+
+        impl From<$model> for Color {
+            fn from(model: $model) -> Self {
+                model.to_color()
+            }
+        }
+
+        impl From<$model> for (f32, f32, f32) {
+            fn from(model: $model) -> Self {
+                (model.$a, model.$b, model.$c)
+            }
+        }
+
+        impl From<(f32, f32, f32)> for $model {
+            fn from(($a, $b, $c): (f32, f32, f32)) -> Self {
+                Self::new($a, $b, $c)
+            }
+        }
+    };
+}
+
+impl_model_conversions!(Hsl, to_hsl, h, s, l);
+impl_model_conversions!(Hsv, to_hsv, h, s, v);
+impl_model_conversions!(Lab, to_lab, l, a, b);
+impl_model_conversions!(Oklch, to_oklch, l, c, h);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let color = Color::new(100, 150, 200);
+        assert_eq!(<(f32, f32, f32)>::from(color.hsl()), color.to_hsl());
+        assert_eq!(<(f32, f32, f32)>::from(color.hsv()), color.to_hsv());
+        assert_eq!(<(f32, f32, f32)>::from(color.lab()), color.to_lab());
+        assert_eq!(<(f32, f32, f32)>::from(color.oklch()), color.to_oklch());
+        assert_eq!(Color::from(color.hsl()), color);
+        assert_eq!(Color::from(color.hsv()), color);
+        assert_eq!(Color::from(color.lab()), color);
+        assert_eq!(Hsl::from((0.5, 1.0, 0.25)), Hsl::new(0.5, 1.0, 0.25));
+    }
+
+    #[test]
+    fn manipulation() {
+        let hsl = Hsl::new(0.9, 0.5, 0.5);
+        assert!((hsl.rotate_hue(0.2).h - 0.1).abs() < 1e-6);
+        assert_eq!(hsl.lighten(1.0).l, 1.0);
+        assert_eq!(hsl.darken(0.25).l, 0.25);
+        assert_eq!(hsl.desaturate(1.0).s, 0.0);
+        let hsv = Hsv::new(0.0, 1.0, 1.0);
+        assert_eq!(hsv.darken(1.0).to_color(), constants::primary::BLACK);
+        assert_eq!(hsv.desaturate(1.0).to_color(), constants::primary::WHITE);
+        let lab = constants::primary::WHITE.lab();
+        assert_eq!(lab.lighten(10.0).l, 100.0);
+        let oklch = constants::primary::RED.oklch();
+        assert!((oklch.rotate_hue(1.0).h - oklch.h).abs() < 1e-6);
+        assert_eq!(
+            oklch.with_chroma(0.0).with_lightness(1.0).to_color(),
+            constants::primary::WHITE
+        );
+    }
+}
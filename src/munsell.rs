@@ -0,0 +1,309 @@
+use super::*;
+use core::str::FromStr;
+
+/// The names of the Munsell hues, in order around the hue circle.
+const HUE_NAMES: [&str; 10] = ["R", "YR", "Y", "GY", "G", "BG", "B", "PB", "P", "RP"];
+
+/// The approximate CIELAB hue angles in degrees of the principal hues (5R, 5YR, ...), which are
+/// spaced unevenly as the Munsell hues are perceptually even.
+const PRINCIPAL_HUE_ANGLES: [f32; 10] = [
+    24.0, 58.0, 92.0, 122.0, 162.0, 194.0, 232.0, 276.0, 318.0, 350.0,
+];
+
+/// The CIELAB chroma of one step of Munsell chroma.
+const CHROMA_SCALE: f32 = 5.0;
+
+/// Gets the luminance (0.0 - 100.0) of a Munsell value with the polynomial of ASTM D1535.
+fn value_to_luminance(v: f32) -> f32 {
+    v * (1.1914 + v * (-0.225_33 + v * (0.233_52 + v * (-0.020_484 + v * 0.000_819_39))))
+}
+
+/// The inverse of [`value_to_luminance()`], which is found with bisection as the polynomial has
+/// no closed form inverse.
+fn luminance_to_value(y: f32) -> f32 {
+    let (mut low, mut high) = (0.0, 10.0);
+    for _ in 0..24 {
+        let mid = (low + high) / 2.0;
+        if value_to_luminance(mid) < y {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    (low + high) / 2.0
+}
+
+/// Interpolates between the principal hues, where `from` and `to` are the positions of the 10
+/// principal hues, and `x` wraps around at `period`.
+fn interpolate_hue(
+    x: f32,
+    from: &[f32; 10],
+    to: &[f32; 10],
+    from_period: f32,
+    to_period: f32,
+) -> f32 {
+    let x = if x < from[0] { x + from_period } else { x };
+    let i = from.iter().rposition(|&start| start <= x).unwrap_or(0);
+    let (start, end) = (
+        from[i],
+        from.get(i + 1).copied().unwrap_or(from[0] + from_period),
+    );
+    let (to_start, to_end) = (to[i], to.get(i + 1).copied().unwrap_or(to[0] + to_period));
+    let t = (x - start) / (end - start);
+    (to_start + (to_end - to_start) * t).rem_euclid(to_period)
+}
+
+/// The positions of the principal hues on the Munsell hue circle.
+const PRINCIPAL_HUES: [f32; 10] = [5.0, 15.0, 25.0, 35.0, 45.0, 55.0, 65.0, 75.0, 85.0, 95.0];
+
+/// A color in the Munsell color system, which is used by soil science, archaeology, and art
+/// conservation. The hue is the position on the Munsell hue circle between 0.0 and 100.0, where
+/// each of the 10 hues spans 10 steps, starting with the reds: 5.0 is 5R, 15.0 is 5YR, and 100.0
+/// (the same as 0.0) is 10RP. The value goes from 0.0 (black) to 10.0 (white), and the chroma
+/// starts at 0.0 for neutral grays.
+///
+/// The conversions approximate the Munsell renotation data by interpolating between the principal
+/// hues in CIELAB, which is close enough to look up or describe a color, but not for colorimetry.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{Color, Munsell};
+///
+/// let brick = Color::new(150, 70, 50).to_munsell();
+///
+/// assert_eq!(brick.to_string(), "10R 3.9/8.5");
+/// assert_eq!("N 5/".parse::<Munsell>().unwrap().to_string(), "N 5/");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Munsell {
+    pub hue: f32,
+    pub value: f32,
+    pub chroma: f32,
+}
+
+impl Munsell {
+    /// Creates Munsell values.
+    #[inline]
+    pub const fn new(hue: f32, value: f32, chroma: f32) -> Self {
+        Self { hue, value, chroma }
+    }
+
+    /// Converts to a [`Color`]. Chromas which are too high to be shown in sRGB are reduced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, Munsell};
+    ///
+    /// let gray: Munsell = "N 5/".parse().unwrap();
+    ///
+    /// assert!(gray.to_color().approx_eq(&Color::new(119, 119, 119), 2));
+    /// ```
+    pub fn to_color(&self) -> Color {
+        let y = value_to_luminance(self.value.clamp(0.0, 10.0)) / 100.0;
+        let l = if y > 216.0 / 24389.0 {
+            116.0 * y.cbrt() - 16.0
+        } else {
+            y * 24389.0 / 27.0
+        };
+        let angles = PRINCIPAL_HUE_ANGLES;
+        let h = interpolate_hue(self.hue, &PRINCIPAL_HUES, &angles, 100.0, 360.0).to_radians();
+        let c = self.chroma.max(0.0) * CHROMA_SCALE;
+        Lab::new(l, c * h.cos(), c * h.sin()).gamut_map(GamutMapping::ChromaReduction)
+    }
+}
+
+impl Color {
+    /// Gets the approximate Munsell hue, value, and chroma of the color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let white = constants::primary::WHITE.to_munsell();
+    /// let red = Color::new(200, 30, 40).to_munsell();
+    ///
+    /// assert!((white.value - 10.0).abs() < 0.01 && white.chroma < 0.1);
+    /// assert!(red.hue > 0.0 && red.hue < 10.0 && red.chroma > 10.0);
+    /// ```
+    pub fn to_munsell(&self) -> Munsell {
+        let y = self.relative_luminance() * 100.0;
+        let (_, a, b) = self.to_lab();
+        let hue = if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            let angle = b.atan2(a).to_degrees().rem_euclid(360.0);
+            let angles = PRINCIPAL_HUE_ANGLES;
+            interpolate_hue(angle, &angles, &PRINCIPAL_HUES, 360.0, 100.0)
+        };
+        Munsell::new(hue, luminance_to_value(y), a.hypot(b) / CHROMA_SCALE)
+    }
+}
+
+/// Rounds to one decimal place, which is as precise as Munsell notation usually gets.
+fn round_tenth(v: f32) -> f32 {
+    (v * 10.0).round() / 10.0
+}
+
+impl Display for Munsell {
+    /// Formats the color in Munsell notation, such as `5R 4/14`, or `N 5/` for neutral colors.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let value = round_tenth(self.value);
+        let chroma = round_tenth(self.chroma);
+        if chroma <= 0.0 {
+            return write!(f, "N {value}/");
+        }
+        // The hue is counted in tenths, so 10R is shown instead of 0YR.
+        let mut tenths = (self.hue * 10.0).round() as i32 % 1000;
+        if tenths <= 0 {
+            tenths += 1000;
+        }
+        let family = (tenths - 1) / 100;
+        let step = (tenths - family * 100) as f32 / 10.0;
+        write!(f, "{step}{} {value}/{chroma}", HUE_NAMES[family as usize])
+    }
+}
+
+impl FromStr for Munsell {
+    type Err = MunsellError;
+
+    /// Parses Munsell notation, such as `5R 4/14`, `2.5YR 5/6`, or `N 5/` for neutral colors.
+    /// The space between the hue and value is optional.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (hue, rest) = if let Some(rest) = s.strip_prefix(['N', 'n']) {
+            (None, rest)
+        } else {
+            let digits = s
+                .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .ok_or(MunsellError::InvalidHue)?;
+            let step: f32 = s[..digits].parse().map_err(|_| MunsellError::InvalidHue)?;
+            let letters = s[digits..]
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .map_or(s.len(), |end| digits + end);
+            let family = HUE_NAMES
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(&s[digits..letters]))
+                .ok_or(MunsellError::InvalidHue)?;
+            if step == 0.0 || !(0.0..=10.0).contains(&step) {
+                return Err(MunsellError::InvalidHue);
+            }
+            (Some(family as f32 * 10.0 + step), &s[letters..])
+        };
+        let (value, chroma) = rest
+            .trim_start()
+            .split_once('/')
+            .unwrap_or((rest.trim_start(), ""));
+        let value: f32 = value
+            .trim()
+            .parse()
+            .map_err(|_| MunsellError::InvalidValue)?;
+        if !(0.0..=10.0).contains(&value) {
+            return Err(MunsellError::InvalidValue);
+        }
+        let chroma = chroma.trim();
+        let chroma: f32 = match (hue, chroma) {
+            (None, "") => 0.0,
+            _ => chroma.parse().map_err(|_| MunsellError::InvalidChroma)?,
+        };
+        if chroma.is_nan() || chroma < 0.0 || (hue.is_none() && chroma != 0.0) {
+            return Err(MunsellError::InvalidChroma);
+        }
+        Ok(Self::new(hue.unwrap_or(0.0), value, chroma))
+    }
+}
+
+/// An error returned when [`Munsell`] notation cannot be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MunsellError {
+    /// The hue is not a step between 0.0 and 10.0 followed by one of the 10 hues, such as `5R`.
+    InvalidHue,
+    /// The value is not a number between 0.0 and 10.0.
+    InvalidValue,
+    /// The chroma is not a number of at least 0.0, or a neutral color has a chroma.
+    InvalidChroma,
+}
+
+impl Display for MunsellError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidHue => write!(f, "invalid Munsell hue"),
+            Self::InvalidValue => write!(f, "invalid Munsell value"),
+            Self::InvalidChroma => write!(f, "invalid Munsell chroma"),
+        }
+    }
+}
+
+impl core::error::Error for MunsellError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn value() {
+        assert!((value_to_luminance(10.0) - 100.0).abs() < 0.01);
+        assert_eq!(value_to_luminance(0.0), 0.0);
+        for i in 0..=100 {
+            let v = i as f32 / 10.0;
+            assert!((luminance_to_value(value_to_luminance(v)) - v).abs() < 0.001);
+        }
+        // Middle gray is about 19% reflectance.
+        assert!((value_to_luminance(5.0) - 19.27).abs() < 0.01);
+    }
+
+    #[test]
+    fn hue() {
+        for i in 0..100 {
+            let hue = i as f32 + 0.5;
+            let angle = interpolate_hue(hue, &PRINCIPAL_HUES, &PRINCIPAL_HUE_ANGLES, 100.0, 360.0);
+            let back = interpolate_hue(angle, &PRINCIPAL_HUE_ANGLES, &PRINCIPAL_HUES, 360.0, 100.0);
+            assert!((back - hue).abs() < 0.001, "{hue}");
+        }
+        let angle = interpolate_hue(15.0, &PRINCIPAL_HUES, &PRINCIPAL_HUE_ANGLES, 100.0, 360.0);
+        assert_eq!(angle, 58.0);
+    }
+
+    #[test]
+    fn round_trip() {
+        for color in [
+            Color::new(150, 70, 50),
+            Color::new(40, 90, 160),
+            Color::new(120, 160, 60),
+            Color::new(200, 200, 200),
+        ] {
+            let munsell = color.to_munsell();
+            assert!(
+                munsell.to_color().approx_eq(&color, 2),
+                "{color:?} {munsell:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn notation() {
+        assert_eq!(Munsell::new(5.0, 4.0, 14.0).to_string(), "5R 4/14");
+        assert_eq!(Munsell::new(12.5, 5.0, 6.0).to_string(), "2.5YR 5/6");
+        assert_eq!(Munsell::new(0.0, 3.0, 2.0).to_string(), "10RP 3/2");
+        assert_eq!(Munsell::new(100.0, 3.0, 2.0).to_string(), "10RP 3/2");
+        assert_eq!(Munsell::new(30.0, 6.5, 0.02).to_string(), "N 6.5/");
+        assert_eq!("5R 4/14".parse(), Ok(Munsell::new(5.0, 4.0, 14.0)));
+        assert_eq!("2.5yr5/6".parse(), Ok(Munsell::new(12.5, 5.0, 6.0)));
+        assert_eq!(" 10RP 3.5/2 ".parse(), Ok(Munsell::new(100.0, 3.5, 2.0)));
+        assert_eq!("N 5/".parse(), Ok(Munsell::new(0.0, 5.0, 0.0)));
+        assert_eq!("N5".parse(), Ok(Munsell::new(0.0, 5.0, 0.0)));
+        assert_eq!("5X 4/14".parse::<Munsell>(), Err(MunsellError::InvalidHue));
+        assert_eq!("0R 4/14".parse::<Munsell>(), Err(MunsellError::InvalidHue));
+        assert_eq!("R 4/14".parse::<Munsell>(), Err(MunsellError::InvalidHue));
+        assert_eq!(
+            "5R 11/14".parse::<Munsell>(),
+            Err(MunsellError::InvalidValue)
+        );
+        assert_eq!("5R /14".parse::<Munsell>(), Err(MunsellError::InvalidValue));
+        assert_eq!("5R 4/".parse::<Munsell>(), Err(MunsellError::InvalidChroma));
+        assert_eq!("N 5/2".parse::<Munsell>(), Err(MunsellError::InvalidChroma));
+    }
+}
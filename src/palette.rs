@@ -0,0 +1,1192 @@
+use super::*;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::ops::Index;
+
+/// A collection of colors, with optional names for each entry.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{Color, Palette};
+///
+/// let mut palette = Palette::new();
+/// palette.push(Color::new(0, 0, 255));
+/// palette.push_named("accent", Color::new(255, 0, 0));
+///
+/// assert_eq!(palette.len(), 2);
+/// assert_eq!(palette.get_named("accent"), Some(&Color::new(255, 0, 0)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Palette {
+    entries: Vec<Entry>,
+}
+
+const SCALE_STEPS: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+const SCALE_LIGHTEST: f32 = 0.97;
+const SCALE_DARKEST: f32 = 0.28;
+
+const CATEGORICAL_LIGHTNESS: (f32, f32) = (0.45, 0.85);
+
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    name: Option<String>,
+    color: Color,
+}
+
+impl Palette {
+    /// Creates an empty [`Palette`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a color to the end of the palette.
+    pub fn push(&mut self, color: Color) {
+        self.entries.push(Entry { name: None, color });
+    }
+
+    /// Adds a named color to the end of the palette. If a color with the same name already exists,
+    /// its color is replaced instead.
+    pub fn push_named(&mut self, name: impl Into<String>, color: Color) {
+        let name = name.into();
+        match self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.name.as_deref() == Some(&name))
+        {
+            Some(entry) => entry.color = color,
+            None => self.entries.push(Entry {
+                name: Some(name),
+                color,
+            }),
+        }
+    }
+
+    /// Removes and returns the color at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Color {
+        self.entries.remove(index).color
+    }
+
+    /// Gets the color at `index`. Returns `None` when `index` is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&Color> {
+        self.entries.get(index).map(|entry| &entry.color)
+    }
+
+    /// Gets a color by its name. Returns `None` when no color has that name.
+    pub fn get_named(&self, name: &str) -> Option<&Color> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name.as_deref() == Some(name))
+            .map(|entry| &entry.color)
+    }
+
+    /// Gets the name of the color at `index`. Returns `None` when `index` is out of bounds or
+    /// the color is unnamed.
+    #[inline]
+    pub fn get_name(&self, index: usize) -> Option<&str> {
+        self.entries
+            .get(index)
+            .and_then(|entry| entry.name.as_deref())
+    }
+
+    /// Gets the number of colors in the palette.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the palette contains no colors.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the colors of the palette.
+    pub fn iter(&self) -> impl Iterator<Item = &Color> {
+        self.entries.iter().map(|entry| &entry.color)
+    }
+
+    /// Returns an iterator that allows modifying the colors of the palette, keeping their names.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Color> {
+        self.entries.iter_mut().map(|entry| &mut entry.color)
+    }
+
+    /// Returns an iterator over the colors of the palette along with their names.
+    pub fn iter_named(&self) -> impl Iterator<Item = (Option<&str>, &Color)> {
+        self.entries
+            .iter()
+            .map(|entry| (entry.name.as_deref(), &entry.color))
+    }
+
+    /// Converts the palette into a [`Vec`] of its colors, discarding the names.
+    pub fn into_colors(self) -> Vec<Color> {
+        self.entries.into_iter().map(|entry| entry.color).collect()
+    }
+
+    /// Generates the 11 step (50, 100, 200, ..., 900, 950) lightness scale used by design
+    /// systems such as Tailwind CSS from a seed color. Each entry is named after its step, and
+    /// shares the hue of the seed color. Equivalent to calling
+    /// [`scale_from_with_curve()`](Palette#method.scale_from_with_curve) with a curve of `1.25`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, Palette};
+    ///
+    /// let scale = Palette::scale_from(&Color::from_hex(0x3B82F6));
+    ///
+    /// assert_eq!(scale.len(), 11);
+    /// assert!(scale.get_named("50").is_some());
+    /// assert!(scale.get_named("950").is_some());
+    /// ```
+    #[inline]
+    pub fn scale_from(seed: &Color) -> Self {
+        Self::scale_from_with_curve(seed, 1.25)
+    }
+
+    /// Same as [`scale_from()`](Palette#method.scale_from), but with a custom contrast curve.
+    /// The perceived lightness (OKLab L) of the steps goes from `0.97` to `0.28`, and `curve` is
+    /// the exponent applied to the position of each step on that range. A curve of `1.0` spaces the
+    /// steps linearly, values above `1.0` keep the lighter steps closer together, and values below
+    /// `1.0` keep the darker steps closer together.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `curve` is <= 0.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, Palette};
+    ///
+    /// let scale = Palette::scale_from_with_curve(&Color::from_hex(0x3B82F6), 1.0);
+    /// let lightness: Vec<f32> = scale.iter().map(|c| c.to_oklab().0).collect();
+    ///
+    /// assert!(lightness.windows(2).all(|l| l[0] > l[1]));
+    /// ```
+    pub fn scale_from_with_curve(seed: &Color, curve: f32) -> Self {
+        if curve <= 0.0 {
+            panic!("Unsupported contrast curve: {curve}");
+        }
+        let (_, c, h) = seed.to_oklch();
+        let mut palette = Self::new();
+        for (i, step) in SCALE_STEPS.iter().enumerate() {
+            let t = (i as f32 / (SCALE_STEPS.len() - 1) as f32).powf(curve);
+            let l = SCALE_LIGHTEST + (SCALE_DARKEST - SCALE_LIGHTEST) * t;
+            palette.push_named(step.to_string(), oklch_to_color_in_gamut(l, c, h));
+        }
+        palette
+    }
+
+    /// Generates `n` maximally distinguishable colors for categorical data (e.g. chart series),
+    /// using farthest-point sampling in OKLab, similar to Glasbey's method. Very dark, very light,
+    /// and gray colors are avoided. The result is deterministic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Palette;
+    ///
+    /// let palette = Palette::categorical(8);
+    ///
+    /// assert_eq!(palette.len(), 8);
+    /// for (i, a) in palette.iter().enumerate() {
+    ///     for b in palette.iter().skip(i + 1) {
+    ///         assert!(a.delta_e(b) > 10.0);
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn categorical(n: usize) -> Self {
+        Self::categorical_with_seeds(n, &[])
+    }
+
+    /// Same as [`categorical()`](Palette#method.categorical), but starts from `seeds` (e.g.
+    /// existing brand colors), which are the first colors of the palette. The remaining colors
+    /// are chosen to be as distinguishable as possible from the seeds and each other. If there
+    /// are `n` or more seeds, only the first `n` are returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Palette};
+    ///
+    /// let palette = Palette::categorical_with_seeds(4, &[constants::primary::RED]);
+    ///
+    /// assert_eq!(palette.len(), 4);
+    /// assert_eq!(palette[0], constants::primary::RED);
+    /// ```
+    pub fn categorical_with_seeds(n: usize, seeds: &[Color]) -> Self {
+        let mut palette: Self = seeds.iter().take(n).cloned().collect();
+        let candidates: Vec<(Color, (f32, f32, f32))> = (0..=255u8)
+            .step_by(15)
+            .flat_map(|r| {
+                (0..=255u8)
+                    .step_by(15)
+                    .flat_map(move |g| (0..=255u8).step_by(15).map(move |b| Color(r, g, b)))
+            })
+            .map(|color| {
+                let lab = color.to_oklab();
+                (color, lab)
+            })
+            .filter(|(_, lab)| (CATEGORICAL_LIGHTNESS.0..=CATEGORICAL_LIGHTNESS.1).contains(&lab.0))
+            .collect();
+        let neutral = (
+            (CATEGORICAL_LIGHTNESS.0 + CATEGORICAL_LIGHTNESS.1) / 2.0,
+            0.0,
+            0.0,
+        );
+        let mut min_distances: Vec<f32> = candidates
+            .iter()
+            .map(|(_, lab)| {
+                palette
+                    .iter()
+                    .map(|color| oklab_distance(*lab, color.to_oklab()))
+                    .fold(oklab_distance(*lab, neutral), f32::min)
+            })
+            .collect();
+        while palette.len() < n {
+            let Some((index, _)) = min_distances
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+            else {
+                break;
+            };
+            let (color, lab) = candidates[index];
+            for (distance, (_, other)) in min_distances.iter_mut().zip(&candidates) {
+                *distance = distance.min(oklab_distance(lab, *other));
+            }
+            palette.push(color);
+        }
+        palette
+    }
+
+    /// Extracts a palette of at most `n` dominant colors from `pixels` (e.g. the pixels of an
+    /// image) using median cut quantization. The colors are ordered from most to least common.
+    /// Fewer than `n` colors are returned when `pixels` contains fewer than `n` distinct colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Palette};
+    ///
+    /// let mut pixels = vec![constants::primary::RED; 30];
+    /// pixels.extend(vec![constants::primary::BLUE; 10]);
+    /// let palette = Palette::extract(&pixels, 2);
+    ///
+    /// assert_eq!(
+    ///     palette.into_colors(),
+    ///     vec![constants::primary::RED, constants::primary::BLUE]
+    /// );
+    /// ```
+    pub fn extract(pixels: &[Color], n: usize) -> Self {
+        median_cut(pixels, n, sort_by_channel)
+    }
+
+    /// Same as [`extract()`](Palette#method.extract), but uses multiple threads, which is faster
+    /// for large buffers. Requires the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    pub fn extract_par(pixels: &[Color], n: usize) -> Self {
+        median_cut(pixels, n, sort_by_channel_par)
+    }
+
+    /// Extracts a palette of at most `k` dominant colors from `pixels` using k-means++
+    /// clustering in OKLab, running at most `iterations` refinement passes. This is slower but
+    /// usually more accurate than [`extract()`](Palette#method.extract). The colors are ordered
+    /// from most to least common, and the result is deterministic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Palette};
+    ///
+    /// let mut pixels = vec![constants::primary::RED; 30];
+    /// pixels.extend(vec![constants::primary::BLUE; 10]);
+    /// let palette = Palette::extract_kmeans(&pixels, 2, 10);
+    ///
+    /// assert_eq!(
+    ///     palette.into_colors(),
+    ///     vec![constants::primary::RED, constants::primary::BLUE]
+    /// );
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn extract_kmeans(pixels: &[Color], k: usize, iterations: usize) -> Self {
+        let points: Vec<_> = pixels.iter().map(Color::to_oklab).collect();
+        kmeans(&points, k, iterations, assign_clusters)
+    }
+
+    /// Same as [`extract_kmeans()`](Palette#method.extract_kmeans), but uses multiple threads,
+    /// which is faster for large buffers. Requires the `rayon` feature.
+    #[cfg(all(feature = "rand", feature = "rayon"))]
+    pub fn extract_kmeans_par(pixels: &[Color], k: usize, iterations: usize) -> Self {
+        use rayon::prelude::*;
+        let points: Vec<_> = pixels.par_iter().map(Color::to_oklab).collect();
+        kmeans(&points, k, iterations, assign_clusters_par)
+    }
+
+    /// Sorts the palette by the hue (H) of HSL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Palette};
+    ///
+    /// let mut palette = Palette::from(vec![constants::primary::BLUE, constants::primary::RED]);
+    /// palette.sort_by_hue();
+    ///
+    /// assert_eq!(palette[0], constants::primary::RED);
+    /// ```
+    #[inline]
+    pub fn sort_by_hue(&mut self) {
+        self.sort(SortBy::Hue);
+    }
+
+    /// Sorts the palette by perceived lightness (OKLab L), from darkest to lightest.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Palette};
+    ///
+    /// let mut palette = Palette::from(vec![constants::primary::WHITE, constants::primary::BLACK]);
+    /// palette.sort_by_lightness();
+    ///
+    /// assert_eq!(palette[0], constants::primary::BLACK);
+    /// ```
+    #[inline]
+    pub fn sort_by_lightness(&mut self) {
+        self.sort(SortBy::Lightness);
+    }
+
+    /// Sorts the palette with one of the [`SortBy`] strategies, keeping the names of the colors.
+    /// Colors which are equal by the strategy keep their order.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `by` is [`SortBy::Step`] with 0 bands.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, Palette, SortBy};
+    ///
+    /// let mut palette: Palette = [200, 0, 100, 255, 50]
+    ///     .into_iter()
+    ///     .map(|v| Color::new(v, v, v))
+    ///     .collect();
+    /// palette.sort(SortBy::NearestNeighbor);
+    /// let values: Vec<u8> = palette.iter().map(|c| c.get_red()).collect();
+    ///
+    /// assert_eq!(values, vec![0, 50, 100, 200, 255]);
+    /// ```
+    pub fn sort(&mut self, by: SortBy) {
+        match by {
+            SortBy::Hue => self.sort_by_key(|color| (color.get_hsl_hue(), 0.0)),
+            SortBy::Lightness => self.sort_by_key(|color| (color.to_oklab().0, 0.0)),
+            SortBy::Saturation => self.sort_by_key(|color| (color.get_hsl_saturation(), 0.0)),
+            SortBy::Luma => self.sort_by_key(|color| {
+                let (r, g, b) = color.to_rgb_float();
+                (0.2126 * r + 0.7152 * g + 0.0722 * b, 0.0)
+            }),
+            SortBy::Step(bands) => {
+                if bands == 0 {
+                    panic!("Unsupported bands: {bands}");
+                }
+                self.sort_by_key(|color| {
+                    let band = ((color.get_hsl_hue() * bands as f32) as u8).min(bands - 1);
+                    let lightness = color.to_oklab().0;
+                    // Every other band goes from light to dark, so that the bands join up smoothly.
+                    if band % 2 == 0 {
+                        (band as f32, lightness)
+                    } else {
+                        (band as f32, -lightness)
+                    }
+                })
+            }
+            SortBy::NearestNeighbor => self.sort_nearest_neighbor(),
+        }
+    }
+
+    /// Sorts the entries by a primary and a secondary key, which are computed once per color.
+    fn sort_by_key(&mut self, key: impl Fn(&Color) -> (f32, f32)) {
+        let mut keyed: Vec<_> = self
+            .entries
+            .drain(..)
+            .map(|entry| (key(&entry.color), entry))
+            .collect();
+        keyed.sort_by(|(a, _), (b, _)| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+        self.entries = keyed.into_iter().map(|(_, entry)| entry).collect();
+    }
+
+    /// Orders the colors into a path which starts at the darkest color and always goes to the
+    /// closest color that has not been visited yet, which is a greedy solution to the traveling
+    /// salesman problem.
+    fn sort_nearest_neighbor(&mut self) {
+        let mut remaining = core::mem::take(&mut self.entries);
+        let Some(start) = remaining
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1.color.to_oklab().0.total_cmp(&b.1.color.to_oklab().0))
+            .map(|(i, _)| i)
+        else {
+            return;
+        };
+        self.entries.push(remaining.remove(start));
+        while !remaining.is_empty() {
+            let last = &self.entries[self.entries.len() - 1].color;
+            let nearest = remaining
+                .iter()
+                .enumerate()
+                .min_by(|a, b| {
+                    last.delta_e(&a.1.color)
+                        .total_cmp(&last.delta_e(&b.1.color))
+                })
+                .map(|(i, _)| i)
+                .unwrap();
+            self.entries.push(remaining.remove(nearest));
+        }
+    }
+
+    /// Stretches or compresses the perceived lightness (OKLab L) of the colors so that the darkest
+    /// color has a lightness of `min_l` and the lightest color has a lightness of `max_l`, with the
+    /// colors in between remapped linearly. The hue and chroma of each color are kept, except that
+    /// the chroma is reduced when the color would otherwise fall outside of the RGB gamut. When
+    /// every color has the same lightness, they are all moved to the middle of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `min_l` or `max_l` is not between 0.0 and 1.0, or when `min_l` > `max_l`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, Palette};
+    ///
+    /// let mut palette = Palette::from(vec![Color::new(10, 10, 30), Color::new(60, 20, 20)]);
+    /// palette.normalize_lightness(0.2, 0.9);
+    ///
+    /// assert!((palette[0].to_oklab().0 - 0.2).abs() < 0.01);
+    /// assert!((palette[1].to_oklab().0 - 0.9).abs() < 0.01);
+    /// ```
+    pub fn normalize_lightness(&mut self, min_l: f32, max_l: f32) {
+        if !(0.0..=1.0).contains(&min_l) || !(0.0..=1.0).contains(&max_l) || min_l > max_l {
+            panic!("Unsupported lightness range: {min_l} - {max_l}");
+        }
+        let (low, high) = self
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(low, high), color| {
+                let l = color.to_oklab().0;
+                (low.min(l), high.max(l))
+            });
+        for color in self.iter_mut() {
+            let (l, c, h) = color.to_oklch();
+            let l = if high - low > f32::EPSILON {
+                min_l + (l - low) / (high - low) * (max_l - min_l)
+            } else {
+                (min_l + max_l) / 2.0
+            };
+            *color = oklch_to_color_in_gamut(l, c, h);
+        }
+    }
+
+    /// Removes colors that are within `delta_e` (see [`delta_e()`](Color#method.delta_e)) of a
+    /// color that comes before them in the palette.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, Palette};
+    ///
+    /// let mut palette = Palette::from(vec![
+    ///     Color::new(255, 0, 0),
+    ///     Color::new(254, 0, 0),
+    ///     Color::new(0, 0, 255),
+    /// ]);
+    /// palette.dedupe_within(1.0);
+    ///
+    /// assert_eq!(palette.len(), 2);
+    /// ```
+    pub fn dedupe_within(&mut self, delta_e: f32) {
+        let mut kept: Vec<Entry> = Vec::with_capacity(self.entries.len());
+        for entry in self.entries.drain(..) {
+            if kept
+                .iter()
+                .all(|other| other.color.delta_e(&entry.color) > delta_e)
+            {
+                kept.push(entry);
+            }
+        }
+        self.entries = kept;
+    }
+
+    /// Gets the dark mode version of every color, keeping their names, the same as
+    /// [`to_dark_mode()`](Color#method.to_dark_mode).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Palette};
+    ///
+    /// let mut tokens = Palette::new();
+    /// tokens.push_named("background", constants::primary::WHITE);
+    /// tokens.push_named("text", constants::primary::BLACK);
+    /// let dark = tokens.dark_variant();
+    ///
+    /// assert_eq!(dark.get_named("background"), Some(&constants::primary::BLACK));
+    /// assert_eq!(dark.get_named("text"), Some(&constants::primary::WHITE));
+    /// ```
+    pub fn dark_variant(&self) -> Self {
+        let mut palette = self.clone();
+        for color in palette.iter_mut() {
+            *color = color.to_dark_mode();
+        }
+        palette
+    }
+
+    /// Gets the color in the palette that is perceptually closest to `color`. Returns `None`
+    /// when the palette is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants, Palette};
+    ///
+    /// let palette = Palette::from(vec![constants::primary::RED, constants::primary::BLUE]);
+    ///
+    /// assert_eq!(palette.nearest(&Color::new(200, 20, 20)), Some(&constants::primary::RED));
+    /// ```
+    pub fn nearest(&self, color: &Color) -> Option<&Color> {
+        self.iter()
+            .map(|other| (other, other.delta_e(color)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(other, _)| other)
+    }
+
+    /// Replaces every pixel with the color in the palette that is perceptually closest to it, the
+    /// same as [`nearest()`](Palette#method.nearest). Does nothing when the palette is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants, Palette};
+    ///
+    /// let palette = Palette::from(vec![constants::primary::RED, constants::primary::BLUE]);
+    /// let mut pixels = [Color::new(200, 20, 20), Color::new(20, 20, 200)];
+    /// palette.quantize(&mut pixels);
+    ///
+    /// assert_eq!(pixels, [constants::primary::RED, constants::primary::BLUE]);
+    /// ```
+    pub fn quantize(&self, pixels: &mut [Color]) {
+        let labs = self.labs();
+        for pixel in pixels {
+            if let Some(color) = nearest_lab(&labs, pixel) {
+                *pixel = color;
+            }
+        }
+    }
+
+    /// Same as [`quantize()`](Palette#method.quantize), but uses multiple threads, which is
+    /// faster for large buffers. Requires the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    pub fn quantize_par(&self, pixels: &mut [Color]) {
+        use rayon::prelude::*;
+        let labs = self.labs();
+        pixels.par_iter_mut().for_each(|pixel| {
+            if let Some(color) = nearest_lab(&labs, pixel) {
+                *pixel = color;
+            }
+        });
+    }
+
+    /// Gets the colors along with their CIELAB values, so they are only converted once.
+    fn labs(&self) -> Vec<(Color, (f32, f32, f32))> {
+        self.iter().map(|color| (*color, color.to_lab())).collect()
+    }
+}
+
+fn nearest_lab(labs: &[(Color, (f32, f32, f32))], color: &Color) -> Option<Color> {
+    let lab = color.to_lab();
+    labs.iter()
+        .map(|(other, other_lab)| (other, ciede2000(*other_lab, lab)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(other, _)| *other)
+}
+
+/// Median cut, which repeatedly splits the box of pixels with the widest range of a channel at
+/// its median, using `sort` to sort the pixels of a box by a channel.
+fn median_cut(pixels: &[Color], n: usize, sort: fn(&mut [Color], usize)) -> Palette {
+    if n == 0 || pixels.is_empty() {
+        return Palette::new();
+    }
+    let mut pixels = pixels.to_vec();
+    let mut boxes = Vec::with_capacity(n.min(pixels.len()));
+    boxes.push(0..pixels.len());
+    while boxes.len() < n {
+        let Some((index, channel, _)) = boxes
+            .iter()
+            .enumerate()
+            .map(|(index, range)| {
+                let (channel, extent) = widest_channel(&pixels[range.clone()]);
+                (index, channel, extent)
+            })
+            .filter(|(_, _, extent)| *extent > 0)
+            .max_by_key(|(_, _, extent)| *extent)
+        else {
+            break;
+        };
+        let range = boxes.swap_remove(index);
+        let pixels = &mut pixels[range.clone()];
+        sort(pixels, channel);
+        // Never split between two equal values, so that each color ends up in one box. This also
+        // means the boxes don't depend on the order of equal values after sorting.
+        let median = get_channel(&pixels[pixels.len() / 2], channel);
+        let mut split = pixels.partition_point(|color| get_channel(color, channel) < median);
+        if split == 0 {
+            split = pixels.partition_point(|color| get_channel(color, channel) <= median);
+        }
+        boxes.push(range.start..range.start + split);
+        boxes.push(range.start + split..range.end);
+    }
+    boxes.sort_by_key(|range| core::cmp::Reverse(range.len()));
+    boxes
+        .into_iter()
+        .map(|range| average_u8(&pixels[range]))
+        .collect()
+}
+
+/// The strategies which [`Palette::sort()`] can sort the colors by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortBy {
+    /// The hue (H) of HSL, starting from red.
+    Hue,
+    /// The perceived lightness (OKLab L), from darkest to lightest.
+    Lightness,
+    /// The saturation (S) of HSL, from gray to the most saturated.
+    Saturation,
+    /// The luma (Y') of the gamma-encoded RGB channels with the Rec. 709 weights, from darkest to
+    /// lightest. This is cheaper than [`SortBy::Lightness`] and matches how video measures
+    /// brightness.
+    Luma,
+    /// Groups the colors into the given number of hue bands, then sorts each band by lightness,
+    /// alternating between dark to light and light to dark. This gives smoother strips than
+    /// sorting by hue alone, which mixes dark and light colors of similar hues.
+    Step(u8),
+    /// Starts at the darkest color and then always picks the most similar remaining color (by
+    /// [`delta_e()`](Color#method.delta_e)), which gives the smoothest strips but is quadratic in
+    /// the number of colors.
+    NearestNeighbor,
+}
+
+fn sort_by_channel(pixels: &mut [Color], channel: usize) {
+    pixels.sort_unstable_by_key(|color| get_channel(color, channel));
+}
+
+#[cfg(feature = "rayon")]
+fn sort_by_channel_par(pixels: &mut [Color], channel: usize) {
+    use rayon::prelude::*;
+    pixels.par_sort_unstable_by_key(|color| get_channel(color, channel));
+}
+
+#[cfg(feature = "rand")]
+type Lab = (f32, f32, f32);
+
+#[cfg(feature = "rand")]
+fn kmeans(
+    points: &[Lab],
+    k: usize,
+    iterations: usize,
+    assign: fn(&[Lab], &[Lab], &mut [usize]),
+) -> Palette {
+    let mut centers = kmeans_plus_plus(points, k);
+    if centers.is_empty() {
+        return Palette::new();
+    }
+    let mut assignments = vec![usize::MAX; points.len()];
+    let mut previous = Vec::new();
+    for _ in 0..iterations {
+        assign(points, &centers, &mut assignments);
+        if assignments == previous {
+            break;
+        }
+        let mut sums = vec![(0.0, 0.0, 0.0, 0usize); centers.len()];
+        for (point, &cluster) in points.iter().zip(&assignments) {
+            let sum = &mut sums[cluster];
+            sum.0 += point.0;
+            sum.1 += point.1;
+            sum.2 += point.2;
+            sum.3 += 1;
+        }
+        for (center, sum) in centers.iter_mut().zip(sums) {
+            if sum.3 > 0 {
+                let count = sum.3 as f32;
+                *center = (sum.0 / count, sum.1 / count, sum.2 / count);
+            }
+        }
+        previous.clone_from(&assignments);
+    }
+    assign(points, &centers, &mut assignments);
+    let mut counts = vec![0usize; centers.len()];
+    for &cluster in &assignments {
+        counts[cluster] += 1;
+    }
+    let mut clusters: Vec<_> = centers
+        .into_iter()
+        .zip(counts)
+        .filter(|(_, count)| *count > 0)
+        .collect();
+    clusters.sort_by_key(|(_, count)| core::cmp::Reverse(*count));
+    clusters
+        .into_iter()
+        .map(|((l, a, b), _)| Color::from_oklab(l, a, b))
+        .collect()
+}
+
+/// Picks the initial cluster centers, where each new center is chosen with a probability
+/// proportional to its squared distance from the closest existing center.
+#[cfg(feature = "rand")]
+fn kmeans_plus_plus(points: &[Lab], k: usize) -> Vec<Lab> {
+    let mut centers = Vec::with_capacity(k.min(points.len()));
+    if k == 0 || points.is_empty() {
+        return centers;
+    }
+    let mut rng = SmallRng::seed_from_u64(0x0C7A_821E);
+    centers.push(points[rng.gen_range(0..points.len())]);
+    let mut distances: Vec<f32> = points
+        .iter()
+        .map(|point| oklab_distance(*point, centers[0]).powi(2))
+        .collect();
+    while centers.len() < k {
+        let total: f32 = distances.iter().sum();
+        if total <= 0.0 {
+            break;
+        }
+        let mut target = rng.gen_range(0.0..total);
+        let index = distances
+            .iter()
+            .position(|distance| {
+                target -= distance;
+                target < 0.0
+            })
+            .unwrap_or(points.len() - 1);
+        let center = points[index];
+        for (distance, point) in distances.iter_mut().zip(points) {
+            *distance = distance.min(oklab_distance(*point, center).powi(2));
+        }
+        centers.push(center);
+    }
+    centers
+}
+
+#[cfg(feature = "rand")]
+fn nearest_center(point: &Lab, centers: &[Lab]) -> usize {
+    centers
+        .iter()
+        .map(|center| oklab_distance(*point, *center))
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(index, _)| index)
+        .unwrap_or(0)
+}
+
+#[cfg(feature = "rand")]
+fn assign_clusters(points: &[Lab], centers: &[Lab], assignments: &mut [usize]) {
+    for (point, assignment) in points.iter().zip(assignments) {
+        *assignment = nearest_center(point, centers);
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "rayon"))]
+fn assign_clusters_par(points: &[Lab], centers: &[Lab], assignments: &mut [usize]) {
+    use rayon::prelude::*;
+    points
+        .par_iter()
+        .zip(assignments)
+        .for_each(|(point, assignment)| *assignment = nearest_center(point, centers));
+}
+
+fn get_channel(color: &Color, channel: usize) -> u8 {
+    match channel {
+        0 => color.0,
+        1 => color.1,
+        _ => color.2,
+    }
+}
+
+/// Gets the RGB channel with the largest range of values, along with that range.
+fn widest_channel(pixels: &[Color]) -> (usize, u8) {
+    (0..3)
+        .map(|channel| {
+            let (min, max) = pixels.iter().fold((u8::MAX, u8::MIN), |(min, max), color| {
+                let value = get_channel(color, channel);
+                (min.min(value), max.max(value))
+            });
+            (channel, max.saturating_sub(min))
+        })
+        .max_by_key(|(_, extent)| *extent)
+        .unwrap()
+}
+
+fn average_u8(pixels: &[Color]) -> Color {
+    let len = pixels.len();
+    let (r, g, b) = pixels.iter().fold((0, 0, 0), |(r, g, b), color| {
+        (
+            r + color.0 as usize,
+            g + color.1 as usize,
+            b + color.2 as usize,
+        )
+    });
+    Color(
+        ((r + len / 2) / len) as u8,
+        ((g + len / 2) / len) as u8,
+        ((b + len / 2) / len) as u8,
+    )
+}
+
+impl Index<usize> for Palette {
+    type Output = Color;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.entries[index].color
+    }
+}
+
+impl From<Vec<Color>> for Palette {
+    fn from(colors: Vec<Color>) -> Self {
+        colors.into_iter().collect()
+    }
+}
+
+impl FromIterator<Color> for Palette {
+    fn from_iter<I: IntoIterator<Item = Color>>(iter: I) -> Self {
+        let entries = iter
+            .into_iter()
+            .map(|color| Entry { name: None, color })
+            .collect();
+        Self { entries }
+    }
+}
+
+impl IntoIterator for Palette {
+    type Item = Color;
+    type IntoIter = alloc::vec::IntoIter<Color>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_colors().into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_entries() {
+        let mut palette = Palette::new();
+        palette.push(constants::primary::BLACK);
+        palette.push_named("primary", constants::primary::RED);
+        palette.push_named("primary", constants::primary::BLUE);
+        assert_eq!(palette.len(), 2);
+        assert_eq!(
+            palette.get_named("primary"),
+            Some(&constants::primary::BLUE)
+        );
+        assert_eq!(palette.get_named("secondary"), None);
+        assert_eq!(palette.get_name(0), None);
+        assert_eq!(palette.get_name(1), Some("primary"));
+        assert_eq!(palette.remove(0), constants::primary::BLACK);
+        assert_eq!(palette.get_name(0), Some("primary"));
+    }
+
+    #[test]
+    fn dark_variant() {
+        let mut palette = Palette::new();
+        palette.push(constants::OCTARINE);
+        palette.push_named("accent", Color::new(30, 90, 200));
+        let dark = palette.dark_variant();
+        assert_eq!(dark.len(), 2);
+        assert_eq!(dark.get_name(1), Some("accent"));
+        for (color, dark) in palette.iter().zip(dark.iter()) {
+            assert_eq!(*dark, color.to_dark_mode());
+        }
+    }
+
+    #[test]
+    fn normalize_lightness() {
+        let mut palette = Palette::new();
+        palette.push(Color::new(20, 40, 30));
+        palette.push_named("accent", Color::new(70, 30, 90));
+        palette.push(Color::new(50, 50, 50));
+        let hue = palette[1].to_oklch().2;
+        palette.normalize_lightness(0.3, 0.8);
+        let lightness: Vec<f32> = palette.iter().map(|c| c.to_oklab().0).collect();
+        assert!((lightness[0] - 0.3).abs() < 0.01);
+        assert!((lightness[1] - 0.8).abs() < 0.01);
+        assert!(lightness[2] > 0.3 && lightness[2] < 0.8);
+        assert!((palette[1].to_oklch().2 - hue).abs() < 0.01);
+        assert_eq!(palette.get_name(1), Some("accent"));
+        let mut flat = Palette::from(vec![Color::new(90, 90, 90); 3]);
+        flat.normalize_lightness(0.4, 0.6);
+        assert!(flat.iter().all(|c| (c.to_oklab().0 - 0.5).abs() < 0.01));
+        let mut empty = Palette::new();
+        empty.normalize_lightness(0.0, 1.0);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn normalize_lightness_reversed() {
+        Palette::from(vec![constants::primary::RED]).normalize_lightness(0.8, 0.2);
+    }
+
+    #[test]
+    fn scale_from() {
+        let seed = Color::from_hex(0x3B82F6);
+        let hue = seed.to_oklch().2;
+        let scale = Palette::scale_from(&seed);
+        let names: Vec<_> = scale.iter_named().map(|(name, _)| name.unwrap()).collect();
+        assert_eq!(
+            names,
+            vec!["50", "100", "200", "300", "400", "500", "600", "700", "800", "900", "950"]
+        );
+        assert!((scale.get_named("50").unwrap().to_oklab().0 - 0.97).abs() < 0.01);
+        assert!((scale.get_named("950").unwrap().to_oklab().0 - 0.28).abs() < 0.01);
+        for color in scale.iter() {
+            assert!((color.to_oklch().2 - hue).abs() < 0.01);
+        }
+        let linear = Palette::scale_from_with_curve(&seed, 1.0);
+        assert!(linear[1].to_oklab().0 < scale[1].to_oklab().0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scale_from_bad_curve() {
+        Palette::scale_from_with_curve(&constants::primary::RED, 0.0);
+    }
+
+    #[test]
+    fn categorical() {
+        let palette = Palette::categorical(12);
+        assert_eq!(palette.len(), 12);
+        assert_eq!(palette, Palette::categorical(12));
+        for (i, a) in palette.iter().enumerate() {
+            assert!(a.to_oklch().1 > 0.02);
+            for b in palette.iter().skip(i + 1) {
+                assert_ne!(a, b);
+            }
+        }
+        let seeds = [constants::primary::RED, constants::primary::BLUE];
+        let palette = Palette::categorical_with_seeds(5, &seeds);
+        assert_eq!(palette.len(), 5);
+        assert_eq!(&palette.clone().into_colors()[..2], &seeds);
+        assert_eq!(Palette::categorical_with_seeds(1, &seeds).len(), 1);
+        assert!(Palette::categorical(0).is_empty());
+    }
+
+    #[test]
+    fn extract() {
+        let mut pixels = Vec::new();
+        for i in 0..30 {
+            pixels.push(Color::new(200 + i % 5, 10, 10));
+            pixels.push(Color::new(10, 10, 200 + i % 5));
+            pixels.push(Color::new(10, 200, 10));
+        }
+        let palette = Palette::extract(&pixels, 3);
+        for color in [
+            Color::new(202, 10, 10),
+            Color::new(10, 10, 202),
+            Color::new(10, 200, 10),
+        ] {
+            assert!(palette.iter().any(|other| *other == color));
+        }
+        assert_eq!(Palette::extract(&pixels, 1).len(), 1);
+        assert_eq!(Palette::extract(&pixels, 100).len(), 11);
+        assert!(Palette::extract(&pixels, 0).is_empty());
+        assert!(Palette::extract(&[], 5).is_empty());
+        let single = vec![constants::primary::RED; 10];
+        assert_eq!(
+            Palette::extract(&single, 4).into_colors(),
+            vec![constants::primary::RED]
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn extract_kmeans() {
+        let mut pixels = Vec::new();
+        for i in 0..40 {
+            pixels.push(Color::new(200 + i % 5, 10, 10));
+        }
+        for i in 0..20 {
+            pixels.push(Color::new(10, 10, 200 + i % 5));
+        }
+        for _ in 0..10 {
+            pixels.push(Color::new(10, 200, 10));
+        }
+        let palette = Palette::extract_kmeans(&pixels, 3, 20).into_colors();
+        assert_eq!(
+            palette,
+            vec![
+                Color::new(202, 10, 10),
+                Color::new(10, 10, 202),
+                Color::new(10, 200, 10)
+            ]
+        );
+        assert_eq!(
+            palette,
+            Palette::extract_kmeans(&pixels, 3, 20).into_colors()
+        );
+        assert_eq!(Palette::extract_kmeans(&pixels, 100, 5).len(), 11);
+        assert!(Palette::extract_kmeans(&pixels, 0, 5).is_empty());
+        assert!(Palette::extract_kmeans(&[], 5, 5).is_empty());
+        assert_eq!(Palette::extract_kmeans(&pixels, 3, 0).len(), 3);
+    }
+
+    #[test]
+    fn quantize() {
+        let palette = Palette::from(vec![
+            constants::primary::BLACK,
+            constants::primary::WHITE,
+            constants::OCTARINE,
+        ]);
+        let pixels: Vec<_> = (0..=255).map(|i| Color::new(i, 255 - i, i / 2)).collect();
+        let mut quantized = pixels.clone();
+        palette.quantize(&mut quantized);
+        for (pixel, quantized) in pixels.iter().zip(&quantized) {
+            assert_eq!(palette.nearest(pixel), Some(quantized));
+        }
+        let mut unchanged = pixels.clone();
+        Palette::new().quantize(&mut unchanged);
+        assert_eq!(unchanged, pixels);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par() {
+        let pixels: Vec<_> = (0..=255)
+            .flat_map(|i| [Color::new(i, 255 - i, i / 2), Color::new(i / 3, i, 0)])
+            .collect();
+        assert_eq!(
+            Palette::extract(&pixels, 7),
+            Palette::extract_par(&pixels, 7)
+        );
+        let palette = Palette::extract(&pixels, 5);
+        let (mut a, mut b) = (pixels.clone(), pixels);
+        palette.quantize(&mut a);
+        palette.quantize_par(&mut b);
+        assert_eq!(a, b);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn extract_kmeans_par() {
+        let pixels: Vec<_> = (0..=255).map(|i| Color::new(i, 255 - i, i / 2)).collect();
+        assert_eq!(
+            Palette::extract_kmeans(&pixels, 5, 10),
+            Palette::extract_kmeans_par(&pixels, 5, 10)
+        );
+    }
+
+    #[test]
+    fn sort() {
+        let mut palette = Palette::new();
+        palette.push_named("blue", constants::primary::BLUE);
+        palette.push_named("green", constants::primary::GREEN);
+        palette.push_named("red", constants::primary::RED);
+        palette.sort_by_hue();
+        let names: Vec<_> = palette
+            .iter_named()
+            .map(|(name, _)| name.unwrap())
+            .collect();
+        assert_eq!(names, vec!["red", "green", "blue"]);
+        palette.sort_by_lightness();
+        let names: Vec<_> = palette
+            .iter_named()
+            .map(|(name, _)| name.unwrap())
+            .collect();
+        assert_eq!(names, vec!["blue", "red", "green"]);
+    }
+
+    #[test]
+    fn sort_by() {
+        let gray = Color::new(128, 128, 128);
+        let dark_red = Color::new(100, 0, 0);
+        let light_red = Color::new(255, 150, 150);
+        let dark_blue = Color::new(0, 0, 100);
+        let light_blue = Color::new(150, 150, 255);
+        let colors = vec![light_blue, gray, dark_red, light_red, dark_blue];
+        let sorted = |by| {
+            let mut palette = Palette::from(colors.clone());
+            palette.sort(by);
+            palette.into_colors()
+        };
+        assert_eq!(sorted(SortBy::Saturation)[0], gray);
+        assert_eq!(sorted(SortBy::Luma)[0], dark_blue);
+        assert_eq!(sorted(SortBy::Lightness)[0], dark_blue);
+        assert_eq!(sorted(SortBy::Luma)[4], light_red);
+        assert_eq!(
+            sorted(SortBy::Step(2)),
+            vec![dark_red, gray, light_red, light_blue, dark_blue]
+        );
+        let path = sorted(SortBy::NearestNeighbor);
+        assert_eq!(path[0], dark_blue);
+        assert_eq!(path.len(), 5);
+        let mut named = Palette::new();
+        named.push_named("light", light_red);
+        named.push_named("dark", dark_red);
+        named.sort(SortBy::NearestNeighbor);
+        assert_eq!(named.get_name(0), Some("dark"));
+        let mut empty = Palette::new();
+        empty.sort(SortBy::NearestNeighbor);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported bands")]
+    fn sort_by_step_without_bands() {
+        Palette::from(vec![constants::primary::RED]).sort(SortBy::Step(0));
+    }
+
+    #[test]
+    fn dedupe_within() {
+        let mut palette: Palette = [
+            Color::new(100, 100, 100),
+            Color::new(101, 100, 100),
+            Color::new(200, 0, 0),
+            Color::new(100, 101, 100),
+        ]
+        .into_iter()
+        .collect();
+        palette.dedupe_within(2.0);
+        assert_eq!(
+            palette.into_colors(),
+            vec![Color::new(100, 100, 100), Color::new(200, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn nearest() {
+        let palette = Palette::from(vec![
+            constants::primary::BLACK,
+            constants::primary::WHITE,
+            constants::primary::RED,
+        ]);
+        assert_eq!(
+            palette.nearest(&Color::new(30, 30, 30)),
+            Some(&constants::primary::BLACK)
+        );
+        assert_eq!(
+            palette.nearest(&Color::new(240, 230, 230)),
+            Some(&constants::primary::WHITE)
+        );
+        assert_eq!(Palette::new().nearest(&constants::primary::RED), None);
+    }
+}
@@ -0,0 +1,140 @@
+use super::*;
+
+/// A color with 16 bits per channel (0 - 65535), which keeps the precision lost by [`Color`]
+/// between the steps of a pipeline. Converting a [`Color`] to a [`Color16`] and back always gives
+/// the same color.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{Color, Color16};
+///
+/// let color = Color::new(255, 128, 0);
+/// let high = Color16::from(color);
+///
+/// assert_eq!(high, Color16 { r: 65535, g: 32896, b: 0 });
+/// assert_eq!(Color::from(high), color);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(C)]
+pub struct Color16 {
+    pub r: u16,
+    pub g: u16,
+    pub b: u16,
+}
+
+impl Color16 {
+    /// Create a color object from RGB values (0 - 65535).
+    #[inline]
+    pub const fn new(r: u16, g: u16, b: u16) -> Self {
+        Self { r, g, b }
+    }
+}
+
+/// A color with an [`f32`] per channel, where 0.0 - 1.0 is the range of [`Color`]. Channels can
+/// go above 1.0 for HDR colors or below 0.0 while a pipeline is running, and are only clamped
+/// when converting back to [`Color`] or [`Color16`].
+///
+/// # Example
+///
+/// ```
+/// use octarine::{Color, ColorF};
+///
+/// let hdr = ColorF::new(2.0, 0.5, -0.1);
+///
+/// assert_eq!(Color::from(hdr), Color::new(255, 128, 0));
+/// assert_eq!(ColorF::from(Color::new(255, 0, 0)), ColorF::new(1.0, 0.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct ColorF {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl ColorF {
+    /// Create a color object from RGB floats, where 0.0 - 1.0 is the range of [`Color`].
+    #[inline]
+    pub const fn new(r: f32, g: f32, b: f32) -> Self {
+        Self { r, g, b }
+    }
+}
+
+// SAFETY: both types are `repr(C)` with fields of the same primitive type, so they have no
+// padding, and any bit pattern (including all zeros) is valid.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Color16 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Color16 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for ColorF {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for ColorF {}
+
+impl From<Color> for Color16 {
+    fn from(color: Color) -> Self {
+        // Multiplying by 257 maps 255 to 65535 exactly.
+        Self::new(
+            color.0 as u16 * 257,
+            color.1 as u16 * 257,
+            color.2 as u16 * 257,
+        )
+    }
+}
+
+impl From<Color16> for Color {
+    fn from(color: Color16) -> Self {
+        let narrow = |v: u16| ((v as u32 + 128) / 257) as u8;
+        Self(narrow(color.r), narrow(color.g), narrow(color.b))
+    }
+}
+
+impl From<Color> for ColorF {
+    fn from(color: Color) -> Self {
+        let (r, g, b) = color.to_rgb_float();
+        Self::new(r, g, b)
+    }
+}
+
+/// Converts to [`Color`], clamping channels which are out of range.
+impl From<ColorF> for Color {
+    fn from(color: ColorF) -> Self {
+        Self::from([color.r, color.g, color.b])
+    }
+}
+
+impl From<Color16> for ColorF {
+    fn from(color: Color16) -> Self {
+        let widen = |v: u16| v as f32 / 65535.0;
+        Self::new(widen(color.r), widen(color.g), widen(color.b))
+    }
+}
+
+/// Converts to [`Color16`], clamping channels which are out of range.
+impl From<ColorF> for Color16 {
+    fn from(color: ColorF) -> Self {
+        let narrow = |v: f32| (v.clamp(0.0, 1.0) * 65535.0 + 0.5) as u16;
+        Self::new(narrow(color.r), narrow(color.g), narrow(color.b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        for v in 0..=255 {
+            let canonical = Color::new(v, 255 - v, v / 2);
+            assert_eq!(Color::from(Color16::from(canonical)), canonical);
+            assert_eq!(Color::from(ColorF::from(canonical)), canonical);
+        }
+        let high = Color16::new(1000, 40000, 65535);
+        assert_eq!(Color16::from(ColorF::from(high)), high);
+        assert_eq!(Color::from(Color16::new(128, 0, 0)), Color::new(0, 0, 0));
+        assert_eq!(Color::from(Color16::new(129, 0, 0)), Color::new(1, 0, 0));
+        let hdr = ColorF::new(4.0, -1.0, 0.5);
+        assert_eq!(Color16::from(hdr), Color16::new(65535, 0, 32768));
+    }
+}
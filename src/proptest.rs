@@ -0,0 +1,113 @@
+//! Strategies for property testing with [proptest](https://docs.rs/proptest), so code which
+//! consumes colors can be tested with generated ones. [`Color`] and [`Color16`] also implement
+//! the `Arbitrary` trait of proptest, so `any::<Color>()` works too. Requires the `proptest`
+//! feature.
+//!
+//! ```
+//! use octarine::proptest::color;
+//! use proptest::test_runner::TestRunner;
+//!
+//! TestRunner::default()
+//!     .run(&color(), |color| {
+//!         assert_eq!(color.invert().invert(), color);
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! ```
+
+use super::*;
+use ::proptest::{
+    arbitrary::{any, Arbitrary},
+    collection::{self, SizeRange},
+    strategy::{Map, Strategy},
+};
+
+impl Arbitrary for Color {
+    type Parameters = ();
+    type Strategy = Map<<(u8, u8, u8) as Arbitrary>::Strategy, fn((u8, u8, u8)) -> Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<(u8, u8, u8)>().prop_map(|(r, g, b)| Self(r, g, b))
+    }
+}
+
+impl Arbitrary for Color16 {
+    type Parameters = ();
+    type Strategy = Map<<(u16, u16, u16) as Arbitrary>::Strategy, fn((u16, u16, u16)) -> Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<(u16, u16, u16)>().prop_map(|(r, g, b)| Self::new(r, g, b))
+    }
+}
+
+/// Generates any color.
+pub fn color() -> impl Strategy<Value = Color> {
+    any::<Color>()
+}
+
+/// Generates shades of gray, from black to white.
+pub fn gray() -> impl Strategy<Value = Color> {
+    any::<u8>().prop_map(|v| Color(v, v, v))
+}
+
+/// Generates lists of colors with a length in `size`, e.g. `1..10`.
+pub fn colors(size: impl Into<SizeRange>) -> impl Strategy<Value = Vec<Color>> {
+    collection::vec(color(), size)
+}
+
+/// Generates valid HSL values, which can be passed to [`Color::from_hsl()`] without panicking.
+pub fn hsl() -> impl Strategy<Value = Hsl> {
+    (0.0f32..1.0, 0.0f32..=1.0, 0.0f32..=1.0).prop_map(Hsl::from)
+}
+
+/// Generates valid HSV values, which can be passed to [`Color::from_hsv()`] without panicking.
+pub fn hsv() -> impl Strategy<Value = Hsv> {
+    (0.0f32..1.0, 0.0f32..=1.0, 0.0f32..=1.0).prop_map(Hsv::from)
+}
+
+/// Generates colors with [`f32`] channels between 0.0 and 1.0, the range of [`Color`].
+pub fn color_f() -> impl Strategy<Value = ColorF> {
+    (0.0f32..=1.0, 0.0f32..=1.0, 0.0f32..=1.0).prop_map(|(r, g, b)| ColorF::new(r, g, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::proptest::{prop_assert, prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        fn hex_round_trip(color in color()) {
+            prop_assert_eq!(Color::from(u32::from(color)), color);
+        }
+
+        #[test]
+        fn precision_round_trip(color in color()) {
+            prop_assert_eq!(Color::from(Color16::from(color)), color);
+            prop_assert_eq!(Color::from(ColorF::from(color)), color);
+        }
+
+        #[test]
+        fn model_round_trip(color in color()) {
+            prop_assert_eq!(Color::from(color.hsl()), color);
+            prop_assert_eq!(Color::from(color.hsv()), color);
+            prop_assert_eq!(Color::from(color.lab()), color);
+            prop_assert_eq!(color.oklch().to_color(), color);
+        }
+
+        #[test]
+        fn valid_models(hsl in hsl(), hsv in hsv(), color in color_f()) {
+            let _ = (hsl.to_color(), hsv.to_color(), Color::from(color));
+        }
+
+        #[test]
+        fn grays(color in gray()) {
+            prop_assert!(color.to_hsl().1 == 0.0);
+        }
+
+        #[test]
+        fn lists(colors in colors(1..10)) {
+            prop_assert!((1..10).contains(&colors.len()));
+        }
+    }
+}
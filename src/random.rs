@@ -0,0 +1,256 @@
+use super::*;
+#[cfg(feature = "std")]
+use core::ops::RangeInclusive;
+use rand::distributions::{Distribution, Standard, Uniform};
+
+/// A builder for random colors within boundaries, inspired by
+/// [randomColor](https://randomcolor.lllllllllllllllll.com/). A color is generated by picking a
+/// random HSL hue, saturation, and lightness within the ranges you set, which are the full ranges
+/// by default.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{HueFamily, RandomColor};
+///
+/// let mut random = RandomColor::pastel()
+///     .with_hue_family(HueFamily::Blue)
+///     .with_seed(42);
+/// let color = random.generate();
+///
+/// assert!(color.get_hsl_luminance() >= 0.74);
+/// assert!((0.53..=0.71).contains(&color.get_hsl_hue()));
+/// assert_eq!(random.generate_n(5).len(), 5);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct RandomColor {
+    hue: (f32, f32),
+    saturation: (f32, f32),
+    lightness: (f32, f32),
+    rng: SmallRng,
+}
+
+#[cfg(feature = "std")]
+impl RandomColor {
+    /// Creates a builder which generates any color.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a builder which generates soft and light colors.
+    pub fn pastel() -> Self {
+        Self::new()
+            .with_saturation(0.4..=0.8)
+            .with_lightness(0.75..=0.9)
+    }
+
+    /// Creates a builder which generates saturated and bright colors.
+    pub fn vivid() -> Self {
+        Self::new()
+            .with_saturation(0.85..=1.0)
+            .with_lightness(0.45..=0.6)
+    }
+
+    /// Creates a builder which generates deep and dark colors.
+    pub fn dark() -> Self {
+        Self::new()
+            .with_saturation(0.5..=1.0)
+            .with_lightness(0.15..=0.35)
+    }
+
+    /// Creates a builder which generates light colors.
+    pub fn light() -> Self {
+        Self::new()
+            .with_saturation(0.5..=1.0)
+            .with_lightness(0.65..=0.85)
+    }
+
+    /// Sets the range of the hue, which is a rotation around the chromatic circle between 0.0 and
+    /// 1.0. When the start is greater than the end, the range wraps around through 0.0, so
+    /// `0.9..=0.1` gives reds.
+    pub fn with_hue(mut self, hue: RangeInclusive<f32>) -> Self {
+        self.hue = (hue.start().rem_euclid(1.0), hue.end().rem_euclid(1.0));
+        if hue.end() - hue.start() >= 1.0 {
+            self.hue = (0.0, 1.0);
+        }
+        self
+    }
+
+    /// Sets the range of the hue to the hues of `family`. As [`HueFamily::Pink`],
+    /// [`HueFamily::Brown`], and the neutral families are also defined by how saturated and light
+    /// they are, the saturation and lightness ranges are set for these too.
+    pub fn with_hue_family(mut self, family: HueFamily) -> Self {
+        let (hue, saturation, lightness) = family.bounds();
+        self = self.with_hue(hue);
+        if let Some(saturation) = saturation {
+            self = self.with_saturation(saturation);
+        }
+        if let Some(lightness) = lightness {
+            self = self.with_lightness(lightness);
+        }
+        self
+    }
+
+    /// Sets the range of the HSL saturation, which is clamped between 0.0 and 1.0.
+    pub fn with_saturation(mut self, saturation: RangeInclusive<f32>) -> Self {
+        self.saturation = clamp_range(saturation);
+        self
+    }
+
+    /// Sets the range of the HSL lightness, which is clamped between 0.0 and 1.0.
+    pub fn with_lightness(mut self, lightness: RangeInclusive<f32>) -> Self {
+        self.lightness = clamp_range(lightness);
+        self
+    }
+
+    /// Seeds the random number generator, so that the same colors are generated every time.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = SmallRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Generates a random color.
+    pub fn generate(&mut self) -> Color {
+        let (start, end) = self.hue;
+        let hue = if start <= end {
+            self.rng.gen_range(start..=end)
+        } else {
+            self.rng.gen_range(start..=end + 1.0).rem_euclid(1.0)
+        };
+        let saturation = self.rng.gen_range(self.saturation.0..=self.saturation.1);
+        let lightness = self.rng.gen_range(self.lightness.0..=self.lightness.1);
+        Color::from_hsl(hue, saturation, lightness)
+    }
+
+    /// Generates `count` random colors.
+    pub fn generate_n(&mut self, count: usize) -> Palette {
+        (0..count).map(|_| self.generate()).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for RandomColor {
+    fn default() -> Self {
+        Self {
+            hue: (0.0, 1.0),
+            saturation: (0.0, 1.0),
+            lightness: (0.0, 1.0),
+            rng: SmallRng::from_entropy(),
+        }
+    }
+}
+
+/// Samples colors where each channel is uniformly random, which allows you to use
+/// [`Rng::gen()`] to get random colors.
+///
+/// # Example
+///
+/// ```
+/// use octarine::Color;
+/// use rand::Rng;
+///
+/// let color: Color = rand::thread_rng().gen();
+/// println!("{color:?}");
+/// ```
+impl Distribution<Color> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Color {
+        let r: u8 = rng.gen();
+        let g: u8 = rng.gen();
+        let b: u8 = rng.gen();
+        Color(r, g, b)
+    }
+}
+
+/// Samples colors where each channel is uniformly random between the channels of two colors.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{Color, UniformColor};
+/// use rand::distributions::Distribution;
+///
+/// let uniform = UniformColor::new(&Color::new(0, 100, 200), &Color::new(50, 100, 250));
+/// let color = uniform.sample(&mut rand::thread_rng());
+///
+/// assert!(color.get_red() <= 50);
+/// assert_eq!(color.get_green(), 100);
+/// assert!(color.get_blue() >= 200);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct UniformColor {
+    r: Uniform<u8>,
+    g: Uniform<u8>,
+    b: Uniform<u8>,
+}
+
+impl UniformColor {
+    /// Creates a sampler where each channel is between the channels of `low` and `high`
+    /// (inclusive). The channels don't need to be in order, so `low` can have a greater red than
+    /// `high`.
+    pub fn new(low: &Color, high: &Color) -> Self {
+        let uniform = |a: u8, b: u8| Uniform::new_inclusive(a.min(b), a.max(b));
+        Self {
+            r: uniform(low.0, high.0),
+            g: uniform(low.1, high.1),
+            b: uniform(low.2, high.2),
+        }
+    }
+}
+
+impl Distribution<Color> for UniformColor {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Color {
+        Color(self.r.sample(rng), self.g.sample(rng), self.b.sample(rng))
+    }
+}
+
+#[cfg(feature = "std")]
+fn clamp_range(range: RangeInclusive<f32>) -> (f32, f32) {
+    let start = range.start().clamp(0.0, 1.0);
+    let end = range.end().clamp(0.0, 1.0);
+    (start.min(end), start.max(end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn generate() {
+        let canonical = RandomColor::vivid().with_seed(1).generate_n(10);
+        let colors = RandomColor::vivid().with_seed(1).generate_n(10);
+        assert_eq!(canonical, colors);
+        let mut reds = RandomColor::vivid().with_hue(0.95..=0.05).with_seed(2);
+        for _ in 0..100 {
+            let (h, _, _) = reds.generate().to_hsl();
+            assert!(!(0.07..=0.93).contains(&h));
+        }
+        let mut grays = RandomColor::vivid()
+            .with_hue_family(HueFamily::Gray)
+            .with_seed(3);
+        for _ in 0..100 {
+            let (_, s, _) = grays.generate().to_hsl();
+            assert!(s <= 0.11);
+        }
+        let mut dark = RandomColor::dark().with_lightness(0.3..=0.1).with_seed(4);
+        for _ in 0..100 {
+            let (_, _, l) = dark.generate().to_hsl();
+            assert!((0.09..=0.31).contains(&l));
+        }
+    }
+
+    #[test]
+    fn distribution() {
+        let mut rng = SmallRng::seed_from_u64(5);
+        let canonical = Color::random_with(&mut rng.clone());
+        let color: Color = rng.gen();
+        assert_eq!(canonical, color);
+        let uniform = UniformColor::new(&Color::new(10, 200, 0), &Color::new(20, 100, 0));
+        for color in uniform.sample_iter(&mut rng).take(100) {
+            assert!((10..=20).contains(&color.get_red()));
+            assert!((100..=200).contains(&color.get_green()));
+            assert_eq!(color.get_blue(), 0);
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Spot color references for [`Color::nearest_reference()`].
+//!
+//! # Sources
+//!
+//! The Pantone values are the sRGB screen values which Pantone lists for each color in its
+//! public online color finder (<https://www.pantone.com/color-finder>). The RAL values are the
+//! sRGB approximations of the RAL Classic chart as listed in Wikipedia's
+//! [List of RAL colours](https://en.wikipedia.org/wiki/List_of_RAL_colours). Only the codes
+//! and a single approximate value per code are included, not the color libraries or the
+//! spectral or print data of either system. PANTONE and RAL are trademarks of their owners,
+//! who don't endorse these values.
+
+use super::*;
+
+/// Common coated Pantone spot colors, as the sRGB values Pantone publishes for screens.
+static PANTONE_COATED: [(&str, Color); 63] = [
+    ("PANTONE 100 C", Color::from_hex(0xF6EB61)),
+    ("PANTONE 109 C", Color::from_hex(0xFFD100)),
+    ("PANTONE 116 C", Color::from_hex(0xFFCD00)),
+    ("PANTONE 123 C", Color::from_hex(0xFFC72C)),
+    ("PANTONE 137 C", Color::from_hex(0xFFA300)),
+    ("PANTONE 151 C", Color::from_hex(0xFF8200)),
+    ("PANTONE 165 C", Color::from_hex(0xFF6720)),
+    ("PANTONE 172 C", Color::from_hex(0xFA4616)),
+    ("PANTONE 185 C", Color::from_hex(0xE4002B)),
+    ("PANTONE 186 C", Color::from_hex(0xC8102E)),
+    ("PANTONE 199 C", Color::from_hex(0xD50032)),
+    ("PANTONE 200 C", Color::from_hex(0xBA0C2F)),
+    ("PANTONE 202 C", Color::from_hex(0x862633)),
+    ("PANTONE 212 C", Color::from_hex(0xF04E98)),
+    ("PANTONE 226 C", Color::from_hex(0xD0006F)),
+    ("PANTONE 232 C", Color::from_hex(0xE93CAC)),
+    ("PANTONE 259 C", Color::from_hex(0x6D2077)),
+    ("PANTONE 266 C", Color::from_hex(0x753BBD)),
+    ("PANTONE 268 C", Color::from_hex(0x582C83)),
+    ("PANTONE 280 C", Color::from_hex(0x012169)),
+    ("PANTONE 281 C", Color::from_hex(0x00205B)),
+    ("PANTONE 285 C", Color::from_hex(0x0072CE)),
+    ("PANTONE 286 C", Color::from_hex(0x0033A0)),
+    ("PANTONE 287 C", Color::from_hex(0x003087)),
+    ("PANTONE 293 C", Color::from_hex(0x003DA5)),
+    ("PANTONE 299 C", Color::from_hex(0x00A3E0)),
+    ("PANTONE 300 C", Color::from_hex(0x005EB8)),
+    ("PANTONE 301 C", Color::from_hex(0x004B87)),
+    ("PANTONE 306 C", Color::from_hex(0x00B5E2)),
+    ("PANTONE 320 C", Color::from_hex(0x009CA6)),
+    ("PANTONE 327 C", Color::from_hex(0x008675)),
+    ("PANTONE 340 C", Color::from_hex(0x00965E)),
+    ("PANTONE 347 C", Color::from_hex(0x009A44)),
+    ("PANTONE 348 C", Color::from_hex(0x00843D)),
+    ("PANTONE 354 C", Color::from_hex(0x00B140)),
+    ("PANTONE 355 C", Color::from_hex(0x009639)),
+    ("PANTONE 361 C", Color::from_hex(0x43B02A)),
+    ("PANTONE 368 C", Color::from_hex(0x78BE20)),
+    ("PANTONE 375 C", Color::from_hex(0x97D700)),
+    ("PANTONE 382 C", Color::from_hex(0xC4D600)),
+    ("PANTONE 425 C", Color::from_hex(0x54585A)),
+    ("PANTONE 430 C", Color::from_hex(0x7C878E)),
+    ("PANTONE 485 C", Color::from_hex(0xDA291C)),
+    ("PANTONE 871 C", Color::from_hex(0x84754E)),
+    ("PANTONE 877 C", Color::from_hex(0x8A8D8F)),
+    ("PANTONE 7406 C", Color::from_hex(0xF1C400)),
+    ("PANTONE Black C", Color::from_hex(0x2D2926)),
+    ("PANTONE Blue 072 C", Color::from_hex(0x10069F)),
+    ("PANTONE Cool Gray 1 C", Color::from_hex(0xD9D9D6)),
+    ("PANTONE Cool Gray 5 C", Color::from_hex(0xB1B3B3)),
+    ("PANTONE Cool Gray 11 C", Color::from_hex(0x53565A)),
+    ("PANTONE Green C", Color::from_hex(0x00AB84)),
+    ("PANTONE Orange 021 C", Color::from_hex(0xFE5000)),
+    ("PANTONE Process Blue C", Color::from_hex(0x0085CA)),
+    ("PANTONE Purple C", Color::from_hex(0xBB29BB)),
+    ("PANTONE Red 032 C", Color::from_hex(0xEF3340)),
+    ("PANTONE Reflex Blue C", Color::from_hex(0x001489)),
+    ("PANTONE Rhodamine Red C", Color::from_hex(0xE10098)),
+    ("PANTONE Rubine Red C", Color::from_hex(0xCE0058)),
+    ("PANTONE Violet C", Color::from_hex(0x440099)),
+    ("PANTONE Warm Red C", Color::from_hex(0xF9423A)),
+    ("PANTONE Yellow C", Color::from_hex(0xFEDD00)),
+    ("PANTONE Yellow 012 C", Color::from_hex(0xFFD700)),
+];
+
+/// Common RAL Classic colors, as the sRGB values usually given for screens.
+static RAL_CLASSIC: [(&str, Color); 41] = [
+    ("RAL 1003", Color::from_hex(0xE5BE01)),
+    ("RAL 1004", Color::from_hex(0xCDA434)),
+    ("RAL 1015", Color::from_hex(0xE6D690)),
+    ("RAL 1018", Color::from_hex(0xFAD201)),
+    ("RAL 1021", Color::from_hex(0xF3DA0B)),
+    ("RAL 1023", Color::from_hex(0xF7B500)),
+    ("RAL 2002", Color::from_hex(0xCB2821)),
+    ("RAL 2003", Color::from_hex(0xFF7514)),
+    ("RAL 2004", Color::from_hex(0xF44611)),
+    ("RAL 2009", Color::from_hex(0xF54021)),
+    ("RAL 3000", Color::from_hex(0xAF2B1E)),
+    ("RAL 3001", Color::from_hex(0xA52019)),
+    ("RAL 3002", Color::from_hex(0xA2231D)),
+    ("RAL 3003", Color::from_hex(0x9B111E)),
+    ("RAL 3005", Color::from_hex(0x5E2129)),
+    ("RAL 3020", Color::from_hex(0xCC0605)),
+    ("RAL 4005", Color::from_hex(0x6C4675)),
+    ("RAL 4006", Color::from_hex(0xA03472)),
+    ("RAL 5002", Color::from_hex(0x20214F)),
+    ("RAL 5005", Color::from_hex(0x1E2460)),
+    ("RAL 5010", Color::from_hex(0x0E294B)),
+    ("RAL 5012", Color::from_hex(0x3B83BD)),
+    ("RAL 5015", Color::from_hex(0x2271B3)),
+    ("RAL 5017", Color::from_hex(0x063971)),
+    ("RAL 6001", Color::from_hex(0x287233)),
+    ("RAL 6005", Color::from_hex(0x2F4538)),
+    ("RAL 6018", Color::from_hex(0x57A639)),
+    ("RAL 6024", Color::from_hex(0x308446)),
+    ("RAL 6029", Color::from_hex(0x20603D)),
+    ("RAL 7001", Color::from_hex(0x8A9597)),
+    ("RAL 7016", Color::from_hex(0x293133)),
+    ("RAL 7035", Color::from_hex(0xD7D7D7)),
+    ("RAL 7040", Color::from_hex(0x9DA1AA)),
+    ("RAL 7042", Color::from_hex(0x8D948D)),
+    ("RAL 8011", Color::from_hex(0x5B3A29)),
+    ("RAL 8017", Color::from_hex(0x45322E)),
+    ("RAL 9001", Color::from_hex(0xFDF4E3)),
+    ("RAL 9003", Color::from_hex(0xF4F4F4)),
+    ("RAL 9005", Color::from_hex(0x0A0A0A)),
+    ("RAL 9010", Color::from_hex(0xFFFFFF)),
+    ("RAL 9016", Color::from_hex(0xF6F6F6)),
+];
+
+/// A set of spot color references from a color matching system, for
+/// [`nearest_reference()`](Color#method.nearest_reference). Only the most common colors of each
+/// system are included, as sRGB approximations which are not endorsed by the owners of the
+/// systems. Spot inks and paints can be outside the sRGB gamut and look different on different
+/// materials, so check the result against a physical swatch book. Requires the `spot-colors`
+/// feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReferenceSet {
+    /// Pantone colors for coated paper, such as "PANTONE 185 C", used for printing.
+    PantoneCoated,
+    /// RAL Classic colors, such as "RAL 3020", used for paints and powder coatings.
+    RalClassic,
+}
+
+impl ReferenceSet {
+    /// Gets the codes and colors of the references in this set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::ReferenceSet;
+    ///
+    /// assert!(ReferenceSet::RalClassic
+    ///     .colors()
+    ///     .iter()
+    ///     .any(|(code, _)| *code == "RAL 9005"));
+    /// ```
+    pub fn colors(self) -> &'static [(&'static str, Color)] {
+        match self {
+            Self::PantoneCoated => &PANTONE_COATED,
+            Self::RalClassic => &RAL_CLASSIC,
+        }
+    }
+
+    /// Gets the color of the reference with `code` (case-insensitive), or `None` when this set
+    /// doesn't include it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ReferenceSet};
+    ///
+    /// assert_eq!(
+    ///     ReferenceSet::PantoneCoated.color("pantone 286 c"),
+    ///     Some(Color::from_hex(0x0033A0))
+    /// );
+    /// ```
+    pub fn color(self, code: &str) -> Option<Color> {
+        self.colors()
+            .iter()
+            .find(|(other, _)| other.eq_ignore_ascii_case(code))
+            .map(|&(_, color)| color)
+    }
+}
+
+impl Color {
+    /// Gets the code of the reference in `set` that is perceptually closest to this color by
+    /// CIEDE2000 Delta E, along with its color, such as the closest coated Pantone color for a
+    /// logo. This is a best-effort lookup, as explained in [`ReferenceSet`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ReferenceSet};
+    ///
+    /// let (code, _) = Color::new(230, 0, 40).nearest_reference(ReferenceSet::PantoneCoated);
+    ///
+    /// assert_eq!(code, "PANTONE 185 C");
+    /// ```
+    pub fn nearest_reference(&self, set: ReferenceSet) -> (&'static str, Color) {
+        let lab = self.to_lab();
+        set.colors()
+            .iter()
+            .map(|&(code, other)| ((code, other), ciede2000(other.to_lab(), lab)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(nearest, _)| nearest)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SETS: [ReferenceSet; 2] = [ReferenceSet::PantoneCoated, ReferenceSet::RalClassic];
+
+    #[test]
+    fn color() {
+        for set in SETS {
+            let colors = set.colors();
+            for (i, (code, color)) in colors.iter().enumerate() {
+                assert!(colors[i + 1..].iter().all(|(other, _)| other != code));
+                assert_eq!(set.color(code), Some(*color));
+            }
+        }
+        assert_eq!(
+            ReferenceSet::RalClassic.color("ral 3020"),
+            Some(Color::new(204, 6, 5))
+        );
+        assert_eq!(ReferenceSet::RalClassic.color("RAL 0000"), None);
+    }
+
+    #[test]
+    fn nearest_reference() {
+        for set in SETS {
+            for &(code, color) in set.colors() {
+                assert_eq!(color.nearest_reference(set), (code, color));
+            }
+        }
+        assert_eq!(
+            constants::primary::BLACK
+                .nearest_reference(ReferenceSet::RalClassic)
+                .0,
+            "RAL 9005"
+        );
+    }
+}
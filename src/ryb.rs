@@ -0,0 +1,164 @@
+use super::*;
+
+/// Converts RGB to RYB with the algorithm by Junichi Sugita and Tokiichiro Takahashi, which
+/// removes the white, splits yellow out of red and green, and then restores the brightness.
+fn rgb_to_ryb(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let white = r.min(g).min(b);
+    let (mut r, mut g, mut b) = (r - white, g - white, b - white);
+    let max_green = r.max(g).max(b);
+    let mut y = r.min(g);
+    r -= y;
+    g -= y;
+    if b > 0.0 && g > 0.0 {
+        b /= 2.0;
+        g /= 2.0;
+    }
+    y += g;
+    b += g;
+    let max_yellow = r.max(y).max(b);
+    if max_yellow > 0.0 {
+        let n = max_green / max_yellow;
+        r *= n;
+        y *= n;
+        b *= n;
+    }
+    (r + white, y + white, b + white)
+}
+
+/// The inverse of [`rgb_to_ryb()`].
+fn ryb_to_rgb(r: f32, y: f32, b: f32) -> (f32, f32, f32) {
+    let white = r.min(y).min(b);
+    let (mut r, mut y, mut b) = (r - white, y - white, b - white);
+    let max_yellow = r.max(y).max(b);
+    let mut g = y.min(b);
+    y -= g;
+    b -= g;
+    if b > 0.0 && g > 0.0 {
+        b *= 2.0;
+        g *= 2.0;
+    }
+    r += y;
+    g += y;
+    let max_green = r.max(g).max(b);
+    if max_green > 0.0 {
+        let n = max_yellow / max_green;
+        r *= n;
+        g *= n;
+        b *= n;
+    }
+    (r + white, g + white, b + white)
+}
+
+impl Color {
+    /// Creates a color from the red, yellow, and blue of the painter's color wheel (0.0 - 1.0),
+    /// where yellow and blue mix to green, and red and yellow mix to orange. Like RGB, all three
+    /// at 1.0 are white.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the R, Y, or B values are < 0.0 or > 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// assert_eq!(Color::from_ryb(0.0, 1.0, 1.0), constants::primary::GREEN);
+    /// assert_eq!(Color::from_ryb(1.0, 1.0, 0.0), Color::new(255, 128, 0));
+    /// ```
+    pub fn from_ryb(r: f32, y: f32, b: f32) -> Self {
+        if out_of_range(r) || out_of_range(y) || out_of_range(b) {
+            panic!("Unsupported RYB color: ({r}, {y}, {b})");
+        }
+        let (r, g, b) = ryb_to_rgb(r, y, b);
+        Self::from_rgb_float(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0))
+    }
+
+    /// Gets the red, yellow, and blue of the painter's color wheel (0.0 - 1.0), the inverse of
+    /// [`from_ryb()`](Color#method.from_ryb).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let (r, y, b) = Color::new(0, 255, 0).to_ryb();
+    ///
+    /// assert!(r < 0.01 && y > 0.99 && b > 0.99);
+    /// ```
+    pub fn to_ryb(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_rgb_float();
+        rgb_to_ryb(r, g, b)
+    }
+
+    /// Gets the complementary color on the painter's red-yellow-blue color wheel, as taught in
+    /// traditional color theory, so red is the complement of green and blue is the complement of
+    /// orange. [`complementary()`](Color#method.complementary) gives the complement on the RGB
+    /// wheel instead, where red is the complement of cyan.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// assert_eq!(constants::primary::RED.artistic_complement(), constants::primary::GREEN);
+    /// assert_eq!(constants::primary::BLUE.artistic_complement(), Color::new(255, 128, 0));
+    /// ```
+    pub fn artistic_complement(&self) -> Self {
+        let (r, y, b) = self.to_ryb();
+        let sum = r.max(y).max(b) + r.min(y).min(b);
+        Self::from_ryb(
+            (sum - r).clamp(0.0, 1.0),
+            (sum - y).clamp(0.0, 1.0),
+            (sum - b).clamp(0.0, 1.0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let color = Color::new(r, g, b);
+                    let (r, y, b) = color.to_ryb();
+                    assert!(color.approx_eq(&Color::from_ryb(r, y, b), 1), "{color:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ryb() {
+        assert_eq!(Color::from_ryb(0.0, 0.0, 0.0), constants::primary::BLACK);
+        assert_eq!(Color::from_ryb(1.0, 1.0, 1.0), constants::primary::WHITE);
+        assert_eq!(Color::from_ryb(1.0, 0.0, 0.0), constants::primary::RED);
+        assert_eq!(Color::from_ryb(0.0, 1.0, 0.0), Color::new(255, 255, 0));
+        assert_eq!(Color::from_ryb(0.0, 0.0, 1.0), constants::primary::BLUE);
+        assert_eq!(Color::from_ryb(1.0, 0.0, 1.0), Color::new(255, 0, 255));
+    }
+
+    #[test]
+    fn artistic_complement() {
+        assert_eq!(
+            Color::new(255, 255, 0).artistic_complement(),
+            Color::new(255, 0, 255)
+        );
+        assert_eq!(
+            constants::primary::WHITE.artistic_complement(),
+            constants::primary::WHITE
+        );
+        let gray = Color::new(128, 128, 128);
+        assert_eq!(gray.artistic_complement(), gray);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported RYB color")]
+    fn out_of_range() {
+        Color::from_ryb(0.0, 1.5, 0.0);
+    }
+}
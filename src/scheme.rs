@@ -0,0 +1,264 @@
+use super::*;
+
+/// The minimum contrast ratio between colors and the "on" colors drawn over them, which is the
+/// WCAG AA level for normal text.
+const MIN_CONTRAST: f32 = 4.5;
+
+/// The chroma in OKLCH of the secondary and neutral tonal palettes, which are capped by the
+/// chroma of the seed color.
+const SECONDARY_CHROMA: f32 = 0.04;
+const NEUTRAL_CHROMA: f32 = 0.01;
+
+/// The seed of the error tonal palette, which is the same red for every scheme.
+const ERROR_SEED: Color = Color::from_hex(0xB3261E);
+
+/// The tones (OKLCH lightness) of the light and dark schemes, in the order color, on color,
+/// container, and on container.
+const LIGHT_TONES: [f32; 4] = [0.45, 1.0, 0.9, 0.2];
+const DARK_TONES: [f32; 4] = [0.8, 0.25, 0.35, 0.9];
+
+/// The tones of the neutral tonal palette, in the order background, on background, surface, and
+/// on surface.
+const LIGHT_NEUTRAL_TONES: [f32; 4] = [0.99, 0.2, 0.96, 0.2];
+const DARK_NEUTRAL_TONES: [f32; 4] = [0.16, 0.9, 0.22, 0.9];
+
+/// The maximum chroma in OKLCH of colors converted by
+/// [`to_dark_mode()`](Color#method.to_dark_mode), since very saturated colors vibrate against
+/// dark backgrounds.
+const DARK_MODE_MAX_CHROMA: f32 = 0.12;
+
+/// How much the tone of an "on" color is moved at a time until it has enough contrast.
+const TONE_STEP: f32 = 0.01;
+
+/// A tonal palette, which has every tone (0.0 - 1.0) of one hue and chroma in OKLCH.
+#[derive(Debug, Clone, Copy)]
+struct Tones {
+    c: f32,
+    h: f32,
+}
+
+impl Tones {
+    fn new(seed: &Color, max_chroma: f32) -> Self {
+        let (_, c, h) = seed.to_oklch();
+        Self {
+            c: c.min(max_chroma),
+            h,
+        }
+    }
+
+    fn tone(&self, tone: f32) -> Color {
+        oklch_to_color_in_gamut(tone, self.c, self.h)
+    }
+
+    /// Gets the tone closest to `tone` which has enough contrast with `background`, moving
+    /// towards black or white, whichever `tone` is already closer to.
+    fn on(&self, tone: f32, background: &Color) -> Color {
+        let step = if tone < 0.5 { -TONE_STEP } else { TONE_STEP };
+        let mut tone = tone;
+        let mut color = self.tone(tone);
+        while color.contrast_ratio(background) < MIN_CONTRAST
+            && (TONE_STEP..=1.0 - TONE_STEP).contains(&tone)
+        {
+            tone += step;
+            color = self.tone(tone);
+        }
+        color
+    }
+
+    /// Gets the color, on color, container, and on container of `tones`.
+    fn roles(&self, [color, on, container, on_container]: [f32; 4]) -> [Color; 4] {
+        let (color, container) = (self.tone(color), self.tone(container));
+        [
+            color,
+            self.on(on, &color),
+            container,
+            self.on(on_container, &container),
+        ]
+    }
+}
+
+/// The colors of a user interface generated from one seed color, such as a brand color, like the
+/// dynamic color of Material Design 3. Each role is taken from a tonal palette, which keeps the
+/// hue of its seed while varying the lightness, so the colors work together. Every "on" color,
+/// which is used for the text and icons drawn over the color it's named after, is moved towards
+/// black or white until it has a contrast ratio of at least 4.5 with it. This is best-effort: the
+/// on color keeps the hue and chroma of its tonal palette and stops short of pure black and white,
+/// so with some seeds it can end up with slightly less contrast. Check the
+/// [`contrast_ratio()`](Color#method.contrast_ratio) when it has to be met.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{Color, Scheme};
+///
+/// let brand = Color::from_hex(0x6750A4);
+/// let light = Scheme::light(&brand);
+/// let dark = Scheme::dark(&brand);
+///
+/// assert!(light.on_primary.contrast_ratio(&light.primary) >= 4.5);
+/// assert!(dark.on_background.contrast_ratio(&dark.background) >= 4.5);
+/// assert!(light.background.relative_luminance() > dark.background.relative_luminance());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Scheme {
+    /// The color of prominent components, such as buttons, in the hue of the seed.
+    pub primary: Color,
+    pub on_primary: Color,
+    /// A less prominent version of the primary color, such as for the background of chips.
+    pub primary_container: Color,
+    pub on_primary_container: Color,
+    /// The color of less prominent components, with the hue of the seed and less chroma.
+    pub secondary: Color,
+    pub on_secondary: Color,
+    pub secondary_container: Color,
+    pub on_secondary_container: Color,
+    /// The color of errors, which is red for every seed.
+    pub error: Color,
+    pub on_error: Color,
+    pub error_container: Color,
+    pub on_error_container: Color,
+    /// The color behind all content, a near neutral in the hue of the seed.
+    pub background: Color,
+    pub on_background: Color,
+    /// The color of surfaces over the background, such as cards and sheets.
+    pub surface: Color,
+    pub on_surface: Color,
+}
+
+impl Scheme {
+    /// Generates the light scheme of `seed`, with dark text over light colors.
+    pub fn light(seed: &Color) -> Self {
+        Self::generate(seed, LIGHT_TONES, LIGHT_NEUTRAL_TONES)
+    }
+
+    /// Generates the dark scheme of `seed`, with light text over dark colors.
+    pub fn dark(seed: &Color) -> Self {
+        Self::generate(seed, DARK_TONES, DARK_NEUTRAL_TONES)
+    }
+
+    fn generate(seed: &Color, tones: [f32; 4], neutral_tones: [f32; 4]) -> Self {
+        let [primary, on_primary, primary_container, on_primary_container] =
+            Tones::new(seed, f32::INFINITY).roles(tones);
+        let [secondary, on_secondary, secondary_container, on_secondary_container] =
+            Tones::new(seed, SECONDARY_CHROMA).roles(tones);
+        let [error, on_error, error_container, on_error_container] =
+            Tones::new(&ERROR_SEED, f32::INFINITY).roles(tones);
+        let [background, on_background, surface, on_surface] =
+            Tones::new(seed, NEUTRAL_CHROMA).roles(neutral_tones);
+        Self {
+            primary,
+            on_primary,
+            primary_container,
+            on_primary_container,
+            secondary,
+            on_secondary,
+            secondary_container,
+            on_secondary_container,
+            error,
+            on_error,
+            error_container,
+            on_error_container,
+            background,
+            on_background,
+            surface,
+            on_surface,
+        }
+    }
+}
+
+impl Color {
+    /// Converts a color for use in a dark mode user interface, by inverting its lightness in
+    /// OKLCH and capping its chroma, while keeping its hue. Light backgrounds become dark
+    /// backgrounds, dark text becomes light text, and the contrast between colors is roughly
+    /// kept. [`Scheme::dark()`] generates a whole dark theme from a seed color instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// assert_eq!(constants::primary::WHITE.to_dark_mode(), constants::primary::BLACK);
+    ///
+    /// let link = Color::new(0, 80, 200);
+    /// let dark_link = link.to_dark_mode();
+    ///
+    /// assert!(dark_link.to_oklch().0 > link.to_oklch().0);
+    /// assert!((dark_link.to_oklch().2 - link.to_oklch().2).abs() < 0.01);
+    /// ```
+    pub fn to_dark_mode(&self) -> Self {
+        let (l, c, h) = self.to_oklch();
+        oklch_to_color_in_gamut(1.0 - l, c.min(DARK_MODE_MAX_CHROMA), h)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEEDS: [Color; 6] = [
+        Color::from_hex(0x6750A4),
+        Color::from_hex(0x0061A4),
+        Color::from_hex(0xFFDE3F),
+        Color::from_hex(0x00FF00),
+        Color::from_hex(0x808080),
+        Color::from_hex(0x000000),
+    ];
+
+    /// Gets the pairs of colors and the "on" colors drawn over them.
+    fn pairs(scheme: &Scheme) -> [(Color, Color); 8] {
+        [
+            (scheme.primary, scheme.on_primary),
+            (scheme.primary_container, scheme.on_primary_container),
+            (scheme.secondary, scheme.on_secondary),
+            (scheme.secondary_container, scheme.on_secondary_container),
+            (scheme.error, scheme.on_error),
+            (scheme.error_container, scheme.on_error_container),
+            (scheme.background, scheme.on_background),
+            (scheme.surface, scheme.on_surface),
+        ]
+    }
+
+    #[test]
+    fn contrast() {
+        for seed in SEEDS {
+            for scheme in [Scheme::light(&seed), Scheme::dark(&seed)] {
+                for (color, on) in pairs(&scheme) {
+                    assert!(on.contrast_ratio(&color) >= MIN_CONTRAST, "{seed:?}");
+                }
+                assert!(scheme.primary.contrast_ratio(&scheme.background) >= 3.0);
+            }
+        }
+    }
+
+    #[test]
+    fn to_dark_mode() {
+        assert_eq!(
+            constants::primary::BLACK.to_dark_mode(),
+            constants::primary::WHITE
+        );
+        let gray = Color::new(99, 99, 99);
+        assert!(gray.to_dark_mode().approx_eq(&gray, 2));
+        let red = constants::primary::RED.to_dark_mode();
+        assert!(red.to_oklch().1 <= DARK_MODE_MAX_CHROMA + 0.001);
+        assert!((red.to_oklch().2 - constants::primary::RED.to_oklch().2).abs() < 0.01);
+        let (background, text) = (Color::new(250, 250, 245), Color::new(40, 40, 60));
+        let ratio = background.contrast_ratio(&text);
+        let dark_ratio = background
+            .to_dark_mode()
+            .contrast_ratio(&text.to_dark_mode());
+        assert!(dark_ratio > ratio / 2.0, "{ratio} {dark_ratio}");
+    }
+
+    #[test]
+    fn hue() {
+        let seed = Color::from_hex(0x0061A4);
+        let hue = seed.oklch().h;
+        let light = Scheme::light(&seed);
+        for color in [light.primary, light.primary_container, light.secondary] {
+            assert!((color.oklch().h - hue).abs() < 0.02, "{color:?}");
+        }
+        let gray = Scheme::dark(&Color::new(128, 128, 128));
+        assert!(gray.primary.get_hsl_saturation() < 0.05);
+        assert!(light.error.get_red() > light.error.get_blue());
+    }
+}
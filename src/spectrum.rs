@@ -0,0 +1,173 @@
+use super::*;
+
+/// A lobe of the color matching functions, with its height, center, and the widths below and
+/// above the center.
+type Lobe = (f32, f32, f32, f32);
+
+const X_LOBES: [Lobe; 3] = [
+    (1.056, 599.8, 37.9, 31.0),
+    (0.362, 442.0, 16.0, 26.7),
+    (-0.065, 501.1, 20.4, 26.2),
+];
+const Y_LOBES: [Lobe; 2] = [(0.821, 568.8, 46.9, 40.5), (0.286, 530.9, 16.3, 31.1)];
+const Z_LOBES: [Lobe; 2] = [(1.217, 437.0, 11.8, 36.0), (0.681, 459.0, 26.0, 13.8)];
+
+/// Gets the CIE 1931 2° color matching functions at the wavelength `nm`, using the multi-lobe
+/// Gaussian fit by Wyman, Sloan, and Shirley (2013).
+fn observer(nm: f32) -> (f32, f32, f32) {
+    let sum = |lobes: &[Lobe]| -> f32 {
+        lobes
+            .iter()
+            .map(|&(height, center, below, above)| {
+                let width = if nm < center { below } else { above };
+                height * (-0.5 * ((nm - center) / width).powi(2)).exp()
+            })
+            .sum()
+    };
+    (sum(&X_LOBES), sum(&Y_LOBES), sum(&Z_LOBES))
+}
+
+/// Integrates `power` of every sample against the color matching functions with the trapezoidal
+/// rule. A single sample is treated as a spectral line.
+fn integrate(samples: &[(f32, f32)], power: impl Fn(f32) -> f32) -> (f32, f32, f32) {
+    let weighted = |&(nm, p): &(f32, f32)| {
+        let (x, y, z) = observer(nm);
+        let p = power(p);
+        (x * p, y * p, z * p)
+    };
+    if let [sample] = samples {
+        return weighted(sample);
+    }
+    let mut total = (0.0, 0.0, 0.0);
+    for pair in samples.windows(2) {
+        let width = pair[1].0 - pair[0].0;
+        if width <= 0.0 {
+            panic!(
+                "Unsupported wavelength order: {} after {}",
+                pair[1].0, pair[0].0
+            );
+        }
+        let (a, b) = (weighted(&pair[0]), weighted(&pair[1]));
+        total.0 += (a.0 + b.0) * 0.5 * width;
+        total.1 += (a.1 + b.1) * 0.5 * width;
+        total.2 += (a.2 + b.2) * 0.5 * width;
+    }
+    total
+}
+
+impl Color {
+    /// Gets the color of light with the spectral power distribution `samples`, which are pairs of
+    /// wavelengths in nanometers and powers, sorted by wavelength. The power is integrated
+    /// against the CIE 1931 2° standard observer. Only the chromaticity of the light matters, as
+    /// the brightness is normalized so the brightest channel is 255, the same as
+    /// [`from_temperature()`](Color#method.from_temperature). Colors that fall outside of the RGB
+    /// gamut, such as the pure spectral colors of lasers, are clamped.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the wavelengths are not increasing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// let flat: Vec<_> = (380..=780).step_by(5).map(|nm| (nm as f32, 1.0)).collect();
+    /// let white = Color::from_spectrum(&flat);
+    /// let laser = Color::from_spectrum(&[(650.0, 1.0)]);
+    ///
+    /// assert!(white.get_red() == 255 && white.get_green() > 200 && white.get_blue() > 200);
+    /// assert_eq!(laser.get_hsl_hue(), 0.0);
+    /// ```
+    pub fn from_spectrum(samples: &[(f32, f32)]) -> Self {
+        let (x, y, z) = integrate(samples, |p| p);
+        let (r, g, b) = xyz_to_linear_rgb(x, y, z);
+        let (r, g, b) = (r.max(0.0), g.max(0.0), b.max(0.0));
+        let max = r.max(g).max(b);
+        if max == 0.0 {
+            return constants::primary::BLACK;
+        }
+        Self::from_linear_rgb(r / max, g / max, b / max)
+    }
+
+    /// Gets the color of a surface with the reflectance curve `samples`, which are pairs of
+    /// wavelengths in nanometers and reflectances between 0.0 and 1.0, sorted by wavelength. The
+    /// surface is lit by light with the same power at every wavelength, adapted to the D65 white
+    /// point of sRGB, so a surface which reflects everything is white.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the wavelengths are not increasing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let white: Vec<_> = (380..=780).step_by(10).map(|nm| (nm as f32, 1.0)).collect();
+    /// let red: Vec<_> = (380..=780)
+    ///     .step_by(10)
+    ///     .map(|nm| (nm as f32, if nm > 600 { 0.9 } else { 0.05 }))
+    ///     .collect();
+    ///
+    /// assert_eq!(Color::from_reflectance(&white), constants::primary::WHITE);
+    /// assert!(Color::from_reflectance(&red).get_red() > 200);
+    /// ```
+    pub fn from_reflectance(samples: &[(f32, f32)]) -> Self {
+        let white = integrate(samples, |_| 1.0);
+        if white.1 == 0.0 {
+            return constants::primary::BLACK;
+        }
+        let (x, y, z) = integrate(samples, |p| p / white.1);
+        let white = (white.0 / white.1, 1.0, white.2 / white.1);
+        let (x, y, z) =
+            ChromaticAdaptation::Bradford.adapt((x, y, z), white, Illuminant::D65.white_point());
+        Self::from_xyz(x, y, z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn observer() {
+        let (_, y, _) = super::observer(555.0);
+        assert!((y - 1.0).abs() < 0.02);
+        let (x, _, _) = super::observer(600.0);
+        assert!((x - 1.062).abs() < 0.02);
+        let (_, _, z) = super::observer(445.0);
+        assert!((z - 1.78).abs() < 0.05);
+        assert!(super::observer(830.0).1 < 0.001);
+    }
+
+    #[test]
+    fn from_spectrum() {
+        assert_eq!(Color::from_spectrum(&[]), constants::primary::BLACK);
+        assert_eq!(
+            Color::from_spectrum(&[(500.0, 0.0), (600.0, 0.0)]),
+            constants::primary::BLACK
+        );
+        let green = Color::from_spectrum(&[(520.0, 1.0), (540.0, 1.0)]);
+        assert_eq!(green.get_green(), 255);
+        assert!(green.get_red() < 100 && green.get_blue() < 100);
+        let bright = Color::from_spectrum(&[(450.0, 2.0), (460.0, 2.0)]);
+        let dim = Color::from_spectrum(&[(450.0, 1.0), (460.0, 1.0)]);
+        assert_eq!(bright, dim);
+    }
+
+    #[test]
+    fn from_reflectance() {
+        let gray: Vec<_> = (400..=700).step_by(20).map(|nm| (nm as f32, 0.2)).collect();
+        let gray = Color::from_reflectance(&gray);
+        assert!(gray.get_hsl_saturation() < 0.01);
+        assert!((gray.relative_luminance() - 0.2).abs() < 0.01);
+        assert_eq!(Color::from_reflectance(&[]), constants::primary::BLACK);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported wavelength order: 500 after 600")]
+    fn wavelength_order() {
+        Color::from_spectrum(&[(600.0, 1.0), (500.0, 1.0)]);
+    }
+}
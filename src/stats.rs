@@ -0,0 +1,266 @@
+use super::*;
+use alloc::vec::Vec;
+use core::f32::consts::TAU;
+
+/// Statistics over a collection of colors, such as the colors of a palette extracted from an
+/// image. Means, medians, and variances are computed per component in a chosen [`ColorSpace`],
+/// and hues are treated as angles, so that red hues on both sides of 0.0 average to red.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{Color, ColorSpace, ColorStats, constants};
+///
+/// let colors = [constants::primary::BLACK, Color::new(100, 100, 100), constants::primary::WHITE];
+/// let stats = ColorStats::from(&colors[..]);
+///
+/// assert_eq!(stats.mean(ColorSpace::Srgb), Some(Color::new(118, 118, 118)));
+/// assert_eq!(stats.median(ColorSpace::Srgb), Some(Color::new(100, 100, 100)));
+/// assert_eq!(stats.lightest(), Some(constants::primary::WHITE));
+/// assert_eq!(stats.histograms()[0][100], 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorStats<'a> {
+    colors: &'a [Color],
+}
+
+impl<'a> From<&'a [Color]> for ColorStats<'a> {
+    fn from(colors: &'a [Color]) -> Self {
+        Self { colors }
+    }
+}
+
+impl ColorStats<'_> {
+    /// Gets the number of colors.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns `true` if there are no colors.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Gets the histograms of the red, green, and blue channels, in that order. Each histogram
+    /// counts how many colors have each of the 256 values of the channel.
+    pub fn histograms(&self) -> [[usize; 256]; 3] {
+        let mut histograms = [[0; 256]; 3];
+        for color in self.colors {
+            histograms[0][color.0 as usize] += 1;
+            histograms[1][color.1 as usize] += 1;
+            histograms[2][color.2 as usize] += 1;
+        }
+        histograms
+    }
+
+    /// Gets the mean of the colors, averaging each component in `space`. Returns `None` when
+    /// there are no colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ColorSpace, ColorStats};
+    ///
+    /// let colors = [Color::from_hsl(0.9, 1.0, 0.5), Color::from_hsl(0.1, 1.0, 0.5)];
+    /// let stats = ColorStats::from(&colors[..]);
+    ///
+    /// assert_eq!(stats.mean(ColorSpace::Hsl), Some(Color::new(255, 0, 0)));
+    /// ```
+    pub fn mean(&self, space: ColorSpace) -> Option<Color> {
+        let components = self.components(space)?;
+        Some(space.to_color(components.map(|values| mean(&values)).into()))
+    }
+
+    /// Gets the median of the colors, which is the median of each component in `space`. Hues are
+    /// unwrapped around their mean before the median is taken. Returns `None` when there are no
+    /// colors.
+    pub fn median(&self, space: ColorSpace) -> Option<Color> {
+        let components = self.components(space)?;
+        let median = components.map(|mut values| {
+            values.sort_unstable_by(f32::total_cmp);
+            let middle = values.len() / 2;
+            if values.len() % 2 == 0 {
+                (values[middle - 1] + values[middle]) / 2.0
+            } else {
+                values[middle]
+            }
+        });
+        Some(space.to_color(median.into()))
+    }
+
+    /// Gets the variance of each component of the colors in `space`, which is how far the colors
+    /// are spread out along that component. Hues are unwrapped around their mean, so their
+    /// variance is in turns squared. Returns `None` when there are no colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, ColorSpace, ColorStats};
+    ///
+    /// let colors = [Color::new(0, 0, 0), Color::new(255, 0, 0)];
+    /// let (red, green, blue) = ColorStats::from(&colors[..]).variance(ColorSpace::Srgb).unwrap();
+    ///
+    /// assert_eq!((red, green, blue), (0.25, 0.0, 0.0));
+    /// ```
+    pub fn variance(&self, space: ColorSpace) -> Option<(f32, f32, f32)> {
+        let components = self.components(space)?;
+        let variance = components.map(|values| {
+            let mean = mean(&values);
+            values.iter().map(|v| (v - mean) * (v - mean)).sum::<f32>() / values.len() as f32
+        });
+        Some(variance.into())
+    }
+
+    /// Gets the color with the highest chroma in OKLCH. Returns `None` when there are no colors.
+    pub fn most_saturated(&self) -> Option<Color> {
+        self.max_by(|color| color.to_oklch().1)
+    }
+
+    /// Gets the color with the lowest chroma in OKLCH. Returns `None` when there are no colors.
+    pub fn least_saturated(&self) -> Option<Color> {
+        self.max_by(|color| -color.to_oklch().1)
+    }
+
+    /// Gets the color with the highest perceived lightness (OKLab L). Returns `None` when there
+    /// are no colors.
+    pub fn lightest(&self) -> Option<Color> {
+        self.max_by(|color| color.to_oklab().0)
+    }
+
+    /// Gets the color with the lowest perceived lightness (OKLab L). Returns `None` when there
+    /// are no colors.
+    pub fn darkest(&self) -> Option<Color> {
+        self.max_by(|color| -color.to_oklab().0)
+    }
+
+    /// Gets the first color with the largest `key`.
+    fn max_by(&self, key: impl Fn(&Color) -> f32) -> Option<Color> {
+        self.colors
+            .iter()
+            .map(|color| (key(color), color))
+            .reduce(|max, next| if next.0 > max.0 { next } else { max })
+            .map(|(_, color)| *color)
+    }
+
+    /// Converts the colors to `space`, with the values of each component collected together. The
+    /// hues are unwrapped to within half a turn of their circular mean, so that they can be
+    /// treated like any other component.
+    fn components(&self, space: ColorSpace) -> Option<[Vec<f32>; 3]> {
+        if self.colors.is_empty() {
+            return None;
+        }
+        let mut components: [Vec<f32>; 3] = Default::default();
+        for color in self.colors {
+            let (v0, v1, v2) = space.components(color);
+            for (values, value) in components.iter_mut().zip([v0, v1, v2]) {
+                values.push(value);
+            }
+        }
+        let hue = match space {
+            ColorSpace::Hsl | ColorSpace::Hsv => Some(0),
+            ColorSpace::Oklch => Some(2),
+            _ => None,
+        };
+        if let Some(hue) = hue {
+            let (sin, cos) = components[hue]
+                .iter()
+                .map(|h| (h * TAU).sin_cos())
+                .fold((0.0, 0.0), |(s, c), (sin, cos)| (s + sin, c + cos));
+            let center = sin.atan2(cos) / TAU;
+            for h in &mut components[hue] {
+                *h = center + (*h - center + 0.5).rem_euclid(1.0) - 0.5;
+            }
+        }
+        Some(components)
+    }
+}
+
+fn mean(values: &[f32]) -> f32 {
+    values.iter().sum::<f32>() / values.len() as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let stats = ColorStats::from(&[][..]);
+        assert!(stats.is_empty());
+        assert_eq!(stats.mean(ColorSpace::Oklab), None);
+        assert_eq!(stats.median(ColorSpace::Srgb), None);
+        assert_eq!(stats.variance(ColorSpace::Hsl), None);
+        assert_eq!(stats.darkest(), None);
+        assert_eq!(stats.histograms(), [[0; 256]; 3]);
+    }
+
+    #[test]
+    fn mean_and_median() {
+        let colors = [
+            constants::primary::BLACK,
+            constants::primary::WHITE,
+            Color::new(10, 20, 30),
+            Color::new(40, 50, 60),
+        ];
+        let stats = ColorStats::from(&colors[..]);
+        assert_eq!(stats.len(), 4);
+        assert_eq!(stats.mean(ColorSpace::LinearRgb), Color::average(&colors));
+        assert_eq!(stats.median(ColorSpace::Srgb), Some(Color::new(25, 35, 45)));
+        let hues = [
+            Color::from_hsl(0.95, 1.0, 0.5),
+            Color::from_hsl(0.0, 1.0, 0.5),
+            Color::from_hsl(0.15, 1.0, 0.5),
+        ];
+        let stats = ColorStats::from(&hues[..]);
+        let median = stats.median(ColorSpace::Hsl).unwrap();
+        assert_eq!(median, constants::primary::RED);
+        let red = constants::primary::RED.to_oklch().2;
+        let around_red = [
+            Color::from_oklch(0.6, 0.1, (red - 0.1).rem_euclid(1.0)),
+            Color::from_oklch(0.6, 0.1, red + 0.1),
+        ];
+        let mean = ColorStats::from(&around_red[..])
+            .mean(ColorSpace::Oklch)
+            .unwrap()
+            .to_oklch()
+            .2;
+        assert!((mean - red).abs() < 0.01);
+    }
+
+    #[test]
+    fn variance() {
+        let reds = [
+            Color::from_hsl(0.95, 1.0, 0.5),
+            Color::from_hsl(0.05, 1.0, 0.5),
+        ];
+        let (hue, saturation, _) = ColorStats::from(&reds[..])
+            .variance(ColorSpace::Hsl)
+            .unwrap();
+        assert!((hue - 0.0025).abs() < 1e-4);
+        assert_eq!(saturation, 0.0);
+        let same = [Color::new(1, 2, 3); 5];
+        let variance = ColorStats::from(&same[..]).variance(ColorSpace::Oklab);
+        assert_eq!(variance, Some((0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn extremes() {
+        let colors = [
+            Color::new(128, 128, 128),
+            constants::primary::RED,
+            Color::new(30, 10, 10),
+            Color::new(250, 250, 240),
+            Color::new(200, 120, 120),
+        ];
+        let stats = ColorStats::from(&colors[..]);
+        assert_eq!(stats.most_saturated(), Some(constants::primary::RED));
+        assert_eq!(stats.least_saturated(), Some(Color::new(128, 128, 128)));
+        assert_eq!(stats.lightest(), Some(Color::new(250, 250, 240)));
+        assert_eq!(stats.darkest(), Some(Color::new(30, 10, 10)));
+        let histograms = stats.histograms();
+        assert_eq!(histograms[1][120], 1);
+        assert_eq!(histograms[2].iter().sum::<usize>(), 5);
+    }
+}
@@ -0,0 +1,237 @@
+use super::*;
+
+/// The range of color temperatures in Kelvin supported by
+/// [`from_temperature()`](Color#method.from_temperature).
+const TEMPERATURE_RANGE: core::ops::RangeInclusive<f32> = 1000.0..=40000.0;
+
+/// The hues where warm colors start (magenta red) and end (yellow green).
+const WARM_HUES: (f32, f32) = (330.0 / 360.0, 90.0 / 360.0);
+
+/// The chroma at or below which [`temperature_class()`](Color#method.temperature_class) considers
+/// colors to be neutral.
+const NEUTRAL_CHROMA: f32 = 0.1;
+
+/// Whether a color looks warm, like fire and sunlight, or cool, like water and ice, as returned
+/// by [`temperature_class()`](Color#method.temperature_class).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TemperatureClass {
+    /// Reds, oranges, and yellows.
+    Warm,
+    /// Greens, blues, and purples.
+    Cool,
+    /// Grays and colors with so little chroma that they have no temperature.
+    Neutral,
+}
+
+impl Color {
+    /// Gets the color of light emitted by a black body at `kelvin`, such as 2700 K for warm white
+    /// bulbs and 6500 K for daylight. This uses the approximation by Tanner Helland with the
+    /// improved fit by Neil Bartlett, which is accurate enough for lighting but not for
+    /// colorimetry.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `kelvin` is < 1000.0 or > 40000.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let candle = Color::from_temperature(1900.0);
+    ///
+    /// assert!(candle.get_red() > candle.get_blue());
+    /// assert!(Color::from_temperature(6500.0).approx_eq(&constants::primary::WHITE, 5));
+    /// ```
+    pub fn from_temperature(kelvin: f32) -> Self {
+        if !TEMPERATURE_RANGE.contains(&kelvin) {
+            panic!("Unsupported temperature: {kelvin}");
+        }
+        let t = kelvin / 100.0;
+        let fit = |a: f32, b: f32, c: f32, x: f32| (a + b * x + c * x.ln()).clamp(0.0, 255.0);
+        let red = if t < 66.0 {
+            255.0
+        } else {
+            fit(351.976_9, 0.114_206_456, -40.253_662, t - 55.0)
+        };
+        let green = if t < 66.0 {
+            fit(-155.254_85, -0.445_969_5, 104.492_165, t - 2.0)
+        } else {
+            fit(325.449_4, 0.079_434_566, -28.085_297, t - 50.0)
+        };
+        let blue = if t >= 66.0 {
+            255.0
+        } else if t <= 20.0 {
+            0.0
+        } else {
+            fit(-254.769_35, 0.827_409_6, 115.679_95, t - 10.0)
+        };
+        Self(red.round() as u8, green.round() as u8, blue.round() as u8)
+    }
+
+    /// Estimates the correlated color temperature of the color in Kelvin, using the formula by
+    /// McCamy. The estimate is most accurate between 2000 K and 12500 K, and only meaningful for
+    /// colors near white, such as light sources. Black is treated as a gray, which has the
+    /// temperature of the D65 white point (about 6500 K).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, constants};
+    ///
+    /// let daylight = constants::primary::WHITE.to_temperature();
+    /// let warm = Color::from_temperature(2700.0).to_temperature();
+    ///
+    /// assert!((daylight - 6500.0).abs() < 10.0);
+    /// assert!((warm - 2700.0).abs() < 200.0);
+    /// ```
+    pub fn to_temperature(&self) -> f32 {
+        let (x, y, z) = if *self == constants::primary::BLACK {
+            constants::primary::WHITE.to_xyz()
+        } else {
+            self.to_xyz()
+        };
+        let sum = x + y + z;
+        let n = (x / sum - 0.3320) / (0.1858 - y / sum);
+        449.0 * n.powi(3) + 3525.0 * n.powi(2) + 6823.3 * n + 5520.33
+    }
+}
+
+impl Color {
+    /// Gets whether the color is warm (hues from magenta red to yellow), cool (hues from yellow
+    /// green to purple), or neutral when its chroma, the difference between its highest and
+    /// lowest RGB channel, is at most 0.1. Refer to
+    /// [`temperature_class_with()`](Color#method.temperature_class_with) to change the boundary
+    /// for neutral colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, TemperatureClass};
+    ///
+    /// assert_eq!(Color::new(230, 120, 20).temperature_class(), TemperatureClass::Warm);
+    /// assert_eq!(Color::new(20, 120, 230).temperature_class(), TemperatureClass::Cool);
+    /// assert_eq!(Color::new(120, 125, 130).temperature_class(), TemperatureClass::Neutral);
+    /// ```
+    #[inline]
+    pub fn temperature_class(&self) -> TemperatureClass {
+        self.temperature_class_with(NEUTRAL_CHROMA)
+    }
+
+    /// Same as [`temperature_class()`](Color#method.temperature_class), but colors are neutral
+    /// when their chroma is at most `neutral_chroma`, which is between 0.0 and 1.0. Lower values
+    /// classify more of the muted colors as warm or cool.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `neutral_chroma` is < 0.0 or > 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, TemperatureClass};
+    ///
+    /// let beige = Color::new(200, 190, 170);
+    ///
+    /// assert_eq!(beige.temperature_class(), TemperatureClass::Warm);
+    /// assert_eq!(beige.temperature_class_with(0.2), TemperatureClass::Neutral);
+    /// ```
+    pub fn temperature_class_with(&self, neutral_chroma: f32) -> TemperatureClass {
+        if !(0.0..=1.0).contains(&neutral_chroma) {
+            panic!("Unsupported neutral chroma: {neutral_chroma}");
+        }
+        let max = self.0.max(self.1).max(self.2);
+        let min = self.0.min(self.1).min(self.2);
+        if (max - min) as f32 / 255.0 <= neutral_chroma {
+            return TemperatureClass::Neutral;
+        }
+        let hue = self.get_hsl_hue();
+        if hue >= WARM_HUES.0 || hue < WARM_HUES.1 {
+            TemperatureClass::Warm
+        } else {
+            TemperatureClass::Cool
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_temperature() {
+        assert_eq!(Color::from_temperature(1000.0), Color::new(255, 58, 0));
+        assert_eq!(Color::from_temperature(6500.0), Color::new(255, 250, 254));
+        let cold = Color::from_temperature(40000.0);
+        assert!(cold.get_blue() == 255 && cold.get_red() < cold.get_green());
+        let mut previous = Color::from_temperature(1000.0);
+        for kelvin in (1100..=40000).step_by(100) {
+            let color = Color::from_temperature(kelvin as f32);
+            assert!(color.get_blue() >= previous.get_blue(), "{kelvin}");
+            previous = color;
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported temperature: 500")]
+    fn from_temperature_range() {
+        Color::from_temperature(500.0);
+    }
+
+    #[test]
+    fn to_temperature() {
+        for kelvin in [2000.0, 3000.0, 4000.0, 5000.0, 6500.0, 8000.0] {
+            let estimate = Color::from_temperature(kelvin).to_temperature();
+            assert!(
+                (estimate - kelvin).abs() / kelvin < 0.1,
+                "{kelvin}: {estimate}"
+            );
+        }
+        assert_eq!(
+            constants::primary::BLACK.to_temperature(),
+            constants::primary::WHITE.to_temperature()
+        );
+        assert_eq!(
+            Color::new(128, 128, 128).to_temperature(),
+            constants::primary::WHITE.to_temperature()
+        );
+    }
+
+    #[test]
+    fn temperature_class() {
+        let classes = [
+            ("red", TemperatureClass::Warm),
+            ("orange", TemperatureClass::Warm),
+            ("gold", TemperatureClass::Warm),
+            ("crimson", TemperatureClass::Warm),
+            ("green", TemperatureClass::Cool),
+            ("teal", TemperatureClass::Cool),
+            ("navy", TemperatureClass::Cool),
+            ("rebeccapurple", TemperatureClass::Cool),
+            ("gray", TemperatureClass::Neutral),
+            ("white", TemperatureClass::Neutral),
+            ("black", TemperatureClass::Neutral),
+        ];
+        for (name, class) in classes {
+            let color = Color::from_web_color(name).unwrap();
+            assert_eq!(color.temperature_class(), class, "{name}");
+        }
+        let muted = Color::new(110, 100, 130);
+        assert_eq!(muted.temperature_class(), TemperatureClass::Cool);
+        assert_eq!(muted.temperature_class_with(0.2), TemperatureClass::Neutral);
+        assert_eq!(
+            constants::primary::RED.temperature_class_with(1.0),
+            TemperatureClass::Neutral
+        );
+        assert_eq!(
+            Color::new(101, 100, 100).temperature_class_with(0.0),
+            TemperatureClass::Warm
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported neutral chroma: 1.5")]
+    fn neutral_chroma() {
+        constants::primary::RED.temperature_class_with(1.5);
+    }
+}
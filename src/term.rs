@@ -0,0 +1,425 @@
+//! Terminal colors using ANSI escape sequences.
+//!
+//! # Example
+//!
+//! ```
+//! use octarine::{term, Color};
+//!
+//! let orange = Color::new(255, 128, 0);
+//!
+//! println!("{}Warning:{} disk almost full", orange.to_ansi_fg(), term::RESET);
+//! assert_eq!(orange.to_ansi_fg().to_string(), "\x1b[38;2;255;128;0m");
+//! assert_eq!(orange.to_ansi256(), 208);
+//! ```
+
+use super::*;
+
+/// The escape sequence which resets the colors (and any other styles) of a terminal.
+pub const RESET: &str = "\x1b[0m";
+
+/// The 16 basic colors with the default values of xterm. Terminals let users change these, so
+/// the actual colors vary.
+const ANSI16: [Color; 16] = [
+    Color(0, 0, 0),
+    Color(205, 0, 0),
+    Color(0, 205, 0),
+    Color(205, 205, 0),
+    Color(0, 0, 238),
+    Color(205, 0, 205),
+    Color(0, 205, 205),
+    Color(229, 229, 229),
+    Color(127, 127, 127),
+    Color(255, 0, 0),
+    Color(0, 255, 0),
+    Color(255, 255, 0),
+    Color(92, 92, 255),
+    Color(255, 0, 255),
+    Color(0, 255, 255),
+    Color(255, 255, 255),
+];
+
+/// The channel values of the 6×6×6 color cube of the 256 color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// How many colors a terminal supports, which decides how a [`Color`] is represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorLevel {
+    /// The 16 basic colors (refer to [`to_ansi16()`](Color#method.to_ansi16)).
+    Ansi16,
+    /// The 256 color palette (refer to [`to_ansi256()`](Color#method.to_ansi256)).
+    Ansi256,
+    /// Any 24-bit RGB color.
+    #[default]
+    TrueColor,
+}
+
+/// An ANSI escape sequence which sets the foreground or background color of a terminal. Use its
+/// [`Display`] implementation to write it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Escape {
+    code: Code,
+    background: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Code {
+    TrueColor(Color),
+    Ansi256(u8),
+    Ansi16(u8),
+}
+
+impl Escape {
+    /// Creates the escape sequence which sets the foreground color to `index` of the 256 color
+    /// palette, e.g. from [`to_ansi256()`](Color#method.to_ansi256).
+    pub fn ansi256_fg(index: u8) -> Self {
+        Self {
+            code: Code::Ansi256(index),
+            background: false,
+        }
+    }
+
+    /// Creates the escape sequence which sets the background color to `index` of the 256 color
+    /// palette.
+    pub fn ansi256_bg(index: u8) -> Self {
+        Self {
+            code: Code::Ansi256(index),
+            background: true,
+        }
+    }
+
+    /// Creates the escape sequence which sets the foreground color to `index` (0 - 15) of the
+    /// basic terminal colors, e.g. from [`to_ansi16()`](Color#method.to_ansi16).
+    ///
+    /// # Panics
+    ///
+    /// Panics when `index` is > 15.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::term::Escape;
+    ///
+    /// assert_eq!(Escape::ansi16_fg(1).to_string(), "\x1b[31m");
+    /// assert_eq!(Escape::ansi16_fg(9).to_string(), "\x1b[91m");
+    /// ```
+    pub fn ansi16_fg(index: u8) -> Self {
+        Self {
+            code: Code::ansi16(index),
+            background: false,
+        }
+    }
+
+    /// Creates the escape sequence which sets the background color to `index` (0 - 15) of the
+    /// basic terminal colors.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `index` is > 15.
+    pub fn ansi16_bg(index: u8) -> Self {
+        Self {
+            code: Code::ansi16(index),
+            background: true,
+        }
+    }
+}
+
+impl Code {
+    fn for_level(color: &Color, level: ColorLevel) -> Self {
+        match level {
+            ColorLevel::Ansi16 => Self::Ansi16(color.to_ansi16()),
+            ColorLevel::Ansi256 => Self::Ansi256(color.to_ansi256()),
+            ColorLevel::TrueColor => Self::TrueColor(*color),
+        }
+    }
+
+    fn ansi16(index: u8) -> Self {
+        if index > 15 {
+            panic!("Unsupported basic terminal color index: {index}");
+        }
+        Self::Ansi16(index)
+    }
+}
+
+impl Display for Escape {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let layer = if self.background { 4 } else { 3 };
+        match self.code {
+            Code::TrueColor(Color(r, g, b)) => write!(f, "\x1b[{layer}8;2;{r};{g};{b}m"),
+            Code::Ansi256(index) => write!(f, "\x1b[{layer}8;5;{index}m"),
+            Code::Ansi16(index @ 0..=7) => write!(f, "\x1b[{layer}{index}m"),
+            Code::Ansi16(index) => {
+                let layer = if self.background { 10 } else { 9 };
+                write!(f, "\x1b[{layer}{}m", index - 8)
+            }
+        }
+    }
+}
+
+impl Color {
+    /// Gets the escape sequence which sets the foreground (text) color of a terminal to this
+    /// color. This needs a terminal with truecolor (24-bit) support.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants;
+    ///
+    /// let escape = constants::primary::RED.to_ansi_fg();
+    ///
+    /// assert_eq!(escape.to_string(), "\x1b[38;2;255;0;0m");
+    /// ```
+    pub fn to_ansi_fg(&self) -> Escape {
+        Escape {
+            code: Code::TrueColor(*self),
+            background: false,
+        }
+    }
+
+    /// Gets the escape sequence which sets the background color of a terminal to this color.
+    /// This needs a terminal with truecolor (24-bit) support.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::constants;
+    ///
+    /// let escape = constants::primary::BLUE.to_ansi_bg();
+    ///
+    /// assert_eq!(escape.to_string(), "\x1b[48;2;0;0;255m");
+    /// ```
+    pub fn to_ansi_bg(&self) -> Escape {
+        Escape {
+            code: Code::TrueColor(*self),
+            background: true,
+        }
+    }
+
+    /// Gets the escape sequence which sets the foreground color of a terminal which supports
+    /// `level`, using the closest color the terminal supports.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{term::ColorLevel, Color};
+    ///
+    /// let orange = Color::new(255, 128, 0);
+    ///
+    /// assert_eq!(orange.to_ansi_fg_for(ColorLevel::Ansi256).to_string(), "\x1b[38;5;208m");
+    /// assert_eq!(orange.to_ansi_fg_for(ColorLevel::TrueColor), orange.to_ansi_fg());
+    /// ```
+    pub fn to_ansi_fg_for(&self, level: ColorLevel) -> Escape {
+        Escape {
+            code: Code::for_level(self, level),
+            background: false,
+        }
+    }
+
+    /// Gets the escape sequence which sets the background color of a terminal which supports
+    /// `level`, using the closest color the terminal supports.
+    pub fn to_ansi_bg_for(&self, level: ColorLevel) -> Escape {
+        Escape {
+            code: Code::for_level(self, level),
+            background: true,
+        }
+    }
+
+    /// Parses the color set by an ANSI escape sequence (SGR), e.g. to recover colors from the
+    /// colored output of a program. The `\x1b[` prefix and `m` suffix are optional, and styles
+    /// before the color (e.g. bold) are skipped. Basic colors use the default colors of xterm.
+    /// Returns `None` when the sequence doesn't set a color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// assert_eq!(Color::from_ansi("\x1b[38;2;255;128;0m"), Some(Color::new(255, 128, 0)));
+    /// assert_eq!(Color::from_ansi("1;48;5;196"), Some(Color::new(255, 0, 0)));
+    /// assert_eq!(Color::from_ansi("\x1b[91m"), Some(Color::new(255, 0, 0)));
+    /// assert_eq!(Color::from_ansi("\x1b[0m"), None);
+    /// ```
+    pub fn from_ansi(code: &str) -> Option<Self> {
+        let code = code.strip_prefix("\x1b[").unwrap_or(code);
+        let code = code.strip_suffix('m').unwrap_or(code);
+        let mut params = code.split(';').map(|param| match param {
+            "" => Some(0),
+            param => param.parse::<u8>().ok(),
+        });
+        while let Some(param) = params.next() {
+            match param? {
+                code @ (30..=37 | 40..=47) => return Some(ANSI16[(code % 10) as usize]),
+                code @ (90..=97 | 100..=107) => return Some(ANSI16[(code % 10 + 8) as usize]),
+                38 | 48 => {
+                    return match params.next()?? {
+                        5 => Some(Self::from_ansi256(params.next()??)),
+                        2 => Some(Self(params.next()??, params.next()??, params.next()??)),
+                        _ => None,
+                    };
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Gets the color at `index` of the 256 color palette of terminals. The first 16 colors use
+    /// the default colors of xterm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::Color;
+    ///
+    /// assert_eq!(Color::from_ansi256(208), Color::new(255, 135, 0));
+    /// assert_eq!(Color::from_ansi256(244), Color::new(128, 128, 128));
+    /// assert_eq!(Color::from_ansi256(208).to_ansi256(), 208);
+    /// ```
+    pub fn from_ansi256(index: u8) -> Self {
+        match index {
+            0..=15 => ANSI16[index as usize],
+            16..=231 => {
+                let index = index - 16;
+                Self(
+                    CUBE_LEVELS[(index / 36) as usize],
+                    CUBE_LEVELS[(index / 6 % 6) as usize],
+                    CUBE_LEVELS[(index % 6) as usize],
+                )
+            }
+            _ => {
+                let level = 8 + 10 * (index - 232);
+                Self(level, level, level)
+            }
+        }
+    }
+
+    /// Gets the index (16 - 255) of the closest color in the 256 color palette of terminals,
+    /// which is made of a 6×6×6 color cube and 24 grays. The first 16 colors are never chosen,
+    /// as they vary between terminals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Color};
+    ///
+    /// assert_eq!(constants::primary::RED.to_ansi256(), 196);
+    /// assert_eq!(Color::new(128, 128, 128).to_ansi256(), 244);
+    /// ```
+    pub fn to_ansi256(&self) -> u8 {
+        let nearest_level = |v: u8| {
+            (0..CUBE_LEVELS.len())
+                .min_by_key(|&i| CUBE_LEVELS[i].abs_diff(v))
+                .unwrap_or(0)
+        };
+        let (r, g, b) = (
+            nearest_level(self.0),
+            nearest_level(self.1),
+            nearest_level(self.2),
+        );
+        let cube = Self(CUBE_LEVELS[r], CUBE_LEVELS[g], CUBE_LEVELS[b]);
+        let average = (self.0 as u32 + self.1 as u32 + self.2 as u32) / 3;
+        let gray_index = (average.saturating_sub(3) / 10).min(23) as u8;
+        let gray_level = 8 + 10 * gray_index;
+        let gray = Self(gray_level, gray_level, gray_level);
+        let lab = self.to_oklab();
+        if oklab_distance(lab, gray.to_oklab()) < oklab_distance(lab, cube.to_oklab()) {
+            232 + gray_index
+        } else {
+            16 + 36 * r as u8 + 6 * g as u8 + b as u8
+        }
+    }
+
+    /// Gets the index (0 - 15) of the closest of the 16 basic terminal colors, using the default
+    /// colors of xterm. Use this for terminals without 256 color support.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{constants, Color};
+    ///
+    /// assert_eq!(constants::primary::BLACK.to_ansi16(), 0);
+    /// assert_eq!(Color::new(200, 10, 0).to_ansi16(), 1);
+    /// assert_eq!(Color::new(250, 250, 80).to_ansi16(), 11);
+    /// ```
+    pub fn to_ansi16(&self) -> u8 {
+        let lab = self.to_oklab();
+        (0..ANSI16.len())
+            .min_by(|&a, &b| {
+                let a = oklab_distance(lab, ANSI16[a].to_oklab());
+                let b = oklab_distance(lab, ANSI16[b].to_oklab());
+                a.total_cmp(&b)
+            })
+            .unwrap_or(0) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn escapes() {
+        let color = Color::new(1, 2, 3);
+        assert_eq!(color.to_ansi_fg().to_string(), "\x1b[38;2;1;2;3m");
+        assert_eq!(color.to_ansi_bg().to_string(), "\x1b[48;2;1;2;3m");
+        assert_eq!(Escape::ansi256_fg(208).to_string(), "\x1b[38;5;208m");
+        assert_eq!(Escape::ansi256_bg(208).to_string(), "\x1b[48;5;208m");
+        assert_eq!(Escape::ansi16_fg(1).to_string(), "\x1b[31m");
+        assert_eq!(Escape::ansi16_bg(1).to_string(), "\x1b[41m");
+        assert_eq!(Escape::ansi16_fg(9).to_string(), "\x1b[91m");
+        assert_eq!(Escape::ansi16_bg(15).to_string(), "\x1b[107m");
+        let red = constants::primary::RED;
+        assert_eq!(red.to_ansi_bg_for(ColorLevel::Ansi16), Escape::ansi16_bg(9));
+        assert_eq!(
+            red.to_ansi_fg_for(ColorLevel::Ansi256),
+            Escape::ansi256_fg(196)
+        );
+        assert_eq!(red.to_ansi_bg_for(ColorLevel::default()), red.to_ansi_bg());
+    }
+
+    #[test]
+    fn from_ansi() {
+        let color = Color::new(1, 2, 3);
+        assert_eq!(
+            Color::from_ansi(&color.to_ansi_fg().to_string()),
+            Some(color)
+        );
+        assert_eq!(
+            Color::from_ansi(&color.to_ansi_bg().to_string()),
+            Some(color)
+        );
+        for index in 0..16 {
+            let escape = Escape::ansi16_bg(index).to_string();
+            assert_eq!(Color::from_ansi(&escape), Some(ANSI16[index as usize]));
+        }
+        assert_eq!(Color::from_ansi("38;5;16"), Some(constants::primary::BLACK));
+        assert_eq!(Color::from_ansi(";31"), Some(ANSI16[1]));
+        assert_eq!(Color::from_ansi("38;2;1;2"), None);
+        assert_eq!(Color::from_ansi("38;2;256;0;0"), None);
+        assert_eq!(Color::from_ansi("1;4"), None);
+        assert_eq!(Color::from_ansi("red"), None);
+        for index in 16..=255 {
+            assert_eq!(Color::from_ansi256(index).to_ansi256(), index);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn ansi16_out_of_range() {
+        Escape::ansi16_fg(16);
+    }
+
+    #[test]
+    fn downsampling() {
+        for (index, &level) in CUBE_LEVELS.iter().enumerate() {
+            let color = Color(level, 0, 0);
+            assert_eq!(color.to_ansi256(), 16 + 36 * index as u8);
+        }
+        assert_eq!(constants::primary::WHITE.to_ansi256(), 231);
+        assert_eq!(Color::new(8, 8, 8).to_ansi256(), 232);
+        assert_eq!(Color::new(238, 238, 238).to_ansi256(), 255);
+        for (index, color) in ANSI16.iter().enumerate() {
+            assert_eq!(color.to_ansi16(), index as u8);
+        }
+    }
+}
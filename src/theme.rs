@@ -0,0 +1,274 @@
+use super::*;
+use alloc::{boxed::Box, collections::BTreeMap, string::String};
+use core::fmt::Formatter;
+
+/// Design tokens, which bind the names of roles such as "bg", "fg", and "accent-500" to colors.
+/// A theme can inherit from a base theme, so that it only has to set the roles it overrides,
+/// such as a dark theme inheriting the accents of a light theme. With the `serde` feature, a
+/// theme is serialized as a map from every role to a `#rrggbb` string, including the roles it
+/// inherits.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{Color, Theme};
+///
+/// let mut light = Theme::new();
+/// light.set("bg", Color::new(255, 255, 255));
+/// light.set("fg", Color::new(20, 20, 20));
+/// light.set("accent", Color::new(0, 90, 200));
+///
+/// let mut dark = Theme::inherit(&light);
+/// dark.set("bg", Color::new(20, 20, 20));
+/// dark.set("fg", Color::new(240, 240, 240));
+///
+/// assert_eq!(dark.get("accent"), Some(Color::new(0, 90, 200)));
+/// assert_eq!(dark.get("bg"), Some(Color::new(20, 20, 20)));
+/// assert!(dark.check_contrast("fg", "bg", 4.5).is_ok());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Theme {
+    base: Option<Box<Theme>>,
+    roles: BTreeMap<String, Color>,
+}
+
+impl Theme {
+    /// Creates an empty [`Theme`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a theme which has every role of `base`, until they are overridden with
+    /// [`set()`](Theme#method.set).
+    pub fn inherit(base: &Theme) -> Self {
+        Self {
+            base: Some(Box::new(base.clone())),
+            roles: BTreeMap::new(),
+        }
+    }
+
+    /// Gets the theme that this theme inherits from.
+    pub fn base(&self) -> Option<&Theme> {
+        self.base.as_deref()
+    }
+
+    /// Sets the color of `role`, overriding the color it inherits.
+    pub fn set(&mut self, role: impl Into<String>, color: Color) {
+        self.roles.insert(role.into(), color);
+    }
+
+    /// Gets the color of `role`, which is inherited from the base theme when this theme doesn't
+    /// override it.
+    pub fn get(&self, role: &str) -> Option<Color> {
+        self.roles
+            .get(role)
+            .copied()
+            .or_else(|| self.base.as_ref()?.get(role))
+    }
+
+    /// Removes the color this theme sets for `role`, so it is inherited again. Returns the
+    /// color, or `None` when this theme didn't set it.
+    pub fn remove(&mut self, role: &str) -> Option<Color> {
+        self.roles.remove(role)
+    }
+
+    /// Checks whether this theme sets `role` itself, rather than inheriting it.
+    pub fn overrides(&self, role: &str) -> bool {
+        self.roles.contains_key(role)
+    }
+
+    /// Gets every role and its color, including the inherited ones, sorted by role.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Color)> {
+        self.resolve().into_iter()
+    }
+
+    /// Gets the number of roles, including the inherited ones.
+    pub fn len(&self) -> usize {
+        self.resolve().len()
+    }
+
+    /// Checks whether the theme has no roles, including inherited ones.
+    pub fn is_empty(&self) -> bool {
+        self.roles.is_empty() && self.base.as_ref().map_or(true, |base| base.is_empty())
+    }
+
+    /// Gets the [`contrast_ratio()`](Color#method.contrast_ratio) between the colors of two
+    /// roles, or `None` when the theme doesn't have one of them.
+    pub fn contrast_ratio(&self, foreground: &str, background: &str) -> Option<f32> {
+        Some(self.get(foreground)?.contrast_ratio(&self.get(background)?))
+    }
+
+    /// Checks that the colors of two roles have a contrast ratio of at least `min_ratio`, such
+    /// as 4.5 for normal text under WCAG AA. Returns the contrast ratio.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeError::UnknownRole`] when the theme doesn't have one of the roles, and
+    /// [`ThemeError::LowContrast`] when the contrast ratio is below `min_ratio`.
+    pub fn check_contrast(
+        &self,
+        foreground: &str,
+        background: &str,
+        min_ratio: f32,
+    ) -> Result<f32, ThemeError> {
+        let color = |role: &str| {
+            self.get(role)
+                .ok_or_else(|| ThemeError::UnknownRole(role.into()))
+        };
+        let ratio = color(foreground)?.contrast_ratio(&color(background)?);
+        if ratio < min_ratio {
+            return Err(ThemeError::LowContrast {
+                foreground: foreground.into(),
+                background: background.into(),
+                ratio,
+            });
+        }
+        Ok(ratio)
+    }
+
+    /// Gets every role and its color, with the roles of this theme replacing those of its base.
+    fn resolve(&self) -> BTreeMap<&str, Color> {
+        let mut roles = self
+            .base
+            .as_ref()
+            .map(|base| base.resolve())
+            .unwrap_or_default();
+        for (role, color) in &self.roles {
+            roles.insert(role, *color);
+        }
+        roles
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, Color)> for Theme {
+    fn from_iter<T: IntoIterator<Item = (S, Color)>>(iter: T) -> Self {
+        Self {
+            base: None,
+            roles: iter
+                .into_iter()
+                .map(|(role, color)| (role.into(), color))
+                .collect(),
+        }
+    }
+}
+
+/// An error returned by [`check_contrast()`](Theme#method.check_contrast).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThemeError {
+    /// The theme doesn't have the role.
+    UnknownRole(String),
+    /// The contrast ratio between the roles is below the minimum.
+    LowContrast {
+        foreground: String,
+        background: String,
+        ratio: f32,
+    },
+}
+
+impl Display for ThemeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownRole(role) => write!(f, "unknown role: {role}"),
+            Self::LowContrast {
+                foreground,
+                background,
+                ratio,
+            } => write!(
+                f,
+                "{foreground} on {background} has a contrast ratio of {ratio:.2}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ThemeError {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Theme {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Theme {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self {
+            base: None,
+            roles: BTreeMap::deserialize(deserializer)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn base() -> Theme {
+        [
+            ("bg", constants::primary::WHITE),
+            ("fg", constants::primary::BLACK),
+            ("accent", Color::new(255, 220, 0)),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn inherit() {
+        let base = base();
+        let mut theme = Theme::inherit(&base);
+        assert_eq!(theme.len(), 3);
+        assert!(!theme.is_empty());
+        assert!(!theme.overrides("bg"));
+        theme.set("bg", constants::primary::BLUE);
+        theme.set("muted", Color::new(90, 90, 90));
+        assert!(theme.overrides("bg"));
+        assert_eq!(theme.get("bg"), Some(constants::primary::BLUE));
+        assert_eq!(theme.get("fg"), Some(constants::primary::BLACK));
+        assert_eq!(theme.get("link"), None);
+        assert_eq!(
+            theme.iter().map(|(role, _)| role).collect::<Vec<_>>(),
+            ["accent", "bg", "fg", "muted"]
+        );
+        assert_eq!(theme.remove("bg"), Some(constants::primary::BLUE));
+        assert_eq!(theme.remove("fg"), None);
+        assert_eq!(theme.get("bg"), Some(constants::primary::WHITE));
+        assert_eq!(theme.base(), Some(&base));
+        assert!(Theme::inherit(&Theme::new()).is_empty());
+    }
+
+    #[test]
+    fn check_contrast() {
+        let theme = base();
+        let ratio = theme.contrast_ratio("fg", "bg").unwrap();
+        assert!((ratio - 21.0).abs() < 0.001);
+        assert_eq!(theme.check_contrast("fg", "bg", 4.5), Ok(ratio));
+        assert_eq!(
+            theme.check_contrast("fg", "link", 4.5),
+            Err(ThemeError::UnknownRole("link".into()))
+        );
+        let error = theme.check_contrast("accent", "bg", 4.5).unwrap_err();
+        assert!(matches!(error, ThemeError::LowContrast { .. }));
+        assert!(error.to_string().starts_with("accent on bg"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut theme = Theme::inherit(&base());
+        theme.set("bg", Color::new(16, 16, 16));
+        let json = serde_json::to_string(&theme).unwrap();
+        assert_eq!(
+            json,
+            r##"{"accent":"#ffdc00","bg":"#101010","fg":"#000000"}"##
+        );
+        let parsed: Theme = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            parsed.iter().collect::<Vec<_>>(),
+            theme.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(parsed.base(), None);
+    }
+}
@@ -0,0 +1,179 @@
+use super::*;
+use core::time::Duration;
+
+/// An animation from one color to another over a duration, such as fading a highlight in a TUI
+/// or a game. Unlike stepping through a [`ColorRange`], the tween can be sampled at any elapsed
+/// time, so the animation is as smooth as the frame rate allows.
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+/// use octarine::{Color, constants, Easing, Tween};
+///
+/// let tween = Tween::new(
+///     constants::primary::BLACK,
+///     constants::primary::WHITE,
+///     Duration::from_millis(200),
+///     Easing::Linear,
+/// );
+///
+/// assert_eq!(tween.sample(Duration::ZERO), constants::primary::BLACK);
+/// assert_eq!(tween.sample(Duration::from_millis(100)), Color::new(128, 128, 128));
+/// assert_eq!(tween.sample(Duration::from_secs(1)), constants::primary::WHITE);
+/// assert!(tween.is_finished(Duration::from_millis(200)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tween {
+    from: Color,
+    to: Color,
+    duration: Duration,
+    easing: Easing,
+    space: ColorSpace,
+    hue_direction: HueDirection,
+}
+
+impl Tween {
+    /// Creates a tween from `from` to `to` which takes `duration`, with its progress shaped by
+    /// `easing`. The colors are interpolated in [`ColorSpace::Srgb`] by default, the same as
+    /// [`Gradient`].
+    pub fn new(from: Color, to: Color, duration: Duration, easing: Easing) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            easing,
+            space: ColorSpace::Srgb,
+            hue_direction: HueDirection::Specified,
+        }
+    }
+
+    /// Sets the color space the colors are interpolated in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use octarine::{Color, ColorSpace, constants, Easing, Tween};
+    ///
+    /// let tween = Tween::new(
+    ///     constants::primary::BLACK,
+    ///     constants::primary::WHITE,
+    ///     Duration::from_secs(1),
+    ///     Easing::Linear,
+    /// )
+    /// .with_space(ColorSpace::LinearRgb);
+    ///
+    /// assert_eq!(tween.sample(Duration::from_millis(500)), Color::new(188, 188, 188));
+    /// ```
+    pub fn with_space(mut self, space: ColorSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Sets the [`HueDirection`] the hue is interpolated in, which is
+    /// [`HueDirection::Specified`] by default. This only applies to color spaces with a hue.
+    pub fn with_hue_direction(mut self, direction: HueDirection) -> Self {
+        self.hue_direction = direction;
+        self
+    }
+
+    /// Gets the duration of the tween.
+    #[inline]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Checks whether the tween has reached its end color after `elapsed`.
+    #[inline]
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        elapsed >= self.duration
+    }
+
+    /// Gets the progress of the tween (0.0 - 1.0) after `elapsed`, before easing.
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        if self.is_finished(elapsed) {
+            1.0
+        } else {
+            (elapsed.as_secs_f64() / self.duration.as_secs_f64()) as f32
+        }
+    }
+
+    /// Gets the color of the tween after `elapsed`. Before the tween starts, this is the start
+    /// color, and after it finishes, this is the end color.
+    pub fn sample(&self, elapsed: Duration) -> Color {
+        let (r, g, b) = self.sample_float(elapsed);
+        Color::from_rgb_float(r, g, b)
+    }
+
+    /// Same as [`sample()`](Tween#method.sample), but gets the color as a [`ColorF`] before it is
+    /// rounded to 8 bits, such as for rendering with a GPU.
+    pub fn sample_f(&self, elapsed: Duration) -> ColorF {
+        let (r, g, b) = self.sample_float(elapsed);
+        ColorF::new(r, g, b)
+    }
+
+    fn sample_float(&self, elapsed: Duration) -> (f32, f32, f32) {
+        let t = self.easing.apply(self.progress(elapsed));
+        let (a, b) = self
+            .space
+            .endpoints(&self.from, &self.to, self.hue_direction);
+        self.space.to_rgb_float((
+            a.0 + (b.0 - a.0) * t,
+            a.1 + (b.1 - a.1) * t,
+            a.2 + (b.2 - a.2) * t,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample() {
+        let (from, to) = (Color::new(200, 30, 30), Color::new(30, 30, 200));
+        let tween = Tween::new(from, to, Duration::from_secs(2), Easing::EaseInOut);
+        assert_eq!(tween.sample(Duration::ZERO), from);
+        assert_eq!(tween.sample(Duration::from_secs(2)), to);
+        assert_eq!(tween.sample(Duration::from_secs(3)), to);
+        assert_eq!(tween.progress(Duration::from_millis(500)), 0.25);
+        let linear = Tween::new(from, to, Duration::from_secs(2), Easing::Linear);
+        let quarter = Duration::from_millis(500);
+        assert!(tween.sample(quarter).delta_e(&from) < linear.sample(quarter).delta_e(&from));
+        let mut previous = from;
+        for millis in (0..=2000).step_by(16) {
+            let color = linear.sample(Duration::from_millis(millis));
+            assert!(color.delta_e(&previous) < 2.0);
+            previous = color;
+        }
+        assert!(!linear.is_finished(Duration::from_millis(1999)));
+    }
+
+    #[test]
+    fn space() {
+        let tween = Tween::new(
+            constants::primary::RED,
+            constants::primary::BLUE,
+            Duration::from_secs(1),
+            Easing::Linear,
+        )
+        .with_space(ColorSpace::Hsl)
+        .with_hue_direction(HueDirection::Increasing);
+        let middle = Duration::from_millis(500);
+        assert_eq!(tween.sample(middle), Color::new(0, 255, 0));
+        assert_eq!(tween.sample_f(middle), ColorF::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn zero_duration() {
+        let tween = Tween::new(
+            constants::primary::RED,
+            constants::primary::BLUE,
+            Duration::ZERO,
+            Easing::Linear,
+        );
+        assert!(tween.is_finished(Duration::ZERO));
+        assert_eq!(tween.sample(Duration::ZERO), constants::primary::BLUE);
+    }
+}
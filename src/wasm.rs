@@ -0,0 +1,75 @@
+//! Functions for JavaScript, exported with [`wasm-bindgen`](https://docs.rs/wasm-bindgen).
+//! Colors are passed in as `0xRRGGBB` numbers and returned as CSS strings (refer to
+//! [`to_css_string()`](Color#method.to_css_string)), so they can be assigned to the DOM directly.
+//! Requires the `wasm` feature.
+//!
+//! ```js
+//! import { rangeTo } from "./pkg/octarine.js";
+//!
+//! document.body.style.background = rangeTo(0xFF0000, 0x0000FF, 5)[2];
+//! ```
+
+use super::*;
+use alloc::{string::String, vec::Vec};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Converts a `0xRRGGBB` number to a CSS string.
+#[wasm_bindgen(js_name = cssString)]
+pub fn css_string(rgb: u32) -> String {
+    Color::from_hex(rgb).to_css_string()
+}
+
+/// Gets the CSS string of a W3C web color. Returns `undefined` when the color cannot be found.
+#[wasm_bindgen(js_name = webColor)]
+pub fn web_color(name: &str) -> Option<String> {
+    Color::from_web_color(name).map(|color| color.to_css_string())
+}
+
+/// Gets `steps` colors from `start` to `end`, interpolated in OKLab. Returns an empty array when
+/// `steps` is 0.
+#[wasm_bindgen(js_name = rangeTo)]
+pub fn range_to(start: u32, end: u32, steps: usize) -> Vec<String> {
+    Color::from_hex(start)
+        .try_range_to(Color::from_hex(end), steps)
+        .map(|range| {
+            range
+                .with_space(ColorSpace::Oklab)
+                .map(|color| color.to_css_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Gets `count` colors which are evenly spread around the color wheel, the same as
+/// [`ColorWheel::take_distinct()`] with `seed`. Requires the `rand` feature.
+#[cfg(feature = "rand")]
+#[wasm_bindgen(js_name = distinctColors)]
+pub fn distinct_colors(count: usize, seed: u64) -> Vec<String> {
+    ColorWheel::new()
+        .with_seed(seed)
+        .take_distinct(count)
+        .iter()
+        .map(|color| color.to_css_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn helpers() {
+        assert_eq!(css_string(0x0080FF), "#0080ff");
+        assert_eq!(web_color("Red").as_deref(), Some("#ff0000"));
+        assert_eq!(web_color("octarine"), None);
+        assert_eq!(range_to(0x000000, 0xFFFFFF, 2), ["#000000", "#ffffff"]);
+        assert!(range_to(0x000000, 0xFFFFFF, 0).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn distinct() {
+        assert_eq!(distinct_colors(4, 1), distinct_colors(4, 1));
+        assert_eq!(distinct_colors(4, 1).len(), 4);
+    }
+}
@@ -0,0 +1,596 @@
+use super::*;
+use constants::css;
+use core::str::FromStr;
+
+/// The 148 named colors of CSS, which can be used instead of the names of
+/// [`from_web_color()`](Color#method.from_web_color) to have them checked at compile time. The
+/// variants are in alphabetical order, and aliases such as [`Gray`](WebColor::Gray) and
+/// [`Grey`](WebColor::Grey) are separate variants with the same color.
+///
+/// # Example
+///
+/// ```
+/// use octarine::{Color, WebColor};
+///
+/// let color = WebColor::RebeccaPurple;
+///
+/// assert_eq!(color.color(), Color::new(102, 51, 153));
+/// assert_eq!(color.to_string(), "rebeccapurple");
+/// assert_eq!("RebeccaPurple".parse(), Ok(color));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebColor {
+    AliceBlue,
+    AntiqueWhite,
+    Aqua,
+    Aquamarine,
+    Azure,
+    Beige,
+    Bisque,
+    Black,
+    BlanchedAlmond,
+    Blue,
+    BlueViolet,
+    Brown,
+    BurlyWood,
+    CadetBlue,
+    Chartreuse,
+    Chocolate,
+    Coral,
+    CornflowerBlue,
+    Cornsilk,
+    Crimson,
+    Cyan,
+    DarkBlue,
+    DarkCyan,
+    DarkGoldenrod,
+    DarkGray,
+    DarkGreen,
+    DarkGrey,
+    DarkKhaki,
+    DarkMagenta,
+    DarkOliveGreen,
+    DarkOrange,
+    DarkOrchid,
+    DarkRed,
+    DarkSalmon,
+    DarkSeaGreen,
+    DarkSlateBlue,
+    DarkSlateGray,
+    DarkSlateGrey,
+    DarkTurquoise,
+    DarkViolet,
+    DeepPink,
+    DeepSkyBlue,
+    DimGray,
+    DimGrey,
+    DodgerBlue,
+    FireBrick,
+    FloralWhite,
+    ForestGreen,
+    Fuchsia,
+    Gainsboro,
+    GhostWhite,
+    Gold,
+    Goldenrod,
+    Gray,
+    Green,
+    GreenYellow,
+    Grey,
+    Honeydew,
+    HotPink,
+    IndianRed,
+    Indigo,
+    Ivory,
+    Khaki,
+    Lavender,
+    LavenderBlush,
+    LawnGreen,
+    LemonChiffon,
+    LightBlue,
+    LightCoral,
+    LightCyan,
+    LightGoldenrodYellow,
+    LightGray,
+    LightGreen,
+    LightGrey,
+    LightPink,
+    LightSalmon,
+    LightSeaGreen,
+    LightSkyBlue,
+    LightSlateGray,
+    LightSlateGrey,
+    LightSteelBlue,
+    LightYellow,
+    Lime,
+    LimeGreen,
+    Linen,
+    Magenta,
+    Maroon,
+    MediumAquamarine,
+    MediumBlue,
+    MediumOrchid,
+    MediumPurple,
+    MediumSeaGreen,
+    MediumSlateBlue,
+    MediumSpringGreen,
+    MediumTurquoise,
+    MediumVioletRed,
+    MidnightBlue,
+    MintCream,
+    MistyRose,
+    Moccasin,
+    NavajoWhite,
+    Navy,
+    OldLace,
+    Olive,
+    OliveDrab,
+    Orange,
+    OrangeRed,
+    Orchid,
+    PaleGoldenrod,
+    PaleGreen,
+    PaleTurquoise,
+    PaleVioletRed,
+    PapayaWhip,
+    PeachPuff,
+    Peru,
+    Pink,
+    Plum,
+    PowderBlue,
+    Purple,
+    RebeccaPurple,
+    Red,
+    RosyBrown,
+    RoyalBlue,
+    SaddleBrown,
+    Salmon,
+    SandyBrown,
+    SeaGreen,
+    Seashell,
+    Sienna,
+    Silver,
+    SkyBlue,
+    SlateBlue,
+    SlateGray,
+    SlateGrey,
+    Snow,
+    SpringGreen,
+    SteelBlue,
+    Tan,
+    Teal,
+    Thistle,
+    Tomato,
+    Turquoise,
+    Violet,
+    Wheat,
+    White,
+    WhiteSmoke,
+    Yellow,
+    YellowGreen,
+}
+
+/// The names and colors of the variants of [`WebColor`], in the same order.
+const WEB_COLORS: [(&str, Color); 148] = [
+    ("aliceblue", css::ALICEBLUE),
+    ("antiquewhite", css::ANTIQUEWHITE),
+    ("aqua", css::AQUA),
+    ("aquamarine", css::AQUAMARINE),
+    ("azure", css::AZURE),
+    ("beige", css::BEIGE),
+    ("bisque", css::BISQUE),
+    ("black", css::BLACK),
+    ("blanchedalmond", css::BLANCHEDALMOND),
+    ("blue", css::BLUE),
+    ("blueviolet", css::BLUEVIOLET),
+    ("brown", css::BROWN),
+    ("burlywood", css::BURLYWOOD),
+    ("cadetblue", css::CADETBLUE),
+    ("chartreuse", css::CHARTREUSE),
+    ("chocolate", css::CHOCOLATE),
+    ("coral", css::CORAL),
+    ("cornflowerblue", css::CORNFLOWERBLUE),
+    ("cornsilk", css::CORNSILK),
+    ("crimson", css::CRIMSON),
+    ("cyan", css::CYAN),
+    ("darkblue", css::DARKBLUE),
+    ("darkcyan", css::DARKCYAN),
+    ("darkgoldenrod", css::DARKGOLDENROD),
+    ("darkgray", css::DARKGRAY),
+    ("darkgreen", css::DARKGREEN),
+    ("darkgrey", css::DARKGREY),
+    ("darkkhaki", css::DARKKHAKI),
+    ("darkmagenta", css::DARKMAGENTA),
+    ("darkolivegreen", css::DARKOLIVEGREEN),
+    ("darkorange", css::DARKORANGE),
+    ("darkorchid", css::DARKORCHID),
+    ("darkred", css::DARKRED),
+    ("darksalmon", css::DARKSALMON),
+    ("darkseagreen", css::DARKSEAGREEN),
+    ("darkslateblue", css::DARKSLATEBLUE),
+    ("darkslategray", css::DARKSLATEGRAY),
+    ("darkslategrey", css::DARKSLATEGREY),
+    ("darkturquoise", css::DARKTURQUOISE),
+    ("darkviolet", css::DARKVIOLET),
+    ("deeppink", css::DEEPPINK),
+    ("deepskyblue", css::DEEPSKYBLUE),
+    ("dimgray", css::DIMGRAY),
+    ("dimgrey", css::DIMGREY),
+    ("dodgerblue", css::DODGERBLUE),
+    ("firebrick", css::FIREBRICK),
+    ("floralwhite", css::FLORALWHITE),
+    ("forestgreen", css::FORESTGREEN),
+    ("fuchsia", css::FUCHSIA),
+    ("gainsboro", css::GAINSBORO),
+    ("ghostwhite", css::GHOSTWHITE),
+    ("gold", css::GOLD),
+    ("goldenrod", css::GOLDENROD),
+    ("gray", css::GRAY),
+    ("green", css::GREEN),
+    ("greenyellow", css::GREENYELLOW),
+    ("grey", css::GREY),
+    ("honeydew", css::HONEYDEW),
+    ("hotpink", css::HOTPINK),
+    ("indianred", css::INDIANRED),
+    ("indigo", css::INDIGO),
+    ("ivory", css::IVORY),
+    ("khaki", css::KHAKI),
+    ("lavender", css::LAVENDER),
+    ("lavenderblush", css::LAVENDERBLUSH),
+    ("lawngreen", css::LAWNGREEN),
+    ("lemonchiffon", css::LEMONCHIFFON),
+    ("lightblue", css::LIGHTBLUE),
+    ("lightcoral", css::LIGHTCORAL),
+    ("lightcyan", css::LIGHTCYAN),
+    ("lightgoldenrodyellow", css::LIGHTGOLDENRODYELLOW),
+    ("lightgray", css::LIGHTGRAY),
+    ("lightgreen", css::LIGHTGREEN),
+    ("lightgrey", css::LIGHTGREY),
+    ("lightpink", css::LIGHTPINK),
+    ("lightsalmon", css::LIGHTSALMON),
+    ("lightseagreen", css::LIGHTSEAGREEN),
+    ("lightskyblue", css::LIGHTSKYBLUE),
+    ("lightslategray", css::LIGHTSLATEGRAY),
+    ("lightslategrey", css::LIGHTSLATEGREY),
+    ("lightsteelblue", css::LIGHTSTEELBLUE),
+    ("lightyellow", css::LIGHTYELLOW),
+    ("lime", css::LIME),
+    ("limegreen", css::LIMEGREEN),
+    ("linen", css::LINEN),
+    ("magenta", css::MAGENTA),
+    ("maroon", css::MAROON),
+    ("mediumaquamarine", css::MEDIUMAQUAMARINE),
+    ("mediumblue", css::MEDIUMBLUE),
+    ("mediumorchid", css::MEDIUMORCHID),
+    ("mediumpurple", css::MEDIUMPURPLE),
+    ("mediumseagreen", css::MEDIUMSEAGREEN),
+    ("mediumslateblue", css::MEDIUMSLATEBLUE),
+    ("mediumspringgreen", css::MEDIUMSPRINGGREEN),
+    ("mediumturquoise", css::MEDIUMTURQUOISE),
+    ("mediumvioletred", css::MEDIUMVIOLETRED),
+    ("midnightblue", css::MIDNIGHTBLUE),
+    ("mintcream", css::MINTCREAM),
+    ("mistyrose", css::MISTYROSE),
+    ("moccasin", css::MOCCASIN),
+    ("navajowhite", css::NAVAJOWHITE),
+    ("navy", css::NAVY),
+    ("oldlace", css::OLDLACE),
+    ("olive", css::OLIVE),
+    ("olivedrab", css::OLIVEDRAB),
+    ("orange", css::ORANGE),
+    ("orangered", css::ORANGERED),
+    ("orchid", css::ORCHID),
+    ("palegoldenrod", css::PALEGOLDENROD),
+    ("palegreen", css::PALEGREEN),
+    ("paleturquoise", css::PALETURQUOISE),
+    ("palevioletred", css::PALEVIOLETRED),
+    ("papayawhip", css::PAPAYAWHIP),
+    ("peachpuff", css::PEACHPUFF),
+    ("peru", css::PERU),
+    ("pink", css::PINK),
+    ("plum", css::PLUM),
+    ("powderblue", css::POWDERBLUE),
+    ("purple", css::PURPLE),
+    ("rebeccapurple", css::REBECCAPURPLE),
+    ("red", css::RED),
+    ("rosybrown", css::ROSYBROWN),
+    ("royalblue", css::ROYALBLUE),
+    ("saddlebrown", css::SADDLEBROWN),
+    ("salmon", css::SALMON),
+    ("sandybrown", css::SANDYBROWN),
+    ("seagreen", css::SEAGREEN),
+    ("seashell", css::SEASHELL),
+    ("sienna", css::SIENNA),
+    ("silver", css::SILVER),
+    ("skyblue", css::SKYBLUE),
+    ("slateblue", css::SLATEBLUE),
+    ("slategray", css::SLATEGRAY),
+    ("slategrey", css::SLATEGREY),
+    ("snow", css::SNOW),
+    ("springgreen", css::SPRINGGREEN),
+    ("steelblue", css::STEELBLUE),
+    ("tan", css::TAN),
+    ("teal", css::TEAL),
+    ("thistle", css::THISTLE),
+    ("tomato", css::TOMATO),
+    ("turquoise", css::TURQUOISE),
+    ("violet", css::VIOLET),
+    ("wheat", css::WHEAT),
+    ("white", css::WHITE),
+    ("whitesmoke", css::WHITESMOKE),
+    ("yellow", css::YELLOW),
+    ("yellowgreen", css::YELLOWGREEN),
+];
+
+impl WebColor {
+    /// Every web color, in alphabetical order.
+    pub const ALL: [Self; 148] = [
+        Self::AliceBlue,
+        Self::AntiqueWhite,
+        Self::Aqua,
+        Self::Aquamarine,
+        Self::Azure,
+        Self::Beige,
+        Self::Bisque,
+        Self::Black,
+        Self::BlanchedAlmond,
+        Self::Blue,
+        Self::BlueViolet,
+        Self::Brown,
+        Self::BurlyWood,
+        Self::CadetBlue,
+        Self::Chartreuse,
+        Self::Chocolate,
+        Self::Coral,
+        Self::CornflowerBlue,
+        Self::Cornsilk,
+        Self::Crimson,
+        Self::Cyan,
+        Self::DarkBlue,
+        Self::DarkCyan,
+        Self::DarkGoldenrod,
+        Self::DarkGray,
+        Self::DarkGreen,
+        Self::DarkGrey,
+        Self::DarkKhaki,
+        Self::DarkMagenta,
+        Self::DarkOliveGreen,
+        Self::DarkOrange,
+        Self::DarkOrchid,
+        Self::DarkRed,
+        Self::DarkSalmon,
+        Self::DarkSeaGreen,
+        Self::DarkSlateBlue,
+        Self::DarkSlateGray,
+        Self::DarkSlateGrey,
+        Self::DarkTurquoise,
+        Self::DarkViolet,
+        Self::DeepPink,
+        Self::DeepSkyBlue,
+        Self::DimGray,
+        Self::DimGrey,
+        Self::DodgerBlue,
+        Self::FireBrick,
+        Self::FloralWhite,
+        Self::ForestGreen,
+        Self::Fuchsia,
+        Self::Gainsboro,
+        Self::GhostWhite,
+        Self::Gold,
+        Self::Goldenrod,
+        Self::Gray,
+        Self::Green,
+        Self::GreenYellow,
+        Self::Grey,
+        Self::Honeydew,
+        Self::HotPink,
+        Self::IndianRed,
+        Self::Indigo,
+        Self::Ivory,
+        Self::Khaki,
+        Self::Lavender,
+        Self::LavenderBlush,
+        Self::LawnGreen,
+        Self::LemonChiffon,
+        Self::LightBlue,
+        Self::LightCoral,
+        Self::LightCyan,
+        Self::LightGoldenrodYellow,
+        Self::LightGray,
+        Self::LightGreen,
+        Self::LightGrey,
+        Self::LightPink,
+        Self::LightSalmon,
+        Self::LightSeaGreen,
+        Self::LightSkyBlue,
+        Self::LightSlateGray,
+        Self::LightSlateGrey,
+        Self::LightSteelBlue,
+        Self::LightYellow,
+        Self::Lime,
+        Self::LimeGreen,
+        Self::Linen,
+        Self::Magenta,
+        Self::Maroon,
+        Self::MediumAquamarine,
+        Self::MediumBlue,
+        Self::MediumOrchid,
+        Self::MediumPurple,
+        Self::MediumSeaGreen,
+        Self::MediumSlateBlue,
+        Self::MediumSpringGreen,
+        Self::MediumTurquoise,
+        Self::MediumVioletRed,
+        Self::MidnightBlue,
+        Self::MintCream,
+        Self::MistyRose,
+        Self::Moccasin,
+        Self::NavajoWhite,
+        Self::Navy,
+        Self::OldLace,
+        Self::Olive,
+        Self::OliveDrab,
+        Self::Orange,
+        Self::OrangeRed,
+        Self::Orchid,
+        Self::PaleGoldenrod,
+        Self::PaleGreen,
+        Self::PaleTurquoise,
+        Self::PaleVioletRed,
+        Self::PapayaWhip,
+        Self::PeachPuff,
+        Self::Peru,
+        Self::Pink,
+        Self::Plum,
+        Self::PowderBlue,
+        Self::Purple,
+        Self::RebeccaPurple,
+        Self::Red,
+        Self::RosyBrown,
+        Self::RoyalBlue,
+        Self::SaddleBrown,
+        Self::Salmon,
+        Self::SandyBrown,
+        Self::SeaGreen,
+        Self::Seashell,
+        Self::Sienna,
+        Self::Silver,
+        Self::SkyBlue,
+        Self::SlateBlue,
+        Self::SlateGray,
+        Self::SlateGrey,
+        Self::Snow,
+        Self::SpringGreen,
+        Self::SteelBlue,
+        Self::Tan,
+        Self::Teal,
+        Self::Thistle,
+        Self::Tomato,
+        Self::Turquoise,
+        Self::Violet,
+        Self::Wheat,
+        Self::White,
+        Self::WhiteSmoke,
+        Self::Yellow,
+        Self::YellowGreen,
+    ];
+
+    /// Gets the color.
+    #[inline]
+    pub const fn color(self) -> Color {
+        WEB_COLORS[self as usize].1
+    }
+
+    /// Gets the CSS name of the color, which is in lowercase.
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        WEB_COLORS[self as usize].0
+    }
+}
+
+impl From<WebColor> for Color {
+    #[inline]
+    fn from(web_color: WebColor) -> Self {
+        web_color.color()
+    }
+}
+
+impl Display for WebColor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for WebColor {
+    type Err = WebColorError;
+
+    /// Parses the CSS name of a color, ignoring ASCII case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|web_color| web_color.name().eq_ignore_ascii_case(s))
+            .ok_or(WebColorError::UnknownName)
+    }
+}
+
+/// An error returned when a [`WebColor`] cannot be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebColorError {
+    /// The name is not one of the named colors of CSS.
+    UnknownName,
+}
+
+impl Display for WebColorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownName => write!(f, "unknown web color name"),
+        }
+    }
+}
+
+impl core::error::Error for WebColorError {}
+
+impl Color {
+    /// Gets the web color that is perceptually closest to this color. Unlike
+    /// [`get_web_color()`](Color#method.get_web_color), this finds a color for every color, not
+    /// only the exact web colors. When aliases have the same color, the first one in alphabetical
+    /// order is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use octarine::{Color, WebColor};
+    ///
+    /// assert_eq!(Color::new(250, 5, 5).closest_web_color_enum(), WebColor::Red);
+    /// assert_eq!(Color::new(128, 128, 128).closest_web_color_enum(), WebColor::Gray);
+    /// ```
+    pub fn closest_web_color_enum(&self) -> WebColor {
+        let lab = self.to_lab();
+        WebColor::ALL
+            .into_iter()
+            .map(|web_color| (web_color, ciede2000(web_color.color().to_lab(), lab)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(web_color, _)| web_color)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn names() {
+        for web_color in WebColor::ALL {
+            assert_eq!(
+                Color::from_web_color(web_color.name()),
+                Some(web_color.color())
+            );
+            assert_eq!(web_color.to_string().parse(), Ok(web_color));
+            assert_eq!(
+                web_color.color().closest_web_color_enum().color(),
+                web_color.color()
+            );
+        }
+        assert!(WebColor::ALL
+            .windows(2)
+            .all(|pair| pair[0].name() < pair[1].name()));
+        assert_eq!("GhostWhite".parse(), Ok(WebColor::GhostWhite));
+        assert_eq!(
+            "navyblue".parse::<WebColor>(),
+            Err(WebColorError::UnknownName)
+        );
+        assert_eq!("".parse::<WebColor>(), Err(WebColorError::UnknownName));
+        assert_eq!(Color::from(WebColor::Teal), Color::new(0, 128, 128));
+    }
+
+    #[test]
+    fn table_order() {
+        assert_eq!(WebColor::ALL.len(), WEB_COLORS.len());
+        for (i, web_color) in WebColor::ALL.into_iter().enumerate() {
+            assert_eq!(web_color as usize, i);
+            assert_eq!(format!("{web_color:?}").to_lowercase(), web_color.name());
+        }
+    }
+}